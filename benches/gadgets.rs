@@ -0,0 +1,132 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use criterion::{
+    black_box, criterion_group, criterion_main, BenchmarkId, Criterion,
+};
+use dusk_jubjub::GENERATOR_EXTENDED;
+use dusk_plonk::prelude::*;
+
+/// Declare a circuit repeating a gadget `N` times over the witnesses `a` and
+/// `b` and the point `p`
+macro_rules! gadget {
+    ($name:ident, |$composer:ident, $a:ident, $b:ident, $p:ident| $gadget:expr) => {
+        #[derive(Debug, Default, Clone, Copy)]
+        struct $name<const N: usize>;
+
+        impl<const N: usize> Circuit for $name<N> {
+            fn circuit<C>(&self, $composer: &mut C) -> Result<(), Error>
+            where
+                C: Composer,
+            {
+                let $a = $composer.append_witness(BlsScalar::from(0xbeef));
+                let $b = $composer.append_witness(BlsScalar::from(0xcafe));
+                let $p = $composer.append_point(
+                    GENERATOR_EXTENDED * JubJubScalar::from(0xf00d_u64),
+                );
+
+                for _ in 0..N {
+                    $gadget;
+                }
+
+                Ok(())
+            }
+        }
+    };
+}
+
+gadget!(Arithmetic, |composer, a, b, _p| {
+    composer.gate_mul(Constraint::new().mult(1).a(a).b(b))
+});
+gadget!(Range, |composer, a, _b, _p| {
+    composer.component_range::<32>(a)
+});
+gadget!(Xor, |composer, a, b, _p| {
+    composer.append_logic_xor::<32>(a, b)
+});
+gadget!(Decomposition, |composer, a, _b, _p| {
+    composer.component_decomposition::<254>(a)
+});
+gadget!(AddPoint, |composer, _a, _b, p| {
+    composer.component_add_point(p, p)
+});
+gadget!(MulGenerator, |composer, a, _b, _p| {
+    composer.component_mul_generator(a, GENERATOR_EXTENDED)?
+});
+gadget!(MulPoint, |composer, a, _b, p| {
+    composer.component_mul_point(a, p)
+});
+
+/// Bench the synthesis and the proof of the circuit `C`
+fn run<C>(c: &mut Criterion, pp: &PublicParameters, name: &str, reps: usize)
+where
+    C: Circuit,
+{
+    let (prover, verifier) = Compiler::compile::<C>(pp, name.as_bytes())
+        .expect("failed to compile circuit");
+    let circuit = C::default();
+
+    // sanity run
+    let (proof, public_inputs) = prover
+        .prove(&mut rand_core::OsRng, &circuit)
+        .expect("failed to prove");
+    verifier
+        .verify(&proof, &public_inputs)
+        .expect("failed to verify proof");
+
+    let mut group = c.benchmark_group(name);
+    let reps = format!("{reps} x {} gates", prover.constraints());
+
+    group.bench_with_input(
+        BenchmarkId::new("synthesis", &reps),
+        &circuit,
+        |b, circuit| {
+            b.iter(|| {
+                let mut builder = Builder::initialized();
+                black_box(circuit.circuit(&mut builder))
+            })
+        },
+    );
+
+    group.bench_with_input(
+        BenchmarkId::new("prove", &reps),
+        &circuit,
+        |b, circuit| {
+            b.iter(|| black_box(prover.prove(&mut rand_core::OsRng, circuit)))
+        },
+    );
+
+    group.finish();
+}
+
+fn gadgets_benchmark(c: &mut Criterion) {
+    const MAX_DEGREE: usize = 16;
+
+    let pp = PublicParameters::setup(1 << MAX_DEGREE, &mut rand_core::OsRng)
+        .expect("failed to generate pp");
+
+    run::<Arithmetic<1>>(c, &pp, "arithmetic", 1);
+    run::<Arithmetic<256>>(c, &pp, "arithmetic", 256);
+    run::<Range<1>>(c, &pp, "range 64 bits", 1);
+    run::<Range<64>>(c, &pp, "range 64 bits", 64);
+    run::<Xor<1>>(c, &pp, "xor 64 bits", 1);
+    run::<Xor<64>>(c, &pp, "xor 64 bits", 64);
+    run::<Decomposition<1>>(c, &pp, "decomposition 254 bits", 1);
+    run::<Decomposition<16>>(c, &pp, "decomposition 254 bits", 16);
+    run::<AddPoint<1>>(c, &pp, "add point", 1);
+    run::<AddPoint<256>>(c, &pp, "add point", 256);
+    run::<MulGenerator<1>>(c, &pp, "mul generator", 1);
+    run::<MulGenerator<16>>(c, &pp, "mul generator", 16);
+    run::<MulPoint<1>>(c, &pp, "mul point", 1);
+    run::<MulPoint<16>>(c, &pp, "mul point", 16);
+}
+
+criterion_group! {
+    name = gadgets;
+    config = Criterion::default().sample_size(10);
+    targets = gadgets_benchmark
+}
+criterion_main!(gadgets);
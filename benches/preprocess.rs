@@ -0,0 +1,82 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use criterion::{
+    black_box, criterion_group, criterion_main, BenchmarkId, Criterion,
+};
+use dusk_plonk::prelude::*;
+
+/// Circuit of `DEGREE` arithmetic gates
+#[derive(Debug, Default, Clone, Copy)]
+struct GatesCircuit<const DEGREE: usize>;
+
+impl<const DEGREE: usize> Circuit for GatesCircuit<DEGREE> {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer,
+    {
+        let mut a = composer.append_witness(BlsScalar::one());
+
+        while composer.constraints() < DEGREE {
+            let constraint = Constraint::new().left(1).right(1).a(a).b(a);
+            a = composer.gate_add(constraint);
+        }
+
+        Ok(())
+    }
+}
+
+fn compile<const DEGREE: usize>(c: &mut Criterion, pp: &PublicParameters) {
+    let power = (DEGREE as f64).log2() as usize;
+    let mut group = c.benchmark_group("preprocess");
+
+    group.bench_with_input(
+        BenchmarkId::new("compile", format!("2^{power}")),
+        &DEGREE,
+        |b, _| {
+            b.iter(|| {
+                Compiler::compile::<GatesCircuit<DEGREE>>(
+                    black_box(pp),
+                    b"preprocess",
+                )
+            })
+        },
+    );
+
+    group.bench_with_input(
+        BenchmarkId::new("compile mixed radix", format!("2^{power}")),
+        &DEGREE,
+        |b, _| {
+            b.iter(|| {
+                Compiler::compile_mixed_radix::<GatesCircuit<DEGREE>>(
+                    black_box(pp),
+                    b"preprocess",
+                )
+            })
+        },
+    );
+
+    group.finish();
+}
+
+fn preprocess_benchmark(c: &mut Criterion) {
+    const MAX_DEGREE: usize = 16;
+
+    let pp = PublicParameters::setup(1 << MAX_DEGREE, &mut rand_core::OsRng)
+        .expect("failed to generate pp");
+
+    compile::<{ 1 << 8 }>(c, &pp);
+    compile::<{ 1 << 10 }>(c, &pp);
+    compile::<{ 1 << 12 }>(c, &pp);
+    compile::<{ 1 << 14 }>(c, &pp);
+}
+
+criterion_group! {
+    name = preprocess;
+    config = Criterion::default().sample_size(10);
+    targets = preprocess_benchmark
+}
+criterion_main!(preprocess);
@@ -0,0 +1,22 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![no_main]
+
+use dusk_plonk::fuzzing::prove_and_verify;
+use libfuzzer_sys::fuzz_target;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+fuzz_target!(|data: &[u8]| {
+    // the randomness is derived from the input to reproduce the crashes
+    let seed = data.iter().fold(0u64, |seed, byte| {
+        seed.rotate_left(8) ^ *byte as u64
+    });
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    prove_and_verify(data, &mut rng);
+});
@@ -0,0 +1,145 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ *
+ * Copyright (c) DUSK NETWORK. All rights reserved. */
+
+#ifndef DUSK_PLONK_H
+#define DUSK_PLONK_H
+
+/* Generated by cbindgen with `make header`, do not edit. */
+
+#include <stdint.h>
+#include <stddef.h>
+
+// Size of the bytes of a proof
+#define PLONK_PROOF_SIZE 1040
+
+// Size of the bytes of a public input
+#define PLONK_SCALAR_SIZE 32
+
+
+
+// Status returned by the functions of the C bindings
+typedef enum PlonkStatus {
+  // The call succeeded
+  PLONK_STATUS_OK = 0,
+  // A required pointer argument is null
+  PLONK_STATUS_NULL_POINTER = 1,
+  // The bytes of a prover or verifier key are malformed
+  PLONK_STATUS_INVALID_KEY = 2,
+  // The zkInterface stream of a circuit is malformed
+  PLONK_STATUS_INVALID_CIRCUIT = 3,
+  // The bytes of a proof are malformed
+  PLONK_STATUS_INVALID_PROOF = 4,
+  // The bytes of the public inputs are malformed
+  PLONK_STATUS_INVALID_PUBLIC_INPUTS = 5,
+  // The circuit is unsatisfied, or isn't the circuit of the prover key
+  // and its public inputs
+  PLONK_STATUS_PROOF_FAILED = 6,
+  // The proof doesn't verify against the verifier key
+  PLONK_STATUS_VERIFICATION_FAILED = 7,
+  // The output buffer of the public inputs is too small
+  PLONK_STATUS_BUFFER_TOO_SMALL = 8,
+  // The call panicked, only returned when the library is built with
+  // unwinding
+  PLONK_STATUS_PANIC = 9,
+} PlonkStatus;
+
+// Prover key of a circuit
+typedef struct PlonkProver PlonkProver;
+
+// Verifier key of a circuit
+typedef struct PlonkVerifier PlonkVerifier;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Static description of a status, as a nul-terminated string.
+const char *plonk_status_message(enum PlonkStatus status);
+
+// Load a prover key from the bytes of [`Prover::to_bytes`].
+//
+// On success, `*prover` is set to a key to release with
+// [`plonk_prover_free`].
+//
+// # Safety
+//
+// `bytes` must point to `len` readable bytes, and `prover` must be a valid
+// pointer.
+enum PlonkStatus plonk_prover_load(const uint8_t *bytes, size_t len, struct PlonkProver **prover);
+
+// Release a prover key loaded by [`plonk_prover_load`].
+//
+// # Safety
+//
+// `prover` must be null, or a key of [`plonk_prover_load`] not yet
+// released.
+void plonk_prover_free(struct PlonkProver *prover);
+
+// Load a verifier key from the bytes of [`Verifier::to_bytes`].
+//
+// On success, `*verifier` is set to a key to release with
+// [`plonk_verifier_free`].
+//
+// # Safety
+//
+// `bytes` must point to `len` readable bytes, and `verifier` must be a valid
+// pointer.
+enum PlonkStatus plonk_verifier_load(const uint8_t *bytes,
+                                     size_t len,
+                                     struct PlonkVerifier **verifier);
+
+// Release a verifier key loaded by [`plonk_verifier_load`].
+//
+// # Safety
+//
+// `verifier` must be null, or a key of [`plonk_verifier_load`] not yet
+// released.
+void plonk_verifier_free(struct PlonkVerifier *verifier);
+
+// Prove the circuit of the zkInterface stream `circuit`, with randomness of
+// the operating system.
+//
+// The [`PLONK_PROOF_SIZE`] bytes of the proof are written to `proof`, and
+// the [`PLONK_SCALAR_SIZE`] bytes of every public input to `public_inputs`.
+// `*public_inputs_len` holds the capacity of `public_inputs` on input, and
+// the size of the public inputs on output, also when the capacity is too
+// small.
+//
+// # Safety
+//
+// `prover` must be a key of [`plonk_prover_load`], `circuit` must point to
+// `circuit_len` readable bytes, `proof` to [`PLONK_PROOF_SIZE`] writable
+// bytes, and `public_inputs` to `*public_inputs_len` writable bytes.
+enum PlonkStatus plonk_prove(const struct PlonkProver *prover,
+                             const uint8_t *circuit,
+                             size_t circuit_len,
+                             uint8_t *proof,
+                             uint8_t *public_inputs,
+                             size_t *public_inputs_len);
+
+// Verify a proof against a verifier key.
+//
+// The `proof` is given either by its [`PLONK_PROOF_SIZE`] bytes or by
+// [`Proof::to_versioned_bytes`], and the `public_inputs` by the
+// concatenation of the [`PLONK_SCALAR_SIZE`] bytes of every public input,
+// as written by [`plonk_prove`].
+//
+// # Safety
+//
+// `verifier` must be a key of [`plonk_verifier_load`], `proof` must point to
+// `proof_len` readable bytes, and `public_inputs` to `public_inputs_len`
+// readable bytes.
+enum PlonkStatus plonk_verify(const struct PlonkVerifier *verifier,
+                              const uint8_t *proof,
+                              size_t proof_len,
+                              const uint8_t *public_inputs,
+                              size_t public_inputs_len);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* DUSK_PLONK_H */
@@ -0,0 +1,394 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Backend of the constraint opcodes of ACIR, the intermediate
+//! representation of [Noir](https://noir-lang.org) programs.
+//!
+//! An [`AcirCircuit`] is made of [`Opcode`]s over the witnesses of the
+//! program, indexed as in ACIR. The assertions of expressions are compiled
+//! into arithmetic gates, and the `RANGE`, `AND` and `XOR` black box functions
+//! into the range and logic gadgets of the composer. The values of the
+//! witnesses are the ones solved by the ACVM when executing the program.
+//!
+//! ```
+//! use dusk_plonk::acir::{AcirCircuit, Expression, Opcode};
+//! use dusk_plonk::prelude::*;
+//! use rand_core::OsRng;
+//!
+//! # fn main() -> Result<(), Error> {
+//! // assert(x * y == z), with z public and x < 2^8
+//! let opcodes = vec![
+//!     Opcode::AssertZero(Expression {
+//!         mul_terms: vec![(BlsScalar::one(), 1, 2)],
+//!         linear_combinations: vec![(-BlsScalar::one(), 3)],
+//!         q_c: BlsScalar::zero(),
+//!     }),
+//!     Opcode::Range { input: 1, bits: 8 },
+//! ];
+//! let circuit = AcirCircuit::new(4, vec![3], opcodes)?;
+//!
+//! let pp = PublicParameters::setup(1 << 6, &mut OsRng)?;
+//! let (prover, verifier) =
+//!     Compiler::compile_with_circuit(&pp, b"acir", &circuit)?;
+//!
+//! let witness = [0, 3, 5, 15].map(BlsScalar::from).to_vec();
+//! let circuit = circuit.with_witness(witness)?;
+//! let (proof, public_inputs) = prover.prove(&mut OsRng, &circuit)?;
+//! verifier.verify(&proof, &public_inputs)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use dusk_bls12_381::BlsScalar;
+
+use crate::bit_iterator::BitIterator8;
+use crate::composer::{Circuit, Composer};
+use crate::constraint_system::{Constraint, Witness};
+use crate::error::Error;
+
+/// Maximum number of bits of the range and logic opcodes
+const MAX_BITS: u32 = 254;
+
+/// Polynomial expression of degree 2 over the witnesses of a program
+///
+/// `Σ q_m · w_l · w_r + Σ q · w + q_c`
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Expression {
+    /// Products of two witnesses, with their coefficient
+    pub mul_terms: Vec<(BlsScalar, u32, u32)>,
+    /// Witnesses, with their coefficient
+    pub linear_combinations: Vec<(BlsScalar, u32)>,
+    /// Constant term
+    pub q_c: BlsScalar,
+}
+
+/// Constraint opcode of ACIR
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Opcode {
+    /// Assert that the expression evaluates to zero
+    AssertZero(Expression),
+    /// `RANGE` black box function: assert that the `input` is lower than
+    /// `2^bits`
+    Range {
+        /// Witness to constrain
+        input: u32,
+        /// Number of bits of the witness
+        bits: u32,
+    },
+    /// `AND` black box function: assert that `output = lhs & rhs` over
+    /// `bits` bits
+    And {
+        /// Left operand
+        lhs: u32,
+        /// Right operand
+        rhs: u32,
+        /// Result of the operation
+        output: u32,
+        /// Number of bits of the operands
+        bits: u32,
+    },
+    /// `XOR` black box function: assert that `output = lhs ^ rhs` over
+    /// `bits` bits
+    Xor {
+        /// Left operand
+        lhs: u32,
+        /// Right operand
+        rhs: u32,
+        /// Result of the operation
+        output: u32,
+        /// Number of bits of the operands
+        bits: u32,
+    },
+}
+
+impl Opcode {
+    /// Witnesses referenced by the opcode
+    fn witnesses(&self) -> Vec<u32> {
+        match self {
+            Self::AssertZero(expression) => expression
+                .mul_terms
+                .iter()
+                .flat_map(|(_, l, r)| [*l, *r])
+                .chain(expression.linear_combinations.iter().map(|(_, w)| *w))
+                .collect(),
+            Self::Range { input, .. } => vec![*input],
+            Self::And {
+                lhs, rhs, output, ..
+            }
+            | Self::Xor {
+                lhs, rhs, output, ..
+            } => vec![*lhs, *rhs, *output],
+        }
+    }
+
+    fn bits(&self) -> u32 {
+        match self {
+            Self::AssertZero(_) => 0,
+            Self::Range { bits, .. }
+            | Self::And { bits, .. }
+            | Self::Xor { bits, .. } => *bits,
+        }
+    }
+}
+
+/// ACIR program, with the values of its witnesses
+///
+/// The default circuit is empty. Compile a circuit with no witness through
+/// [`Compiler::compile_with_circuit`](crate::prelude::Compiler), and prove
+/// it once its witness is set with [`AcirCircuit::with_witness`].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct AcirCircuit {
+    public_inputs: Vec<u32>,
+    opcodes: Vec<Opcode>,
+    witness: Vec<BlsScalar>,
+}
+
+impl AcirCircuit {
+    /// Circuit of the `opcodes` over `witnesses` witnesses, with every
+    /// witness set to zero.
+    ///
+    /// The `public_inputs` are the indexes of the public parameters and
+    /// return values of the program, and are public inputs of the proof in
+    /// increasing order. An opcode referencing a missing witness, or over more
+    /// than 254 bits, is rejected with [`Error::InvalidAcirOpcode`].
+    pub fn new(
+        witnesses: u32,
+        mut public_inputs: Vec<u32>,
+        opcodes: Vec<Opcode>,
+    ) -> Result<Self, Error> {
+        if let Some(opcode) = opcodes.iter().position(|opcode| {
+            opcode.bits() > MAX_BITS
+                || opcode.witnesses().iter().any(|w| *w >= witnesses)
+        }) {
+            return Err(Error::InvalidAcirOpcode { opcode });
+        }
+        if let Some(w) = public_inputs.iter().filter(|w| **w >= witnesses).max()
+        {
+            return Err(Error::CircuitInputsNotFound {
+                expected: witnesses as usize,
+                provided: *w as usize + 1,
+            });
+        }
+
+        public_inputs.sort_unstable();
+        public_inputs.dedup();
+
+        Ok(Self {
+            public_inputs,
+            opcodes,
+            witness: vec![BlsScalar::zero(); witnesses as usize],
+        })
+    }
+
+    /// Set the values of the witnesses of the circuit, by index
+    pub fn with_witness(self, witness: Vec<BlsScalar>) -> Result<Self, Error> {
+        match witness.len() == self.witness.len() {
+            true => Ok(Self { witness, ..self }),
+            false => Err(Error::CircuitInputsNotFound {
+                expected: self.witness.len(),
+                provided: witness.len(),
+            }),
+        }
+    }
+
+    /// Public inputs of the circuit, in the order of the proof
+    pub fn public_inputs(&self) -> Vec<BlsScalar> {
+        self.public_inputs
+            .iter()
+            .map(|w| self.witness[*w as usize])
+            .collect()
+    }
+}
+
+/// Constrain `constant + Σ q_m · l · r + Σ coeff · witness = 0`, with at most
+/// one product
+fn assert_zero<C>(
+    composer: &mut C,
+    product: Option<(BlsScalar, Witness, Witness)>,
+    mut linear: Vec<(BlsScalar, Witness)>,
+    mut constant: BlsScalar,
+) where
+    C: Composer,
+{
+    // the wires left by the product for the linear terms, the extra terms
+    // being accumulated into one of them
+    let wires = match product {
+        Some(_) => 2,
+        None => 4,
+    };
+    if linear.len() > wires {
+        let tail = linear.split_off(linear.len() - (wires - 1));
+        let acc = composer.component_linear_combination(&linear, constant);
+
+        linear = vec![(BlsScalar::one(), acc)];
+        linear.extend(tail);
+        constant = BlsScalar::zero();
+    }
+
+    let mut constraint = Constraint::new().constant(constant);
+    if let Some((q_m, l, r)) = product {
+        constraint = constraint.mult(q_m).a(l).b(r);
+    }
+    for (i, (coeff, witness)) in linear.iter().enumerate() {
+        constraint = match i + 4 - wires {
+            0 => constraint.left(*coeff).a(*witness),
+            1 => constraint.right(*coeff).b(*witness),
+            2 => constraint.output(*coeff).o(*witness),
+            _ => constraint.fourth(*coeff).d(*witness),
+        };
+    }
+
+    composer.append_gate(constraint);
+}
+
+/// Constrain `witness < 2^bits`, with the range gadget when the bits are a
+/// power of two multiple of `2`, and with a decomposition into boolean
+/// witnesses otherwise
+fn assert_range<C>(composer: &mut C, witness: Witness, bits: u32)
+where
+    C: Composer,
+{
+    match bits {
+        2 => composer.component_range::<1>(witness),
+        4 => composer.component_range::<2>(witness),
+        8 => composer.component_range::<4>(witness),
+        16 => composer.component_range::<8>(witness),
+        32 => composer.component_range::<16>(witness),
+        64 => composer.component_range::<32>(witness),
+        128 => composer.component_range::<64>(witness),
+        _ => {
+            let value = composer[witness].to_bytes();
+            let mut linear: Vec<_> = BitIterator8::new(value)
+                .skip(256 - bits as usize)
+                .collect::<Vec<_>>()
+                .into_iter()
+                .rev()
+                .scan(BlsScalar::one(), |power, bit| {
+                    let bit =
+                        composer.append_witness(BlsScalar::from(bit as u64));
+                    composer.component_boolean(bit);
+
+                    let term = (*power, bit);
+                    *power = power.double();
+                    Some(term)
+                })
+                .collect();
+            linear.push((-BlsScalar::one(), witness));
+
+            assert_zero(composer, None, linear, BlsScalar::zero());
+        }
+    }
+}
+
+/// `lhs & rhs` or `lhs ^ rhs` over the smallest supported number of bits
+/// covering `bits`
+fn logic<C>(
+    composer: &mut C,
+    lhs: Witness,
+    rhs: Witness,
+    bits: u32,
+    xor: bool,
+) -> Witness
+where
+    C: Composer,
+{
+    macro_rules! logic {
+        ($pairs:literal) => {
+            match xor {
+                true => composer.append_logic_xor::<$pairs>(lhs, rhs),
+                false => composer.append_logic_and::<$pairs>(lhs, rhs),
+            }
+        };
+    }
+
+    match bits {
+        0..=2 => logic!(1),
+        3..=4 => logic!(2),
+        5..=8 => logic!(4),
+        9..=16 => logic!(8),
+        17..=32 => logic!(16),
+        33..=64 => logic!(32),
+        65..=128 => logic!(64),
+        _ => logic!(128),
+    }
+}
+
+impl Circuit for AcirCircuit {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer,
+    {
+        let witnesses: Vec<Witness> = self
+            .witness
+            .iter()
+            .enumerate()
+            .map(|(index, value)| {
+                match self.public_inputs.binary_search(&(index as u32)) {
+                    Ok(_) => composer.append_public(*value),
+                    Err(_) => composer.append_witness(*value),
+                }
+            })
+            .collect();
+        let w = |index: &u32| witnesses[*index as usize];
+
+        for opcode in &self.opcodes {
+            match opcode {
+                Opcode::AssertZero(expression) => {
+                    let mut linear: Vec<_> = expression
+                        .linear_combinations
+                        .iter()
+                        .map(|(coeff, witness)| (*coeff, w(witness)))
+                        .collect();
+
+                    // the products past the first one are evaluated into
+                    // witnesses first
+                    let (first, rest) = match expression.mul_terms.split_first()
+                    {
+                        Some((first, rest)) => (Some(first), rest),
+                        None => (None, &[][..]),
+                    };
+                    for (q_m, l, r) in rest {
+                        let constraint =
+                            Constraint::new().mult(*q_m).a(w(l)).b(w(r));
+                        linear.push((
+                            BlsScalar::one(),
+                            composer.gate_mul(constraint),
+                        ));
+                    }
+
+                    let product = first.map(|(q_m, l, r)| (*q_m, w(l), w(r)));
+                    assert_zero(composer, product, linear, expression.q_c);
+                }
+                Opcode::Range { input, bits } => {
+                    assert_range(composer, w(input), *bits)
+                }
+                Opcode::And {
+                    lhs,
+                    rhs,
+                    output,
+                    bits,
+                } => {
+                    let result = logic(composer, w(lhs), w(rhs), *bits, false);
+                    composer.assert_equal(result, w(output));
+                }
+                Opcode::Xor {
+                    lhs,
+                    rhs,
+                    output,
+                    bits,
+                } => {
+                    let result = logic(composer, w(lhs), w(rhs), *bits, true);
+                    composer.assert_equal(result, w(output));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,328 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! C bindings of the prover and verifier.
+//!
+//! The functions of this module have a stable C ABI, declared by the
+//! `include/dusk_plonk.h` header generated with `make header`. Link the
+//! static or dynamic library built by `make capi`.
+//!
+//! The functions catch the panics of the prover and the verifier, and return
+//! [`PlonkStatus::Panic`] instead of unwinding into C. Panics can only be
+//! caught when the library is built with unwinding, as `make capi` does with
+//! the `capi` profile: under the `release` profile of the crate, which sets
+//! `panic = "abort"`, a panic aborts the process.
+//!
+//! The prover and verifier keys are the bytes of [`Prover::to_bytes`] and
+//! [`Verifier::to_bytes`], for circuits compiled from a zkInterface stream
+//! with [`Compiler::compile_with_circuit`](crate::prelude::Compiler). The
+//! circuits are proven from the zkInterface stream of their constraint system
+//! and witness, as read by [`ZkifCircuit::from_bytes`].
+//!
+//! ```c
+//! #include "dusk_plonk.h"
+//!
+//! PlonkProver *prover = NULL;
+//! PlonkStatus status = plonk_prover_load(key, key_len, &prover);
+//!
+//! uint8_t proof[PLONK_PROOF_SIZE];
+//! uint8_t public_inputs[4 * PLONK_SCALAR_SIZE];
+//! uintptr_t public_inputs_len = sizeof(public_inputs);
+//! status = plonk_prove(prover, circuit, circuit_len, proof, public_inputs,
+//!                      &public_inputs_len);
+//!
+//! plonk_prover_free(prover);
+//! ```
+
+use std::os::raw::c_char;
+use std::panic::{self, AssertUnwindSafe};
+use std::slice;
+
+use dusk_bls12_381::BlsScalar;
+use dusk_bytes::{DeserializableSlice, Serializable};
+use rand_core::OsRng;
+
+use crate::composer::{Prover, Verifier};
+use crate::proof_system::Proof;
+use crate::zkinterface::ZkifCircuit;
+
+/// Size of the bytes of a proof
+pub const PLONK_PROOF_SIZE: usize = 1040;
+
+/// Size of the bytes of a public input
+pub const PLONK_SCALAR_SIZE: usize = 32;
+
+const _: () = assert!(PLONK_PROOF_SIZE == Proof::SIZE);
+const _: () = assert!(PLONK_SCALAR_SIZE == BlsScalar::SIZE);
+
+/// Status returned by the functions of the C bindings
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlonkStatus {
+    /// The call succeeded
+    Ok = 0,
+    /// A required pointer argument is null
+    NullPointer = 1,
+    /// The bytes of a prover or verifier key are malformed
+    InvalidKey = 2,
+    /// The zkInterface stream of a circuit is malformed
+    InvalidCircuit = 3,
+    /// The bytes of a proof are malformed
+    InvalidProof = 4,
+    /// The bytes of the public inputs are malformed
+    InvalidPublicInputs = 5,
+    /// The circuit is unsatisfied, or isn't the circuit of the prover key
+    /// and its public inputs
+    ProofFailed = 6,
+    /// The proof doesn't verify against the verifier key
+    VerificationFailed = 7,
+    /// The output buffer of the public inputs is too small
+    BufferTooSmall = 8,
+    /// The call panicked, only returned when the library is built with
+    /// unwinding
+    Panic = 9,
+}
+
+/// Prover key of a circuit
+pub struct PlonkProver(Prover);
+
+/// Verifier key of a circuit
+pub struct PlonkVerifier(Verifier);
+
+/// Run `f`, catching its panics
+fn guard<F>(f: F) -> PlonkStatus
+where
+    F: FnOnce() -> Result<(), PlonkStatus>,
+{
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(())) => PlonkStatus::Ok,
+        Ok(Err(status)) => status,
+        Err(_) => PlonkStatus::Panic,
+    }
+}
+
+/// Slice of the `len` bytes at `ptr`, that may be null when `len` is zero
+unsafe fn bytes<'a>(
+    ptr: *const u8,
+    len: usize,
+) -> Result<&'a [u8], PlonkStatus> {
+    match (ptr.is_null(), len) {
+        (_, 0) => Ok(&[]),
+        (true, _) => Err(PlonkStatus::NullPointer),
+        (false, _) => Ok(slice::from_raw_parts(ptr, len)),
+    }
+}
+
+/// Static description of a status, as a nul-terminated string.
+#[no_mangle]
+pub extern "C" fn plonk_status_message(status: PlonkStatus) -> *const c_char {
+    let message: &'static [u8] = match status {
+        PlonkStatus::Ok => b"success\0",
+        PlonkStatus::NullPointer => b"null pointer argument\0",
+        PlonkStatus::InvalidKey => b"invalid key bytes\0",
+        PlonkStatus::InvalidCircuit => b"invalid zkInterface circuit\0",
+        PlonkStatus::InvalidProof => b"invalid proof bytes\0",
+        PlonkStatus::InvalidPublicInputs => b"invalid public input bytes\0",
+        PlonkStatus::ProofFailed => b"proof creation failed\0",
+        PlonkStatus::VerificationFailed => b"proof verification failed\0",
+        PlonkStatus::BufferTooSmall => b"output buffer too small\0",
+        PlonkStatus::Panic => b"internal panic\0",
+    };
+
+    message.as_ptr() as *const c_char
+}
+
+/// Load a prover key from the bytes of [`Prover::to_bytes`].
+///
+/// On success, `*prover` is set to a key to release with
+/// [`plonk_prover_free`].
+///
+/// # Safety
+///
+/// `bytes` must point to `len` readable bytes, and `prover` must be a valid
+/// pointer.
+#[no_mangle]
+pub unsafe extern "C" fn plonk_prover_load(
+    bytes: *const u8,
+    len: usize,
+    prover: *mut *mut PlonkProver,
+) -> PlonkStatus {
+    guard(|| {
+        let bytes = self::bytes(bytes, len)?;
+        let prover = prover.as_mut().ok_or(PlonkStatus::NullPointer)?;
+
+        let key = Prover::try_from_bytes(bytes)
+            .map_err(|_| PlonkStatus::InvalidKey)?;
+        *prover = Box::into_raw(Box::new(PlonkProver(key)));
+
+        Ok(())
+    })
+}
+
+/// Release a prover key loaded by [`plonk_prover_load`].
+///
+/// # Safety
+///
+/// `prover` must be null, or a key of [`plonk_prover_load`] not yet
+/// released.
+#[no_mangle]
+pub unsafe extern "C" fn plonk_prover_free(prover: *mut PlonkProver) {
+    if !prover.is_null() {
+        drop(Box::from_raw(prover));
+    }
+}
+
+/// Load a verifier key from the bytes of [`Verifier::to_bytes`].
+///
+/// On success, `*verifier` is set to a key to release with
+/// [`plonk_verifier_free`].
+///
+/// # Safety
+///
+/// `bytes` must point to `len` readable bytes, and `verifier` must be a valid
+/// pointer.
+#[no_mangle]
+pub unsafe extern "C" fn plonk_verifier_load(
+    bytes: *const u8,
+    len: usize,
+    verifier: *mut *mut PlonkVerifier,
+) -> PlonkStatus {
+    guard(|| {
+        let bytes = self::bytes(bytes, len)?;
+        let verifier = verifier.as_mut().ok_or(PlonkStatus::NullPointer)?;
+
+        let key = Verifier::try_from_bytes(bytes)
+            .map_err(|_| PlonkStatus::InvalidKey)?;
+        *verifier = Box::into_raw(Box::new(PlonkVerifier(key)));
+
+        Ok(())
+    })
+}
+
+/// Release a verifier key loaded by [`plonk_verifier_load`].
+///
+/// # Safety
+///
+/// `verifier` must be null, or a key of [`plonk_verifier_load`] not yet
+/// released.
+#[no_mangle]
+pub unsafe extern "C" fn plonk_verifier_free(verifier: *mut PlonkVerifier) {
+    if !verifier.is_null() {
+        drop(Box::from_raw(verifier));
+    }
+}
+
+/// Prove the circuit of the zkInterface stream `circuit`, with randomness of
+/// the operating system.
+///
+/// The [`PLONK_PROOF_SIZE`] bytes of the proof are written to `proof`, and
+/// the [`PLONK_SCALAR_SIZE`] bytes of every public input to `public_inputs`.
+/// `*public_inputs_len` holds the capacity of `public_inputs` on input, and
+/// the size of the public inputs on output, also when the capacity is too
+/// small.
+///
+/// # Safety
+///
+/// `prover` must be a key of [`plonk_prover_load`], `circuit` must point to
+/// `circuit_len` readable bytes, `proof` to [`PLONK_PROOF_SIZE`] writable
+/// bytes, and `public_inputs` to `*public_inputs_len` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn plonk_prove(
+    prover: *const PlonkProver,
+    circuit: *const u8,
+    circuit_len: usize,
+    proof: *mut u8,
+    public_inputs: *mut u8,
+    public_inputs_len: *mut usize,
+) -> PlonkStatus {
+    guard(|| {
+        let prover = prover.as_ref().ok_or(PlonkStatus::NullPointer)?;
+        let circuit = bytes(circuit, circuit_len)?;
+        let capacity =
+            public_inputs_len.as_mut().ok_or(PlonkStatus::NullPointer)?;
+
+        let circuit = ZkifCircuit::from_bytes(circuit)
+            .map_err(|_| PlonkStatus::InvalidCircuit)?;
+
+        // the size of the public inputs is known before proving
+        let len = circuit.public_inputs().len() * PLONK_SCALAR_SIZE;
+        let available = core::mem::replace(capacity, len);
+        if available < len {
+            return Err(PlonkStatus::BufferTooSmall);
+        }
+        if proof.is_null() || (len > 0 && public_inputs.is_null()) {
+            return Err(PlonkStatus::NullPointer);
+        }
+
+        let (p, pi) = prover
+            .0
+            .prove(&mut OsRng, &circuit)
+            .map_err(|_| PlonkStatus::ProofFailed)?;
+
+        // the stream declares other public inputs than the prover key
+        if pi.len() * PLONK_SCALAR_SIZE != len {
+            return Err(PlonkStatus::ProofFailed);
+        }
+
+        slice::from_raw_parts_mut(proof, PLONK_PROOF_SIZE)
+            .copy_from_slice(&p.to_bytes());
+        if len > 0 {
+            slice::from_raw_parts_mut(public_inputs, len)
+                .chunks_exact_mut(PLONK_SCALAR_SIZE)
+                .zip(pi.iter())
+                .for_each(|(bytes, pi)| bytes.copy_from_slice(&pi.to_bytes()));
+        }
+
+        Ok(())
+    })
+}
+
+/// Verify a proof against a verifier key.
+///
+/// The `proof` is given either by its [`PLONK_PROOF_SIZE`] bytes or by
+/// [`Proof::to_versioned_bytes`], and the `public_inputs` by the
+/// concatenation of the [`PLONK_SCALAR_SIZE`] bytes of every public input,
+/// as written by [`plonk_prove`].
+///
+/// # Safety
+///
+/// `verifier` must be a key of [`plonk_verifier_load`], `proof` must point to
+/// `proof_len` readable bytes, and `public_inputs` to `public_inputs_len`
+/// readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn plonk_verify(
+    verifier: *const PlonkVerifier,
+    proof: *const u8,
+    proof_len: usize,
+    public_inputs: *const u8,
+    public_inputs_len: usize,
+) -> PlonkStatus {
+    guard(|| {
+        let verifier = verifier.as_ref().ok_or(PlonkStatus::NullPointer)?;
+        let proof = bytes(proof, proof_len)?;
+        let public_inputs = bytes(public_inputs, public_inputs_len)?;
+
+        let proof = match proof.len() {
+            Proof::SIZE => Proof::from_slice(proof).ok(),
+            _ => Proof::from_versioned_bytes(proof).ok(),
+        }
+        .ok_or(PlonkStatus::InvalidProof)?;
+
+        if public_inputs.len() % PLONK_SCALAR_SIZE != 0 {
+            return Err(PlonkStatus::InvalidPublicInputs);
+        }
+        let public_inputs = public_inputs
+            .chunks(PLONK_SCALAR_SIZE)
+            .map(BlsScalar::from_slice)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| PlonkStatus::InvalidPublicInputs)?;
+
+        verifier
+            .0
+            .verify(&proof, &public_inputs)
+            .map_err(|_| PlonkStatus::VerificationFailed)
+    })
+}
@@ -0,0 +1,301 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Import of circuits compiled by [circom](https://docs.circom.io).
+//!
+//! The `.r1cs` constraint system and the `.wtns` witness are read in their
+//! binary formats, and every R1CS row `A · B = C` is compiled into width-4
+//! gates. The circuit must be compiled over the scalar field of BLS12-381,
+//! with `circom --r1cs --prime bls12381`.
+//!
+//! ```no_run
+//! use dusk_plonk::circom::{self, CircomCircuit, R1cs};
+//! use dusk_plonk::prelude::*;
+//! use rand_core::OsRng;
+//!
+//! # fn main() -> Result<(), Error> {
+//! let r1cs = R1cs::from_bytes(&std::fs::read("circuit.r1cs")?)?;
+//! let witness = circom::read_witness(&std::fs::read("witness.wtns")?)?;
+//!
+//! let pp = PublicParameters::setup(1 << 12, &mut OsRng)?;
+//! let circuit = CircomCircuit::new(r1cs);
+//! let (prover, verifier) =
+//!     Compiler::compile_with_circuit(&pp, b"circom", &circuit)?;
+//!
+//! let circuit = circuit.with_witness(witness)?;
+//! let (proof, public_inputs) = prover.prove(&mut OsRng, &circuit)?;
+//! verifier.verify(&proof, &public_inputs)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use dusk_bls12_381::BlsScalar;
+use dusk_bytes::Serializable;
+
+use crate::composer::{Circuit, Composer};
+use crate::constraint_system::Witness;
+use crate::error::Error;
+use crate::r1cs::{self, LinearCombination};
+
+/// Little-endian bytes of the order of the scalar field of BLS12-381
+const MODULUS: [u8; 32] = [
+    0x01, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xfe, 0x5b, 0xfe, 0xff,
+    0x02, 0xa4, 0xbd, 0x53, 0x05, 0xd8, 0xa1, 0x09, 0x08, 0xd8, 0x39, 0x33,
+    0x48, 0x7d, 0x9d, 0x29, 0x53, 0xa7, 0xed, 0x73,
+];
+
+/// Sections of the `.r1cs` format
+const R1CS_HEADER: u32 = 1;
+const R1CS_CONSTRAINTS: u32 = 2;
+
+/// Sections of the `.wtns` format
+const WTNS_HEADER: u32 = 1;
+const WTNS_WITNESS: u32 = 2;
+
+/// Reader of the little-endian fields of a circom binary file
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        if self.0.len() < len {
+            return Err(Error::InvalidCircomFile);
+        }
+
+        let (bytes, rest) = self.0.split_at(len);
+        self.0 = rest;
+
+        Ok(bytes)
+    }
+
+    fn u32(&mut self) -> Result<u32, Error> {
+        let mut bytes = [0u8; 4];
+        bytes.copy_from_slice(self.bytes(4)?);
+
+        Ok(u32::from_le_bytes(bytes))
+    }
+
+    fn u64(&mut self) -> Result<u64, Error> {
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(self.bytes(8)?);
+
+        Ok(u64::from_le_bytes(bytes))
+    }
+
+    fn len(&mut self) -> Result<usize, Error> {
+        usize::try_from(self.u32()?).map_err(|_| Error::InvalidCircomFile)
+    }
+
+    fn scalar(&mut self) -> Result<BlsScalar, Error> {
+        let mut bytes = [0u8; BlsScalar::SIZE];
+        bytes.copy_from_slice(self.bytes(BlsScalar::SIZE)?);
+
+        Option::from(BlsScalar::from_bytes(&bytes))
+            .ok_or(Error::InvalidCircomFile)
+    }
+
+    /// Check the field of the file, as the size of its elements followed by
+    /// its order
+    fn field(&mut self) -> Result<(), Error> {
+        let size = self.len()?;
+        match size == MODULUS.len() && self.bytes(size)? == MODULUS {
+            true => Ok(()),
+            false => Err(Error::UnsupportedCircomPrime),
+        }
+    }
+
+    /// Check the magic bytes and version of the file, and return its sections
+    /// by type
+    fn sections(
+        mut self,
+        magic: &[u8; 4],
+        versions: &[u32],
+    ) -> Result<Vec<(u32, &'a [u8])>, Error> {
+        if self.bytes(4)? != magic || !versions.contains(&self.u32()?) {
+            return Err(Error::InvalidCircomFile);
+        }
+
+        (0..self.u32()?)
+            .map(|_| {
+                let kind = self.u32()?;
+                let len = usize::try_from(self.u64()?)
+                    .map_err(|_| Error::InvalidCircomFile)?;
+
+                Ok((kind, self.bytes(len)?))
+            })
+            .collect()
+    }
+}
+
+/// Content of the section `kind`, that must appear once
+fn section<'a>(
+    sections: &[(u32, &'a [u8])],
+    kind: u32,
+) -> Result<Reader<'a>, Error> {
+    let mut found = sections.iter().filter(|(k, _)| *k == kind);
+
+    match (found.next(), found.next()) {
+        (Some((_, bytes)), None) => Ok(Reader(bytes)),
+        _ => Err(Error::InvalidCircomFile),
+    }
+}
+
+/// Constraint system of a circom circuit, read from a `.r1cs` file
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct R1cs {
+    wires: usize,
+    public_inputs: usize,
+    constraints: Vec<[LinearCombination; 3]>,
+}
+
+impl R1cs {
+    /// Read the constraint system from the bytes of a `.r1cs` file
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let sections = Reader(bytes).sections(b"r1cs", &[1])?;
+
+        let mut header = section(&sections, R1CS_HEADER)?;
+        header.field()?;
+        let wires = header.len()?;
+        let public_outputs = header.len()?;
+        let public_inputs = header.len()?;
+        let _private_inputs = header.len()?;
+        let _labels = header.u64()?;
+        let len = header.len()?;
+
+        // the wire `0` is the constant `1`, followed by the public outputs
+        // and inputs
+        let public_inputs = public_outputs + public_inputs;
+        if public_inputs >= wires {
+            return Err(Error::InvalidCircomFile);
+        }
+
+        let mut reader = section(&sections, R1CS_CONSTRAINTS)?;
+        let mut combination = || {
+            (0..reader.len()?)
+                .map(|_| {
+                    let wire = reader.len()?;
+                    let coeff = reader.scalar()?;
+
+                    match wire < wires {
+                        true => Ok((wire, coeff)),
+                        false => Err(Error::InvalidCircomFile),
+                    }
+                })
+                .collect::<Result<LinearCombination, Error>>()
+        };
+
+        let constraints = (0..len)
+            .map(|_| Ok([combination()?, combination()?, combination()?]))
+            .collect::<Result<_, Error>>()?;
+
+        Ok(Self {
+            wires,
+            public_inputs,
+            constraints,
+        })
+    }
+
+    /// Number of wires of the circuit, including the constant `1`
+    pub fn wires(&self) -> usize {
+        self.wires
+    }
+
+    /// Number of public outputs and inputs of the circuit
+    pub fn public_inputs(&self) -> usize {
+        self.public_inputs
+    }
+
+    /// Number of R1CS constraints of the circuit
+    pub fn constraints(&self) -> usize {
+        self.constraints.len()
+    }
+}
+
+/// Read the values of the wires from the bytes of a `.wtns` file
+pub fn read_witness(bytes: &[u8]) -> Result<Vec<BlsScalar>, Error> {
+    let sections = Reader(bytes).sections(b"wtns", &[1, 2])?;
+
+    let mut header = section(&sections, WTNS_HEADER)?;
+    header.field()?;
+    let len = header.len()?;
+
+    let mut reader = section(&sections, WTNS_WITNESS)?;
+    (0..len).map(|_| reader.scalar()).collect()
+}
+
+/// Circom circuit, with the values of its wires
+///
+/// The default circuit is empty. Compile a circuit with no witness through
+/// [`Compiler::compile_with_circuit`](crate::prelude::Compiler), and prove
+/// it once its witness is set with [`CircomCircuit::with_witness`].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CircomCircuit {
+    r1cs: R1cs,
+    witness: Vec<BlsScalar>,
+}
+
+impl CircomCircuit {
+    /// Circuit of the constraint system `r1cs`, with every wire set to zero
+    pub fn new(r1cs: R1cs) -> Self {
+        let witness = vec![BlsScalar::zero(); r1cs.wires];
+
+        Self { r1cs, witness }
+    }
+
+    /// Set the values of the wires of the circuit, as read by
+    /// [`read_witness`]
+    pub fn with_witness(self, witness: Vec<BlsScalar>) -> Result<Self, Error> {
+        match witness.len() == self.r1cs.wires {
+            true => Ok(Self { witness, ..self }),
+            false => Err(Error::CircuitInputsNotFound {
+                expected: self.r1cs.wires,
+                provided: witness.len(),
+            }),
+        }
+    }
+
+    /// Public outputs and inputs of the circuit, in the order of the proof
+    pub fn public_inputs(&self) -> &[BlsScalar] {
+        self.witness
+            .get(1..=self.r1cs.public_inputs)
+            .unwrap_or_default()
+    }
+}
+
+impl Circuit for CircomCircuit {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer,
+    {
+        if self.witness.len() != self.r1cs.wires {
+            return Err(Error::CircuitInputsNotFound {
+                expected: self.r1cs.wires,
+                provided: self.witness.len(),
+            });
+        }
+
+        let wires: Vec<Witness> = self
+            .witness
+            .iter()
+            .enumerate()
+            .map(|(wire, value)| match wire {
+                0 => C::ONE,
+                _ if wire <= self.r1cs.public_inputs => {
+                    composer.append_public(*value)
+                }
+                _ => composer.append_witness(*value),
+            })
+            .collect();
+
+        self.r1cs.constraints.iter().for_each(|constraint| {
+            r1cs::append_constraint(composer, constraint, &wires)
+        });
+
+        Ok(())
+    }
+}
@@ -13,19 +13,87 @@
 //! FRI are not homomorphic and therefore for PLONK to be usable with all
 //! commitment schemes without modification, one would need to remove the
 //! linearizer
+//!
+//! # KZG10
+//!
+//! The KZG10 operations used by the proof system are exposed so auxiliary
+//! protocols can commit to and open polynomials over the same
+//! [`PublicParameters`] as the circuits. Polynomials are given by their
+//! coefficients, in increasing degree order.
+//!
+//! ```
+//! use dusk_plonk::commitment_scheme::PublicParameters;
+//! use dusk_plonk::prelude::BlsScalar;
+//! use merlin::Transcript;
+//! use rand::rngs::StdRng;
+//! use rand::SeedableRng;
+//!
+//! let mut rng = StdRng::seed_from_u64(0xbeef);
+//! let pp = PublicParameters::setup(1 << 4, &mut rng)?;
+//! let (commit_key, opening_key) = pp.trim(1 << 4)?;
+//!
+//! // p(x) = 3x² + 2x + 1 and q(x) = x³ + 5
+//! let p = [BlsScalar::from(1), BlsScalar::from(2), BlsScalar::from(3)];
+//! let q = [BlsScalar::from(5), BlsScalar::zero(), BlsScalar::zero(), BlsScalar::from(1)];
+//! let point = BlsScalar::from(7);
+//!
+//! // single opening
+//! let commitment = commit_key.commit(&p)?;
+//! let proof = commit_key.open(&p, &point)?;
+//! assert_eq!(proof.commitment(), commitment);
+//! assert_eq!(proof.evaluation(), BlsScalar::from(162));
+//! opening_key.verify(&point, &proof)?;
+//!
+//! // several polynomials opened at the same point
+//! let mut transcript = Transcript::new(b"auxiliary protocol");
+//! let proof = commit_key.batch_open(&[&p[..], &q], &point, &mut transcript)?;
+//!
+//! let mut transcript = Transcript::new(b"auxiliary protocol");
+//! opening_key.verify_aggregate(&point, &proof, &mut transcript)?;
+//! # Ok::<(), dusk_plonk::prelude::Error>(())
+//! ```
+//!
+//! # Backends
+//!
+//! The [`PolynomialCommitment`] trait abstracts the commitment scheme of
+//! auxiliary protocols. Besides [`Kzg`], the `ipa` feature enables the
+//! transparent `Ipa` backend, based on the inner product argument, for users
+//! who can't rely on a trusted setup ceremony. The proof system itself
+//! remains backed by KZG10.
 
 mod kzg10;
 
-pub(crate) use kzg10::Commitment;
+#[cfg(feature = "alloc")]
+mod backend;
+#[cfg(feature = "ipa")]
+mod ipa;
+#[cfg(feature = "alloc")]
+pub(crate) mod msm;
 
 #[cfg(feature = "alloc")]
-pub(crate) use kzg10::AggregateProof;
+pub use backend::{Kzg, PolynomialCommitment};
+#[cfg(feature = "ipa")]
+pub use ipa::{Ipa, IpaParameters, IpaProof};
+
+pub use kzg10::{Commitment, OpeningProof};
+
+#[cfg(feature = "heapless")]
+pub use kzg10::OpeningKey;
+
+#[cfg(feature = "heapless")]
+pub(crate) use kzg10::flatten;
+
+#[cfg(feature = "verifier")]
+pub use kzg10::AggregateProof;
 
 #[cfg(feature = "alloc")]
-pub(crate) use kzg10::{CommitKey, OpeningKey};
+pub use kzg10::{CommitKey, LagrangeKey};
 
 #[cfg(feature = "alloc")]
-pub use kzg10::PublicParameters;
+pub use kzg10::{Contribution, PowersOfTau, PublicParameters, TestParameters};
+
+#[cfg(all(feature = "mmap", unix))]
+pub use kzg10::MappedPublicParameters;
 
 #[cfg(all(feature = "alloc", feature = "rkyv-impl"))]
 pub use kzg10::{
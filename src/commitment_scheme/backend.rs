@@ -0,0 +1,102 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Common interface of the polynomial commitment backends.
+
+use dusk_bls12_381::BlsScalar;
+
+use super::{CommitKey, Commitment, OpeningKey, OpeningProof};
+use crate::error::Error;
+use crate::transcript::TranscriptProtocol;
+
+/// Polynomial commitment scheme committing to polynomials over [`BlsScalar`]
+/// and proving their evaluations.
+///
+/// Polynomials are given by their coefficients, in increasing degree order.
+/// The transcripts passed to [`Self::open`] and [`Self::verify`] must have the
+/// same view for the verification to pass.
+pub trait PolynomialCommitment {
+    /// Key used to commit to and open polynomials
+    type CommitKey;
+    /// Key used to verify openings
+    type OpeningKey;
+    /// Proof of the evaluation of a committed polynomial
+    type Proof;
+
+    /// Commit to the polynomial with the given `coefficients`.
+    fn commit(
+        key: &Self::CommitKey,
+        coefficients: &[BlsScalar],
+    ) -> Result<Commitment, Error>;
+
+    /// Prove the evaluation at `point` of the polynomial with the given
+    /// `coefficients`.
+    fn open<T: TranscriptProtocol>(
+        key: &Self::CommitKey,
+        coefficients: &[BlsScalar],
+        point: &BlsScalar,
+        transcript: &mut T,
+    ) -> Result<Self::Proof, Error>;
+
+    /// Commitment of the polynomial a `proof` is about.
+    fn commitment(proof: &Self::Proof) -> Commitment;
+
+    /// Evaluation claimed by a `proof`.
+    fn evaluation(proof: &Self::Proof) -> BlsScalar;
+
+    /// Verify an opening `proof` at `point`.
+    fn verify<T: TranscriptProtocol>(
+        key: &Self::OpeningKey,
+        point: &BlsScalar,
+        proof: &Self::Proof,
+        transcript: &mut T,
+    ) -> Result<(), Error>;
+}
+
+/// KZG10 backend, requiring the [`PublicParameters`](super::PublicParameters)
+/// of a trusted setup and producing constant size proofs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Kzg;
+
+impl PolynomialCommitment for Kzg {
+    type CommitKey = CommitKey;
+    type OpeningKey = OpeningKey;
+    type Proof = OpeningProof;
+
+    fn commit(
+        key: &CommitKey,
+        coefficients: &[BlsScalar],
+    ) -> Result<Commitment, Error> {
+        key.commit(coefficients)
+    }
+
+    // The KZG10 openings don't depend on the transcript
+    fn open<T: TranscriptProtocol>(
+        key: &CommitKey,
+        coefficients: &[BlsScalar],
+        point: &BlsScalar,
+        _transcript: &mut T,
+    ) -> Result<OpeningProof, Error> {
+        key.open(coefficients, point)
+    }
+
+    fn commitment(proof: &OpeningProof) -> Commitment {
+        proof.commitment()
+    }
+
+    fn evaluation(proof: &OpeningProof) -> BlsScalar {
+        proof.evaluation()
+    }
+
+    fn verify<T: TranscriptProtocol>(
+        key: &OpeningKey,
+        point: &BlsScalar,
+        proof: &OpeningProof,
+        _transcript: &mut T,
+    ) -> Result<(), Error> {
+        key.verify(point, proof)
+    }
+}
@@ -0,0 +1,69 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Implementation of a transparent polynomial commitment scheme based on the
+//! inner product argument of Bulletproofs.
+//!
+//! The generators are derived by hashing to the curve, hence no trusted setup
+//! is required. The opening proofs hold two points for every halving of the
+//! polynomial, and the verification is linear in the degree of the
+//! polynomial.
+
+mod params;
+mod proof;
+
+pub use params::IpaParameters;
+pub use proof::IpaProof;
+
+use dusk_bls12_381::BlsScalar;
+
+use super::{Commitment, PolynomialCommitment};
+use crate::error::Error;
+use crate::transcript::TranscriptProtocol;
+
+/// Inner product argument backend, requiring no trusted setup and producing
+/// logarithmic size proofs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ipa;
+
+impl PolynomialCommitment for Ipa {
+    type CommitKey = IpaParameters;
+    type OpeningKey = IpaParameters;
+    type Proof = IpaProof;
+
+    fn commit(
+        key: &IpaParameters,
+        coefficients: &[BlsScalar],
+    ) -> Result<Commitment, Error> {
+        key.commit(coefficients)
+    }
+
+    fn open<T: TranscriptProtocol>(
+        key: &IpaParameters,
+        coefficients: &[BlsScalar],
+        point: &BlsScalar,
+        transcript: &mut T,
+    ) -> Result<IpaProof, Error> {
+        key.open(coefficients, point, transcript)
+    }
+
+    fn commitment(proof: &IpaProof) -> Commitment {
+        proof.commitment()
+    }
+
+    fn evaluation(proof: &IpaProof) -> BlsScalar {
+        proof.evaluation()
+    }
+
+    fn verify<T: TranscriptProtocol>(
+        key: &IpaParameters,
+        point: &BlsScalar,
+        proof: &IpaProof,
+        transcript: &mut T,
+    ) -> Result<(), Error> {
+        key.verify(point, proof, transcript)
+    }
+}
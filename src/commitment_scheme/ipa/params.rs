@@ -0,0 +1,364 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use alloc::vec::Vec;
+
+use dusk_bls12_381::{
+    multiscalar_mul::msm_variable_base, BlsScalar, G1Affine, G1Projective,
+};
+use dusk_bytes::{DeserializableSlice, Serializable};
+use sha2::{Digest, Sha512};
+
+use super::IpaProof;
+use crate::commitment_scheme::msm::msm;
+use crate::commitment_scheme::Commitment;
+use crate::error::Error;
+use crate::transcript::{TranscriptExt, TranscriptProtocol};
+use crate::util;
+
+/// Domain separator of the hash deriving the generators.
+const GENERATORS_DOMAIN: &[u8] = b"dusk-plonk-ipa-generators";
+
+/// Generators of the inner product argument commitments.
+///
+/// The generators are derived deterministically from their index, hence
+/// anyone can recompute them and nobody knows their discrete logarithms.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IpaParameters {
+    /// Generators the coefficients are committed with.
+    g: Vec<G1Affine>,
+    /// Generator the inner product is committed with.
+    u: G1Affine,
+}
+
+impl IpaParameters {
+    /// Derive the generators committing to polynomials up to and including
+    /// `max_degree`.
+    ///
+    /// The number of generators is rounded up to the next power of two.
+    pub fn new(max_degree: usize) -> Result<Self, Error> {
+        if max_degree == 0 {
+            return Err(Error::DegreeIsZero);
+        }
+
+        let n = (max_degree + 1).next_power_of_two();
+        let g = (0..n as u64).map(generator).collect();
+        let u = generator(u64::MAX);
+
+        Ok(Self { g, u })
+    }
+
+    /// Returns the maximum degree polynomial that you can commit to.
+    pub fn max_degree(&self) -> usize {
+        self.g.len() - 1
+    }
+
+    /// Serializes the [`IpaParameters`] into a byte vector, the generators of
+    /// the coefficients followed by the one of the inner product.
+    pub fn to_var_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity((self.g.len() + 1) * G1Affine::SIZE);
+
+        self.g
+            .iter()
+            .chain(core::iter::once(&self.u))
+            .for_each(|g| bytes.extend_from_slice(&g.to_bytes()));
+
+        bytes
+    }
+
+    /// Deserializes [`IpaParameters`] from the bytes created by
+    /// [`Self::to_var_bytes`], checking each of the generators.
+    ///
+    /// The generators aren't compared to the derived ones, so parameters of
+    /// an untrusted source should be derived with [`Self::new`] instead.
+    pub fn from_slice(bytes: &[u8]) -> Result<Self, Error> {
+        let mut g = bytes
+            .chunks(G1Affine::SIZE)
+            .map(G1Affine::from_slice)
+            .collect::<Result<Vec<G1Affine>, dusk_bytes::Error>>()?;
+
+        let u = g.pop().ok_or(Error::DegreeIsZero)?;
+        if g.len() < 2 || !g.len().is_power_of_two() {
+            return Err(dusk_bytes::Error::BadLength {
+                found: bytes.len(),
+                expected: (g.len().max(2).next_power_of_two() + 1)
+                    * G1Affine::SIZE,
+            }
+            .into());
+        }
+
+        Ok(Self { g, u })
+    }
+
+    /// Commits to the polynomial with the given `coefficients`, returning the
+    /// corresponding [`Commitment`].
+    ///
+    /// Returns an error if the polynomial's degree is more than the max degree
+    /// of the parameters.
+    pub fn commit(
+        &self,
+        coefficients: &[BlsScalar],
+    ) -> Result<Commitment, Error> {
+        let len = self.check_len(coefficients)?;
+
+        if len == 0 {
+            return Ok(Commitment::default());
+        }
+
+        Ok(Commitment::from(msm(&self.g[..len], &coefficients[..len])))
+    }
+
+    /// Creates an [`IpaProof`] of the evaluation at `point` of the polynomial
+    /// with the given `coefficients`.
+    ///
+    /// The commitment, the point and the evaluation are appended to the
+    /// `transcript`, which must then have the same view as the one passed to
+    /// [`Self::verify`].
+    pub fn open<T: TranscriptProtocol>(
+        &self,
+        coefficients: &[BlsScalar],
+        point: &BlsScalar,
+        transcript: &mut T,
+    ) -> Result<IpaProof, Error> {
+        let commitment = self.commit(coefficients)?;
+
+        // the coefficients are padded to a power of two
+        let len = self.check_len(coefficients)?;
+        let n = len.max(1).next_power_of_two();
+
+        let mut a = coefficients[..len].to_vec();
+        a.resize(n, BlsScalar::zero());
+        let mut b = util::powers_of(point, n - 1);
+        let mut g: Vec<G1Affine> = self.g[..n].to_vec();
+
+        let evaluation = inner_product(&a, &b);
+
+        let w = challenge(transcript, &commitment, point, &evaluation);
+        let u = self.u * w;
+
+        let rounds = n.trailing_zeros() as usize;
+        let mut l = Vec::with_capacity(rounds);
+        let mut r = Vec::with_capacity(rounds);
+
+        while a.len() > 1 {
+            let half = a.len() / 2;
+            let (a_lo, a_hi) = a.split_at(half);
+            let (b_lo, b_hi) = b.split_at(half);
+            let (g_lo, g_hi) = g.split_at(half);
+
+            let l_j =
+                msm_variable_base(g_hi, a_lo) + u * inner_product(a_lo, b_hi);
+            let r_j =
+                msm_variable_base(g_lo, a_hi) + u * inner_product(a_hi, b_lo);
+            let l_j = G1Affine::from(l_j);
+            let r_j = G1Affine::from(r_j);
+
+            transcript.append_point(b"ipa_l", &l_j);
+            transcript.append_point(b"ipa_r", &r_j);
+            let (x, x_inv) = round_challenge(transcript)?;
+
+            a = fold(a_lo, a_hi, &x, &x_inv);
+            b = fold(b_lo, b_hi, &x_inv, &x);
+
+            let folded: Vec<G1Projective> = g_lo
+                .iter()
+                .zip(g_hi.iter())
+                .map(|(lo, hi)| lo * x_inv + hi * x)
+                .collect();
+            g = vec![G1Affine::identity(); half];
+            G1Projective::batch_normalize(&folded, &mut g);
+
+            l.push(l_j);
+            r.push(r_j);
+        }
+
+        Ok(IpaProof {
+            commitment,
+            evaluation,
+            l,
+            r,
+            a: a[0],
+        })
+    }
+
+    /// Verifies an [`IpaProof`] of an evaluation at `point`.
+    ///
+    /// The `transcript` must have the same view as the one passed to
+    /// [`Self::open`].
+    pub fn verify<T: TranscriptProtocol>(
+        &self,
+        point: &BlsScalar,
+        proof: &IpaProof,
+        transcript: &mut T,
+    ) -> Result<(), Error> {
+        let rounds = proof.l.len();
+        if rounds != proof.r.len() {
+            return Err(Error::ProofVerificationError);
+        }
+        if rounds > self.g.len().trailing_zeros() as usize {
+            return Err(Error::PolynomialDegreeTooLarge {
+                degree: (1 << rounds) - 1,
+                max_degree: self.max_degree(),
+            });
+        }
+
+        let w =
+            challenge(transcript, &proof.commitment, point, &proof.evaluation);
+
+        let mut challenges = Vec::with_capacity(rounds);
+        for (l, r) in proof.l.iter().zip(proof.r.iter()) {
+            transcript.append_point(b"ipa_l", l);
+            transcript.append_point(b"ipa_r", r);
+            challenges.push(round_challenge(transcript)?);
+        }
+
+        // The folded generator is `Σ s_i · g_i`, where `s_i` is the product of
+        // the challenges `x_j` for the bits `j` of `i` that are set and their
+        // inverse otherwise; the first round matches the most significant bit.
+        // Likewise, the folded powers of the point are
+        // `Π (x_j^-1 + x_j · z^(2^(k-1-j)))`.
+        let mut s = vec![BlsScalar::one()];
+        let mut b = BlsScalar::one();
+        let mut z = *point;
+        for (x, x_inv) in challenges.iter().rev() {
+            s = s
+                .iter()
+                .map(|s| s * x_inv)
+                .chain(s.iter().map(|s| s * x))
+                .collect();
+            b *= x_inv + x * z;
+            z = z.square();
+        }
+
+        // a · Σ s_i · g_i + (a · b - v) · w · u
+        //     = c + Σ x_j^2 · l_j + Σ x_j^-2 · r_j
+        let n = s.len();
+        let mut points = Vec::with_capacity(n + 2 + 2 * rounds);
+        let mut scalars = Vec::with_capacity(n + 2 + 2 * rounds);
+
+        points.extend_from_slice(&self.g[..n]);
+        scalars.extend(s.iter().map(|s| proof.a * s));
+
+        points.push(self.u);
+        scalars.push((proof.a * b - proof.evaluation) * w);
+
+        points.push(proof.commitment.into());
+        scalars.push(-BlsScalar::one());
+
+        challenges
+            .iter()
+            .zip(proof.l.iter().zip(proof.r.iter()))
+            .for_each(|((x, x_inv), (l, r))| {
+                points.push(*l);
+                scalars.push(-x.square());
+                points.push(*r);
+                scalars.push(-x_inv.square());
+            });
+
+        if msm_variable_base(&points, &scalars) != G1Projective::identity() {
+            return Err(Error::ProofVerificationError);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the length of the coefficients without the trailing zeros,
+    /// checking it fits the generators.
+    fn check_len(&self, coefficients: &[BlsScalar]) -> Result<usize, Error> {
+        let len = coefficients
+            .iter()
+            .rposition(|c| c != &BlsScalar::zero())
+            .map(|degree| degree + 1)
+            .unwrap_or(0);
+
+        if len > self.g.len() {
+            return Err(Error::PolynomialDegreeTooLarge {
+                degree: len - 1,
+                max_degree: self.max_degree(),
+            });
+        }
+
+        Ok(len)
+    }
+}
+
+/// Derive the generator of the given `index` by hashing to the curve.
+///
+/// The hash is interpreted as a compressed point, incrementing a counter until
+/// it is a valid one; the cofactor is then cleared to land in the prime order
+/// subgroup.
+fn generator(index: u64) -> G1Affine {
+    let mut counter = 0u32;
+
+    loop {
+        let mut hasher = Sha512::new();
+        hasher.update(GENERATORS_DOMAIN);
+        hasher.update(index.to_be_bytes());
+        hasher.update(counter.to_be_bytes());
+        let hash = hasher.finalize();
+
+        let mut bytes = [0u8; G1Affine::SIZE];
+        bytes.copy_from_slice(&hash[..G1Affine::SIZE]);
+        // compressed, not the identity, and the sign of `y` from the hash
+        bytes[0] = (bytes[0] & 0x3f) | 0x80;
+
+        let point: Option<G1Affine> =
+            G1Affine::from_compressed_unchecked(&bytes).into();
+        if let Some(point) = point {
+            let point =
+                G1Affine::from(G1Projective::from(point).clear_cofactor());
+            if point != G1Affine::identity() {
+                return point;
+            }
+        }
+
+        counter += 1;
+    }
+}
+
+/// Absorb the statement of an opening and derive the scalar binding the
+/// inner product to it.
+fn challenge<T: TranscriptProtocol>(
+    transcript: &mut T,
+    commitment: &Commitment,
+    point: &BlsScalar,
+    evaluation: &BlsScalar,
+) -> BlsScalar {
+    transcript.append_commitment(b"ipa_commitment", commitment);
+    transcript.append_scalar(b"ipa_point", point);
+    transcript.append_scalar(b"ipa_evaluation", evaluation);
+
+    transcript.challenge_scalar(b"ipa_w")
+}
+
+/// Derive the challenge of a round along with its inverse.
+fn round_challenge<T: TranscriptProtocol>(
+    transcript: &mut T,
+) -> Result<(BlsScalar, BlsScalar), Error> {
+    let x = transcript.challenge_scalar(b"ipa_x");
+    let x_inv = x.invert();
+
+    x_inv
+        .map(|x_inv| (x, x_inv))
+        .ok_or(Error::ProofVerificationError)
+}
+
+/// Fold the halves of a vector into `lo · x_lo + hi · x_hi`.
+fn fold(
+    lo: &[BlsScalar],
+    hi: &[BlsScalar],
+    x_lo: &BlsScalar,
+    x_hi: &BlsScalar,
+) -> Vec<BlsScalar> {
+    lo.iter()
+        .zip(hi.iter())
+        .map(|(lo, hi)| lo * x_lo + hi * x_hi)
+        .collect()
+}
+
+fn inner_product(a: &[BlsScalar], b: &[BlsScalar]) -> BlsScalar {
+    a.iter().zip(b.iter()).map(|(a, b)| a * b).sum()
+}
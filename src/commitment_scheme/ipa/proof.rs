@@ -0,0 +1,101 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use alloc::vec::Vec;
+
+use dusk_bls12_381::{BlsScalar, G1Affine};
+use dusk_bytes::{DeserializableSlice, Serializable};
+
+use crate::commitment_scheme::Commitment;
+
+/// Proof that a polynomial `p` committed with the inner product argument was
+/// correctly evaluated at a point `z`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IpaProof {
+    /// Commitment to the polynomial the proof is about.
+    pub(crate) commitment: Commitment,
+    /// Evaluation `p(z)` of the polynomial.
+    pub(crate) evaluation: BlsScalar,
+    /// Left cross terms, one for every round of the argument.
+    pub(crate) l: Vec<G1Affine>,
+    /// Right cross terms, one for every round of the argument.
+    pub(crate) r: Vec<G1Affine>,
+    /// The polynomial coefficients folded into a single scalar.
+    pub(crate) a: BlsScalar,
+}
+
+impl IpaProof {
+    /// Commitment to the polynomial the proof is about.
+    pub const fn commitment(&self) -> Commitment {
+        self.commitment
+    }
+
+    /// Claimed evaluation `p(z)` of the polynomial.
+    pub const fn evaluation(&self) -> BlsScalar {
+        self.evaluation
+    }
+
+    /// Number of rounds of the argument, which is the logarithm of the padded
+    /// length of the polynomial.
+    pub fn rounds(&self) -> usize {
+        self.l.len()
+    }
+
+    /// Serializes the [`IpaProof`] into a byte vector.
+    ///
+    /// The commitment, the evaluation and the folded scalar are followed by
+    /// the pairs of cross terms of every round.
+    pub fn to_var_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(
+            Commitment::SIZE
+                + BlsScalar::SIZE * 2
+                + G1Affine::SIZE * 2 * self.l.len(),
+        );
+
+        bytes.extend_from_slice(&self.commitment.to_bytes());
+        bytes.extend_from_slice(&self.evaluation.to_bytes());
+        bytes.extend_from_slice(&self.a.to_bytes());
+        self.l.iter().zip(self.r.iter()).for_each(|(l, r)| {
+            bytes.extend_from_slice(&l.to_bytes());
+            bytes.extend_from_slice(&r.to_bytes());
+        });
+
+        bytes
+    }
+
+    /// Deserializes an [`IpaProof`] from the bytes created by
+    /// [`Self::to_var_bytes`].
+    pub fn from_slice(bytes: &[u8]) -> Result<Self, dusk_bytes::Error> {
+        let mut buffer = bytes;
+
+        let commitment = Commitment::from_reader(&mut buffer)?;
+        let evaluation = BlsScalar::from_reader(&mut buffer)?;
+        let a = BlsScalar::from_reader(&mut buffer)?;
+
+        let rounds = buffer.chunks_exact(G1Affine::SIZE * 2);
+        if !rounds.remainder().is_empty() {
+            return Err(dusk_bytes::Error::BadLength {
+                found: bytes.len(),
+                expected: bytes.len() - rounds.remainder().len(),
+            });
+        }
+
+        let mut l = Vec::with_capacity(rounds.len());
+        let mut r = Vec::with_capacity(rounds.len());
+        for mut round in rounds {
+            l.push(G1Affine::from_reader(&mut round)?);
+            r.push(G1Affine::from_reader(&mut round)?);
+        }
+
+        Ok(Self {
+            commitment,
+            evaluation,
+            l,
+            r,
+            a,
+        })
+    }
+}
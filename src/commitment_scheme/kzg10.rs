@@ -7,15 +7,31 @@
 //! Implementation of the KZG10 polynomial commitment scheme.
 
 cfg_if::cfg_if!(
-if #[cfg(feature = "alloc")]
+if #[cfg(feature = "heapless")]
 {
     pub mod key;
+
+    pub use key::OpeningKey;
+    pub(crate) use proof::flatten;
+});
+
+#[cfg(feature = "verifier")]
+pub use proof::alloc::AggregateProof;
+
+cfg_if::cfg_if!(
+if #[cfg(feature = "alloc")]
+{
+    pub mod lagrange;
     pub mod srs;
 
-    pub(crate) use proof::alloc::AggregateProof;
+    pub use key::CommitKey;
+    pub use lagrange::LagrangeKey;
+    pub use srs::{
+        Contribution, PowersOfTau, PublicParameters, TestParameters,
+    };
 
-    pub use key::{CommitKey, OpeningKey};
-    pub use srs::PublicParameters;
+    #[cfg(all(feature = "mmap", unix))]
+    pub use srs::MappedPublicParameters;
 
     cfg_if::cfg_if!(
         if #[cfg(feature = "rkyv-impl")] {
@@ -28,4 +44,5 @@ if #[cfg(feature = "alloc")]
 pub(crate) mod commitment;
 pub(crate) mod proof;
 
-pub(crate) use commitment::Commitment;
+pub use commitment::Commitment;
+pub use proof::OpeningProof;
@@ -18,6 +18,7 @@ use rkyv::{
 
 /// Holds a commitment to a polynomial in a form of a [`G1Affine`]-bls12_381
 /// point.
+/// KZG10 commitment to a polynomial.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(
     feature = "rkyv-impl",
@@ -25,7 +26,7 @@ use rkyv::{
     archive(bound(serialize = "__S: Serializer + ScratchSpace")),
     archive_attr(derive(CheckBytes))
 )]
-pub(crate) struct Commitment(
+pub struct Commitment(
     /// The commitment is a group element.
     #[cfg_attr(feature = "rkyv-impl", omit_bounds)]
     pub(crate) G1Affine,
@@ -37,6 +38,12 @@ impl From<G1Affine> for Commitment {
     }
 }
 
+impl From<Commitment> for G1Affine {
+    fn from(commitment: Commitment) -> G1Affine {
+        commitment.0
+    }
+}
+
 impl From<G1Projective> for Commitment {
     fn from(point: G1Projective) -> Commitment {
         Commitment(point.into())
@@ -7,17 +7,27 @@
 //! Key module contains the utilities and data structures
 //! that support the generation and usage of Commit and
 //! Opening keys.
-use super::{proof::Proof, Commitment};
+use super::OpeningProof;
+use crate::{error::Error, transcript::TranscriptProtocol};
+use dusk_bls12_381::{BlsScalar, G1Affine, G1Projective, G2Affine};
+
+#[cfg(feature = "verifier")]
+use super::AggregateProof;
+#[cfg(feature = "verifier")]
+use crate::transcript::TranscriptExt;
+#[cfg(feature = "verifier")]
+use dusk_bls12_381::G2Prepared;
+
+#[cfg(feature = "alloc")]
 use crate::{
-    error::Error, fft::Polynomial, transcript::TranscriptProtocol, util,
+    commitment_scheme::msm::{self, MsmTable},
+    commitment_scheme::Commitment,
+    fft::Polynomial,
+    util,
 };
+#[cfg(feature = "alloc")]
 use alloc::vec::Vec;
-use dusk_bls12_381::{
-    multiscalar_mul::msm_variable_base, BlsScalar, G1Affine, G1Projective,
-    G2Affine, G2Prepared,
-};
 use dusk_bytes::{DeserializableSlice, Serializable};
-use merlin::Transcript;
 
 #[cfg(feature = "rkyv-impl")]
 use bytecheck::CheckBytes;
@@ -29,6 +39,7 @@ use rkyv::{
 
 /// CommitKey is used to commit to a polynomial which is bounded by the
 /// max_degree.
+#[cfg(feature = "alloc")]
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(
     feature = "rkyv-impl",
@@ -43,6 +54,7 @@ pub struct CommitKey {
     pub(crate) powers_of_g: Vec<G1Affine>,
 }
 
+#[cfg(feature = "alloc")]
 impl CommitKey {
     /// Serialize the [`CommitKey`] into bytes.
     ///
@@ -124,7 +136,7 @@ impl CommitKey {
     }
 
     /// Returns the maximum degree polynomial that you can commit to.
-    pub(crate) fn max_degree(&self) -> usize {
+    pub fn max_degree(&self) -> usize {
         self.powers_of_g.len() - 1
     }
 
@@ -139,7 +151,10 @@ impl CommitKey {
             // Check that the truncated degree is not zero
             0 => Err(Error::TruncatedDegreeIsZero),
             // Check that max degree is less than truncated degree
-            i if i > self.max_degree() => Err(Error::TruncatedDegreeTooLarge),
+            i if i > self.max_degree() => Err(Error::TruncatedDegreeTooLarge {
+                degree: i,
+                max_degree: self.max_degree(),
+            }),
             i => {
                 if i == 1 {
                     truncated_degree += 1
@@ -163,49 +178,162 @@ impl CommitKey {
     ) -> Result<(), Error> {
         match (poly_degree == 0, poly_degree > self.max_degree()) {
             (true, _) => Err(Error::PolynomialDegreeIsZero),
-            (false, true) => Err(Error::PolynomialDegreeTooLarge),
+            (false, true) => Err(Error::PolynomialDegreeTooLarge {
+                degree: poly_degree,
+                max_degree: self.max_degree(),
+            }),
             (false, false) => Ok(()),
         }
     }
 
-    /// Commits to a [`Polynomial`] returning the corresponding [`Commitment`].
+    /// Commits to the polynomial with the given `coefficients`, returning the
+    /// corresponding [`Commitment`].
     ///
-    /// Returns an error if the polynomial's degree is more than the max degree
-    /// of the commit key.
-    pub(crate) fn commit(
+    /// Returns an error if the polynomial's degree is zero, or more than the
+    /// max degree of the commit key.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "trace",
+            skip_all,
+            fields(len = coefficients.len())
+        )
+    )]
+    pub fn commit(
         &self,
-        polynomial: &Polynomial,
+        coefficients: &[BlsScalar],
+    ) -> Result<Commitment, Error> {
+        self.commit_with(coefficients, None)
+    }
+
+    /// Commit to the polynomial with the given `coefficients`, with the
+    /// multiples of the powers of the key precomputed in `table`, if any.
+    pub(crate) fn commit_with(
+        &self,
+        coefficients: &[BlsScalar],
+        table: Option<&MsmTable>,
     ) -> Result<Commitment, Error> {
+        let degree = coefficients
+            .iter()
+            .rposition(|c| c != &BlsScalar::zero())
+            .unwrap_or(0);
+
         // Check whether we can safely commit to this polynomial
-        self.check_commit_degree_is_within_bounds(polynomial.degree())?;
+        self.check_commit_degree_is_within_bounds(degree)?;
 
         // Compute commitment
-        Ok(Commitment::from(msm_variable_base(
-            &self.powers_of_g,
-            polynomial,
-        )))
+        let coefficients = &coefficients[..=degree];
+        let commitment = match table {
+            Some(table) if table.len() > degree => table.msm(coefficients),
+            _ => msm::msm(&self.powers_of_g, coefficients),
+        };
+
+        Ok(Commitment::from(commitment))
+    }
+
+    /// Precompute the multiples of the powers of the key used by
+    /// [`Self::commit_with`]
+    pub(crate) fn msm_table(&self) -> MsmTable {
+        MsmTable::new(&self.powers_of_g)
+    }
+
+    /// Creates an [`OpeningProof`] of the evaluation at `point` of the
+    /// polynomial with the given `coefficients`.
+    ///
+    /// Returns an error if the polynomial can't be committed to.
+    pub fn open(
+        &self,
+        coefficients: &[BlsScalar],
+        point: &BlsScalar,
+    ) -> Result<OpeningProof, Error> {
+        let polynomial =
+            Polynomial::from_coefficients_vec(coefficients.to_vec());
+
+        // The witness `(p(x) - p(z)) / (x - z)` is the quotient of `p(x) / (x
+        // - z)`, as the remainder is dropped by Ruffini's method
+        let witness = polynomial.ruffini(*point);
+
+        Ok(OpeningProof {
+            commitment_to_witness: self.commit(&witness)?,
+            evaluated_point: polynomial.evaluate(point),
+            commitment_to_polynomial: self.commit(&polynomial)?,
+        })
+    }
+
+    /// Creates an [`AggregateProof`] of the evaluations at `point` of several
+    /// polynomials, given by their coefficients.
+    ///
+    /// The point, commitments and evaluations are appended to the
+    /// `transcript`, which must then have the same view as the one passed to
+    /// [`OpeningKey::verify_aggregate`].
+    pub fn batch_open<T, P>(
+        &self,
+        polynomials: &[P],
+        point: &BlsScalar,
+        transcript: &mut T,
+    ) -> Result<AggregateProof, Error>
+    where
+        T: TranscriptProtocol,
+        P: AsRef<[BlsScalar]>,
+    {
+        if polynomials.is_empty() {
+            return Err(Error::InvalidOpeningBatch {
+                points: 1,
+                proofs: 0,
+            });
+        }
+
+        let polynomials: Vec<Polynomial> = polynomials
+            .iter()
+            .map(|p| Polynomial::from_coefficients_vec(p.as_ref().to_vec()))
+            .collect();
+
+        transcript.append_scalar(b"kzg_point", point);
+
+        let mut proof = AggregateProof::with_witness(Commitment::default());
+        for polynomial in polynomials.iter() {
+            let commitment = self.commit(polynomial)?;
+            let evaluation = polynomial.evaluate(point);
+
+            transcript.append_commitment(b"kzg_commitment", &commitment);
+            transcript.append_scalar(b"kzg_evaluation", &evaluation);
+
+            proof.add_part((evaluation, commitment));
+        }
+
+        let polynomials: Vec<&Polynomial> = polynomials.iter().collect();
+        let witness =
+            self.compute_aggregate_witness(&polynomials, point, transcript);
+        proof.commitment_to_witness = self.commit(&witness)?;
+
+        Ok(proof)
     }
 
     /// Computes a single witness for multiple polynomials at the same point, by
     /// taking a random linear combination of the individual witnesses.
     /// We apply the same optimization mentioned in when computing each witness;
     /// removing f(z).
-    pub(crate) fn compute_aggregate_witness(
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all)
+    )]
+    pub(crate) fn compute_aggregate_witness<T: TranscriptProtocol>(
         &self,
-        polynomials: &[Polynomial],
+        polynomials: &[&Polynomial],
         point: &BlsScalar,
-        transcript: &mut Transcript,
+        transcript: &mut T,
     ) -> Polynomial {
         let v_challenge = transcript.challenge_scalar(b"v_challenge");
         let powers = util::powers_of(&v_challenge, polynomials.len() - 1);
 
         assert_eq!(powers.len(), polynomials.len());
 
-        let numerator: Polynomial = polynomials
+        // accumulate in place so a single polynomial is allocated
+        let mut numerator = Polynomial::zero();
+        polynomials
             .iter()
             .zip(powers.iter())
-            .map(|(poly, v_challenge)| poly * v_challenge)
-            .sum();
+            .for_each(|(poly, v_challenge)| numerator += (*v_challenge, *poly));
         numerator.ruffini(*point)
     }
 }
@@ -231,9 +359,11 @@ pub struct OpeningKey {
     #[cfg_attr(feature = "rkyv-impl", omit_bounds)]
     pub(crate) beta_h: G2Affine,
     /// The generator of G2, prepared for use in pairings.
+    #[cfg(feature = "verifier")]
     #[cfg_attr(feature = "rkyv-impl", omit_bounds)]
     pub(crate) prepared_h: G2Prepared,
     /// \beta times the above generator of G2, prepared for use in pairings.
+    #[cfg(feature = "verifier")]
     #[cfg_attr(feature = "rkyv-impl", omit_bounds)]
     pub(crate) prepared_beta_h: G2Prepared,
 }
@@ -269,57 +399,160 @@ impl OpeningKey {
         h: G2Affine,
         beta_h: G2Affine,
     ) -> OpeningKey {
-        let prepared_h = G2Prepared::from(h);
-        let prepared_beta_h = G2Prepared::from(beta_h);
         OpeningKey {
             g,
             h,
             beta_h,
-            prepared_h,
-            prepared_beta_h,
+            #[cfg(feature = "verifier")]
+            prepared_h: G2Prepared::from(h),
+            #[cfg(feature = "verifier")]
+            prepared_beta_h: G2Prepared::from(beta_h),
+        }
+    }
+
+    /// Verifies an [`OpeningProof`] of an evaluation at `point`.
+    #[cfg(feature = "verifier")]
+    pub fn verify(
+        &self,
+        point: &BlsScalar,
+        proof: &OpeningProof,
+    ) -> Result<(), Error> {
+        // e(c - v·g, h) · e(-w, beta_h - z·h) = 1
+        let inner_a: G1Affine = (proof.commitment_to_polynomial.0
+            - (self.g * proof.evaluated_point))
+            .into();
+
+        let inner_b: G2Affine = (self.beta_h - (self.h * point)).into();
+        let prepared_inner_b = G2Prepared::from(-inner_b);
+
+        let pairing = dusk_bls12_381::multi_miller_loop(&[
+            (&inner_a, &self.prepared_h),
+            (&proof.commitment_to_witness.0, &prepared_inner_b),
+        ])
+        .final_exponentiation();
+
+        if pairing != dusk_bls12_381::Gt::identity() {
+            return Err(Error::PairingCheckFailure);
+        };
+        Ok(())
+    }
+
+    /// Verifies an [`AggregateProof`] of evaluations at `point`.
+    ///
+    /// The `transcript` must have the same view as the one passed to
+    /// [`CommitKey::batch_open`].
+    #[cfg(feature = "verifier")]
+    pub fn verify_aggregate<T: TranscriptProtocol>(
+        &self,
+        point: &BlsScalar,
+        proof: &AggregateProof,
+        transcript: &mut T,
+    ) -> Result<(), Error> {
+        let parts = proof.commitments_to_polynomials.len();
+        if parts == 0 || parts != proof.evaluated_points.len() {
+            return Err(Error::InvalidOpeningBatch {
+                points: proof.evaluated_points.len(),
+                proofs: parts,
+            });
         }
+
+        transcript.append_scalar(b"kzg_point", point);
+        proof
+            .commitments_to_polynomials
+            .iter()
+            .zip(proof.evaluated_points.iter())
+            .for_each(|(commitment, evaluation)| {
+                transcript.append_commitment(b"kzg_commitment", commitment);
+                transcript.append_scalar(b"kzg_evaluation", evaluation);
+            });
+
+        self.verify(point, &proof.flatten(transcript))
     }
 
     /// Checks whether a batch of polynomials evaluated at different points,
     /// returned their specified value.
-    pub(crate) fn batch_check(
+    ///
+    /// The `transcript` provides the randomness combining the proofs, hence
+    /// it should have absorbed the points and proofs beforehand.
+    pub fn batch_verify<T: TranscriptProtocol>(
         &self,
         points: &[BlsScalar],
-        proofs: &[Proof],
-        transcript: &mut Transcript,
+        proofs: &[OpeningProof],
+        transcript: &mut T,
     ) -> Result<(), Error> {
+        if proofs.is_empty() || points.len() != proofs.len() {
+            return Err(Error::InvalidOpeningBatch {
+                points: points.len(),
+                proofs: proofs.len(),
+            });
+        }
+
+        let (total_w, total_c) = self.accumulate(points, proofs, transcript);
+
+        self.check_accumulated(total_w, total_c)
+    }
+
+    /// Reduces the check of a batch of polynomials evaluated at different
+    /// points to a pair `(w, c)` such that the batch is valid iff
+    /// `e(-w, beta_h) · e(c, h) = 1`.
+    ///
+    /// Pairs of independent batches can be combined with random scalars and
+    /// checked at once with [`Self::check_accumulated`].
+    pub(crate) fn accumulate<T: TranscriptProtocol>(
+        &self,
+        points: &[BlsScalar],
+        proofs: &[OpeningProof],
+        transcript: &mut T,
+    ) -> (G1Projective, G1Projective) {
         let mut total_c = G1Projective::identity();
         let mut total_w = G1Projective::identity();
 
         let u_challenge = transcript.challenge_scalar(b"batch"); // XXX: Verifier can add their own randomness at this point
-        let powers = util::powers_of(&u_challenge, proofs.len() - 1);
+        let mut power = BlsScalar::one();
         // Instead of multiplying g and gamma_g in each turn, we simply
         // accumulate their coefficients and perform a final
         // multiplication at the end.
         let mut g_multiplier = BlsScalar::zero();
 
-        for ((proof, u_challenge), point) in
-            proofs.iter().zip(powers).zip(points)
-        {
+        for (proof, point) in proofs.iter().zip(points) {
             let mut c = G1Projective::from(proof.commitment_to_polynomial.0);
             let w = proof.commitment_to_witness.0;
             c += w * point;
-            g_multiplier += u_challenge * proof.evaluated_point;
+            g_multiplier += power * proof.evaluated_point;
+
+            total_c += c * power;
+            total_w += w * power;
 
-            total_c += c * u_challenge;
-            total_w += w * u_challenge;
+            power *= u_challenge;
         }
         total_c -= self.g * g_multiplier;
 
+        (total_w, total_c)
+    }
+
+    /// Checks that `e(-w, beta_h) · e(c, h) = 1` for a pair returned by
+    /// [`Self::accumulate`].
+    pub(crate) fn check_accumulated(
+        &self,
+        total_w: G1Projective,
+        total_c: G1Projective,
+    ) -> Result<(), Error> {
         let affine_total_w = G1Affine::from(-total_w);
         let affine_total_c = G1Affine::from(total_c);
 
+        #[cfg(feature = "verifier")]
         let pairing = dusk_bls12_381::multi_miller_loop(&[
             (&affine_total_w, &self.prepared_beta_h),
             (&affine_total_c, &self.prepared_h),
         ])
         .final_exponentiation();
 
+        // without an allocator the G2 points can't be prepared, so the
+        // pairings are computed one after the other
+        #[cfg(not(feature = "verifier"))]
+        let pairing = dusk_bls12_381::pairing(&affine_total_w, &self.beta_h)
+            + dusk_bls12_381::pairing(&affine_total_c, &self.h);
+
         if pairing != dusk_bls12_381::Gt::identity() {
             return Err(Error::PairingCheckFailure);
         };
@@ -340,21 +573,12 @@ mod test {
 
     // Checks that a polynomial `p` was evaluated at a point `z` and returned
     // the value specified `v`. ie. v = p(z).
-    fn check(op_key: &OpeningKey, point: BlsScalar, proof: Proof) -> bool {
-        let inner_a: G1Affine = (proof.commitment_to_polynomial.0
-            - (op_key.g * proof.evaluated_point))
-            .into();
-
-        let inner_b: G2Affine = (op_key.beta_h - (op_key.h * point)).into();
-        let prepared_inner_b = G2Prepared::from(-inner_b);
-
-        let pairing = dusk_bls12_381::multi_miller_loop(&[
-            (&inner_a, &op_key.prepared_h),
-            (&proof.commitment_to_witness.0, &prepared_inner_b),
-        ])
-        .final_exponentiation();
-
-        pairing == dusk_bls12_381::Gt::identity()
+    fn check(
+        op_key: &OpeningKey,
+        point: BlsScalar,
+        proof: OpeningProof,
+    ) -> bool {
+        op_key.verify(&point, &proof).is_ok()
     }
 
     // Creates an opening proof that a polynomial `p` was correctly evaluated at
@@ -365,9 +589,9 @@ mod test {
         polynomial: &Polynomial,
         value: &BlsScalar,
         point: &BlsScalar,
-    ) -> Result<Proof, Error> {
+    ) -> Result<OpeningProof, Error> {
         let witness_poly = compute_single_witness(polynomial, point);
-        Ok(Proof {
+        Ok(OpeningProof {
             commitment_to_witness: ck.commit(&witness_poly)?,
             evaluated_point: *value,
             commitment_to_polynomial: ck.commit(polynomial)?,
@@ -392,8 +616,9 @@ mod test {
         }
 
         // Compute the aggregate witness for polynomials
+        let polynomials: Vec<&Polynomial> = polynomials.iter().collect();
         let witness_poly =
-            ck.compute_aggregate_witness(polynomials, point, transcript);
+            ck.compute_aggregate_witness(&polynomials, point, transcript);
 
         // Commit to witness polynomial
         let witness_commitment = ck.commit(&witness_poly)?;
@@ -461,7 +686,7 @@ mod test {
         let proof_b = open_single(&ck, &poly_b, &value_b, &point_b)?;
         assert!(check(&vk, point_b, proof_b));
 
-        vk.batch_check(
+        vk.batch_verify(
             &[point_a, point_b],
             &[proof_a, proof_b],
             &mut Transcript::new(b""),
@@ -546,7 +771,7 @@ mod test {
         let mut transcript = Transcript::new(b"agg_batch");
         let flattened_proof = aggregated_proof.flatten(&mut transcript);
 
-        opening_key.batch_check(
+        opening_key.batch_verify(
             &[point_a, point_b],
             &[flattened_proof, single_proof],
             &mut transcript,
@@ -0,0 +1,243 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Commit key in the Lagrange basis of an evaluation domain.
+
+use alloc::vec::Vec;
+
+use dusk_bls12_381::{BlsScalar, G1Affine, G1Projective};
+use dusk_bytes::{DeserializableSlice, Serializable};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use super::{CommitKey, Commitment};
+use crate::commitment_scheme::msm;
+use crate::error::Error;
+use crate::fft::EvaluationDomain;
+
+/// Commit key in the Lagrange basis of an [`EvaluationDomain`], converted from
+/// a [`CommitKey`] with [`CommitKey::to_lagrange`].
+///
+/// It commits to the polynomials of degree lower than the size of the domain
+/// given by their evaluations over the domain, without interpolating them.
+/// The commitments are the ones of the [`CommitKey`] to the interpolated
+/// polynomials.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LagrangeKey {
+    domain: EvaluationDomain,
+    /// Group elements of the form `{ L_i(\beta) G }`, for the Lagrange
+    /// polynomials `L_i` of the domain
+    bases: Vec<G1Affine>,
+}
+
+impl LagrangeKey {
+    /// Evaluation domain of the key
+    pub fn domain(&self) -> &EvaluationDomain {
+        &self.domain
+    }
+
+    /// Commits to the polynomial with the given `evaluations` over the
+    /// elements of the domain, in order. The missing evaluations at the end
+    /// are zero.
+    ///
+    /// Returns an error if there are more evaluations than elements of the
+    /// domain.
+    pub fn commit(
+        &self,
+        evaluations: &[BlsScalar],
+    ) -> Result<Commitment, Error> {
+        if evaluations.len() > self.bases.len() {
+            return Err(Error::PolynomialDegreeTooLarge {
+                degree: evaluations.len() - 1,
+                max_degree: self.bases.len() - 1,
+            });
+        }
+
+        Ok(Commitment::from(msm::msm(&self.bases, evaluations)))
+    }
+
+    /// Serializes the [`LagrangeKey`] into a byte vector, the bytes of its
+    /// domain followed by the ones of its bases.
+    pub fn to_var_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(
+            EvaluationDomain::SIZE + self.bases.len() * G1Affine::SIZE,
+        );
+
+        bytes.extend_from_slice(&self.domain.to_bytes());
+        self.bases
+            .iter()
+            .for_each(|base| bytes.extend_from_slice(&base.to_bytes()));
+
+        bytes
+    }
+
+    /// Deserializes a [`LagrangeKey`] from the bytes created by
+    /// [`Self::to_var_bytes`], checking each of its bases.
+    pub fn from_slice(bytes: &[u8]) -> Result<Self, Error> {
+        let mut buffer = bytes;
+        let domain = EvaluationDomain::from_reader(&mut buffer)?;
+
+        let expected = domain
+            .size()
+            .saturating_mul(G1Affine::SIZE)
+            .saturating_add(EvaluationDomain::SIZE);
+        if bytes.len() != expected {
+            return Err(dusk_bytes::Error::BadLength {
+                found: bytes.len(),
+                expected,
+            }
+            .into());
+        }
+
+        let bases = buffer
+            .chunks(G1Affine::SIZE)
+            .map(G1Affine::from_slice)
+            .collect::<Result<Vec<G1Affine>, dusk_bytes::Error>>()?;
+
+        Ok(Self { domain, bases })
+    }
+}
+
+impl CommitKey {
+    /// Convert the key to the Lagrange basis of the `domain`.
+    ///
+    /// The conversion is an inverse FFT over G1 of the first powers of the
+    /// key, computed once for all the commitments over the domain.
+    ///
+    /// Returns an error if the key can't commit to polynomials of degree
+    /// lower than the size of the domain.
+    pub fn to_lagrange(
+        &self,
+        domain: &EvaluationDomain,
+    ) -> Result<LagrangeKey, Error> {
+        let size = domain.size();
+        if size > self.powers_of_g.len() {
+            return Err(Error::PolynomialDegreeTooLarge {
+                degree: size - 1,
+                max_degree: self.max_degree(),
+            });
+        }
+
+        let powers: Vec<G1Projective> = self.powers_of_g[..size]
+            .iter()
+            .map(G1Projective::from)
+            .collect();
+
+        // L_i(X) = 1/n · Σ_j ω^(-ij) X^j
+        let size_inv = BlsScalar::from(size as u64).invert().unwrap();
+        let bases: Vec<G1Projective> =
+            fft(&powers, domain.group_gen().invert().unwrap())
+                .into_iter()
+                .map(|basis| basis * size_inv)
+                .collect();
+
+        let mut affine = vec![G1Affine::identity(); size];
+        G1Projective::batch_normalize(&bases, &mut affine);
+
+        Ok(LagrangeKey {
+            domain: *domain,
+            bases: affine,
+        })
+    }
+}
+
+/// FFT over G1 of the `points` with the root of unity `omega`, of order the
+/// number of points, either a power of two or three times a power of two
+fn fft(points: &[G1Projective], omega: BlsScalar) -> Vec<G1Projective> {
+    let n = points.len();
+    let radix = match n {
+        0 | 1 => return points.to_vec(),
+        n if n % 2 == 0 => 2,
+        _ => 3,
+    };
+    let m = n / radix;
+
+    // the FFTs of the points strided by the radix
+    let omega_radix = omega.pow(&[radix as u64, 0, 0, 0]);
+
+    #[cfg(not(feature = "parallel"))]
+    let parts = (0..radix).into_iter();
+
+    #[cfg(feature = "parallel")]
+    let parts = (0..radix).into_par_iter();
+
+    let parts: Vec<Vec<G1Projective>> = parts
+        .map(|r| {
+            let part: Vec<G1Projective> =
+                points.iter().skip(r).step_by(radix).copied().collect();
+            fft(&part, omega_radix)
+        })
+        .collect();
+
+    let mut powers = Vec::with_capacity(n);
+    let mut power = BlsScalar::one();
+    for _ in 0..n {
+        powers.push(power);
+        power *= omega;
+    }
+
+    // X_k = Σ_r ω^(rk) P_r[k mod m]
+    #[cfg(not(feature = "parallel"))]
+    let range = (0..n).into_iter();
+
+    #[cfg(feature = "parallel")]
+    let range = (0..n).into_par_iter();
+
+    range
+        .map(|k| {
+            parts
+                .iter()
+                .enumerate()
+                .skip(1)
+                .fold(parts[0][k % m], |sum, (r, part)| {
+                    sum + part[k % m] * powers[(r * k) % n]
+                })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commitment_scheme::PublicParameters;
+    use ff::Field;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn lagrange_commitments() {
+        let mut rng = StdRng::seed_from_u64(0x1a9);
+        let pp = PublicParameters::setup(1 << 5, &mut rng).unwrap();
+        let (commit_key, _) = pp.trim(1 << 5).unwrap();
+
+        for domain in [
+            EvaluationDomain::new(16).unwrap(),
+            EvaluationDomain::new_mixed_radix(24).unwrap(),
+        ] {
+            let lagrange = commit_key.to_lagrange(&domain).unwrap();
+
+            let evals: Vec<BlsScalar> = (0..domain.size())
+                .map(|_| BlsScalar::random(&mut rng))
+                .collect();
+            let coeffs = domain.ifft(&evals);
+            assert_eq!(
+                lagrange.commit(&evals).unwrap(),
+                commit_key.commit(&coeffs).unwrap()
+            );
+
+            let too_many = vec![BlsScalar::one(); domain.size() + 1];
+            assert!(lagrange.commit(&too_many).is_err());
+
+            let bytes = lagrange.to_var_bytes();
+            assert_eq!(LagrangeKey::from_slice(&bytes), Ok(lagrange));
+            assert!(LagrangeKey::from_slice(&bytes[..bytes.len() - 1]).is_err());
+        }
+
+        let domain = EvaluationDomain::new(64).unwrap();
+        assert!(commit_key.to_lagrange(&domain).is_err());
+    }
+}
@@ -6,12 +6,12 @@
 
 use super::Commitment;
 use dusk_bls12_381::BlsScalar;
+use dusk_bytes::{DeserializableSlice, Serializable};
 
 /// Proof that a polynomial `p` was correctly evaluated at a point `z`
 /// producing the evaluated point p(z).
-#[derive(Copy, Clone, Debug)]
-#[allow(dead_code)]
-pub(crate) struct Proof {
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct OpeningProof {
     /// This is a commitment to the witness polynomial.
     pub(crate) commitment_to_witness: Commitment,
     /// This is the result of evaluating a polynomial at the point `z`.
@@ -21,22 +21,98 @@ pub(crate) struct Proof {
     pub(crate) commitment_to_polynomial: Commitment,
 }
 
-#[cfg(feature = "alloc")]
+impl OpeningProof {
+    /// Commitment to the polynomial the proof is about.
+    pub const fn commitment(&self) -> Commitment {
+        self.commitment_to_polynomial
+    }
+
+    /// Commitment to the witness polynomial `(p(x) - p(z)) / (x - z)`.
+    pub const fn witness(&self) -> Commitment {
+        self.commitment_to_witness
+    }
+
+    /// Claimed evaluation `p(z)` of the polynomial.
+    pub const fn evaluation(&self) -> BlsScalar {
+        self.evaluated_point
+    }
+}
+
+impl Serializable<{ Commitment::SIZE * 2 + BlsScalar::SIZE }> for OpeningProof {
+    type Error = dusk_bytes::Error;
+
+    #[allow(unused_must_use)]
+    fn to_bytes(&self) -> [u8; Self::SIZE] {
+        use dusk_bytes::Write;
+
+        let mut buf = [0u8; Self::SIZE];
+        let mut writer = &mut buf[..];
+
+        writer.write(&self.commitment_to_polynomial.to_bytes());
+        writer.write(&self.evaluated_point.to_bytes());
+        writer.write(&self.commitment_to_witness.to_bytes());
+
+        buf
+    }
+
+    fn from_bytes(buf: &[u8; Self::SIZE]) -> Result<Self, Self::Error> {
+        let mut buffer = &buf[..];
+
+        let commitment_to_polynomial = Commitment::from_reader(&mut buffer)?;
+        let evaluated_point = BlsScalar::from_reader(&mut buffer)?;
+        let commitment_to_witness = Commitment::from_reader(&mut buffer)?;
+
+        Ok(Self {
+            commitment_to_witness,
+            evaluated_point,
+            commitment_to_polynomial,
+        })
+    }
+}
+
+/// Flattens the openings of the polynomials of `commitments` to their
+/// `evaluations` at a point `z`, whose aggregated witness is committed to in
+/// `witness`, into an [`OpeningProof`].
+///
+/// The transcript must have the same view as the transcript that was used to
+/// aggregate the witness in the proving stage.
+#[cfg(feature = "heapless")]
+pub(crate) fn flatten<T: crate::transcript::TranscriptProtocol>(
+    witness: Commitment,
+    evaluations: &[BlsScalar],
+    commitments: &[Commitment],
+    transcript: &mut T,
+) -> OpeningProof {
+    let v_challenge = transcript.challenge_scalar(b"v_challenge");
+
+    let mut power = BlsScalar::one();
+    let mut evaluated_point = BlsScalar::zero();
+    let mut commitment = dusk_bls12_381::G1Projective::identity();
+
+    for (eval, comm) in evaluations.iter().zip(commitments) {
+        evaluated_point += eval * power;
+        commitment += comm.0 * power;
+        power *= v_challenge;
+    }
+
+    OpeningProof {
+        commitment_to_witness: witness,
+        evaluated_point,
+        commitment_to_polynomial: Commitment::from(commitment),
+    }
+}
+
+#[cfg(feature = "verifier")]
 pub(crate) mod alloc {
     use super::*;
     use crate::transcript::TranscriptProtocol;
-    use crate::util::powers_of;
     #[rustfmt::skip]
     use ::alloc::vec::Vec;
-    use dusk_bls12_381::G1Projective;
-    use merlin::Transcript;
-    #[cfg(feature = "std")]
-    use rayon::prelude::*;
 
     /// Proof that multiple polynomials were correctly evaluated at a point `z`,
     /// each producing their respective evaluated points p_i(z).
-    #[derive(Debug)]
-    pub(crate) struct AggregateProof {
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct AggregateProof {
         /// This is a commitment to the aggregated witness polynomial.
         pub(crate) commitment_to_witness: Commitment,
         /// These are the results of the evaluating each polynomial at the
@@ -48,6 +124,69 @@ pub(crate) mod alloc {
     }
 
     impl AggregateProof {
+        /// Commitment to the aggregated witness polynomial.
+        pub const fn witness(&self) -> Commitment {
+            self.commitment_to_witness
+        }
+
+        /// Commitments to the opened polynomials.
+        pub fn commitments(&self) -> &[Commitment] {
+            &self.commitments_to_polynomials
+        }
+
+        /// Claimed evaluations of the opened polynomials, in the order of
+        /// [`Self::commitments`].
+        pub fn evaluations(&self) -> &[BlsScalar] {
+            &self.evaluated_points
+        }
+
+        /// Serializes the [`AggregateProof`] into a byte vector.
+        ///
+        /// The witness commitment is followed by every commitment along with
+        /// its evaluation.
+        pub fn to_var_bytes(&self) -> Vec<u8> {
+            let part = Commitment::SIZE + BlsScalar::SIZE;
+            let mut bytes = Vec::with_capacity(
+                Commitment::SIZE + part * self.evaluated_points.len(),
+            );
+
+            bytes.extend_from_slice(&self.commitment_to_witness.to_bytes());
+            self.commitments_to_polynomials
+                .iter()
+                .zip(self.evaluated_points.iter())
+                .for_each(|(commitment, eval)| {
+                    bytes.extend_from_slice(&commitment.to_bytes());
+                    bytes.extend_from_slice(&eval.to_bytes());
+                });
+
+            bytes
+        }
+
+        /// Deserializes an [`AggregateProof`] from the bytes created by
+        /// [`Self::to_var_bytes`].
+        pub fn from_slice(bytes: &[u8]) -> Result<Self, dusk_bytes::Error> {
+            let part = Commitment::SIZE + BlsScalar::SIZE;
+            let mut buffer = bytes;
+
+            let witness = Commitment::from_reader(&mut buffer)?;
+            let parts = buffer.chunks_exact(part);
+            if !parts.remainder().is_empty() {
+                return Err(dusk_bytes::Error::BadLength {
+                    found: bytes.len(),
+                    expected: Commitment::SIZE + part * (parts.len() + 1),
+                });
+            }
+
+            let mut proof = Self::with_witness(witness);
+            for mut part in parts {
+                let commitment = Commitment::from_reader(&mut part)?;
+                let eval = BlsScalar::from_reader(&mut part)?;
+                proof.add_part((eval, commitment));
+            }
+
+            Ok(proof)
+        }
+
         /// Initializes an `AggregatedProof` with the commitment to the witness.
         pub(crate) fn with_witness(witness: Commitment) -> AggregateProof {
             AggregateProof {
@@ -64,50 +203,19 @@ pub(crate) mod alloc {
             self.commitments_to_polynomials.push(part.1);
         }
 
-        /// Flattens an `AggregateProof` into a `Proof`.
+        /// Flattens an `AggregateProof` into an [`OpeningProof`].
         /// The transcript must have the same view as the transcript that was
         /// used to aggregate the witness in the proving stage.
-        pub(crate) fn flatten(&self, transcript: &mut Transcript) -> Proof {
-            let v_challenge = transcript.challenge_scalar(b"v_challenge");
-            let powers = powers_of(
-                &v_challenge,
-                self.commitments_to_polynomials.len() - 1,
-            );
-
-            #[cfg(not(feature = "std"))]
-            let flattened_poly_commitments_iter =
-                self.commitments_to_polynomials.iter().zip(powers.iter());
-            #[cfg(not(feature = "std"))]
-            let flattened_poly_evaluations_iter =
-                self.evaluated_points.iter().zip(powers.iter());
-
-            #[cfg(feature = "std")]
-            let flattened_poly_commitments_iter = self
-                .commitments_to_polynomials
-                .par_iter()
-                .zip(powers.par_iter());
-            #[cfg(feature = "std")]
-            let flattened_poly_evaluations_iter =
-                self.evaluated_points.par_iter().zip(powers.par_iter());
-
-            // Flattened polynomial commitments using challenge `v`
-            let flattened_poly_commitments: G1Projective =
-                flattened_poly_commitments_iter
-                    .map(|(poly, v_challenge)| poly.0 * v_challenge)
-                    .sum();
-            // Flattened evaluation points
-            let flattened_poly_evaluations: BlsScalar =
-                flattened_poly_evaluations_iter
-                    .map(|(eval, v_challenge)| eval * v_challenge)
-                    .sum();
-
-            Proof {
-                commitment_to_witness: self.commitment_to_witness,
-                evaluated_point: flattened_poly_evaluations,
-                commitment_to_polynomial: Commitment::from(
-                    flattened_poly_commitments,
-                ),
-            }
+        pub(crate) fn flatten<T: TranscriptProtocol>(
+            &self,
+            transcript: &mut T,
+        ) -> OpeningProof {
+            super::flatten(
+                self.commitment_to_witness,
+                &self.evaluated_points,
+                &self.commitments_to_polynomials,
+                transcript,
+            )
         }
     }
 }
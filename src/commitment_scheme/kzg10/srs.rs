@@ -7,6 +7,7 @@
 //! The Public Parameters can also be referred to as the Structured Reference
 //! String (SRS).
 use super::key::{CommitKey, OpeningKey};
+use crate::composer::{Builder, Circuit, Compiler, Composer};
 use crate::{error::Error, util};
 use alloc::vec::Vec;
 use dusk_bls12_381::{BlsScalar, G1Affine, G1Projective, G2Affine};
@@ -14,6 +15,18 @@ use dusk_bytes::{DeserializableSlice, Serializable};
 use ff::Field;
 use rand_core::{CryptoRng, RngCore};
 
+mod ceremony;
+mod contribution;
+#[cfg(all(feature = "mmap", unix))]
+mod mmap;
+mod testing;
+
+pub use ceremony::PowersOfTau;
+pub use contribution::Contribution;
+#[cfg(all(feature = "mmap", unix))]
+pub use mmap::MappedPublicParameters;
+pub use testing::TestParameters;
+
 #[cfg(feature = "rkyv-impl")]
 use bytecheck::CheckBytes;
 #[cfg(feature = "rkyv-impl")]
@@ -55,9 +68,30 @@ impl PublicParameters {
     /// In reality, a `Trusted party` or a `Multiparty Computation` will be used
     /// to generate the SRS. Returns an error if the configured degree is less
     /// than one.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(max_degree))
+    )]
     pub fn setup<R: RngCore + CryptoRng>(
-        mut max_degree: usize,
+        max_degree: usize,
         mut rng: &mut R,
+    ) -> Result<PublicParameters, Error> {
+        // Generate the secret scalar x
+        let x = BlsScalar::random(&mut rng);
+
+        let g = util::random_g1_point(&mut rng);
+        let h = util::random_g2_point(&mut rng);
+
+        Self::from_secret(max_degree, x, g, h.into())
+    }
+
+    /// Generate the public parameters of the secret scalar `x` over the
+    /// generators `g` and `h`
+    fn from_secret(
+        mut max_degree: usize,
+        x: BlsScalar,
+        g: G1Projective,
+        h: G2Affine,
     ) -> Result<PublicParameters, Error> {
         // Cannot commit to constants
         if max_degree < 1 {
@@ -67,14 +101,10 @@ impl PublicParameters {
         // we update the degree to match the required one (n + 6)
         max_degree = max_degree + Self::ADDED_BLINDING_DEGREE;
 
-        // Generate the secret scalar x
-        let x = BlsScalar::random(&mut rng);
-
         // Compute powers of x up to and including x^max_degree
         let powers_of_x = util::powers_of(&x, max_degree);
 
         // Powers of G1 that will be used to commit to a specified polynomial
-        let g = util::random_g1_point(&mut rng);
         let powers_of_g: Vec<G1Projective> =
             util::slow_multiscalar_mul_single_base(&powers_of_x, g);
         assert_eq!(powers_of_g.len(), max_degree + 1);
@@ -85,7 +115,6 @@ impl PublicParameters {
 
         // Compute x_2 = x*h element and stored cached elements for verifying
         // multiple proofs.
-        let h: G2Affine = util::random_g2_point(&mut rng).into();
         let x_2: G2Affine = (h * x).into();
 
         Ok(PublicParameters {
@@ -181,7 +210,7 @@ impl PublicParameters {
     ///
     /// Returns an error if the truncated degree is larger than the public
     /// parameters configured degree.
-    pub(crate) fn trim(
+    pub fn trim(
         &self,
         truncated_degree: usize,
     ) -> Result<(CommitKey, OpeningKey), Error> {
@@ -192,6 +221,73 @@ impl PublicParameters {
         Ok((truncated_prover_key, opening_key))
     }
 
+    /// Trim the [`PublicParameters`] to allow the prover to commit to
+    /// polynomials up to and including `degree`, after checking the
+    /// structure of the trimmed parameters.
+    ///
+    /// Every point must be on the curve and in the prime order subgroup, and
+    /// the powers of `g` must be consistent with `[x]_2`. Returns
+    /// [`Error::PowerMalformed`] with the index of the first invalid power,
+    /// [`Error::PointMalformed`] if the opening key is invalid, and
+    /// [`Error::PairingCheckFailure`] if the powers are inconsistent.
+    pub fn trim_checked(
+        &self,
+        degree: usize,
+    ) -> Result<PublicParameters, Error> {
+        if degree == 0 {
+            return Err(Error::TruncatedDegreeIsZero);
+        }
+
+        let commit_key = self
+            .commit_key
+            .truncate(degree + Self::ADDED_BLINDING_DEGREE)?;
+
+        let OpeningKey { g, h, beta_h, .. } = self.opening_key;
+        let valid_g2 =
+            |p: &G2Affine| bool::from(p.is_on_curve() & p.is_torsion_free());
+        if !valid_g2(&h) || !valid_g2(&beta_h) {
+            return Err(Error::PointMalformed);
+        }
+
+        let identity = G1Affine::identity();
+        if let Some(index) = commit_key.powers_of_g.iter().position(|p| {
+            p == &identity || !bool::from(p.is_on_curve() & p.is_torsion_free())
+        }) {
+            return Err(Error::PowerMalformed { index });
+        }
+
+        if commit_key.powers_of_g[0] != g {
+            return Err(Error::PointMalformed);
+        }
+
+        ceremony::check_powers(&commit_key.powers_of_g, &h, &beta_h)?;
+
+        Ok(PublicParameters {
+            commit_key,
+            opening_key: self.opening_key.clone(),
+        })
+    }
+
+    /// Trim the [`PublicParameters`] to the minimal degree needed to compile
+    /// the circuit `C`, discarding the powers the circuit doesn't use.
+    ///
+    /// Use the default implementation of the circuit.
+    pub fn trim_to_circuit<C>(&self) -> Result<PublicParameters, Error>
+    where
+        C: Circuit,
+    {
+        let mut builder = Builder::initialized();
+        C::default().circuit(&mut builder)?;
+
+        let (commit_key, opening_key) =
+            self.trim(Compiler::degree(&builder))?;
+
+        Ok(PublicParameters {
+            commit_key,
+            opening_key,
+        })
+    }
+
     /// Max degree specifies the largest Polynomial
     /// that this prover key can commit to.
     pub fn max_degree(&self) -> usize {
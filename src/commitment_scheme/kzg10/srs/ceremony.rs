@@ -0,0 +1,214 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Import of the public parameters generated by a trusted setup ceremony.
+
+use alloc::vec::Vec;
+
+use dusk_bls12_381::{
+    multiscalar_mul::msm_variable_base, G1Affine, G2Affine, G2Prepared,
+};
+use dusk_bytes::Serializable;
+use merlin::Transcript;
+
+use super::{CommitKey, OpeningKey, PublicParameters};
+use crate::error::Error;
+use crate::transcript::TranscriptProtocol;
+use crate::util;
+
+/// Layout of a transcript of the `powersoftau` ceremony over BLS12-381, as
+/// run for Zcash Sapling and Filecoin.
+///
+/// A transcript is a 64 bytes hash followed by the accumulator of the
+/// ceremony: `2^(power + 1) - 1` powers of tau in G1, then `2^power` powers of
+/// tau in G2, followed by the powers of `alpha · tau` and `beta · tau` that
+/// are not needed by PLONK. Challenge files store uncompressed points, while
+/// response files store compressed points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PowersOfTau {
+    power: u32,
+    compressed: bool,
+}
+
+impl PowersOfTau {
+    const HASH_SIZE: usize = 64;
+
+    /// Layout of a challenge file of a ceremony of `2^power` powers of tau
+    pub const fn challenge(power: u32) -> Self {
+        Self {
+            power,
+            compressed: false,
+        }
+    }
+
+    /// Layout of a response file of a ceremony of `2^power` powers of tau
+    pub const fn response(power: u32) -> Self {
+        Self {
+            power,
+            compressed: true,
+        }
+    }
+
+    /// Number of powers of tau in G1 stored in the transcript
+    pub const fn g1_powers(&self) -> usize {
+        (1 << (self.power + 1)) - 1
+    }
+
+    const fn g1_size(&self) -> usize {
+        if self.compressed {
+            48
+        } else {
+            96
+        }
+    }
+
+    const fn g2_size(&self) -> usize {
+        2 * self.g1_size()
+    }
+
+    fn read_g1(&self, bytes: &[u8], i: usize) -> Result<G1Affine, Error> {
+        let offset = Self::HASH_SIZE + i * self.g1_size();
+        let bytes = bytes
+            .get(offset..offset + self.g1_size())
+            .ok_or(Error::NotEnoughBytes)?;
+
+        let point = if self.compressed {
+            let bytes = <&[u8; 48]>::try_from(bytes).expect("checked len");
+            G1Affine::from_compressed(bytes)
+        } else {
+            let bytes = <&[u8; 96]>::try_from(bytes).expect("checked len");
+            G1Affine::from_uncompressed(bytes)
+        };
+
+        Option::from(point).ok_or(Error::PointMalformed)
+    }
+
+    fn read_g2(&self, bytes: &[u8], i: usize) -> Result<G2Affine, Error> {
+        let offset = Self::HASH_SIZE
+            + self.g1_powers() * self.g1_size()
+            + i * self.g2_size();
+        let bytes = bytes
+            .get(offset..offset + self.g2_size())
+            .ok_or(Error::NotEnoughBytes)?;
+
+        let point = if self.compressed {
+            let bytes = <&[u8; 96]>::try_from(bytes).expect("checked len");
+            G2Affine::from_compressed(bytes)
+        } else {
+            let bytes = <&[u8; 192]>::try_from(bytes).expect("checked len");
+            G2Affine::from_uncompressed(bytes)
+        };
+
+        Option::from(point).ok_or(Error::PointMalformed)
+    }
+}
+
+impl PublicParameters {
+    /// Create the public parameters from the powers of a secret `tau` computed
+    /// by a trusted setup: `tau^i · g` in G1 for `i` in `0..=max_degree`,
+    /// along with `h` and `tau · h` in G2.
+    ///
+    /// The powers are checked to be consistent with each other, and not to be
+    /// the identity. Returns an error if the check fails.
+    pub fn from_powers(
+        powers_of_g: Vec<G1Affine>,
+        h: G2Affine,
+        beta_h: G2Affine,
+    ) -> Result<Self, Error> {
+        if powers_of_g.len() < 2 {
+            return Err(Error::DegreeIsZero);
+        }
+
+        check_powers(&powers_of_g, &h, &beta_h)?;
+
+        let opening_key = OpeningKey::new(powers_of_g[0], h, beta_h);
+        let commit_key = CommitKey { powers_of_g };
+
+        Ok(Self {
+            commit_key,
+            opening_key,
+        })
+    }
+
+    /// Import the public parameters from a transcript of a Powers of Tau
+    /// ceremony, capable of proving circuits of up to `max_degree`
+    /// constraints.
+    ///
+    /// Only the part of the transcript needed for the given degree is read
+    /// and checked, see [`Self::from_powers`].
+    pub fn from_powers_of_tau(
+        bytes: &[u8],
+        layout: PowersOfTau,
+        max_degree: usize,
+    ) -> Result<Self, Error> {
+        if max_degree < 1 {
+            return Err(Error::DegreeIsZero);
+        }
+
+        let degree = max_degree + Self::ADDED_BLINDING_DEGREE;
+        if degree >= layout.g1_powers() {
+            return Err(Error::TruncatedDegreeTooLarge {
+                degree,
+                max_degree: layout.g1_powers() - 1,
+            });
+        }
+
+        let powers_of_g = (0..=degree)
+            .map(|i| layout.read_g1(bytes, i))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let h = layout.read_g2(bytes, 0)?;
+        let beta_h = layout.read_g2(bytes, 1)?;
+
+        Self::from_powers(powers_of_g, h, beta_h)
+    }
+}
+
+/// Check that `powers_of_g[i + 1] = tau · powers_of_g[i]` for every `i`, where
+/// `beta_h = tau · h`.
+///
+/// The checks are combined with the powers of a challenge derived from the
+/// points, and performed with a single pairing check:
+/// `e(Σ r^i · powers_of_g[i], beta_h) = e(Σ r^i · powers_of_g[i + 1], h)`.
+pub(super) fn check_powers(
+    powers_of_g: &[G1Affine],
+    h: &G2Affine,
+    beta_h: &G2Affine,
+) -> Result<(), Error> {
+    let identity = G1Affine::identity();
+    if powers_of_g.iter().any(|p| p == &identity)
+        || h == &G2Affine::identity()
+        || beta_h == &G2Affine::identity()
+    {
+        return Err(Error::PointMalformed);
+    }
+
+    let mut transcript = Transcript::new(b"dusk-plonk-powers-of-tau");
+    powers_of_g
+        .iter()
+        .for_each(|p| transcript.append_message(b"g", &p.to_bytes()));
+    transcript.append_message(b"h", &h.to_bytes());
+    transcript.append_message(b"beta_h", &beta_h.to_bytes());
+
+    let r = transcript.challenge_scalar(b"r");
+    let n = powers_of_g.len() - 1;
+    let powers_of_r = util::powers_of(&r, n - 1);
+
+    let lhs = msm_variable_base(&powers_of_g[..n], &powers_of_r);
+    let rhs = msm_variable_base(&powers_of_g[1..], &powers_of_r);
+
+    let pairing = dusk_bls12_381::multi_miller_loop(&[
+        (&lhs.into(), &G2Prepared::from(*beta_h)),
+        (&(-rhs).into(), &G2Prepared::from(*h)),
+    ])
+    .final_exponentiation();
+
+    if pairing != dusk_bls12_381::Gt::identity() {
+        return Err(Error::PairingCheckFailure);
+    }
+
+    Ok(())
+}
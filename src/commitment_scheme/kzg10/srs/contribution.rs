@@ -0,0 +1,243 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Contributions to a trusted setup ceremony.
+//!
+//! Every participant multiplies the secret `tau` of the public parameters by
+//! a fresh secret `s`, and publishes a [`Contribution`] proving the update.
+//! The resulting parameters are secure as long as a single participant
+//! discarded their secret.
+
+use alloc::vec::Vec;
+
+use dusk_bls12_381::{
+    BlsScalar, G1Affine, G1Projective, G2Affine, G2Prepared, G2Projective,
+};
+use dusk_bytes::{DeserializableSlice, Serializable};
+use ff::Field;
+use merlin::Transcript;
+use rand_core::{CryptoRng, RngCore};
+
+use super::{CommitKey, OpeningKey, PublicParameters};
+use crate::error::Error;
+use crate::transcript::TranscriptProtocol;
+use crate::util;
+
+/// Proof that a participant updated the public parameters of a ceremony with
+/// a secret of their own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Contribution {
+    /// `tau · g` after the update
+    tau_g: G1Affine,
+    /// `s · g`
+    s_g: G1Affine,
+    /// `s · h`
+    s_h: G2Affine,
+    /// Commitment of the proof of knowledge of `s`
+    r_g: G1Affine,
+    /// Response of the proof of knowledge of `s`
+    z: BlsScalar,
+}
+
+impl Contribution {
+    /// `tau · g` after the update, to be compared by the participants with
+    /// the parameters they received
+    pub const fn tau_g(&self) -> &G1Affine {
+        &self.tau_g
+    }
+
+    fn challenge(&self, previous: &G1Affine) -> BlsScalar {
+        let mut transcript = Transcript::new(b"dusk-plonk-contribution");
+
+        transcript.append_message(b"previous", &previous.to_bytes());
+        transcript.append_message(b"tau_g", &self.tau_g.to_bytes());
+        transcript.append_message(b"s_g", &self.s_g.to_bytes());
+        transcript.append_message(b"s_h", &self.s_h.to_bytes());
+        transcript.append_message(b"r_g", &self.r_g.to_bytes());
+
+        transcript.challenge_scalar(b"c")
+    }
+
+    /// Check the contribution updated `previous` into `self.tau_g`
+    fn verify(&self, previous: &G1Affine, g: &G1Affine, h: &G2Affine) -> bool {
+        let identity = G1Affine::identity();
+        if self.s_g == identity || self.s_h == G2Affine::identity() {
+            return false;
+        }
+
+        // knowledge of `s`
+        let c = self.challenge(previous);
+        let knows_s = g * self.z == G1Projective::from(self.r_g) + self.s_g * c;
+
+        // `s · g` and `s · h` share the same `s`, and `tau_g = s · previous`
+        knows_s
+            && pairing_eq(&self.s_g, h, g, &self.s_h)
+            && pairing_eq(&self.tau_g, h, previous, &self.s_h)
+    }
+}
+
+impl Serializable<{ 3 * G1Affine::SIZE + G2Affine::SIZE + BlsScalar::SIZE }>
+    for Contribution
+{
+    type Error = dusk_bytes::Error;
+
+    #[allow(unused_must_use)]
+    fn to_bytes(&self) -> [u8; Self::SIZE] {
+        use dusk_bytes::Write;
+        let mut buf = [0u8; Self::SIZE];
+        let mut writer = &mut buf[..];
+        // This can't fail therefore we don't care about the Result nor use it.
+        writer.write(&self.tau_g.to_bytes());
+        writer.write(&self.s_g.to_bytes());
+        writer.write(&self.s_h.to_bytes());
+        writer.write(&self.r_g.to_bytes());
+        writer.write(&self.z.to_bytes());
+
+        buf
+    }
+
+    fn from_bytes(buf: &[u8; Self::SIZE]) -> Result<Self, Self::Error> {
+        let mut buffer = &buf[..];
+
+        let tau_g = G1Affine::from_reader(&mut buffer)?;
+        let s_g = G1Affine::from_reader(&mut buffer)?;
+        let s_h = G2Affine::from_reader(&mut buffer)?;
+        let r_g = G1Affine::from_reader(&mut buffer)?;
+        let z = BlsScalar::from_reader(&mut buffer)?;
+
+        Ok(Self {
+            tau_g,
+            s_g,
+            s_h,
+            r_g,
+            z,
+        })
+    }
+}
+
+/// Check `e(a, b) = e(c, d)`
+fn pairing_eq(a: &G1Affine, b: &G2Affine, c: &G1Affine, d: &G2Affine) -> bool {
+    dusk_bls12_381::multi_miller_loop(&[
+        (a, &G2Prepared::from(*b)),
+        (&(-c), &G2Prepared::from(*d)),
+    ])
+    .final_exponentiation()
+        == dusk_bls12_381::Gt::identity()
+}
+
+impl PublicParameters {
+    /// Create the public parameters a ceremony starts from, capable of
+    /// proving circuits of up to `max_degree` constraints.
+    ///
+    /// These parameters are generated with `tau = 1` and are insecure until
+    /// at least one participant [contributed](Self::contribute) to them.
+    pub fn ceremony(max_degree: usize) -> Result<Self, Error> {
+        if max_degree < 1 {
+            return Err(Error::DegreeIsZero);
+        }
+
+        let g = G1Affine::generator();
+        let h = G2Affine::generator();
+        let powers_of_g = vec![g; max_degree + Self::ADDED_BLINDING_DEGREE + 1];
+
+        Ok(Self {
+            commit_key: CommitKey { powers_of_g },
+            opening_key: OpeningKey::new(g, h, h),
+        })
+    }
+
+    /// Update the secret `tau` of the parameters with a fresh random secret
+    /// and return the proof of the update.
+    ///
+    /// The contributions must be published along with the final parameters
+    /// so anyone can [verify](Self::verify_contributions) them.
+    pub fn contribute<R>(&mut self, rng: &mut R) -> Contribution
+    where
+        R: RngCore + CryptoRng,
+    {
+        let s = loop {
+            let s = BlsScalar::random(&mut *rng);
+            if s != BlsScalar::zero() {
+                break s;
+            }
+        };
+
+        let powers = &mut self.commit_key.powers_of_g;
+        let previous = powers[1];
+
+        let powers_of_s = util::powers_of(&s, powers.len() - 1);
+        let updated: Vec<G1Projective> = powers
+            .iter()
+            .zip(powers_of_s.iter())
+            .map(|(p, s)| p * s)
+            .collect();
+        G1Projective::batch_normalize(&updated, powers);
+
+        let h = self.opening_key.h;
+        self.opening_key = OpeningKey::new(
+            self.opening_key.g,
+            h,
+            (self.opening_key.beta_h * s).into(),
+        );
+
+        let g = self.opening_key.g;
+        let k = BlsScalar::random(&mut *rng);
+
+        let mut contribution = Contribution {
+            tau_g: self.commit_key.powers_of_g[1],
+            s_g: (g * s).into(),
+            s_h: G2Affine::from(G2Projective::from(h) * s),
+            r_g: (g * k).into(),
+            z: BlsScalar::zero(),
+        };
+        contribution.z = k + contribution.challenge(&previous) * s;
+
+        contribution
+    }
+
+    /// Verify the parameters result from the given chain of contributions to
+    /// the parameters of a [ceremony](Self::ceremony).
+    ///
+    /// Returns [`Error::InvalidContribution`] with the index of the first
+    /// contribution that doesn't follow from the previous one, or an error if
+    /// the parameters aren't consistent with the last contribution. Returns
+    /// [`Error::PointMalformed`] if the parameters don't share the generators
+    /// of the ceremony.
+    pub fn verify_contributions(
+        &self,
+        contributions: &[Contribution],
+    ) -> Result<(), Error> {
+        let g = self.opening_key.g;
+        let h = self.opening_key.h;
+
+        if g != G1Affine::generator() || h != G2Affine::generator() {
+            return Err(Error::PointMalformed);
+        }
+
+        let tau_g = contributions.iter().enumerate().try_fold(
+            g,
+            |previous, (index, contribution)| {
+                contribution
+                    .verify(&previous, &g, &h)
+                    .then_some(contribution.tau_g)
+                    .ok_or(Error::InvalidContribution { index })
+            },
+        )?;
+
+        if self.commit_key.powers_of_g.get(1) != Some(&tau_g) {
+            return Err(Error::InvalidContribution {
+                index: contributions.len(),
+            });
+        }
+
+        Self::from_powers(
+            self.commit_key.powers_of_g.clone(),
+            h,
+            self.opening_key.beta_h,
+        )
+        .map(|_| ())
+    }
+}
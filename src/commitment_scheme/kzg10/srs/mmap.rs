@@ -0,0 +1,179 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Public parameters memory-mapped from a file.
+
+use std::fs::File;
+use std::os::unix::io::AsRawFd;
+use std::path::Path;
+use std::{io, ptr, slice};
+
+use dusk_bls12_381::G1Affine;
+use dusk_bytes::{DeserializableSlice, Serializable};
+
+use super::PublicParameters;
+use crate::commitment_scheme::{CommitKey, OpeningKey};
+use crate::composer::{Builder, Circuit, Compiler, Composer};
+use crate::error::Error;
+
+/// [`PublicParameters`] memory-mapped from a file written with
+/// [`PublicParameters::to_raw_var_bytes`].
+///
+/// The file isn't copied to the heap: only the powers loaded with
+/// [`Self::load`] or [`Self::trim_to_circuit`] are deserialized, from pages
+/// the operating system shares between the processes mapping the same file.
+#[derive(Debug)]
+pub struct MappedPublicParameters {
+    map: *const u8,
+    map_len: usize,
+    opening_key: OpeningKey,
+    powers: usize,
+}
+
+// Safety: the mapping is private to the struct, read-only, and released on
+// drop
+unsafe impl Send for MappedPublicParameters {}
+unsafe impl Sync for MappedPublicParameters {}
+
+/// Size of the header of the commit key, with its number of powers
+const POWERS_LEN_SIZE: usize = u64::SIZE;
+
+impl MappedPublicParameters {
+    /// Map the public parameters written to the file at `path` with
+    /// [`PublicParameters::to_raw_var_bytes`].
+    ///
+    /// Returns an error if the file can't be mapped or is too short for the
+    /// powers it declares.
+    ///
+    /// # Safety
+    /// As with [`PublicParameters::from_slice_unchecked`], the points of the
+    /// file aren't checked and must come from a trusted source. The file must
+    /// not be modified while it's mapped.
+    pub unsafe fn open<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let file = File::open(path)?;
+        let map_len = file.metadata()?.len() as usize;
+        if map_len < OpeningKey::SIZE + POWERS_LEN_SIZE {
+            return Err(Error::NotEnoughBytes);
+        }
+
+        let map = libc::mmap(
+            ptr::null_mut(),
+            map_len,
+            libc::PROT_READ,
+            libc::MAP_SHARED,
+            file.as_raw_fd(),
+            0,
+        );
+        if map == libc::MAP_FAILED {
+            return Err(io::Error::last_os_error().into());
+        }
+
+        let map = map as *const u8;
+        match Self::header(slice::from_raw_parts(map, map_len)) {
+            Ok((opening_key, powers)) => Ok(Self {
+                map,
+                map_len,
+                opening_key,
+                powers,
+            }),
+            Err(e) => {
+                libc::munmap(map as *mut libc::c_void, map_len);
+                Err(e)
+            }
+        }
+    }
+
+    /// Opening key and number of powers of the raw bytes of the parameters,
+    /// checking the bytes hold the powers
+    fn header(bytes: &[u8]) -> Result<(OpeningKey, usize), Error> {
+        let opening_key = OpeningKey::from_slice(&bytes[..OpeningKey::SIZE])?;
+
+        let mut powers = [0u8; POWERS_LEN_SIZE];
+        powers.copy_from_slice(
+            &bytes[OpeningKey::SIZE..OpeningKey::SIZE + POWERS_LEN_SIZE],
+        );
+        let powers = u64::from_le_bytes(powers) as usize;
+
+        let required = powers.checked_mul(G1Affine::RAW_SIZE).and_then(|len| {
+            len.checked_add(OpeningKey::SIZE + POWERS_LEN_SIZE)
+        });
+        match required {
+            Some(required) if powers > 0 && required <= bytes.len() => {
+                Ok((opening_key, powers))
+            }
+            _ => Err(Error::NotEnoughBytes),
+        }
+    }
+
+    /// Max degree of the polynomials the parameters can commit to
+    pub fn max_degree(&self) -> usize {
+        self.powers - 1
+    }
+
+    /// Load the [`PublicParameters`] up to and including `max_degree`,
+    /// deserializing only the powers of the commit key it needs.
+    ///
+    /// Returns an error if `max_degree` is larger than the max degree of the
+    /// mapped parameters.
+    pub fn load(&self, max_degree: usize) -> Result<PublicParameters, Error> {
+        if max_degree > self.max_degree() {
+            return Err(Error::TruncatedDegreeTooLarge {
+                degree: max_degree,
+                max_degree: self.max_degree(),
+            });
+        }
+
+        let start = OpeningKey::SIZE + POWERS_LEN_SIZE;
+        let end = start + (max_degree + 1) * G1Affine::RAW_SIZE;
+
+        // Safety: the points are trusted by the caller of `open`
+        let powers_of_g = self.bytes()[start..end]
+            .chunks_exact(G1Affine::RAW_SIZE)
+            .map(|c| unsafe { G1Affine::from_slice_unchecked(c) })
+            .collect();
+
+        Ok(PublicParameters {
+            commit_key: CommitKey { powers_of_g },
+            opening_key: self.opening_key.clone(),
+        })
+    }
+
+    /// Load the [`PublicParameters`] with the minimal degree needed to compile
+    /// the circuit `C`, as with [`PublicParameters::trim_to_circuit`].
+    ///
+    /// Use the default implementation of the circuit.
+    pub fn trim_to_circuit<C>(&self) -> Result<PublicParameters, Error>
+    where
+        C: Circuit,
+    {
+        let mut builder = Builder::initialized();
+        C::default().circuit(&mut builder)?;
+
+        let degree = Compiler::degree(&builder);
+        let (commit_key, opening_key) = self
+            .load(degree + PublicParameters::ADDED_BLINDING_DEGREE)?
+            .trim(degree)?;
+
+        Ok(PublicParameters {
+            commit_key,
+            opening_key,
+        })
+    }
+
+    fn bytes(&self) -> &[u8] {
+        // Safety: the mapping is valid until the struct is dropped
+        unsafe { slice::from_raw_parts(self.map, self.map_len) }
+    }
+}
+
+impl Drop for MappedPublicParameters {
+    fn drop(&mut self) {
+        // Safety: the mapping isn't used after the struct is dropped
+        unsafe {
+            libc::munmap(self.map as *mut libc::c_void, self.map_len);
+        }
+    }
+}
@@ -0,0 +1,91 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Insecure public parameters derived from a seed, for tests.
+//!
+//! The secret of the parameters is derived from the seed, so anyone knowing
+//! the seed can forge proofs. The parameters are typed apart from the
+//! [`PublicParameters`], and only give access to them with the `testing`
+//! feature, so circuits can't be compiled and proven with them by accident.
+
+#[cfg(feature = "testing")]
+use core::ops::Deref;
+
+use dusk_bls12_381::{BlsScalar, G1Affine, G2Affine};
+use sha2::{Digest, Sha512};
+
+use super::PublicParameters;
+use crate::error::Error;
+
+/// Domain separator of the derivation of the secrets from the seed
+const SEED_DOMAIN: &[u8] = b"dusk-plonk-insecure-srs";
+
+/// Insecure public parameters, deterministically derived from a seed with
+/// [`PublicParameters::insecure_from_seed`].
+///
+/// With the `testing` feature, the parameters dereference to
+/// [`PublicParameters`] to compile circuits in tests. Without it, they can't
+/// be used to compile circuits, so a prover can't be created from them.
+#[derive(Debug, Clone)]
+pub struct TestParameters {
+    seed: u64,
+    pp: PublicParameters,
+}
+
+impl TestParameters {
+    /// Seed the parameters are derived from
+    pub const fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Max degree specifies the largest Polynomial that the parameters can
+    /// commit to.
+    pub fn max_degree(&self) -> usize {
+        self.pp.max_degree()
+    }
+}
+
+#[cfg(feature = "testing")]
+impl Deref for TestParameters {
+    type Target = PublicParameters;
+
+    fn deref(&self) -> &PublicParameters {
+        &self.pp
+    }
+}
+
+impl PublicParameters {
+    /// Derive insecure public parameters of the given `max_degree` from a
+    /// `seed`, for fast and deterministic test setups.
+    ///
+    /// The secret scalar and the generators of the parameters are hashed from
+    /// the seed, so the same seed always yields the same parameters, and
+    /// anyone knowing it can forge proofs. The parameters are returned as
+    /// [`TestParameters`], which only compile circuits with the `testing`
+    /// feature. Returns an error if the configured degree is less than one.
+    pub fn insecure_from_seed(
+        seed: u64,
+        max_degree: usize,
+    ) -> Result<TestParameters, Error> {
+        let x = derive(seed, b"x");
+        let g = G1Affine::generator() * derive(seed, b"g");
+        let h = G2Affine::generator() * derive(seed, b"h");
+
+        let pp = Self::from_secret(max_degree, x, g, h.into())?;
+
+        Ok(TestParameters { seed, pp })
+    }
+}
+
+/// Derive the scalar of the given `label` from the `seed`
+fn derive(seed: u64, label: &[u8]) -> BlsScalar {
+    let mut hasher = Sha512::new();
+    hasher.update(SEED_DOMAIN);
+    hasher.update(seed.to_be_bytes());
+    hasher.update(label);
+
+    BlsScalar::from_bytes_wide(&hasher.finalize().into())
+}
@@ -0,0 +1,287 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Multi-scalar multiplications over G1 with Pippenger's algorithm.
+//!
+//! The scalars are split in windows of `w` bits recoded as signed digits in
+//! `[-2^(w-1), 2^(w-1)]`, so every window accumulates the points in
+//! `2^(w-1)` buckets. The window is picked from the number of points to
+//! minimize the additions of a multiplication.
+//!
+//! When the points are fixed, as the powers of a commit key, an [`MsmTable`]
+//! precomputes the multiples `2^(j·w)·P` of every point, so that the windows
+//! share a single set of buckets and no doubling is left.
+
+use alloc::vec::Vec;
+
+use dusk_bls12_381::{BlsScalar, G1Affine, G1Projective};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Largest window, in bits
+const MAX_WINDOW: usize = 20;
+
+/// Multiply the `points` by the `scalars` and sum the products. The longest of
+/// the two slices is truncated to the length of the other.
+pub(crate) fn msm(points: &[G1Affine], scalars: &[BlsScalar]) -> G1Projective {
+    let len = points.len().min(scalars.len());
+
+    // without precomputation, every window sums its buckets and the window
+    // sums are combined with doublings
+    let w = (1..=MAX_WINDOW)
+        .min_by_key(|&w| windows(w) * (len + (1 << w)))
+        .unwrap_or(1);
+
+    let limbs: Vec<([u64; 4], &G1Affine)> = scalars[..len]
+        .iter()
+        .zip(points)
+        .filter(|(s, _)| *s != &BlsScalar::zero())
+        .map(|(s, p)| (limbs(s), p))
+        .collect();
+
+    #[cfg(not(feature = "parallel"))]
+    let range = (0..windows(w)).into_iter();
+
+    #[cfg(feature = "parallel")]
+    let range = (0..windows(w)).into_par_iter();
+
+    let window_sums: Vec<G1Projective> = range
+        .map(|j| {
+            let mut buckets = vec![G1Projective::identity(); 1 << (w - 1)];
+            limbs.iter().for_each(|(limbs, point)| {
+                add_to_bucket(&mut buckets, digit(limbs, w, j), point)
+            });
+
+            sum_buckets(&buckets)
+        })
+        .collect();
+
+    window_sums
+        .iter()
+        .rev()
+        .fold(G1Projective::identity(), |acc, sum| {
+            (0..w).fold(acc, |acc, _| acc.double()) + sum
+        })
+}
+
+/// Precomputed multiples of fixed points, to speed up their multi-scalar
+/// multiplications with [`MsmTable::msm`].
+///
+/// The table holds `ceil(256 / w)` points for every point.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct MsmTable {
+    window: usize,
+    windows: usize,
+    multiples: Vec<G1Affine>,
+}
+
+impl MsmTable {
+    /// Precompute the multiples of the `points`
+    pub(crate) fn new(points: &[G1Affine]) -> Self {
+        let len = points.len();
+
+        // with precomputation, the windows are summed in the same buckets
+        let window = (1..=MAX_WINDOW)
+            .min_by_key(|&w| windows(w) * len + (1 << w))
+            .unwrap_or(1);
+        let windows = windows(window);
+
+        let mut multiples = vec![G1Affine::identity(); len * windows];
+
+        #[cfg(not(feature = "parallel"))]
+        let rows = multiples.chunks_mut(windows);
+
+        #[cfg(feature = "parallel")]
+        let rows = multiples.par_chunks_mut(windows);
+
+        rows.zip(points).for_each(|(row, point)| {
+            let mut multiple = G1Projective::from(point);
+            let powers: Vec<G1Projective> = (0..windows)
+                .map(|_| {
+                    let power = multiple;
+                    multiple = (0..window).fold(power, |p, _| p.double());
+                    power
+                })
+                .collect();
+
+            G1Projective::batch_normalize(&powers, row);
+        });
+
+        Self {
+            window,
+            windows,
+            multiples,
+        }
+    }
+
+    /// Number of points of the table
+    pub(crate) fn len(&self) -> usize {
+        self.multiples.len() / self.windows
+    }
+
+    /// Multiply the points of the table by the `scalars` and sum the products.
+    /// The scalars beyond the points of the table are ignored.
+    pub(crate) fn msm(&self, scalars: &[BlsScalar]) -> G1Projective {
+        let rows = self.multiples.chunks(self.windows).zip(scalars);
+
+        let sum = |rows: &[(&[G1Affine], &BlsScalar)]| {
+            let mut buckets =
+                vec![G1Projective::identity(); 1 << (self.window - 1)];
+            rows.iter()
+                .filter(|(_, s)| *s != &BlsScalar::zero())
+                .for_each(|(row, s)| {
+                    let limbs = limbs(s);
+                    row.iter().enumerate().for_each(|(j, multiple)| {
+                        let digit = digit(&limbs, self.window, j);
+                        add_to_bucket(&mut buckets, digit, multiple)
+                    });
+                });
+
+            sum_buckets(&buckets)
+        };
+
+        let rows: Vec<(&[G1Affine], &BlsScalar)> = rows.collect();
+
+        #[cfg(not(feature = "parallel"))]
+        let msm = sum(&rows);
+
+        #[cfg(feature = "parallel")]
+        let msm = {
+            let chunk = (rows.len() / rayon::current_num_threads()).max(1024);
+            rows.par_chunks(chunk)
+                .map(sum)
+                .reduce(G1Projective::identity, |a, b| a + b)
+        };
+
+        msm
+    }
+}
+
+/// Number of windows of `w` bits covering the signed digits of a scalar
+const fn windows(w: usize) -> usize {
+    // a scalar has 255 bits, and the last window absorbs the carry of the
+    // signed digits
+    match 256 % w {
+        0 => 256 / w,
+        _ => 256 / w + 1,
+    }
+}
+
+/// Canonical little endian limbs of a scalar
+fn limbs(scalar: &BlsScalar) -> [u64; 4] {
+    let bytes = scalar.to_bytes();
+
+    let mut limbs = [0u64; 4];
+    limbs
+        .iter_mut()
+        .zip(bytes.chunks_exact(8))
+        .for_each(|(limb, b)| {
+            let mut le = [0u8; 8];
+            le.copy_from_slice(b);
+            *limb = u64::from_le_bytes(le);
+        });
+
+    limbs
+}
+
+/// `len < 64` bits of `limbs` starting at bit `start`
+fn bits(limbs: &[u64; 4], start: usize, len: usize) -> u64 {
+    let (i, shift) = (start / 64, start % 64);
+    if i >= limbs.len() {
+        return 0;
+    }
+
+    let mut bits = limbs[i] >> shift;
+    if shift + len > 64 && i + 1 < limbs.len() {
+        bits |= limbs[i + 1] << (64 - shift);
+    }
+
+    bits & ((1 << len) - 1)
+}
+
+/// Signed digit of the window `j` of `w` bits, in `[-2^(w-1), 2^(w-1)]`.
+///
+/// The carry into a window is the top bit of the window below, that is then
+/// negative.
+fn digit(limbs: &[u64; 4], w: usize, j: usize) -> i64 {
+    let window = bits(limbs, j * w, w) as i64;
+    let carry = match j {
+        0 => 0,
+        _ => bits(limbs, j * w - 1, 1) as i64,
+    };
+
+    match window >> (w - 1) {
+        0 => window + carry,
+        _ => window + carry - (1 << w),
+    }
+}
+
+fn add_to_bucket(buckets: &mut [G1Projective], digit: i64, point: &G1Affine) {
+    match digit {
+        d if d > 0 => buckets[d as usize - 1] += point,
+        d if d < 0 => buckets[(-d) as usize - 1] -= point,
+        _ => (),
+    }
+}
+
+/// Sum of the buckets weighted by their index, with two running sums
+fn sum_buckets(buckets: &[G1Projective]) -> G1Projective {
+    let mut running = G1Projective::identity();
+    let mut sum = G1Projective::identity();
+    buckets.iter().rev().for_each(|bucket| {
+        running += bucket;
+        sum += running;
+    });
+
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dusk_bls12_381::multiscalar_mul::msm_variable_base;
+    use ff::Field;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn msm_matches_variable_base() {
+        let mut rng = StdRng::seed_from_u64(0x3535);
+
+        for len in [0, 1, 2, 31, 100, 1500] {
+            let points: Vec<G1Affine> = (0..len)
+                .map(|_| {
+                    G1Affine::from(
+                        G1Affine::generator() * BlsScalar::random(&mut rng),
+                    )
+                })
+                .collect();
+
+            let mut scalars: Vec<BlsScalar> =
+                (0..len).map(|_| BlsScalar::random(&mut rng)).collect();
+            // zeros, units and the largest scalar
+            if len > 3 {
+                scalars[0] = BlsScalar::zero();
+                scalars[1] = BlsScalar::one();
+                scalars[2] = -BlsScalar::one();
+            }
+
+            let expected = msm_variable_base(&points, &scalars);
+            assert_eq!(msm(&points, &scalars), expected);
+
+            let table = MsmTable::new(&points);
+            assert_eq!(table.len(), len);
+            assert_eq!(table.msm(&scalars), expected);
+
+            // truncated scalars
+            let half = len / 2;
+            let expected = msm_variable_base(&points[..half], &scalars[..half]);
+            assert_eq!(msm(&points, &scalars[..half]), expected);
+            assert_eq!(table.msm(&scalars[..half]), expected);
+        }
+    }
+}
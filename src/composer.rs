@@ -6,36 +6,86 @@
 
 //! PLONK turbo composer definitions
 
-use alloc::vec::Vec;
-use core::cmp;
-use core::ops::Index;
-
-use dusk_bls12_381::BlsScalar;
-use dusk_jubjub::{JubJubAffine, JubJubExtended, JubJubScalar};
-
-use crate::bit_iterator::BitIterator8;
-use crate::constraint_system::ecc::WnafRound;
-use crate::constraint_system::{
-    Constraint, Selector, WiredWitness, Witness, WitnessPoint,
-};
-use crate::error::Error;
-use crate::runtime::{Runtime, RuntimeEvent};
-
-mod builder;
-mod circuit;
-mod compiler;
-mod polynomial;
-mod prover;
-mod verifier;
-
-pub use builder::Builder;
-pub use circuit::Circuit;
-pub use compiler::Compiler;
-pub use polynomial::Polynomial;
-pub use prover::Prover;
-pub use verifier::Verifier;
+cfg_if::cfg_if!(
+    if #[cfg(feature = "alloc")] {
+        use alloc::vec::Vec;
+        use core::cmp;
+        use core::ops::Index;
+
+        use dusk_bls12_381::BlsScalar;
+        use dusk_jubjub::{JubJubAffine, JubJubExtended, JubJubScalar};
+
+        use crate::bit_iterator::BitIterator8;
+        use crate::constraint_system::ecc::WnafRound;
+        #[cfg(feature = "constant_time")]
+        use crate::constraint_system::ecc::{naf, naf_addend};
+        use crate::constraint_system::{
+            Constraint, Selector, WiredWitness, Witness, WitnessPoint,
+        };
+        use crate::error::Error;
+        use crate::hades::{Permutation, RATE, WIDTH};
+        use crate::runtime::{Runtime, RuntimeEvent};
+
+        mod blinding;
+        mod builder;
+        mod cancellation;
+        mod circuit;
+        mod compiler;
+        mod dump;
+        mod equivalence;
+        mod optimizer;
+        mod parallel;
+        mod polynomial;
+        mod progress;
+        mod prover;
+        mod satisfiability;
+        mod stats;
+
+        pub use blinding::Blinding;
+        pub use builder::Builder;
+        pub use cancellation::CancellationToken;
+        pub use circuit::Circuit;
+        pub use compiler::Compiler;
+        pub use equivalence::Divergence;
+        pub use optimizer::{
+            CommonSubexpressionElimination, ConstantFolding, DeadGateElimination,
+            OptLevel, OptReport, Optimizer, Pass,
+        };
+        pub use polynomial::Polynomial;
+        pub use progress::ProveStage;
+        pub use prover::Prover;
+        pub use stats::ProveStats;
+    }
+);
+
+cfg_if::cfg_if!(
+    if #[cfg(feature = "verifier")] {
+        mod accumulator;
+        mod bundle;
+        #[cfg(feature = "std")]
+        mod cache;
+        mod cost;
+        mod padded;
+        mod public_input;
+        mod verifier;
+
+        pub use accumulator::Accumulator;
+        pub use bundle::ProofBundle;
+        pub use cost::VerifierCost;
+        pub use padded::PaddedVec;
+        pub use public_input::PublicInputValue;
+        pub use verifier::Verifier;
+
+        pub use crate::hades::hash;
+    }
+);
+
+mod heapless;
+
+pub use heapless::HeaplessVerifier;
 
 /// Circuit builder tool
+#[cfg(feature = "alloc")]
 pub trait Composer: Sized + Index<Witness, Output = BlsScalar> {
     /// Zero representation inside the constraint system.
     ///
@@ -81,6 +131,65 @@ pub trait Composer: Sized + Index<Witness, Output = BlsScalar> {
     /// PLONK runtime controller
     fn runtime(&mut self) -> &mut Runtime;
 
+    /// Merge the arithmetic gates that evaluate the same expression over the
+    /// same inputs, rewiring the duplicated outputs to the first one.
+    ///
+    /// Gates that take part in range, logic and curve addition components, and
+    /// gates with public inputs, are left untouched.
+    ///
+    /// The pass must be called from within [`Circuit::circuit`] so the prover
+    /// and the verifier agree on the resulting circuit description, once all
+    /// gates are appended: a merged output is no longer defined by a gate of
+    /// its own.
+    ///
+    /// Return the number of removed gates. Composers that don't support the
+    /// pass leave the gates untouched and return `0`.
+    fn eliminate_common_subexpressions(&mut self) -> usize {
+        0
+    }
+
+    /// Propagate the witnesses constrained to a constant into the selectors of
+    /// the arithmetic gates that consume them, and remove the gates that
+    /// become trivially satisfied or define an unreferenced constant.
+    ///
+    /// A gate whose inputs are all constant defines its output as a constant
+    /// as well, so constants propagate through the circuit.
+    ///
+    /// Just like [`Composer::eliminate_common_subexpressions`], the pass must
+    /// be called from within [`Circuit::circuit`] once all gates are appended.
+    ///
+    /// Return the number of removed gates. Composers that don't support the
+    /// pass leave the gates untouched and return `0`.
+    fn fold_constants(&mut self) -> usize {
+        0
+    }
+
+    /// Remove the arithmetic gates whose output isn't consumed by any other
+    /// gate, along with the computations that only feed them.
+    ///
+    /// Such gates never reach a public input or an assertion, so they don't
+    /// constrain the circuit. The outputs listed in `keep` are preserved, which
+    /// allows a circuit to opt out of the elimination for specific witnesses.
+    ///
+    /// Just like [`Composer::eliminate_common_subexpressions`], the pass must
+    /// be called from within [`Circuit::circuit`] once all gates are appended.
+    ///
+    /// Return the number of removed gates. Composers that don't support the
+    /// pass leave the gates untouched and return `0`.
+    fn eliminate_dead_gates(&mut self, keep: &[Witness]) -> usize {
+        let _ = keep;
+        0
+    }
+
+    /// Run the optimization passes of the given level over the gates of the
+    /// circuit, and report the gates removed by each pass.
+    ///
+    /// The passes must be run from within [`Circuit::circuit`] once all gates
+    /// are appended. Use an [`Optimizer`] to assemble a custom pipeline.
+    fn optimize(&mut self, level: OptLevel) -> OptReport {
+        Optimizer::with_level(level).run(self)
+    }
+
     /// Initialize the constraint system with dummy gates
     fn initialized() -> Self {
         #[allow(deprecated)]
@@ -111,6 +220,24 @@ pub trait Composer: Sized + Index<Witness, Output = BlsScalar> {
         witness
     }
 
+    /// Label the gates appended from now on, until the next label, so that
+    /// [`Builder::check_satisfied`] can report the label of an unsatisfied
+    /// gate.
+    ///
+    /// Labels don't change the circuit description, and are ignored by the
+    /// composers that don't record them.
+    fn label(&mut self, label: &'static str) {
+        let _ = label;
+    }
+
+    /// Value assigned to the `witness`, as with indexing the composer.
+    ///
+    /// Useful to inspect intermediate values while debugging an unsatisfied
+    /// circuit.
+    fn witness_of(&self, witness: Witness) -> BlsScalar {
+        self[witness]
+    }
+
     /// Append a new width-4 poly gate/constraint.
     fn append_custom_gate(&mut self, constraint: Constraint) {
         self.runtime()
@@ -242,6 +369,9 @@ pub trait Composer: Sized + Index<Witness, Output = BlsScalar> {
     ///
     /// `generator` will be appended to the circuit description as constant
     ///
+    /// With the `constant_time` feature, the digits of `jubjub` and the
+    /// points they add are computed in constant time.
+    ///
     /// Will error if `jubjub` doesn't fit `Fr`
     fn component_mul_generator<P: Into<JubJubExtended>>(
         &mut self,
@@ -281,8 +411,10 @@ pub trait Composer: Sized + Index<Witness, Output = BlsScalar> {
                 None => return Err(Error::BlsScalarMalformed),
             };
 
-        let width = 2;
-        let wnaf_entries = scalar.compute_windowed_naf(width);
+        #[cfg(not(feature = "constant_time"))]
+        let wnaf_entries = scalar.compute_windowed_naf(2);
+        #[cfg(feature = "constant_time")]
+        let wnaf_entries = naf(&scalar);
 
         // this will pass as long as `compute_windowed_naf` returns an array
         // with 256 elements
@@ -303,12 +435,16 @@ pub trait Composer: Sized + Index<Witness, Output = BlsScalar> {
             .rev()
             .enumerate()
             .map(|(i, entry)| {
+                #[cfg(not(feature = "constant_time"))]
                 let (scalar_to_add, point_to_add) = match entry {
                     0 => (BlsScalar::zero(), JubJubAffine::identity()),
                     -1 => (BlsScalar::one().neg(), -wnaf_point_multiples[i]),
                     1 => (BlsScalar::one(), wnaf_point_multiples[i]),
                     _ => return Err(Error::UnsupportedWNAF2k),
                 };
+                #[cfg(feature = "constant_time")]
+                let (scalar_to_add, point_to_add) =
+                    naf_addend(*entry, &wnaf_point_multiples[i]);
 
                 let prev_accumulator = two * scalar_acc[i];
                 let scalar = prev_accumulator + scalar_to_add;
@@ -397,6 +533,171 @@ pub trait Composer: Sized + Index<Witness, Output = BlsScalar> {
         Ok(WitnessPoint::new(acc_x, acc_y))
     }
 
+    /// Evaluate the Pedersen commitment `value · G + blinder · H` as a
+    /// [`WitnessPoint`], where `G` is [`dusk_jubjub::GENERATOR_EXTENDED`] and
+    /// `H` is [`dusk_jubjub::GENERATOR_NUMS_EXTENDED`]
+    ///
+    /// Will error if `value` or `blinder` don't fit `Fr`
+    fn component_pedersen_commitment(
+        &mut self,
+        value: Witness,
+        blinder: Witness,
+    ) -> Result<WitnessPoint, Error> {
+        let value = self
+            .component_mul_generator(value, dusk_jubjub::GENERATOR_EXTENDED)?;
+        let blinder = self.component_mul_generator(
+            blinder,
+            dusk_jubjub::GENERATOR_NUMS_EXTENDED,
+        )?;
+
+        Ok(self.component_add_point(value, blinder))
+    }
+
+    /// Asserts that `value` and `blinder` open the Pedersen `commitment`,
+    /// computed as in [`Composer::component_pedersen_commitment`].
+    ///
+    /// The commitment can be created outside of the circuit, e.g. by a
+    /// sigma protocol holding the same value, and is added as public inputs
+    /// `(x, y)`, binding the proof to it.
+    ///
+    /// Will error if `value` or `blinder` don't fit `Fr`
+    fn assert_pedersen_opening<P: Into<JubJubAffine>>(
+        &mut self,
+        value: Witness,
+        blinder: Witness,
+        commitment: P,
+    ) -> Result<(), Error> {
+        let point = self.component_pedersen_commitment(value, blinder)?;
+        self.assert_equal_public_point(point, commitment);
+
+        Ok(())
+    }
+
+    /// Evaluate the ElGamal encryption of `value` under `public_key` with
+    /// the `randomness` as the two [`WitnessPoint`]s of the ciphertext
+    /// `(randomness · G, value · G + randomness · public_key)`, where `G` is
+    /// [`dusk_jubjub::GENERATOR_EXTENDED`]
+    ///
+    /// The holder of the secret key `sk` of `public_key` decrypts the
+    /// ciphertext `(c1, c2)` to `value · G = c2 - sk · c1`, from which small
+    /// values are recovered by searching their discrete logarithm.
+    ///
+    /// Will error if `value` or `randomness` don't fit `Fr`
+    fn component_elgamal_encrypt(
+        &mut self,
+        public_key: WitnessPoint,
+        value: Witness,
+        randomness: Witness,
+    ) -> Result<(WitnessPoint, WitnessPoint), Error> {
+        let c1 = self.component_mul_generator(
+            randomness,
+            dusk_jubjub::GENERATOR_EXTENDED,
+        )?;
+
+        let value = self
+            .component_mul_generator(value, dusk_jubjub::GENERATOR_EXTENDED)?;
+        let shared = self.component_mul_point(randomness, public_key);
+        let c2 = self.component_add_point(value, shared);
+
+        Ok((c1, c2))
+    }
+
+    /// Asserts that `ciphertext` is the ElGamal encryption of `value` under
+    /// `public_key` with the `randomness`, computed as in
+    /// [`Composer::component_elgamal_encrypt`].
+    ///
+    /// The two points of the ciphertext are added as public inputs
+    /// `(x, y)`, in order, so the verifier of the proof learns that the
+    /// ciphertext encrypts the value constrained by the rest of the circuit,
+    /// e.g. to an auditor whose key is appended with
+    /// [`Composer::append_public_point`].
+    ///
+    /// Will error if `value` or `randomness` don't fit `Fr`
+    fn assert_elgamal_encryption<P: Into<JubJubAffine>>(
+        &mut self,
+        public_key: WitnessPoint,
+        value: Witness,
+        randomness: Witness,
+        ciphertext: (P, P),
+    ) -> Result<(), Error> {
+        let (c1, c2) =
+            self.component_elgamal_encrypt(public_key, value, randomness)?;
+
+        self.assert_equal_public_point(c1, ciphertext.0);
+        self.assert_equal_public_point(c2, ciphertext.1);
+
+        Ok(())
+    }
+
+    /// Asserts that the ElGamal `ciphertext` under `public_key`, computed as
+    /// in [`Composer::component_elgamal_encrypt`], decrypts to `value` with
+    /// the `secret_key`, and that `value · G` is the `plaintext` point.
+    ///
+    /// The public key is constrained to be `secret_key · G`, and the
+    /// decryption `(c1, c2)` to `value · G` to hold as
+    /// `c2 = value · G + secret_key · c1`. The public key, the two points of
+    /// the ciphertext and the plaintext point are added as public inputs
+    /// `(x, y)`, in order, so the key holder can disclose what a ciphertext
+    /// encrypts without revealing the key.
+    ///
+    /// Will error if `secret_key` or `value` don't fit `Fr`
+    fn assert_elgamal_decryption<P: Into<JubJubAffine>>(
+        &mut self,
+        secret_key: Witness,
+        value: Witness,
+        public_key: P,
+        ciphertext: (P, P),
+        plaintext: P,
+    ) -> Result<(), Error> {
+        let point = self.component_mul_generator(
+            secret_key,
+            dusk_jubjub::GENERATOR_EXTENDED,
+        )?;
+        self.assert_equal_public_point(point, public_key);
+
+        let c1 = self.append_public_point(ciphertext.0);
+        let c2 = self.append_public_point(ciphertext.1);
+
+        let value = self
+            .component_mul_generator(value, dusk_jubjub::GENERATOR_EXTENDED)?;
+        let shared = self.component_mul_point(secret_key, c1);
+        let point = self.component_add_point(value, shared);
+        self.assert_equal_point(point, c2);
+
+        self.assert_equal_public_point(value, plaintext);
+
+        Ok(())
+    }
+
+    /// Evaluate the stealth address `H(secret · public_key) · G + spend_key`
+    /// as a [`WitnessPoint`], where `G` is
+    /// [`dusk_jubjub::GENERATOR_EXTENDED`]
+    ///
+    /// The Diffie-Hellman shared secret `secret · public_key` is the same for
+    /// the sender, with its ephemeral secret `r` and the view key `A = a · G`
+    /// of the receiver, and for the receiver, with its view secret `a` and the
+    /// ephemeral key `R = r · G`. `H` is the [hash](Self::component_hash) of
+    /// the coordinates `(x, y)` of the shared secret, truncated to its 250
+    /// lowest bits to fit `Fr`, as the bytes of the canonical [`hash`] with
+    /// the six highest bits cleared.
+    ///
+    /// Will error if `secret` doesn't fit `Fr`
+    fn component_stealth_address(
+        &mut self,
+        secret: Witness,
+        public_key: WitnessPoint,
+        spend_key: WitnessPoint,
+    ) -> Result<WitnessPoint, Error> {
+        let shared = self.component_mul_point(secret, public_key);
+        let hash = self.component_hash(&[*shared.x(), *shared.y()]);
+        let hash = truncate(self, hash);
+
+        let point = self
+            .component_mul_generator(hash, dusk_jubjub::GENERATOR_EXTENDED)?;
+
+        Ok(self.component_add_point(point, spend_key))
+    }
+
     /// Append a new width-4 poly gate/constraint.
     ///
     /// The constraint added will enforce the following:
@@ -573,6 +874,89 @@ pub trait Composer: Sized + Index<Witness, Output = BlsScalar> {
         witness
     }
 
+    /// Append the [expansion](PublicInputValue::expand) of `value` as public
+    /// inputs, returning their witnesses.
+    ///
+    /// The proof is then verified against the expansion of the same values,
+    /// in order, with [`PublicInputValue::expand_all`].
+    fn append_public_value<V: Into<PublicInputValue>>(
+        &mut self,
+        value: V,
+    ) -> Vec<Witness> {
+        value
+            .into()
+            .expand()
+            .into_iter()
+            .map(|scalar| self.append_public(scalar))
+            .collect()
+    }
+
+    /// Append the length and the padded values of `vec` as `N + 1` public
+    /// inputs, returning the witnesses of the length and of the `N` padded
+    /// values.
+    ///
+    /// The values past the length are constrained to be zero, with `4N + 1`
+    /// gates besides the public inputs. The proof is verified against
+    /// [`PaddedVec::expand`].
+    fn append_public_padded<const N: usize>(
+        &mut self,
+        vec: &PaddedVec<N>,
+    ) -> (Witness, [Witness; N]) {
+        let len = vec.values().len();
+        let length = self.append_public(BlsScalar::from(len as u64));
+
+        let mut values = [Self::ZERO; N];
+        let mut count = Self::ZERO;
+        let mut previous = Self::ONE;
+
+        for (i, value) in vec.padded().iter().enumerate() {
+            values[i] = self.append_public(*value);
+
+            // the flags of the values within the length are a prefix of ones
+            let flag = self.append_witness(BlsScalar::from((i < len) as u64));
+            self.component_boolean(flag);
+            let constraint = Constraint::new()
+                .mult(-BlsScalar::one())
+                .left(1)
+                .a(flag)
+                .b(previous);
+            self.append_gate(constraint);
+
+            // the padding is zero
+            let constraint = Constraint::new()
+                .mult(-BlsScalar::one())
+                .left(1)
+                .a(values[i])
+                .b(flag);
+            self.append_gate(constraint);
+
+            let constraint =
+                Constraint::new().left(1).right(1).a(count).b(flag);
+            count = self.gate_add(constraint);
+            previous = flag;
+        }
+
+        self.assert_equal(count, length);
+
+        (length, values)
+    }
+
+    /// Evaluate the [hash](Self::component_hash) of `inputs` and append it as
+    /// a public input standing for all of them, returning the hash.
+    ///
+    /// The proof is then verified against the [`hash`] of the values of the
+    /// inputs, e.g. with [`Verifier::verify_hashed`], instead of against each
+    /// value. The gates of the hash make the verification and the public
+    /// inputs sent to the verifier independent of the number of values.
+    fn append_public_hash(&mut self, inputs: &[Witness]) -> Witness {
+        let hash = self.component_hash(inputs);
+        let value = self[hash];
+
+        self.assert_equal_constant(hash, BlsScalar::zero(), Some(value));
+
+        hash
+    }
+
     /// Asserts `a == b` by appending a gate
     fn assert_equal(&mut self, a: Witness, b: Witness) {
         let constraint =
@@ -760,6 +1144,185 @@ pub trait Composer: Sized + Index<Witness, Output = BlsScalar> {
         decomposition
     }
 
+    /// Evaluate the hash of `inputs` with a sponge of the Hades252
+    /// permutation, as [`hash`] computes it outside of circuits.
+    ///
+    /// Only the permutation and its parameters are shared with
+    /// `dusk-poseidon`, the hash differs from the ones of its sponge.
+    ///
+    /// Consumes 967 gates for each permutation, one per group of four inputs,
+    /// with the round constants folded into the gates of the S-boxes and of
+    /// the linear layers, plus a gate per input absorbed after the first
+    /// group.
+    fn component_hash(&mut self, inputs: &[Witness]) -> Witness {
+        let permutation = Permutation::new();
+
+        let mut state = [Self::ZERO; WIDTH];
+        state[0] = self.append_constant(BlsScalar::from(inputs.len() as u64));
+
+        let mut chunks = inputs.chunks(RATE);
+        let first = chunks.next().unwrap_or(&[]);
+
+        for (i, chunk) in core::iter::once(first).chain(chunks).enumerate() {
+            for (s, input) in state[1..].iter_mut().zip(chunk) {
+                *s = match i {
+                    0 => *input,
+                    _ => self.gate_add(
+                        Constraint::new().left(1).a(*s).right(1).b(*input),
+                    ),
+                };
+            }
+
+            permute(self, &permutation, &mut state);
+        }
+
+        state[1]
+    }
+
+    /// Evaluate the root of a Merkle tree of arity `A` from a `leaf` and the
+    /// levels of its opening `path`, from the leaf to the root.
+    ///
+    /// Each level holds the `A` children of a node, and the `position` among
+    /// them of the node evaluated from the previous level, constrained to be
+    /// lower than `A`. A node is the [hash](Self::component_hash) of its
+    /// children, as [`hash`] computes it outside of circuits. The four inputs
+    /// absorbed by each permutation make a level of arity 4 as costly as a
+    /// binary one, with half the levels.
+    ///
+    /// Consumes per level the gates of [`Self::component_hash`] of `A` inputs,
+    /// and about `4 · A` gates to select the node among the children.
+    fn component_merkle_root<const A: usize>(
+        &mut self,
+        leaf: Witness,
+        path: &[([Witness; A], Witness)],
+    ) -> Witness {
+        // Static assertion
+        assert!(A > 1);
+
+        path.iter().fold(leaf, |node, (children, position)| {
+            let p = self[*position];
+
+            // one-hot bits of the position
+            let bits: Vec<Witness> = (0..A)
+                .map(|j| {
+                    let bit = p == BlsScalar::from(j as u64);
+                    let bit = self.append_witness(BlsScalar::from(bit as u64));
+                    self.component_boolean(bit);
+                    bit
+                })
+                .collect();
+
+            let terms: Vec<(BlsScalar, Witness)> =
+                bits.iter().map(|bit| (BlsScalar::one(), *bit)).collect();
+            let sum = self.component_linear_combination(&terms, 0);
+            self.assert_equal_constant(sum, BlsScalar::one(), None);
+
+            let terms: Vec<(BlsScalar, Witness)> = bits
+                .iter()
+                .enumerate()
+                .map(|(j, bit)| (BlsScalar::from(j as u64), *bit))
+                .collect();
+            let index = self.component_linear_combination(&terms, 0);
+            self.assert_equal(index, *position);
+
+            // the node is the child at the position
+            let terms: Vec<(BlsScalar, Witness)> = bits
+                .iter()
+                .zip(children)
+                .map(|(bit, child)| {
+                    (BlsScalar::one(), self.component_select_zero(*bit, *child))
+                })
+                .collect();
+            let child = self.component_linear_combination(&terms, 0);
+            self.assert_equal(child, node);
+
+            self.component_hash(children)
+        })
+    }
+
+    /// Synthesize groups of gadgets without data dependencies between them,
+    /// computing their witnesses on a thread pool with the `parallel`
+    /// feature, e.g. the openings of many independent Merkle paths.
+    ///
+    /// Each group lists the witnesses of the circuit it takes as inputs, along
+    /// with data of its own. The `gadget` appends the gates of a group to a
+    /// builder of its own, over the inputs imported into that builder, and
+    /// returns the witnesses of its outputs. The groups are then merged into
+    /// the circuit in order, with the imported inputs wired back to the
+    /// witnesses of the circuit, so the gates and the public inputs are the
+    /// ones of appending the gadgets one after the other.
+    ///
+    /// A gadget can only wire the witnesses of its own builder: the witnesses
+    /// of the circuit are reachable through its inputs alone.
+    ///
+    /// Returns the outputs of every group, as witnesses of the circuit.
+    fn component_parallel<T, F>(
+        &mut self,
+        groups: &[(Vec<Witness>, T)],
+        gadget: F,
+    ) -> Vec<Vec<Witness>>
+    where
+        T: Sync,
+        F: Fn(&mut Builder, &[Witness], &T) -> Vec<Witness> + Sync,
+    {
+        let values: Vec<(Vec<BlsScalar>, &T)> = groups
+            .iter()
+            .map(|(inputs, data)| {
+                (inputs.iter().map(|w| self[*w]).collect(), data)
+            })
+            .collect();
+
+        parallel::synthesize(&values, &gadget)
+            .into_iter()
+            .zip(groups)
+            .map(|(synthesized, (inputs, _))| {
+                parallel::merge(self, inputs, synthesized)
+            })
+            .collect()
+    }
+
+    /// Evaluate and return `o = constant + Σ coeff · witness` for an arbitrary
+    /// number of `terms`.
+    ///
+    /// The first gate consumes up to three terms through the `a`, `b` and `d`
+    /// wires; every subsequent gate carries the accumulated sum in `a` and
+    /// consumes up to two more terms.
+    ///
+    /// Consumes `1 + ⌈(n - 3) / 2⌉` gates for `n > 3` terms, and a single gate
+    /// otherwise.
+    fn component_linear_combination<C: Into<BlsScalar>>(
+        &mut self,
+        terms: &[(BlsScalar, Witness)],
+        constant: C,
+    ) -> Witness {
+        let (head, tail) = terms.split_at(cmp::min(terms.len(), 3));
+
+        let mut constraint = Constraint::new().constant(constant);
+        for (i, (coeff, witness)) in head.iter().enumerate() {
+            constraint = match i {
+                0 => constraint.left(*coeff).a(*witness),
+                1 => constraint.right(*coeff).b(*witness),
+                _ => constraint.fourth(*coeff).d(*witness),
+            };
+        }
+
+        let acc = self.gate_add(constraint);
+
+        tail.chunks(2).fold(acc, |acc, chunk| {
+            let mut constraint = Constraint::new()
+                .left(1)
+                .a(acc)
+                .right(chunk[0].0)
+                .b(chunk[0].1);
+
+            if let Some((coeff, witness)) = chunk.get(1) {
+                constraint = constraint.fourth(*coeff).d(*witness);
+            }
+
+            self.gate_add(constraint)
+        })
+    }
+
     /// Conditionally selects identity as [`WitnessPoint`] based on an input
     /// bit.
     ///
@@ -800,6 +1363,42 @@ pub trait Composer: Sized + Index<Witness, Output = BlsScalar> {
         result
     }
 
+    /// Evaluate the multi-scalar multiplication `Σ jubjub · point` of the
+    /// `terms` as a [`WitnessPoint`]
+    ///
+    /// The bits of the scalars are interleaved, so a single doubling per bit
+    /// is shared by all the terms, instead of a doubling per bit and term
+    /// with [`Composer::component_mul_point`].
+    ///
+    /// Consumes `505 + 1008` gates per term, and `502` gates for the
+    /// doublings.
+    fn component_msm(
+        &mut self,
+        terms: &[(Witness, WitnessPoint)],
+    ) -> WitnessPoint {
+        let scalar_bits: Vec<[Witness; 252]> = terms
+            .iter()
+            .map(|(jubjub, _)| self.component_decomposition::<252>(*jubjub))
+            .collect();
+
+        let mut result = Self::IDENTITY;
+
+        for i in (0..252).rev() {
+            // the first doubling is the one of the identity
+            if i < 251 {
+                result = self.component_add_point(result, result);
+            }
+
+            for (bits, (_, point)) in scalar_bits.iter().zip(terms) {
+                let point_to_add =
+                    self.component_select_identity(bits[i], *point);
+                result = self.component_add_point(result, point_to_add);
+            }
+        }
+
+        result
+    }
+
     /// Conditionally selects a [`Witness`] based on an input bit.
     ///
     /// bit == 1 => a,
@@ -1049,6 +1648,15 @@ pub trait Composer: Sized + Index<Witness, Output = BlsScalar> {
     }
 
     /// Prove a circuit with a builder initialized with `constraints` capacity.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "synthesis",
+            level = "debug",
+            skip_all,
+            fields(constraints)
+        )
+    )]
     fn prove<C>(constraints: usize, circuit: &C) -> Result<Self, Error>
     where
         C: Circuit,
@@ -1059,7 +1667,10 @@ pub trait Composer: Sized + Index<Witness, Output = BlsScalar> {
 
         // assert that the circuit has the expected amount of constraints
         if builder.constraints() != constraints {
-            return Err(Error::InvalidCircuitSize);
+            return Err(Error::InvalidCircuitSize {
+                expected: constraints,
+                provided: builder.constraints(),
+            });
         }
 
         builder.runtime().event(RuntimeEvent::ProofFinished);
@@ -1067,3 +1678,105 @@ pub trait Composer: Sized + Index<Witness, Output = BlsScalar> {
         Ok(builder)
     }
 }
+
+/// Apply the Hades252 `permutation` to the witnesses of `state`
+#[cfg(feature = "alloc")]
+fn permute<C: Composer>(
+    composer: &mut C,
+    permutation: &Permutation,
+    state: &mut [Witness; WIDTH],
+) {
+    for (round, constants) in permutation.round_constants().enumerate() {
+        // round constants of the elements skipping the S-box, added by the
+        // linear layer
+        let mut pending = [BlsScalar::zero(); WIDTH];
+
+        for (i, (s, c)) in state.iter_mut().zip(constants).enumerate() {
+            match Permutation::is_full_round(round) || i == WIDTH - 1 {
+                true => *s = quintic(composer, *s, *c),
+                false => pending[i] = *c,
+            }
+        }
+
+        let mut next = [C::ZERO; WIDTH];
+        for (n, row) in next.iter_mut().zip(permutation.mds()) {
+            let terms: Vec<(BlsScalar, Witness)> =
+                row.iter().copied().zip(state.iter().copied()).collect();
+            let constant: BlsScalar =
+                row.iter().zip(pending.iter()).map(|(m, c)| m * c).sum();
+
+            *n = composer.component_linear_combination(&terms, constant);
+        }
+
+        *state = next;
+    }
+}
+
+/// Truncate `scalar` to its 250 lowest bits
+///
+/// The bits are the ones of the canonical representation of the scalar,
+/// lower than the modulus `p`, so the truncation is unique.
+#[cfg(feature = "alloc")]
+fn truncate<C: Composer>(composer: &mut C, scalar: Witness) -> Witness {
+    const BITS: usize = 250;
+
+    let bits = composer.component_decomposition::<255>(scalar);
+    let high = &bits[BITS..];
+
+    // the 250 lowest bits, as the scalar without the highest ones
+    let mut terms: Vec<(BlsScalar, Witness)> = high
+        .iter()
+        .enumerate()
+        .map(|(i, bit)| (-BlsScalar::pow_of_2((BITS + i) as u64), *bit))
+        .collect();
+    terms.push((BlsScalar::one(), scalar));
+    let low = composer.component_linear_combination(&terms, 0);
+
+    // the five highest bits of `p` are `0b11100`, so the highest bits are at
+    // most the ones of `p`, and if equal, the lowest bits are lower than the
+    // ones of `p`
+    let top =
+        composer.gate_mul(Constraint::new().mult(1).a(high[4]).b(high[3]));
+    let top = composer.gate_mul(Constraint::new().mult(1).a(top).b(high[2]));
+    let any = composer.gate_mul(
+        Constraint::new()
+            .mult(-BlsScalar::one())
+            .left(1)
+            .right(1)
+            .a(high[1])
+            .b(high[0]),
+    );
+    composer.append_gate(Constraint::new().mult(1).a(top).b(any));
+
+    // `p_low - 1 - low`, where `p_low = p - 28 · 2^250`, fits 250 bits
+    let p_low = BlsScalar::from(28) * BlsScalar::pow_of_2(BITS as u64);
+    let diff = composer.gate_add(
+        Constraint::new()
+            .left(-BlsScalar::one())
+            .a(low)
+            .constant(-p_low - BlsScalar::one()),
+    );
+    let diff = composer.component_select_zero(top, diff);
+    composer.component_decomposition::<BITS>(diff);
+
+    low
+}
+
+/// Evaluate `(x + c)^5` with three gates
+#[cfg(feature = "alloc")]
+fn quintic<C: Composer>(composer: &mut C, x: Witness, c: BlsScalar) -> Witness {
+    // (x + c)^2 = x^2 + 2c · x + c^2
+    let x2 = composer.gate_mul(
+        Constraint::new()
+            .mult(1)
+            .a(x)
+            .b(x)
+            .fourth(c.double())
+            .d(x)
+            .constant(c.square()),
+    );
+    let x4 = composer.gate_mul(Constraint::new().mult(1).a(x2).b(x2));
+
+    // (x + c)^4 · (x + c) = x^4 · x + c · x^4
+    composer.gate_mul(Constraint::new().mult(1).a(x4).b(x).fourth(c).d(x4))
+}
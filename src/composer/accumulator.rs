@@ -0,0 +1,89 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_bls12_381::{BlsScalar, G1Affine, G1Projective};
+use dusk_bytes::{DeserializableSlice, Serializable};
+use ff::Field;
+use rand_core::{CryptoRng, RngCore};
+
+/// Pairing checks deferred by [`Verifier::accumulate`](super::Verifier).
+///
+/// Every accumulated proof is reduced to a pair of points, and folded into
+/// the accumulator with a random scalar. The accumulator is valid iff every
+/// proof folded into it is valid, which is checked at once by
+/// [`Verifier::discharge`](super::Verifier) with a single multi-pairing.
+///
+/// The accumulated proofs might belong to different circuits, as long as
+/// their verifiers are compiled from the same
+/// [`PublicParameters`](crate::prelude::PublicParameters).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Accumulator {
+    pub(crate) w: G1Affine,
+    pub(crate) c: G1Affine,
+}
+
+impl Default for Accumulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Accumulator {
+    /// Create an empty accumulator
+    pub fn new() -> Self {
+        Self {
+            w: G1Affine::identity(),
+            c: G1Affine::identity(),
+        }
+    }
+
+    /// Fold the pair `(w, c)` of a pairing check into the accumulator
+    pub(crate) fn fold<R>(
+        &mut self,
+        rng: &mut R,
+        w: G1Projective,
+        c: G1Projective,
+    ) where
+        R: RngCore + CryptoRng,
+    {
+        let r = BlsScalar::random(&mut *rng);
+
+        self.w = (G1Projective::from(self.w) + w * r).into();
+        self.c = (G1Projective::from(self.c) + c * r).into();
+    }
+
+    /// Fold the pairing checks deferred into `other`
+    pub fn merge<R>(&mut self, rng: &mut R, other: &Self)
+    where
+        R: RngCore + CryptoRng,
+    {
+        self.fold(rng, other.w.into(), other.c.into());
+    }
+}
+
+impl Serializable<{ 2 * G1Affine::SIZE }> for Accumulator {
+    type Error = dusk_bytes::Error;
+
+    #[allow(unused_must_use)]
+    fn to_bytes(&self) -> [u8; Self::SIZE] {
+        use dusk_bytes::Write;
+
+        let mut buf = [0u8; Self::SIZE];
+        let mut writer = &mut buf[..];
+        writer.write(&self.w.to_bytes());
+        writer.write(&self.c.to_bytes());
+
+        buf
+    }
+
+    fn from_bytes(buf: &[u8; Self::SIZE]) -> Result<Self, Self::Error> {
+        let mut buffer = &buf[..];
+        let w = G1Affine::from_reader(&mut buffer)?;
+        let c = G1Affine::from_reader(&mut buffer)?;
+
+        Ok(Self { w, c })
+    }
+}
@@ -0,0 +1,75 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+/// Degrees of the blinding polynomials added by the
+/// [`Prover`](super::Prover) to the wire and permutation polynomials.
+///
+/// A polynomial blinded with a polynomial of degree `d`, multiplied by the
+/// vanishing polynomial of the domain, reveals nothing about the witnesses
+/// from up to `d + 1` of its evaluations outside the domain: its hiding bound.
+/// The proof opens the wires at `z` and `z·ω`, and the permutation at the
+/// same points, so the default degrees of `1` and `2` hide them with a margin
+/// for the permutation. Protocols opening the polynomials at more points raise
+/// the degrees accordingly.
+///
+/// A degree `d` raises the degree of the blinded polynomials over a domain of
+/// size `n` to `n + d`, and the quotient grows with four times the wire degree
+/// plus the permutation degree, so the commit key of the prover must fit them.
+/// The [`Verifier`](super::Verifier) is the same for any degrees.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Blinding {
+    wires: usize,
+    permutation: usize,
+}
+
+impl Default for Blinding {
+    fn default() -> Self {
+        Self::new(1, 2)
+    }
+}
+
+impl Blinding {
+    /// Create the blinding degrees of the `wires` and of the `permutation`
+    pub const fn new(wires: usize, permutation: usize) -> Self {
+        Self { wires, permutation }
+    }
+
+    /// Create the blinding degrees hiding the wire and permutation
+    /// polynomials from the given number of `openings` each, keeping the
+    /// margin of the permutation of the default degrees
+    ///
+    /// Returns `None` if `openings` is zero.
+    pub const fn hiding(openings: usize) -> Option<Self> {
+        match openings {
+            0 => None,
+            _ => Some(Self::new(openings - 1, openings)),
+        }
+    }
+
+    /// Degree of the blinding polynomials of the wires
+    pub const fn wires(&self) -> usize {
+        self.wires
+    }
+
+    /// Degree of the blinding polynomial of the permutation
+    pub const fn permutation(&self) -> usize {
+        self.permutation
+    }
+
+    /// Number of evaluations of the wire and permutation polynomials,
+    /// respectively, that reveal nothing about the witnesses
+    pub const fn hiding_bound(&self) -> (usize, usize) {
+        (self.wires + 1, self.permutation + 1)
+    }
+
+    /// Degree added to the polynomials committed by the prover, reached by
+    /// the highest part of the split quotient
+    ///
+    /// The default degrees add the `6` degrees of the public parameters.
+    pub(crate) fn added_degree(&self) -> usize {
+        4 * self.wires + self.permutation
+    }
+}
@@ -11,10 +11,11 @@ use dusk_bls12_381::BlsScalar;
 use hashbrown::HashMap;
 
 use crate::constraint_system::{Constraint, Selector, WiredWitness, Witness};
+use crate::error::Error;
 use crate::permutation::Permutation;
 use crate::runtime::Runtime;
 
-use super::{Composer, Polynomial};
+use super::{optimizer, Composer, Polynomial};
 
 /// Construct and prove circuits
 #[derive(Debug, Clone)]
@@ -33,6 +34,9 @@ pub struct Builder {
 
     /// PLONK runtime controller
     pub(crate) runtime: Runtime,
+
+    /// Labels of the gates, with the index of the first gate they apply to
+    pub(crate) labels: Vec<(usize, &'static str)>,
 }
 
 impl Builder {
@@ -52,6 +56,83 @@ impl Builder {
             .collect()
     }
 
+    /// Check that every gate is wired to witnesses allocated by the builder
+    pub(crate) fn check_witnesses(&self) -> Result<(), Error> {
+        self.constraints
+            .iter()
+            .enumerate()
+            .try_for_each(|(gate, poly)| {
+                match poly.foreign_wire(self.witnesses.len()) {
+                    Some(w) => Err(Error::WitnessNotFound {
+                        gate,
+                        witness: w.index(),
+                    }),
+                    None => Ok(()),
+                }
+            })
+    }
+
+    /// Value assigned to the `witness`, or `None` if it wasn't allocated by
+    /// the builder
+    pub fn try_witness_of(&self, witness: Witness) -> Option<BlsScalar> {
+        self.witnesses.get(witness.index()).copied()
+    }
+
+    /// Export the values of the wires of every gate, in gate order, as
+    /// `[a, b, o, d]`.
+    ///
+    /// The rows can be compared against the assignment of another run, or
+    /// replayed with [`Self::import_witness`].
+    pub fn export_witness(&self) -> Vec<[BlsScalar; 4]> {
+        self.constraints
+            .iter()
+            .map(|poly| {
+                poly.wires().map(|w| {
+                    self.witnesses.get(w.index()).copied().unwrap_or_default()
+                })
+            })
+            .collect()
+    }
+
+    /// Assign the values of the wires of every gate, given in gate order as
+    /// `[a, b, o, d]` by [`Self::export_witness`].
+    ///
+    /// Returns an error if the number of rows doesn't match the gates, if a
+    /// gate is wired to a witness that wasn't allocated, or if the rows
+    /// assign different values to the same witness. The builder is left
+    /// untouched on error.
+    pub fn import_witness(
+        &mut self,
+        rows: &[[BlsScalar; 4]],
+    ) -> Result<(), Error> {
+        if rows.len() != self.constraints.len() {
+            return Err(Error::InvalidCircuitSize {
+                expected: self.constraints.len(),
+                provided: rows.len(),
+            });
+        }
+        self.check_witnesses()?;
+
+        let mut witnesses = self.witnesses.clone();
+        let mut assigned = vec![false; witnesses.len()];
+
+        let gates = self.constraints.iter().zip(rows).enumerate();
+        for (gate, (poly, row)) in gates {
+            for (w, value) in poly.wires().iter().zip(row) {
+                let witness = w.index();
+                if assigned[witness] && witnesses[witness] != *value {
+                    return Err(Error::InconsistentWitness { gate, witness });
+                }
+
+                witnesses[witness] = *value;
+                assigned[witness] = true;
+            }
+        }
+
+        self.witnesses = witnesses;
+        Ok(())
+    }
+
     pub(crate) fn dense_public_inputs(
         public_input_indexes: &[usize],
         public_inputs: &[BlsScalar],
@@ -68,11 +149,17 @@ impl Builder {
     }
 }
 
+/// Value of a witness that wasn't allocated by the builder
+const FOREIGN_WITNESS: BlsScalar = BlsScalar::zero();
+
+/// A witness that wasn't allocated by the builder evaluates to zero, so the
+/// gadgets wired to it don't panic. The gates wired to it are reported as
+/// [`Error::WitnessNotFound`] when the circuit is compiled or proved.
 impl ops::Index<Witness> for Builder {
     type Output = BlsScalar;
 
     fn index(&self, w: Witness) -> &Self::Output {
-        &self.witnesses[w.index()]
+        self.witnesses.get(w.index()).unwrap_or(&FOREIGN_WITNESS)
     }
 }
 
@@ -84,6 +171,7 @@ impl Composer for Builder {
             witnesses: Vec::new(),
             perm: Permutation::new(),
             runtime: Runtime::new(),
+            labels: Vec::new(),
         }
     }
 
@@ -143,6 +231,7 @@ impl Composer for Builder {
             w_d,
         };
 
+        let foreign = poly.foreign_wire(self.witnesses.len());
         self.constraints.push(poly);
 
         if constraint.has_public_input() {
@@ -151,10 +240,30 @@ impl Composer for Builder {
             self.public_inputs.insert(n, pi);
         }
 
-        self.perm.add_witnesses_to_map(w_a, w_b, w_o, w_d, n);
+        // A gate wired to a foreign witness is left out of the permutation,
+        // and rejected before the permutation is used
+        if foreign.is_none() {
+            self.perm.add_witnesses_to_map(w_a, w_b, w_o, w_d, n);
+        }
     }
 
     fn runtime(&mut self) -> &mut Runtime {
         &mut self.runtime
     }
+
+    fn label(&mut self, label: &'static str) {
+        self.labels.push((self.constraints.len(), label));
+    }
+
+    fn eliminate_common_subexpressions(&mut self) -> usize {
+        optimizer::eliminate_common_subexpressions(self)
+    }
+
+    fn fold_constants(&mut self) -> usize {
+        optimizer::fold_constants(self)
+    }
+
+    fn eliminate_dead_gates(&mut self, keep: &[Witness]) -> usize {
+        optimizer::eliminate_dead_gates(self, keep)
+    }
 }
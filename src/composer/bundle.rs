@@ -0,0 +1,133 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use alloc::vec::Vec;
+
+use dusk_bls12_381::BlsScalar;
+use dusk_bytes::{DeserializableSlice, Serializable};
+
+use crate::error::Error;
+use crate::proof_system::Proof;
+
+/// Proof packaged with everything needed to verify it, created with
+/// [`Prover::prove_bundle`](super::Prover::prove_bundle) and verified with
+/// [`Verifier::verify_bundle`](super::Verifier::verify_bundle).
+///
+/// The bundle carries the public inputs in the order the prover appended
+/// them, and the [fingerprint](super::Verifier::fingerprint) of the circuit,
+/// so it can't be verified against the wrong circuit or with reordered public
+/// inputs.
+///
+/// The bundle is serialized as:
+///
+/// | field         | size                       |                                    |
+/// |---------------|----------------------------|------------------------------------|
+/// | version       | 1                          | [`Self::VERSION`]                  |
+/// | fingerprint   | 32                         | fingerprint of the circuit         |
+/// | proof         | [`Proof::VERSIONED_SIZE`]  | [`Proof::to_versioned_bytes`]      |
+/// | length        | 8                          | big endian number of public inputs |
+/// | public inputs | 32 × `length`              | the public inputs                  |
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProofBundle {
+    proof: Proof,
+    public_inputs: Vec<BlsScalar>,
+    fingerprint: [u8; 32],
+}
+
+impl ProofBundle {
+    /// Version of the protocol of the bundle and of its encoding
+    pub const VERSION: u8 = 1;
+
+    /// Package a `proof` of the circuit of the given `fingerprint` with its
+    /// `public_inputs`
+    pub fn new(
+        proof: Proof,
+        public_inputs: Vec<BlsScalar>,
+        fingerprint: [u8; 32],
+    ) -> Self {
+        Self {
+            proof,
+            public_inputs,
+            fingerprint,
+        }
+    }
+
+    /// Proof of the bundle
+    pub fn proof(&self) -> &Proof {
+        &self.proof
+    }
+
+    /// Public inputs of the proof, in the order of the circuit
+    pub fn public_inputs(&self) -> &[BlsScalar] {
+        &self.public_inputs
+    }
+
+    /// Fingerprint of the circuit of the proof
+    pub fn fingerprint(&self) -> &[u8; 32] {
+        &self.fingerprint
+    }
+
+    /// Serialize the bundle into bytes
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let size = 1
+            + self.fingerprint.len()
+            + Proof::VERSIONED_SIZE
+            + 8
+            + self.public_inputs.len() * BlsScalar::SIZE;
+
+        let mut bytes = Vec::with_capacity(size);
+
+        bytes.push(Self::VERSION);
+        bytes.extend(self.fingerprint);
+        bytes.extend(self.proof.to_versioned_bytes());
+        bytes.extend((self.public_inputs.len() as u64).to_be_bytes());
+        self.public_inputs
+            .iter()
+            .for_each(|pi| bytes.extend(pi.to_bytes()));
+
+        bytes
+    }
+
+    /// Deserialize a bundle from bytes generated via [`Self::to_bytes`]
+    pub fn from_slice(bytes: &[u8]) -> Result<Self, Error> {
+        let bytes = match bytes.split_first() {
+            Some((&Self::VERSION, bytes)) => bytes,
+            Some((&version, _)) => {
+                return Err(Error::UnsupportedVersion { version })
+            }
+            None => return Err(Error::NotEnoughBytes),
+        };
+
+        if bytes.len() < 32 + Proof::VERSIONED_SIZE + 8 {
+            return Err(Error::NotEnoughBytes);
+        }
+
+        let (fingerprint, bytes) = bytes.split_at(32);
+        let fingerprint = fingerprint.try_into().expect("checked len");
+
+        let (proof, bytes) = bytes.split_at(Proof::VERSIONED_SIZE);
+        let proof = Proof::from_versioned_bytes(proof)?;
+
+        let (len, bytes) = bytes.split_at(8);
+        let len = <[u8; 8]>::try_from(len).expect("checked len");
+        let len = u64::from_be_bytes(len) as usize;
+
+        if Some(bytes.len()) != len.checked_mul(BlsScalar::SIZE) {
+            return Err(Error::NotEnoughBytes);
+        }
+
+        let public_inputs = bytes
+            .chunks_exact(BlsScalar::SIZE)
+            .map(BlsScalar::from_slice)
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            proof,
+            public_inputs,
+            fingerprint,
+        })
+    }
+}
@@ -0,0 +1,101 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Encoding of the provers and verifiers cached to disk.
+//!
+//! The serialized prover or verifier is framed as:
+//!
+//! | field        | size      |                                      |
+//! |--------------|-----------|--------------------------------------|
+//! | version      | 1         | [`VERSION`]                          |
+//! | circuit hash | 32        | [`VerifierKey::circuit_hash`]        |
+//! | length       | 8         | big endian length of the payload     |
+//! | payload      | `length`  | `to_bytes` of the prover or verifier |
+//! | checksum     | 32        | SHA-256 of all the previous fields   |
+
+#[cfg(doc)]
+use crate::proof_system::VerifierKey;
+
+/// Version of the encoding of the cached provers and verifiers
+const VERSION: u8 = 1;
+
+pub(crate) use io::{read, write};
+
+mod io {
+    use alloc::vec::Vec;
+    use std::io::{Read, Write};
+
+    use sha2::{Digest, Sha256};
+
+    use super::VERSION;
+    use crate::error::Error;
+
+    /// Write the `payload` framed with its `circuit_hash` and checksum
+    pub(crate) fn write<W: Write>(
+        mut writer: W,
+        circuit_hash: &[u8; 32],
+        payload: &[u8],
+    ) -> Result<(), Error> {
+        let mut hasher = Sha256::new();
+        let mut write = |bytes: &[u8]| {
+            hasher.update(bytes);
+            writer.write_all(bytes)
+        };
+
+        write(&[VERSION])?;
+        write(circuit_hash)?;
+        write(&(payload.len() as u64).to_be_bytes())?;
+        write(payload)?;
+
+        writer.write_all(&hasher.finalize())?;
+        writer.flush()?;
+
+        Ok(())
+    }
+
+    /// Read a payload written with [`write`], returning it along with its
+    /// circuit hash once its checksum is verified
+    pub(crate) fn read<R: Read>(
+        mut reader: R,
+    ) -> Result<([u8; 32], Vec<u8>), Error> {
+        let mut hasher = Sha256::new();
+
+        let mut version = [0u8; 1];
+        reader.read_exact(&mut version)?;
+        if version[0] != VERSION {
+            return Err(Error::UnsupportedVersion {
+                version: version[0],
+            });
+        }
+        hasher.update(version);
+
+        let mut circuit_hash = [0u8; 32];
+        reader.read_exact(&mut circuit_hash)?;
+        hasher.update(circuit_hash);
+
+        let mut len = [0u8; 8];
+        reader.read_exact(&mut len)?;
+        hasher.update(len);
+
+        // the payload is read through `take` so a corrupted length fails on
+        // the missing bytes instead of allocating them upfront
+        let len = u64::from_be_bytes(len);
+        let mut payload = Vec::new();
+        reader.by_ref().take(len).read_to_end(&mut payload)?;
+        if payload.len() as u64 != len {
+            return Err(Error::NotEnoughBytes);
+        }
+        hasher.update(&payload);
+
+        let mut checksum = [0u8; 32];
+        reader.read_exact(&mut checksum)?;
+        if checksum[..] != hasher.finalize()[..] {
+            return Err(Error::InvalidChecksum);
+        }
+
+        Ok((circuit_hash, payload))
+    }
+}
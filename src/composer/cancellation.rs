@@ -0,0 +1,174 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use core::future::Future;
+use core::pin::Pin;
+use core::sync::atomic::{AtomicBool, Ordering};
+use core::task::{Context, Poll, Waker};
+#[cfg(not(feature = "std"))]
+use core::task::{RawWaker, RawWakerVTable};
+
+use super::ProveStage;
+use crate::error::Error;
+
+/// Token aborting the proofs of
+/// [`Prover::prove_async`](super::Prover::prove_async) between two rounds of
+/// the prover.
+///
+/// Clones of the token share the same state, so a proof can be cancelled from
+/// another thread or task, e.g. when a deadline is reached.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    /// Create a token that isn't cancelled
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancel the proofs observing the token
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Release);
+    }
+
+    /// Check if the token was cancelled
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Acquire)
+    }
+}
+
+/// Observer of the completed stages of the prover
+type Observer<'a> = &'a mut (dyn FnMut(ProveStage) + Send);
+
+/// Checkpoint of the prover between two rounds
+#[derive(Default)]
+pub(crate) struct Checkpoint<'a> {
+    token: Option<&'a CancellationToken>,
+    yields: bool,
+    observer: Option<Observer<'a>>,
+}
+
+impl<'a> Checkpoint<'a> {
+    /// Checkpoint yielding to the executor and observing `token`
+    pub(crate) fn cancellable(token: &'a CancellationToken) -> Self {
+        Self {
+            token: Some(token),
+            yields: true,
+            observer: None,
+        }
+    }
+
+    /// Checkpoint reporting the completed stages to `observer`
+    pub(crate) fn observed(observer: Observer<'a>) -> Self {
+        Self {
+            token: None,
+            yields: false,
+            observer: Some(observer),
+        }
+    }
+
+    /// Report the completion of a stage of the prover
+    pub(crate) fn completed(&mut self, stage: ProveStage) {
+        if let Some(observer) = self.observer.as_mut() {
+            observer(stage);
+        }
+    }
+
+    /// Enter the given round of the prover, failing if the proof was
+    /// cancelled
+    pub(crate) async fn round(&mut self, _round: usize) -> Result<(), Error> {
+        if self.yields {
+            YieldNow(false).await;
+        }
+
+        match self.token {
+            Some(token) if token.is_cancelled() => Err(Error::ProofCancelled),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Future returning [`Poll::Pending`] once, so the executor can run other
+/// tasks
+struct YieldNow(bool);
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.0 {
+            return Poll::Ready(());
+        }
+
+        self.0 = true;
+        cx.waker().wake_by_ref();
+
+        Poll::Pending
+    }
+}
+
+/// Run a future to completion on the current thread.
+///
+/// The thread is parked while the future is pending, and unparked by its
+/// waker.
+#[cfg(feature = "std")]
+pub(crate) fn block_on<F: Future>(future: F) -> F::Output {
+    use std::task::Wake;
+    use std::thread::{self, Thread};
+
+    /// Waker unparking the thread blocked on the future
+    struct Unpark(Thread);
+
+    impl Wake for Unpark {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+
+        fn wake_by_ref(self: &Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let waker = Waker::from(Arc::new(Unpark(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+
+    let mut future = Box::pin(future);
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+/// Run a future to completion on the current thread.
+///
+/// Without `std` the thread can't be parked, so the future is polled in a
+/// loop, which is only suitable for futures that are pending just to yield,
+/// such as the rounds of the prover.
+#[cfg(not(feature = "std"))]
+pub(crate) fn block_on<F: Future>(future: F) -> F::Output {
+    const VTABLE: RawWakerVTable = RawWakerVTable::new(
+        |_| RawWaker::new(core::ptr::null(), &VTABLE),
+        |_| {},
+        |_| {},
+        |_| {},
+    );
+
+    // Safety: the vtable functions don't use the data pointer
+    let waker =
+        unsafe { Waker::from_raw(RawWaker::new(core::ptr::null(), &VTABLE)) };
+    let mut cx = Context::from_waker(&waker);
+
+    let mut future = Box::pin(future);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
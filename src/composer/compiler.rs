@@ -15,8 +15,11 @@ use crate::error::Error;
 use crate::fft::{EvaluationDomain, Evaluations, Polynomial as FftPolynomial};
 use crate::proof_system::preprocess::Polynomials;
 use crate::proof_system::{widget, ProverKey};
+use crate::transcript::TranscriptProtocol;
 
-use super::{Builder, Circuit, Composer, Polynomial, Prover, Verifier};
+use super::{
+    Blinding, Builder, Circuit, Composer, Polynomial, Prover, Verifier,
+};
 
 #[cfg(feature = "alloc")]
 mod compress;
@@ -24,6 +27,31 @@ mod compress;
 /// Generate the arguments to prove and verify a circuit
 pub struct Compiler;
 
+/// Radix of the FFTs over the evaluation domain of a circuit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Radix {
+    /// Domains of size `2^k`
+    Two,
+    /// Domains of size `2^k` or `3·2^k`
+    Mixed,
+}
+
+impl Radix {
+    /// Smallest domain size of the radix fitting `constraints`
+    fn size(&self, constraints: usize) -> usize {
+        match self {
+            Self::Two => constraints.next_power_of_two(),
+            Self::Mixed => EvaluationDomain::mixed_radix_size(constraints),
+        }
+    }
+
+    /// Degree of the public parameters needed to compile the circuit of
+    /// `builder`
+    fn degree(&self, builder: &Builder) -> usize {
+        self.size(builder.constraints() + 6)
+    }
+}
+
 impl Compiler {
     /// Create a new arguments set from a given circuit instance
     ///
@@ -38,7 +66,7 @@ impl Compiler {
         let mut builder = Builder::initialized();
         C::default().circuit(&mut builder)?;
 
-        Self::compile_with_builder(pp, label, &builder)
+        Self::compile_with_builder(pp, label, &builder, Radix::Two)
     }
 
     /// Create a new arguments set from a given circuit instance
@@ -55,7 +83,79 @@ impl Compiler {
         let mut builder = Builder::initialized();
         circuit.circuit(&mut builder)?;
 
-        Self::compile_with_builder(pp, label, &builder)
+        Self::compile_with_builder(pp, label, &builder, Radix::Two)
+    }
+
+    /// Create a new arguments set from a given circuit instance, deriving the
+    /// challenges of the protocol with the transcript `T`
+    ///
+    /// Use the default implementation of the circuit
+    pub fn compile_with_transcript<C, T>(
+        pp: &PublicParameters,
+        label: &[u8],
+    ) -> Result<(Prover<T>, Verifier<T>), Error>
+    where
+        C: Circuit,
+        T: TranscriptProtocol,
+    {
+        let mut builder = Builder::initialized();
+        C::default().circuit(&mut builder)?;
+
+        Self::compile_with_builder(pp, label, &builder, Radix::Two)
+    }
+
+    /// Create a new arguments set from a given circuit instance, over an
+    /// evaluation domain of size `2^k` or `3·2^k`, whichever is the smallest.
+    ///
+    /// Circuits just above a power of two are then proven over a domain
+    /// `3/4` of the size of [`Self::compile`], with public parameters of a
+    /// lower degree. The FFTs over domains of size `3·2^k` are computed with
+    /// a mixed radix, and the proofs are only compatible with a verifier
+    /// compiled the same way.
+    ///
+    /// Use the default implementation of the circuit
+    pub fn compile_mixed_radix<C>(
+        pp: &PublicParameters,
+        label: &[u8],
+    ) -> Result<(Prover, Verifier), Error>
+    where
+        C: Circuit,
+    {
+        let mut builder = Builder::initialized();
+        C::default().circuit(&mut builder)?;
+
+        Self::compile_with_builder(pp, label, &builder, Radix::Mixed)
+    }
+
+    /// Create a new arguments set from a given circuit instance, with a prover
+    /// blinding its polynomials with the given degrees
+    ///
+    /// The commit key of the prover is trimmed to fit the blinded polynomials,
+    /// and the verifier is the one of [`Self::compile`].
+    ///
+    /// Use the default implementation of the circuit
+    pub fn compile_with_blinding<C>(
+        pp: &PublicParameters,
+        label: &[u8],
+        blinding: Blinding,
+    ) -> Result<(Prover, Verifier), Error>
+    where
+        C: Circuit,
+    {
+        let mut builder = Builder::initialized();
+        C::default().circuit(&mut builder)?;
+        builder.check_witnesses()?;
+
+        let added_degree = blinding
+            .added_degree()
+            .saturating_sub(Blinding::default().added_degree());
+        let degree = Radix::Two.degree(&builder) + added_degree;
+        let (commit, opening) = pp.trim(degree)?;
+
+        let (prover, verifier) =
+            Self::preprocess(label, commit, opening, &builder, Radix::Two)?;
+
+        Ok((prover.with_blinding(blinding)?, verifier))
     }
 
     /// Return a bytes representation of a compressed circuit, capable of
@@ -81,35 +181,55 @@ impl Compiler {
     /// Create a new arguments set from a given circuit instance
     ///
     /// Use the default implementation of the circuit
-    fn compile_with_builder(
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(constraints = builder.constraints())
+        )
+    )]
+    fn compile_with_builder<T: TranscriptProtocol>(
         pp: &PublicParameters,
         label: &[u8],
         builder: &Builder,
-    ) -> Result<(Prover, Verifier), Error> {
-        let n = (builder.constraints() + 6).next_power_of_two();
+        radix: Radix,
+    ) -> Result<(Prover<T>, Verifier<T>), Error> {
+        builder.check_witnesses()?;
 
-        let (commit, opening) = pp.trim(n)?;
+        let (commit, opening) = pp.trim(radix.degree(builder))?;
 
         let (prover, verifier) =
-            Self::preprocess(label, commit, opening, &builder)?;
+            Self::preprocess(label, commit, opening, &builder, radix)?;
 
         Ok((prover, verifier))
     }
 
-    fn preprocess(
+    /// Degree of the public parameters needed to compile the circuit of
+    /// `builder`
+    pub(crate) fn degree(builder: &Builder) -> usize {
+        Radix::Two.degree(builder)
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all)
+    )]
+    fn preprocess<T: TranscriptProtocol>(
         label: &[u8],
         commit_key: CommitKey,
         opening_key: OpeningKey,
         prover: &Builder,
-    ) -> Result<(Prover, Verifier), Error> {
+        radix: Radix,
+    ) -> Result<(Prover<T>, Verifier<T>), Error> {
         let mut perm = prover.perm.clone();
 
         let constraints = prover.constraints();
-        let size = constraints.next_power_of_two();
+        let size = radix.size(constraints);
 
-        let domain = EvaluationDomain::new(size - 1)?;
+        let domain = EvaluationDomain::new_mixed_radix(size)?;
 
-        // 1. pad circuit to a power of two
+        // 1. pad circuit to the size of the domain
         //
         // we use allocated vectors because the current ifft api only accepts
         // slices
@@ -268,8 +388,8 @@ impl Compiler {
         // The polynomial needs an evaluation domain of 4n.
         // Plus, adding the blinding factors translates to
         // the polynomial not fitting in 4n, so now we need
-        // 8n, the next power of 2
-        let domain_8n = EvaluationDomain::new(8 * domain.size())?;
+        // 8n
+        let domain_8n = EvaluationDomain::new_mixed_radix(8 * domain.size())?;
 
         let q_m_eval_8n = Evaluations::from_vec_and_domain(
             domain_8n.coset_fft(&selectors.q_m),
@@ -395,6 +515,7 @@ impl Compiler {
             variable_base: curve_addition_prover_key,
             fixed_base: ecc_prover_key,
             v_h_coset_8n,
+            circuit_hash: Some(verifier_key.circuit_hash()),
         };
 
         let public_input_indexes = prover.public_input_indexes();
@@ -408,7 +529,7 @@ impl Compiler {
             verifier_key.clone(),
             size,
             constraints,
-        );
+        )?;
 
         let verifier = Verifier::new(
             label,
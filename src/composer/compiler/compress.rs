@@ -12,10 +12,9 @@ use alloc::vec::Vec;
 
 use super::{
     BlsScalar, Builder, Circuit, Compiler, Composer, Constraint, Error,
-    Polynomial, Prover, PublicParameters, Selector, Verifier, Witness,
+    Polynomial, Prover, PublicParameters, Radix, Selector, Verifier, Witness,
 };
-
-mod hades;
+use crate::hades;
 
 #[derive(
     Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, MsgPacker,
@@ -370,6 +369,6 @@ impl CompressedCircuit {
             builder.append_custom_gate(constraint);
         }
 
-        Compiler::compile_with_builder(pp, label, &builder)
+        Compiler::compile_with_builder(pp, label, &builder, Radix::Two)
     }
 }
@@ -0,0 +1,123 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_bls12_381::BlsScalar;
+use dusk_bytes::Serializable;
+
+use crate::proof_system::Proof;
+
+/// Points of the multi-scalar multiplications of a verification, in order:
+/// the split quotient, the linearization, the two aggregated openings, and
+/// the accumulated commitments and witnesses of the pairing check
+const MSM_SIZES: [usize; 6] = [4, 12, 9, 4, 5, 2];
+
+/// Pairs of the pairing check
+const PAIRINGS: usize = 2;
+
+/// Inversions of scalars, for the vanishing polynomial, the first Lagrange
+/// polynomial and the batched evaluation of the public inputs
+const INVERSIONS: usize = 3;
+
+/// Exponentiations of the evaluation challenge
+const EXPONENTIATIONS: usize = 5;
+
+/// Challenges squeezed from the transcript
+const CHALLENGES: usize = 11;
+
+/// Gas of a `G1MSM` call of EIP-2537 without discount, per point
+const EIP2537_G1_MUL: u64 = 12000;
+
+/// Discounts of the `G1MSM` calls of EIP-2537, per mille, by number of points
+const EIP2537_G1_DISCOUNTS: [u64; 16] = [
+    1000, 949, 848, 797, 764, 750, 738, 728, 719, 712, 705, 698, 692, 687, 682,
+    677,
+];
+
+/// Gas of a `PAIRING_CHECK` call of EIP-2537, per pair and base
+const EIP2537_PAIRING: (u64, u64) = (32600, 37700);
+
+/// Cost profile of the verification of a proof, returned by
+/// [`Verifier::cost`](super::Verifier::cost).
+///
+/// The counts are those of the verifier of this crate, and don't depend on
+/// the size of the circuit except for the public inputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VerifierCost {
+    public_inputs: usize,
+}
+
+impl VerifierCost {
+    pub(crate) const fn new(public_inputs: usize) -> Self {
+        Self { public_inputs }
+    }
+
+    /// Number of public inputs of a proof
+    pub const fn public_inputs(&self) -> usize {
+        self.public_inputs
+    }
+
+    /// Size of a proof, in bytes
+    pub const fn proof_size(&self) -> usize {
+        Proof::SIZE
+    }
+
+    /// Size of the public inputs of a proof, in bytes
+    pub const fn public_inputs_size(&self) -> usize {
+        self.public_inputs * BlsScalar::SIZE
+    }
+
+    /// Number of points of every multi-scalar multiplication over G1
+    pub const fn msm_sizes(&self) -> &'static [usize] {
+        &MSM_SIZES
+    }
+
+    /// Total number of scalar multiplications over G1
+    pub fn g1_scalar_muls(&self) -> usize {
+        MSM_SIZES.iter().sum()
+    }
+
+    /// Number of pairings, computed with a single final exponentiation
+    pub const fn pairings(&self) -> usize {
+        PAIRINGS
+    }
+
+    /// Number of inversions of scalars
+    ///
+    /// The public inputs are evaluated with a single batched inversion.
+    pub const fn field_inversions(&self) -> usize {
+        INVERSIONS
+    }
+
+    /// Maximum number of exponentiations of scalars, five for the powers of
+    /// the evaluation challenge and one for the evaluation of every public
+    /// input that isn't zero
+    pub const fn field_exponentiations(&self) -> usize {
+        EXPONENTIATIONS + self.public_inputs
+    }
+
+    /// Number of challenges squeezed from the transcript
+    pub const fn challenges(&self) -> usize {
+        CHALLENGES
+    }
+
+    /// Estimate of the gas of the elliptic curve operations of a verifier on
+    /// an EVM with the BLS12-381 precompiles of EIP-2537.
+    ///
+    /// Every multi-scalar multiplication is a `G1MSM` call and the pairings a
+    /// single `PAIRING_CHECK` call. The calldata, the scalar arithmetic and
+    /// the transcript are not accounted for.
+    pub fn eip2537_gas(&self) -> u64 {
+        let msm_gas = MSM_SIZES.iter().map(|&k| {
+            let discount = EIP2537_G1_DISCOUNTS[k.min(16) - 1];
+            k as u64 * EIP2537_G1_MUL * discount / 1000
+        });
+
+        let (per_pair, base) = EIP2537_PAIRING;
+        let pairing_gas = PAIRINGS as u64 * per_pair + base;
+
+        msm_gas.sum::<u64>() + pairing_gas
+    }
+}
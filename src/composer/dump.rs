@@ -0,0 +1,104 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use alloc::string::String;
+use core::fmt::{self, Write};
+use core::ops::Range;
+
+use dusk_bls12_381::BlsScalar;
+
+use super::{Builder, Polynomial};
+
+/// Scalar as a signed integer when it fits in 64 bits, and in hexadecimal
+/// otherwise
+struct Short(BlsScalar);
+
+impl fmt::Display for Short {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let small = |scalar: BlsScalar| {
+            let bytes = scalar.to_bytes();
+            match bytes[8..].iter().all(|b| *b == 0) {
+                true => {
+                    let mut le = [0u8; 8];
+                    le.copy_from_slice(&bytes[..8]);
+                    Some(u64::from_le_bytes(le))
+                }
+                false => None,
+            }
+        };
+
+        match (small(self.0), small(-self.0)) {
+            (Some(n), _) => write!(f, "{n}"),
+            (_, Some(n)) => write!(f, "-{n}"),
+            _ => write!(f, "{:?}", self.0),
+        }
+    }
+}
+
+impl Builder {
+    /// Table of the gates in `range`, one per line, with their non-zero
+    /// selectors, their public input, and the witnesses of their wires with
+    /// their current values.
+    ///
+    /// The range is clamped to the gates of the builder. Print the table to
+    /// inspect the rows around a failing constraint:
+    ///
+    /// ```
+    /// use dusk_plonk::prelude::*;
+    ///
+    /// let mut builder = Builder::initialized();
+    /// let a = builder.append_witness(BlsScalar::from(3));
+    /// let b = builder.append_witness(BlsScalar::from(5));
+    /// builder.gate_mul(Constraint::new().mult(1).a(a).b(b));
+    ///
+    /// let last = builder.constraints() - 1;
+    /// println!("{}", builder.dump_gates(last..last + 1));
+    /// ```
+    pub fn dump_gates(&self, range: Range<usize>) -> String {
+        let end = range.end.min(self.constraints.len());
+        let start = range.start.min(end);
+
+        let mut table = String::new();
+        self.constraints[start..end].iter().enumerate().for_each(
+            |(i, poly)| {
+                // writing to a string doesn't fail
+                let _ = self.dump_gate(&mut table, start + i, poly);
+            },
+        );
+
+        table
+    }
+
+    fn dump_gate(
+        &self,
+        table: &mut String,
+        gate: usize,
+        poly: &Polynomial,
+    ) -> fmt::Result {
+        write!(table, "{gate:>6} |")?;
+
+        for (name, selector) in poly.named_selectors() {
+            if selector != BlsScalar::zero() {
+                write!(table, " {name}={}", Short(selector))?;
+            }
+        }
+        if let Some(pi) = self.public_inputs.get(&gate) {
+            write!(table, " pi={}", Short(*pi))?;
+        }
+        write!(table, " |")?;
+
+        for (name, w) in Polynomial::WIRE_NAMES.iter().zip(poly.wires()) {
+            match self.witnesses.get(w.index()) {
+                Some(value) => {
+                    write!(table, " {name}=#{}:{}", w.index(), Short(*value))?
+                }
+                None => write!(table, " {name}=#{}:?", w.index())?,
+            }
+        }
+
+        writeln!(table)
+    }
+}
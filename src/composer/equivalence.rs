@@ -0,0 +1,145 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Structural comparison of synthesized circuits.
+//!
+//! Two circuits are equivalent when they have the same gates, with the same
+//! selectors and public input positions, and the same wiring up to a renaming
+//! of the witnesses. Equivalent circuits have the same preprocessed keys, so a
+//! refactored gadget can be checked against the original one.
+
+use core::fmt;
+
+use hashbrown::HashMap;
+
+use crate::constraint_system::Witness;
+use crate::error::Error;
+
+use super::{Builder, Circuit, Compiler, Composer, Polynomial};
+
+/// First divergence between two circuits, as found by
+/// [`Builder::first_divergence`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Divergence {
+    /// The circuits have a different number of gates
+    Constraints {
+        /// Gates of the first circuit
+        left: usize,
+        /// Gates of the second circuit
+        right: usize,
+    },
+    /// A selector of a gate differs
+    Selector {
+        /// Index of the gate
+        gate: usize,
+        /// Name of the selector, such as `q_m`
+        selector: &'static str,
+    },
+    /// A gate holds a public input in only one of the circuits
+    PublicInput {
+        /// Index of the gate
+        gate: usize,
+    },
+    /// A wire of a gate isn't connected to the same wires in both circuits
+    Wiring {
+        /// Index of the gate
+        gate: usize,
+        /// Name of the wire, such as `w_a`
+        wire: &'static str,
+    },
+}
+
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Constraints { left, right } => {
+                write!(f, "the circuits have {left} and {right} gates")
+            }
+            Self::Selector { gate, selector } => {
+                write!(f, "the selector {selector} of the gate {gate} differs")
+            }
+            Self::PublicInput { gate } => {
+                write!(f, "the public input of the gate {gate} differs")
+            }
+            Self::Wiring { gate, wire } => {
+                write!(f, "the wiring of {wire} of the gate {gate} differs")
+            }
+        }
+    }
+}
+
+impl Builder {
+    /// Compare the synthesized circuit with `other`, and return their first
+    /// divergence in gate order, or `None` if they are equivalent.
+    ///
+    /// The witnesses may be allocated in a different order: the wiring only
+    /// has to match up to a renaming of the witnesses. The values of the
+    /// witnesses and of the public inputs are ignored.
+    pub fn first_divergence(&self, other: &Self) -> Option<Divergence> {
+        if self.constraints.len() != other.constraints.len() {
+            return Some(Divergence::Constraints {
+                left: self.constraints.len(),
+                right: other.constraints.len(),
+            });
+        }
+
+        // renaming of the witnesses, in both directions
+        let mut renaming: HashMap<Witness, Witness> = HashMap::new();
+        let mut inverse: HashMap<Witness, Witness> = HashMap::new();
+
+        let gates = self.constraints.iter().zip(other.constraints.iter());
+        for (gate, (left, right)) in gates.enumerate() {
+            let selectors = left
+                .named_selectors()
+                .into_iter()
+                .zip(right.named_selectors());
+            for ((selector, l), (_, r)) in selectors {
+                if l != r {
+                    return Some(Divergence::Selector { gate, selector });
+                }
+            }
+
+            if self.public_inputs.contains_key(&gate)
+                != other.public_inputs.contains_key(&gate)
+            {
+                return Some(Divergence::PublicInput { gate });
+            }
+
+            let wires = Polynomial::WIRE_NAMES
+                .into_iter()
+                .zip(left.wires())
+                .zip(right.wires());
+            for ((wire, l), r) in wires {
+                let renamed = *renaming.entry(l).or_insert(r);
+                let original = *inverse.entry(r).or_insert(l);
+                if renamed != r || original != l {
+                    return Some(Divergence::Wiring { gate, wire });
+                }
+            }
+        }
+
+        None
+    }
+}
+
+impl Compiler {
+    /// Compare the default circuits `A` and `B` with
+    /// [`Builder::first_divergence`], returning their first divergence or
+    /// `None` if they compile to the same circuit description.
+    pub fn first_divergence<A, B>() -> Result<Option<Divergence>, Error>
+    where
+        A: Circuit,
+        B: Circuit,
+    {
+        let mut left = Builder::initialized();
+        A::default().circuit(&mut left)?;
+
+        let mut right = Builder::initialized();
+        B::default().circuit(&mut right)?;
+
+        Ok(left.first_divergence(&right))
+    }
+}
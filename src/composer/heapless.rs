@@ -0,0 +1,230 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_bls12_381::BlsScalar;
+use dusk_bytes::DeserializableSlice;
+use merlin::Transcript;
+
+use crate::commitment_scheme::OpeningKey;
+use crate::error::Error;
+use crate::fft::EvaluationDomain;
+use crate::proof_system::{Proof, VerifierKey};
+use crate::transcript::{self, FiatShamir, TranscriptProtocol};
+
+/// Verify proofs of a given circuit without allocating, for circuits of up to
+/// `N` public inputs.
+///
+/// The verifier is deserialized from the bytes of a
+/// [`Verifier`](super::Verifier), and keeps every buffer of the verification
+/// on the stack, so it runs on targets without a global allocator, such as
+/// microcontrollers checking attestations.
+///
+/// The Fiat-Shamir transcript `T` defaults to the Merlin transcript, and must
+/// match the transcript of the [`Prover`](super::Prover).
+pub struct HeaplessVerifier<const N: usize, T = Transcript> {
+    verifier_key: VerifierKey,
+    opening_key: OpeningKey,
+    public_input_indexes: [usize; N],
+    public_inputs: usize,
+    transcript: T,
+    fiat_shamir: FiatShamir,
+    size: usize,
+}
+
+impl<const N: usize> HeaplessVerifier<N> {
+    /// Attempt to deserialize the verifier from bytes generated via
+    /// [`Verifier::to_bytes`](super::Verifier::to_bytes)
+    ///
+    /// Fails with [`Error::TooManyPublicInputs`] if the circuit has more than
+    /// `N` public inputs.
+    pub fn try_from_bytes<B>(bytes: B) -> Result<Self, Error>
+    where
+        B: AsRef<[u8]>,
+    {
+        Self::try_from_bytes_with_transcript(bytes)
+    }
+}
+
+impl<const N: usize, T: TranscriptProtocol> HeaplessVerifier<N, T> {
+    /// Attempt to deserialize the verifier from bytes generated via
+    /// [`Verifier::to_bytes`](super::Verifier::to_bytes), using the
+    /// transcript `T`
+    pub fn try_from_bytes_with_transcript<B>(bytes: B) -> Result<Self, Error>
+    where
+        B: AsRef<[u8]>,
+    {
+        let bytes = VerifierBytes::split(bytes.as_ref())?;
+
+        let public_inputs = bytes.public_input_indexes.len() / 8;
+        if public_inputs > N {
+            return Err(Error::TooManyPublicInputs {
+                public_inputs,
+                capacity: N,
+            });
+        }
+
+        let mut public_input_indexes = [0; N];
+        public_input_indexes
+            .iter_mut()
+            .zip(bytes.public_input_indexes())
+            .for_each(|(index, i)| *index = i);
+
+        let (fiat_shamir, domain) = transcript::read_settings(bytes.settings)?;
+
+        let verifier_key = VerifierKey::from_slice(bytes.verifier_key)?;
+        let opening_key = OpeningKey::from_slice(bytes.opening_key)?;
+
+        let transcript = transcript::base(
+            bytes.label,
+            domain,
+            &verifier_key,
+            bytes.constraints,
+        );
+
+        Ok(Self {
+            verifier_key,
+            opening_key,
+            public_input_indexes,
+            public_inputs,
+            transcript,
+            fiat_shamir,
+            size: bytes.size,
+        })
+    }
+
+    /// Hash of the preprocessed circuit, identifying the circuit of both the
+    /// prover and the verifier
+    pub fn circuit_hash(&self) -> [u8; 32] {
+        self.verifier_key.circuit_hash()
+    }
+
+    /// Canonical fingerprint of the verifier key, as
+    /// [`Verifier::fingerprint`](super::Verifier::fingerprint)
+    pub fn fingerprint(&self) -> [u8; 32] {
+        self.verifier_key.fingerprint()
+    }
+
+    /// Number of public inputs of the circuit
+    pub fn public_inputs(&self) -> usize {
+        self.public_inputs
+    }
+
+    /// Verify a generated proof
+    pub fn verify(
+        &self,
+        proof: &Proof,
+        public_inputs: &[BlsScalar],
+    ) -> Result<(), Error> {
+        let public_input_indexes =
+            &self.public_input_indexes[..self.public_inputs];
+
+        if public_inputs.len() != public_input_indexes.len() {
+            return Err(Error::InconsistentPublicInputsLen {
+                expected: public_input_indexes.len(),
+                provided: public_inputs.len(),
+            });
+        }
+
+        let mut transcript = self.transcript.clone();
+
+        transcript::append_statement(
+            &mut transcript,
+            self.fiat_shamir,
+            &self.verifier_key,
+            public_input_indexes,
+            public_inputs,
+        );
+
+        let domain = EvaluationDomain::new_mixed_radix(self.size)?;
+
+        let (w, c) = proof.accumulate(
+            &domain,
+            &self.verifier_key,
+            &mut transcript,
+            &self.opening_key,
+            public_input_indexes,
+            public_inputs,
+        )?;
+
+        self.opening_key
+            .check_accumulated(w, c)
+            .map_err(|_| Error::ProofVerificationError)
+    }
+}
+
+/// Fields of a serialized [`Verifier`](super::Verifier), borrowed from its
+/// bytes
+pub(crate) struct VerifierBytes<'a> {
+    pub(crate) label: &'a [u8],
+    pub(crate) verifier_key: &'a [u8],
+    pub(crate) opening_key: &'a [u8],
+    pub(crate) public_input_indexes: &'a [u8],
+    pub(crate) settings: &'a [u8],
+    pub(crate) size: usize,
+    pub(crate) constraints: usize,
+}
+
+impl<'a> VerifierBytes<'a> {
+    /// Split the bytes of a verifier into its fields
+    pub(crate) fn split(bytes: &'a [u8]) -> Result<Self, Error> {
+        let mut bytes = bytes;
+
+        if bytes.len() < 48 {
+            return Err(Error::NotEnoughBytes);
+        }
+
+        let mut header = [0usize; 6];
+        header.iter_mut().for_each(|field| {
+            let n = <[u8; 8]>::try_from(&bytes[..8]).expect("checked len");
+            *field = u64::from_be_bytes(n) as usize;
+            bytes = &bytes[8..];
+        });
+
+        let [label_len, verifier_key_len, opening_key_len, public_input_indexes_len, size, constraints] =
+            header;
+
+        let public_input_indexes_len = public_input_indexes_len
+            .checked_mul(8)
+            .ok_or(Error::NotEnoughBytes)?;
+
+        let len = label_len
+            .checked_add(verifier_key_len)
+            .and_then(|len| len.checked_add(opening_key_len))
+            .and_then(|len| len.checked_add(public_input_indexes_len))
+            .ok_or(Error::NotEnoughBytes)?;
+
+        if bytes.len() < len {
+            return Err(Error::NotEnoughBytes);
+        }
+
+        let (label, bytes) = bytes.split_at(label_len);
+        let (verifier_key, bytes) = bytes.split_at(verifier_key_len);
+        let (opening_key, bytes) = bytes.split_at(opening_key_len);
+        let (public_input_indexes, settings) =
+            bytes.split_at(public_input_indexes_len);
+
+        Ok(Self {
+            label,
+            verifier_key,
+            opening_key,
+            public_input_indexes,
+            settings,
+            size,
+            constraints,
+        })
+    }
+
+    /// Indexes of the gates of the public inputs
+    pub(crate) fn public_input_indexes(
+        &self,
+    ) -> impl Iterator<Item = usize> + 'a {
+        self.public_input_indexes
+            .chunks_exact(8)
+            .map(|c| <[u8; 8]>::try_from(c).expect("checked len"))
+            .map(u64::from_be_bytes)
+            .map(|n| n as usize)
+    }
+}
@@ -0,0 +1,317 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Optimization passes over the gates of a [`Builder`].
+//!
+//! The passes only rewrite plain arithmetic gates, and never touch a gate that
+//! is consumed as the next row of a range, logic or curve addition gate. Their
+//! outcome depends solely on the structure of the circuit, so the prover and
+//! the verifier will agree on the optimized circuit description as long as
+//! the passes are invoked from within [`Circuit::circuit`](super::Circuit).
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use dusk_bls12_381::BlsScalar;
+use hashbrown::HashMap;
+
+use crate::constraint_system::Witness;
+use crate::permutation::Permutation;
+
+use super::{Builder, Composer, Polynomial};
+
+mod constant;
+mod cse;
+mod dead;
+
+pub(crate) use constant::fold_constants;
+pub(crate) use cse::eliminate_common_subexpressions;
+pub(crate) use dead::eliminate_dead_gates;
+
+/// Optimization level of [`Composer::optimize`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum OptLevel {
+    /// Leave the circuit untouched
+    #[default]
+    None,
+    /// Run the common subexpression elimination and the constant folding
+    /// passes once
+    Basic,
+    /// Run every pass, including the dead gate elimination, until they stop
+    /// removing gates
+    Aggressive,
+}
+
+/// Optimization pass over the gates of a [`Composer`]
+pub trait Pass<C: Composer> {
+    /// Name of the pass, as reported by [`OptReport`]
+    fn name(&self) -> &'static str;
+
+    /// Run the pass and return the number of removed gates
+    fn run(&mut self, composer: &mut C) -> usize;
+}
+
+/// Pass running [`Composer::eliminate_common_subexpressions`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CommonSubexpressionElimination;
+
+impl<C: Composer> Pass<C> for CommonSubexpressionElimination {
+    fn name(&self) -> &'static str {
+        "common subexpression elimination"
+    }
+
+    fn run(&mut self, composer: &mut C) -> usize {
+        composer.eliminate_common_subexpressions()
+    }
+}
+
+/// Pass running [`Composer::fold_constants`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ConstantFolding;
+
+impl<C: Composer> Pass<C> for ConstantFolding {
+    fn name(&self) -> &'static str {
+        "constant folding"
+    }
+
+    fn run(&mut self, composer: &mut C) -> usize {
+        composer.fold_constants()
+    }
+}
+
+/// Pass running [`Composer::eliminate_dead_gates`], preserving the outputs in
+/// `keep`
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct DeadGateElimination {
+    keep: Vec<Witness>,
+}
+
+impl DeadGateElimination {
+    /// Create a new pass preserving the outputs in `keep`
+    pub fn new(keep: &[Witness]) -> Self {
+        Self {
+            keep: keep.to_vec(),
+        }
+    }
+}
+
+impl<C: Composer> Pass<C> for DeadGateElimination {
+    fn name(&self) -> &'static str {
+        "dead gate elimination"
+    }
+
+    fn run(&mut self, composer: &mut C) -> usize {
+        composer.eliminate_dead_gates(&self.keep)
+    }
+}
+
+/// Gates removed by an [`Optimizer`] run
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct OptReport {
+    constraints: usize,
+    passes: Vec<(&'static str, usize)>,
+}
+
+impl OptReport {
+    /// Constraints count before the optimization
+    pub const fn constraints(&self) -> usize {
+        self.constraints
+    }
+
+    /// Total number of removed gates
+    pub fn removed(&self) -> usize {
+        self.passes.iter().map(|(_, removed)| removed).sum()
+    }
+
+    /// Removed gates per pass, in the order the passes first ran
+    pub fn passes(&self) -> &[(&'static str, usize)] {
+        &self.passes
+    }
+
+    /// Gates removed by the pass with the given name
+    pub fn removed_by(&self, name: &str) -> usize {
+        self.passes
+            .iter()
+            .find(|(pass, _)| *pass == name)
+            .map(|(_, removed)| *removed)
+            .unwrap_or(0)
+    }
+
+    fn record(&mut self, name: &'static str, removed: usize) {
+        match self.passes.iter_mut().find(|(pass, _)| *pass == name) {
+            Some((_, total)) => *total += removed,
+            None => self.passes.push((name, removed)),
+        }
+    }
+}
+
+/// Pipeline of optimization passes
+pub struct Optimizer<C: Composer> {
+    passes: Vec<Box<dyn Pass<C>>>,
+    fixpoint: bool,
+}
+
+impl<C: Composer> Default for Optimizer<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: Composer> Optimizer<C> {
+    /// Create an empty pipeline
+    pub fn new() -> Self {
+        Self {
+            passes: Vec::new(),
+            fixpoint: false,
+        }
+    }
+
+    /// Create the pipeline of the given optimization level
+    pub fn with_level(level: OptLevel) -> Self {
+        match level {
+            OptLevel::None => Self::new(),
+            OptLevel::Basic => Self::new()
+                .with_pass(CommonSubexpressionElimination)
+                .with_pass(ConstantFolding),
+            OptLevel::Aggressive => Self::new()
+                .with_pass(CommonSubexpressionElimination)
+                .with_pass(ConstantFolding)
+                .with_pass(DeadGateElimination::default())
+                .until_fixpoint(),
+        }
+    }
+
+    /// Append a pass to the pipeline
+    pub fn with_pass<P>(mut self, pass: P) -> Self
+    where
+        P: Pass<C> + 'static,
+    {
+        self.passes.push(Box::new(pass));
+        self
+    }
+
+    /// Repeat the pipeline until it stops removing gates
+    pub fn until_fixpoint(mut self) -> Self {
+        self.fixpoint = true;
+        self
+    }
+
+    /// Run the pipeline over the gates of `composer`
+    ///
+    /// Just like the passes it is made of, the pipeline must be run from
+    /// within [`Circuit::circuit`](super::Circuit) once all gates are
+    /// appended.
+    pub fn run(&mut self, composer: &mut C) -> OptReport {
+        let mut report = OptReport {
+            constraints: composer.constraints(),
+            passes: Vec::with_capacity(self.passes.len()),
+        };
+
+        loop {
+            let removed = self.passes.iter_mut().fold(0, |acc, pass| {
+                let removed = pass.run(composer);
+                report.record(pass.name(), removed);
+                acc + removed
+            });
+
+            if !self.fixpoint || removed == 0 {
+                break;
+            }
+        }
+
+        report
+    }
+}
+
+impl Polynomial {
+    /// Wires of the gate, in the `a`, `b`, `o`, `d` order
+    pub(crate) const fn wires(&self) -> [Witness; 4] {
+        [self.w_a, self.w_b, self.w_o, self.w_d]
+    }
+
+    /// First wire of the gate that isn't one of the `witnesses` allocated by
+    /// the builder, if any
+    pub(crate) fn foreign_wire(&self, witnesses: usize) -> Option<Witness> {
+        self.wires().into_iter().find(|w| w.index() >= witnesses)
+    }
+
+    /// Replace every occurrence of `from` in the wires of the gate by `to`
+    pub(crate) fn rewire(&mut self, from: Witness, to: Witness) {
+        [&mut self.w_a, &mut self.w_b, &mut self.w_o, &mut self.w_d]
+            .into_iter()
+            .filter(|w| **w == from)
+            .for_each(|w| *w = to);
+    }
+
+    /// Whether the gate constrains the wires of the gate that follows it
+    pub(crate) fn reads_next_row(&self) -> bool {
+        [
+            self.q_range,
+            self.q_logic,
+            self.q_fixed_group_add,
+            self.q_variable_group_add,
+        ]
+        .iter()
+        .any(|q| q != &BlsScalar::zero())
+    }
+
+    /// Whether the gate is a plain arithmetic gate
+    pub(crate) fn is_arithmetic(&self) -> bool {
+        self.q_arith == BlsScalar::one() && !self.reads_next_row()
+    }
+}
+
+impl Builder {
+    /// Whether the gate at `index` is an arithmetic gate without public input
+    /// that can be rewritten or removed without affecting its neighbours
+    pub(crate) fn is_rewritable(&self, index: usize) -> bool {
+        let previous_reads_next = index
+            .checked_sub(1)
+            .map(|i| self.constraints[i].reads_next_row())
+            .unwrap_or(false);
+
+        self.constraints[index].is_arithmetic()
+            && !previous_reads_next
+            && !self.public_inputs.contains_key(&index)
+    }
+
+    /// Replace the gates of the builder by `gates`, where every gate is paired
+    /// with the index it had before the optimization.
+    ///
+    /// The public inputs are moved along with their gates, and the
+    /// permutation is rebuilt from the new wiring. The witnesses are kept
+    /// untouched so previously allocated [`Witness`] remain valid.
+    pub(crate) fn replace_gates(&mut self, gates: Vec<(usize, Polynomial)>) {
+        let mut public_inputs =
+            HashMap::with_capacity(self.public_inputs.len());
+        let mut perm = Permutation::with_capacity(self.witnesses.len());
+
+        self.witnesses.iter().for_each(|_| {
+            perm.new_witness();
+        });
+
+        self.constraints = gates
+            .into_iter()
+            .enumerate()
+            .map(|(n, (i, gate))| {
+                if let Some(pi) = self.public_inputs.get(&i) {
+                    public_inputs.insert(n, *pi);
+                }
+
+                if gate.foreign_wire(self.witnesses.len()).is_none() {
+                    perm.add_witnesses_to_map(
+                        gate.w_a, gate.w_b, gate.w_o, gate.w_d, n,
+                    );
+                }
+
+                gate
+            })
+            .collect();
+
+        self.public_inputs = public_inputs;
+        self.perm = perm;
+    }
+}
@@ -0,0 +1,188 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Constant folding and propagation
+
+use alloc::vec::Vec;
+
+use dusk_bls12_381::BlsScalar;
+use hashbrown::HashMap;
+
+use crate::constraint_system::Witness;
+
+use super::{Builder, Polynomial};
+
+/// Fold the witnesses that are constrained to a constant into the selectors of
+/// the arithmetic gates that consume them.
+///
+/// A witness is known to be constant when an arithmetic gate constrains it as
+/// its single unknown term, such as the gates appended by
+/// [`Composer::append_constant`](crate::prelude::Composer::append_constant).
+/// Every subsequent arithmetic gate moves the contribution of a constant wire
+/// into its `q_c` selector, and the wire is replaced by the zero witness. This
+/// way, constants propagate: a gate whose inputs are all constant defines its
+/// output as a constant.
+///
+/// After folding, the gates reduced to `0 = 0` are removed, as well as the
+/// gates defining a constant that isn't referenced anymore.
+///
+/// Return the number of removed gates.
+pub(crate) fn fold_constants(builder: &mut Builder) -> usize {
+    let zero = BlsScalar::zero();
+
+    // witness -> (value, position of the defining gate in `gates`)
+    let mut constants: HashMap<Witness, (BlsScalar, usize)> = HashMap::new();
+    let mut gates: Vec<(usize, Polynomial)> =
+        Vec::with_capacity(builder.constraints.len());
+
+    for (i, gate) in builder.constraints.iter().enumerate() {
+        let mut gate = *gate;
+
+        if !builder.is_rewritable(i) {
+            gates.push((i, gate));
+            continue;
+        }
+
+        let value = |w: Witness| constants.get(&w).map(|(v, _)| *v);
+
+        // fold the multiplication into the linear terms
+        match (value(gate.w_a), value(gate.w_b)) {
+            (Some(a), Some(b)) => {
+                gate.q_c += gate.q_m * a * b;
+                gate.q_m = zero;
+            }
+            (Some(a), None) => {
+                gate.q_r += gate.q_m * a;
+                gate.q_m = zero;
+            }
+            (None, Some(b)) => {
+                gate.q_l += gate.q_m * b;
+                gate.q_m = zero;
+            }
+            (None, None) => (),
+        }
+
+        // fold the linear terms into the constant selector
+        if gate.q_m == zero {
+            let Polynomial {
+                q_l,
+                q_r,
+                q_o,
+                q_c,
+                q_d,
+                w_a,
+                w_b,
+                w_o,
+                w_d,
+                ..
+            } = &mut gate;
+
+            [(q_l, w_a), (q_r, w_b), (q_o, w_o), (q_d, w_d)]
+                .into_iter()
+                .for_each(|(q, w)| {
+                    if let Some(v) = value(*w) {
+                        *q_c += *q * v;
+                        *q = zero;
+                        *w = Witness::ZERO;
+                    }
+                });
+        }
+
+        match unknown(&gate) {
+            // the gate is satisfied regardless of the witnesses
+            Unknown::None if gate.q_c == zero => (),
+
+            // the gate defines a new constant
+            Unknown::One(w, coeff) => {
+                let v = -gate.q_c * coeff.invert().expect("coeff isn't zero");
+
+                constants.insert(w, (v, gates.len()));
+                gates.push((i, gate));
+            }
+
+            _ => gates.push((i, gate)),
+        }
+    }
+
+    // remove the gates defining constants that are no longer referenced
+    let mut references: HashMap<Witness, usize> = HashMap::new();
+    gates
+        .iter()
+        .flat_map(|(_, gate)| gate.wires())
+        .for_each(|w| *references.entry(w).or_default() += 1);
+
+    let mut removed = vec![false; gates.len()];
+    let mut pending = true;
+    while pending {
+        pending = false;
+
+        for (w, (_, g)) in constants.iter() {
+            let definer = &gates[*g].1;
+            let own = definer.wires().iter().filter(|x| *x == w).count();
+
+            if !removed[*g] && references[w] == own {
+                removed[*g] = true;
+                pending = true;
+
+                definer.wires().iter().for_each(|x| {
+                    references.entry(*x).and_modify(|r| *r -= 1);
+                });
+            }
+        }
+    }
+
+    let gates: Vec<_> = gates
+        .into_iter()
+        .zip(removed)
+        .filter_map(|(gate, removed)| (!removed).then_some(gate))
+        .collect();
+
+    let removed = builder.constraints.len() - gates.len();
+
+    builder.replace_gates(gates);
+
+    removed
+}
+
+/// Unknown linear terms of an arithmetic gate
+enum Unknown {
+    /// The gate doesn't depend on any witness
+    None,
+    /// The gate depends on a single witness, with the given coefficient
+    One(Witness, BlsScalar),
+    /// The gate depends on several witnesses, or on a product of witnesses
+    Many,
+}
+
+fn unknown(gate: &Polynomial) -> Unknown {
+    let zero = BlsScalar::zero();
+
+    if gate.q_m != zero {
+        return Unknown::Many;
+    }
+
+    // a witness might be wired more than once
+    let mut terms: Vec<(Witness, BlsScalar)> = Vec::with_capacity(4);
+    [
+        (gate.w_a, gate.q_l),
+        (gate.w_b, gate.q_r),
+        (gate.w_o, gate.q_o),
+        (gate.w_d, gate.q_d),
+    ]
+    .into_iter()
+    .for_each(|(w, q)| match terms.iter_mut().find(|(t, _)| *t == w) {
+        Some((_, c)) => *c += q,
+        None => terms.push((w, q)),
+    });
+
+    terms.retain(|(_, c)| c != &zero);
+
+    match terms.as_slice() {
+        [] => Unknown::None,
+        [(w, c)] => Unknown::One(*w, *c),
+        _ => Unknown::Many,
+    }
+}
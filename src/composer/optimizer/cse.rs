@@ -0,0 +1,138 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Common subexpression elimination
+
+use alloc::vec::Vec;
+
+use dusk_bls12_381::BlsScalar;
+use hashbrown::HashMap;
+
+use crate::constraint_system::Witness;
+
+use super::{Builder, Polynomial};
+
+/// Selectors and input wires of an arithmetic gate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Expression {
+    q_m: BlsScalar,
+    q_l: BlsScalar,
+    q_r: BlsScalar,
+    q_o: BlsScalar,
+    q_c: BlsScalar,
+    q_d: BlsScalar,
+    w_a: Witness,
+    w_b: Witness,
+    w_d: Witness,
+}
+
+impl From<&Polynomial> for Expression {
+    fn from(p: &Polynomial) -> Self {
+        // `q_m · a · b` is commutative; sort the `a` and `b` terms so both
+        // orderings of the same expression match
+        let ((q_l, w_a), (q_r, w_b)) = if p.w_a.index() <= p.w_b.index() {
+            ((p.q_l, p.w_a), (p.q_r, p.w_b))
+        } else {
+            ((p.q_r, p.w_b), (p.q_l, p.w_a))
+        };
+
+        Self {
+            q_m: p.q_m,
+            q_l,
+            q_r,
+            q_o: p.q_o,
+            q_c: p.q_c,
+            q_d: p.q_d,
+            w_a,
+            w_b,
+            w_d: p.w_d,
+        }
+    }
+}
+
+/// Merge the arithmetic gates that evaluate the same expression over the same
+/// inputs.
+///
+/// A gate that repeats a previous gate is removed. If it defines a different
+/// output, this output is rewired to the output of the previous gate, which
+/// is equivalent to the copy constraint `o' = o`.
+///
+/// Return the number of removed gates.
+pub(crate) fn eliminate_common_subexpressions(builder: &mut Builder) -> usize {
+    // expression -> (output wire, whether the output is defined by the gate)
+    let mut expressions: HashMap<Expression, (Witness, bool)> = HashMap::new();
+    let mut renames: HashMap<Witness, Witness> = HashMap::new();
+    let mut gates = Vec::with_capacity(builder.constraints.len());
+
+    for (i, gate) in builder.constraints.iter().enumerate() {
+        let mut gate = *gate;
+        rewire(&mut gate, &renames);
+
+        if !builder.is_rewritable(i) {
+            gates.push((i, gate));
+            continue;
+        }
+
+        // the output is a function of the inputs if its coefficient isn't zero
+        // and it doesn't take part as an input
+        let defines_output = gate.q_o != BlsScalar::zero()
+            && gate.w_o != gate.w_a
+            && gate.w_o != gate.w_b
+            && gate.w_o != gate.w_d;
+
+        let expression = Expression::from(&gate);
+        let previous = expressions
+            .get(&expression)
+            .map(|(o, defined)| (resolve(*o, &renames), *defined));
+
+        match previous {
+            // the very same constraint is already part of the circuit
+            Some((o, _)) if o == gate.w_o || gate.q_o == BlsScalar::zero() => {}
+
+            Some((o, true)) if defines_output => {
+                renames.insert(gate.w_o, o);
+            }
+
+            Some(_) => gates.push((i, gate)),
+
+            None => {
+                expressions.insert(expression, (gate.w_o, defines_output));
+                gates.push((i, gate));
+            }
+        }
+    }
+
+    let removed = builder.constraints.len() - gates.len();
+
+    // outputs might have been used before the gate that defines them
+    gates
+        .iter_mut()
+        .for_each(|(_, gate)| rewire(gate, &renames));
+
+    builder.replace_gates(gates);
+
+    removed
+}
+
+/// Follow the merged outputs of a witness
+fn resolve(mut w: Witness, renames: &HashMap<Witness, Witness>) -> Witness {
+    while let Some(to) = renames.get(&w) {
+        w = *to;
+    }
+
+    w
+}
+
+/// Rewire a gate with the outputs merged so far
+fn rewire(gate: &mut Polynomial, renames: &HashMap<Witness, Witness>) {
+    gate.wires().into_iter().for_each(|w| {
+        let to = resolve(w, renames);
+
+        if to != w {
+            gate.rewire(w, to);
+        }
+    });
+}
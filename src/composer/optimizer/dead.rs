@@ -0,0 +1,81 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Dead gate elimination
+
+use alloc::vec::Vec;
+
+use dusk_bls12_381::BlsScalar;
+use hashbrown::HashMap;
+
+use crate::constraint_system::Witness;
+
+use super::Builder;
+
+/// Remove the arithmetic gates whose output isn't consumed by any other gate.
+///
+/// Such a gate can always be satisfied by picking its output, so it doesn't
+/// constrain the rest of the circuit. Removing a gate might leave its inputs
+/// unreferenced as well; the pass runs until no more gates can be removed, so
+/// whole chains of computations that never reach a public input or an
+/// assertion are dropped.
+///
+/// The outputs listed in `keep` are preserved.
+///
+/// Return the number of removed gates.
+pub(crate) fn eliminate_dead_gates(
+    builder: &mut Builder,
+    keep: &[Witness],
+) -> usize {
+    let mut references: HashMap<Witness, usize> = HashMap::new();
+    builder
+        .constraints
+        .iter()
+        .flat_map(|gate| gate.wires())
+        .for_each(|w| *references.entry(w).or_default() += 1);
+
+    let mut removed = vec![false; builder.constraints.len()];
+    let mut pending = true;
+    while pending {
+        pending = false;
+
+        // gates are usually consumed after they are appended; sweeping from
+        // the last gate removes most chains in a single pass
+        for i in (0..builder.constraints.len()).rev() {
+            let gate = &builder.constraints[i];
+
+            let dead = !removed[i]
+                && builder.is_rewritable(i)
+                && gate.q_o != BlsScalar::zero()
+                && references[&gate.w_o] == 1
+                && !keep.contains(&gate.w_o);
+
+            if dead {
+                removed[i] = true;
+                pending = true;
+
+                gate.wires().iter().for_each(|w| {
+                    references.entry(*w).and_modify(|r| *r -= 1);
+                });
+            }
+        }
+    }
+
+    let gates: Vec<_> = builder
+        .constraints
+        .iter()
+        .copied()
+        .enumerate()
+        .zip(removed)
+        .filter_map(|(gate, removed)| (!removed).then_some(gate))
+        .collect();
+
+    let removed = builder.constraints.len() - gates.len();
+
+    builder.replace_gates(gates);
+
+    removed
+}
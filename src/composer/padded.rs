@@ -0,0 +1,69 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use alloc::vec::Vec;
+
+use dusk_bls12_381::BlsScalar;
+
+use crate::error::Error;
+
+/// Vector of up to `N` scalars, exposed by a circuit as `N + 1` public inputs
+/// with [`Composer::append_public_padded`](super::Composer::append_public_padded).
+///
+/// The public inputs are the length of the vector followed by its values,
+/// padded with zeros to `N`, so circuits processing "up to `N`" items keep a
+/// fixed number of public inputs. The circuit constrains the padding to be
+/// zero, so a vector and its padded values can't be confused.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaddedVec<const N: usize> {
+    values: Vec<BlsScalar>,
+}
+
+impl<const N: usize> Default for PaddedVec<N> {
+    fn default() -> Self {
+        Self { values: Vec::new() }
+    }
+}
+
+impl<const N: usize> PaddedVec<N> {
+    /// Create a vector of the given `values`
+    ///
+    /// Fails with [`Error::PaddedVecTooLong`] if there are more than `N`
+    /// values.
+    pub fn new(values: Vec<BlsScalar>) -> Result<Self, Error> {
+        match values.len() <= N {
+            true => Ok(Self { values }),
+            false => Err(Error::PaddedVecTooLong {
+                length: values.len(),
+                capacity: N,
+            }),
+        }
+    }
+
+    /// Values of the vector, without the padding
+    pub fn values(&self) -> &[BlsScalar] {
+        &self.values
+    }
+
+    /// Values of the vector, padded with zeros to `N`
+    pub fn padded(&self) -> [BlsScalar; N] {
+        let mut padded = [BlsScalar::zero(); N];
+        padded[..self.values.len()].copy_from_slice(&self.values);
+
+        padded
+    }
+
+    /// Public inputs of the vector: its length followed by its
+    /// [padded](Self::padded) values
+    pub fn expand(&self) -> Vec<BlsScalar> {
+        let mut expanded = Vec::with_capacity(N + 1);
+
+        expanded.push(BlsScalar::from(self.values.len() as u64));
+        expanded.extend(self.padded());
+
+        expanded
+    }
+}
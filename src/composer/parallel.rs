@@ -0,0 +1,113 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Synthesis of independent gadgets over builders of their own
+
+use alloc::vec::Vec;
+
+use dusk_bls12_381::BlsScalar;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::constraint_system::{Constraint, Selector, Witness};
+
+use super::{Builder, Composer};
+
+/// Gadget synthesized over a builder of its own
+pub(crate) struct Synthesized {
+    builder: Builder,
+    outputs: Vec<Witness>,
+}
+
+/// Synthesize the `gadget` of every group over a builder of its own, in
+/// parallel with the `parallel` feature.
+///
+/// The builder of a group holds the zero and one witnesses, followed by the
+/// `values` of the inputs of the group, without any gate.
+pub(crate) fn synthesize<T, F>(
+    groups: &[(Vec<BlsScalar>, &T)],
+    gadget: &F,
+) -> Vec<Synthesized>
+where
+    T: Sync,
+    F: Fn(&mut Builder, &[Witness], &T) -> Vec<Witness> + Sync,
+{
+    #[cfg(not(feature = "parallel"))]
+    let jobs = groups.iter();
+
+    #[cfg(feature = "parallel")]
+    let jobs = groups.par_iter();
+
+    jobs.map(|(values, data)| {
+        #[allow(deprecated)]
+        let mut builder = Builder::uninitialized();
+
+        builder.append_witness(0);
+        builder.append_witness(1);
+
+        let inputs: Vec<Witness> =
+            values.iter().map(|v| builder.append_witness(*v)).collect();
+        let outputs = gadget(&mut builder, &inputs, data);
+
+        Synthesized { builder, outputs }
+    })
+    .collect()
+}
+
+/// Append the witnesses and the gates of a synthesized gadget to the
+/// `composer`, wiring the imported `inputs` back to the witnesses of the
+/// circuit, and return its outputs as witnesses of the circuit.
+///
+/// Panics if the gadget wired a witness it didn't allocate.
+pub(crate) fn merge<C: Composer>(
+    composer: &mut C,
+    inputs: &[Witness],
+    gadget: Synthesized,
+) -> Vec<Witness> {
+    let Synthesized { builder, outputs } = gadget;
+
+    let mut map = Vec::with_capacity(builder.witnesses.len());
+    map.push(C::ZERO);
+    map.push(C::ONE);
+    map.extend_from_slice(inputs);
+
+    for value in &builder.witnesses[map.len()..] {
+        let witness = composer.append_witness(*value);
+        map.push(witness);
+    }
+
+    let mut labels = builder.labels.iter().peekable();
+    for (gate, poly) in builder.constraints.iter().enumerate() {
+        while let Some((_, label)) = labels.next_if(|(g, _)| *g == gate) {
+            composer.label(label);
+        }
+
+        let mut constraint = Constraint::default()
+            .set(Selector::Multiplication, poly.q_m)
+            .set(Selector::Left, poly.q_l)
+            .set(Selector::Right, poly.q_r)
+            .set(Selector::Output, poly.q_o)
+            .set(Selector::Constant, poly.q_c)
+            .set(Selector::Fourth, poly.q_d)
+            .set(Selector::Arithmetic, poly.q_arith)
+            .set(Selector::Range, poly.q_range)
+            .set(Selector::Logic, poly.q_logic)
+            .set(Selector::GroupAddFixedBase, poly.q_fixed_group_add)
+            .set(Selector::GroupAddVariableBase, poly.q_variable_group_add)
+            .a(map[poly.w_a.index()])
+            .b(map[poly.w_b.index()])
+            .o(map[poly.w_o.index()])
+            .d(map[poly.w_d.index()]);
+
+        if let Some(pi) = builder.public_inputs.get(&gate) {
+            constraint = constraint.public(*pi);
+        }
+
+        composer.append_custom_gate(constraint);
+    }
+
+    outputs.iter().map(|w| map[w.index()]).collect()
+}
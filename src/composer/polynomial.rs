@@ -44,3 +44,26 @@ pub struct Polynomial {
     /// Output wire witness.
     pub(crate) w_o: Witness,
 }
+
+impl Polynomial {
+    /// Names of the wires of the gate, in the order of [`Self::wires`]
+    pub(crate) const WIRE_NAMES: [&'static str; 4] =
+        ["w_a", "w_b", "w_o", "w_d"];
+
+    /// Selectors of the gate, with their names
+    pub(crate) fn named_selectors(&self) -> [(&'static str, BlsScalar); 11] {
+        [
+            ("q_m", self.q_m),
+            ("q_l", self.q_l),
+            ("q_r", self.q_r),
+            ("q_o", self.q_o),
+            ("q_c", self.q_c),
+            ("q_d", self.q_d),
+            ("q_arith", self.q_arith),
+            ("q_range", self.q_range),
+            ("q_logic", self.q_logic),
+            ("q_fixed_group_add", self.q_fixed_group_add),
+            ("q_variable_group_add", self.q_variable_group_add),
+        ]
+    }
+}
@@ -0,0 +1,36 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+/// Stage of the prover, reported by
+/// [`Prover::prove_with_progress`](super::Prover::prove_with_progress) once it
+/// completes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProveStage {
+    /// The wire polynomials are committed
+    WiresCommitted,
+    /// The permutation argument is committed
+    PermutationCommitted,
+    /// The quotient polynomial is computed and committed
+    QuotientCommitted,
+    /// The evaluations and the opening proofs are computed
+    OpeningsComputed,
+}
+
+impl ProveStage {
+    /// Estimate of the share of the proving time spent up to the end of the
+    /// stage, in percent.
+    ///
+    /// The estimate weights the multi-scalar multiplications and the FFTs of
+    /// every stage; the witness generation of the circuit isn't accounted for.
+    pub const fn percent(&self) -> u8 {
+        match self {
+            Self::WiresCommitted => 30,
+            Self::PermutationCommitted => 40,
+            Self::QuotientCommitted => 80,
+            Self::OpeningsComputed => 100,
+        }
+    }
+}
@@ -4,39 +4,60 @@
 //
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
+use alloc::sync::Arc;
 use alloc::vec::Vec;
 use core::ops;
 
-use dusk_bls12_381::BlsScalar;
+use dusk_bls12_381::{BlsScalar, G1Projective};
 use dusk_bytes::{DeserializableSlice, Serializable};
 use ff::Field;
 use merlin::Transcript;
-use rand_core::{CryptoRng, RngCore};
+use rand_core::{CryptoRng, RngCore, SeedableRng};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
-use crate::commitment_scheme::CommitKey;
+use crate::commitment_scheme::msm::MsmTable;
+use crate::commitment_scheme::{CommitKey, Commitment, LagrangeKey};
 use crate::error::Error;
 use crate::fft::{EvaluationDomain, Polynomial as FftPolynomial};
 use crate::proof_system::proof::Proof;
 use crate::proof_system::{
     linearization_poly, quotient_poly, ProverKey, VerifierKey,
 };
-use crate::transcript::TranscriptProtocol;
-
-use super::{Builder, Circuit, Composer};
+use crate::transcript::{
+    self, Compat, Domain, FiatShamir, Settings, TranscriptExt,
+    TranscriptProtocol,
+};
+use crate::util;
+
+#[cfg(feature = "std")]
+use super::cache;
+use super::cancellation::{block_on, Checkpoint};
+use super::{
+    Blinding, Builder, CancellationToken, Circuit, Composer, ProofBundle,
+    ProveStage, ProveStats,
+};
 
 /// Turbo Prover with processed keys
+///
+/// The Fiat-Shamir transcript `T` defaults to the Merlin transcript, and must
+/// match the transcript of the [`Verifier`](super::Verifier).
 #[derive(Clone)]
-pub struct Prover {
+pub struct Prover<T = Transcript> {
     label: Vec<u8>,
     pub(crate) prover_key: ProverKey,
     pub(crate) commit_key: CommitKey,
+    bases: Option<Arc<MsmTable>>,
+    lagrange: Option<Arc<LagrangeKey>>,
+    blinding: Blinding,
     pub(crate) verifier_key: VerifierKey,
-    pub(crate) transcript: Transcript,
+    pub(crate) transcript: T,
+    pub(crate) settings: Settings,
     pub(crate) size: usize,
     pub(crate) constraints: usize,
 }
 
-impl ops::Deref for Prover {
+impl<T> ops::Deref for Prover<T> {
     type Target = ProverKey;
 
     fn deref(&self) -> &Self::Target {
@@ -45,6 +66,55 @@ impl ops::Deref for Prover {
 }
 
 impl Prover {
+    /// Attempt to deserialize the prover from bytes generated via
+    /// [`Self::to_bytes`]
+    pub fn try_from_bytes<B>(bytes: B) -> Result<Self, Error>
+    where
+        B: AsRef<[u8]>,
+    {
+        Self::try_from_bytes_with_transcript(bytes)
+    }
+
+    /// Reset the transcript of the prover to the one of the upstream
+    /// `dusk-plonk` version `compat`, dropping any application domain and
+    /// strong binding of the statement.
+    ///
+    /// The prover then interoperates with a verifier of the upstream crate, or
+    /// with a [`Verifier`](super::Verifier) of this crate in the same mode.
+    pub fn with_compat(mut self, compat: Compat) -> Self {
+        match compat {
+            Compat::V0_16 => {
+                self.transcript = transcript::base(
+                    self.label.as_slice(),
+                    None,
+                    &self.verifier_key,
+                    self.constraints,
+                );
+                self.settings = Settings::default();
+            }
+        }
+
+        self
+    }
+
+    /// Upstream `dusk-plonk` version the prover is compatible with, if any
+    pub fn compat(&self) -> Option<Compat> {
+        Compat::of(&self.settings)
+    }
+
+    /// Read the prover written with [`Self::to_writer`]
+    #[cfg(feature = "std")]
+    pub fn from_reader<R>(reader: R) -> Result<Self, Error>
+    where
+        R: std::io::Read,
+    {
+        Self::from_reader_with_transcript(reader)
+    }
+}
+
+impl<T: TranscriptProtocol> Prover<T> {
+    /// Fails with [`Error::CircuitHashMismatch`] if the prover key embeds
+    /// another circuit hash than the one of the verifier key
     pub(crate) fn new(
         label: Vec<u8>,
         prover_key: ProverKey,
@@ -52,19 +122,145 @@ impl Prover {
         verifier_key: VerifierKey,
         size: usize,
         constraints: usize,
-    ) -> Self {
-        let transcript =
-            Transcript::base(label.as_slice(), &verifier_key, constraints);
+    ) -> Result<Self, Error> {
+        // keys serialized without their circuit hash can only be checked
+        // with `Prover::verify_integrity`
+        let circuit_hash = verifier_key.circuit_hash();
+        if matches!(prover_key.circuit_hash, Some(h) if h != circuit_hash) {
+            return Err(Error::CircuitHashMismatch);
+        }
 
-        Self {
+        let transcript = transcript::base(
+            label.as_slice(),
+            None,
+            &verifier_key,
+            constraints,
+        );
+
+        Ok(Self {
             label,
             prover_key,
             commit_key,
+            bases: None,
+            lagrange: None,
+            blinding: Blinding::default(),
             verifier_key,
             transcript,
+            settings: Settings::default(),
             size,
             constraints,
+        })
+    }
+
+    /// Absorb the application `domain` in the transcript of the prover.
+    ///
+    /// The proofs will only verify with a [`Verifier`](super::Verifier) of the
+    /// same domain.
+    pub fn with_domain(mut self, domain: Domain) -> Self {
+        self.transcript = transcript::base(
+            self.label.as_slice(),
+            Some(domain.as_parts()),
+            &self.verifier_key,
+            self.constraints,
+        );
+        self.settings.domain = Some(domain);
+
+        self
+    }
+
+    /// Application domain of the prover, if any
+    pub fn domain(&self) -> Option<&Domain> {
+        self.settings.domain.as_ref()
+    }
+
+    /// Set the binding of the statement of the proofs to the transcript.
+    ///
+    /// The prover and the verifier must use the same mode.
+    pub fn with_fiat_shamir(mut self, fiat_shamir: FiatShamir) -> Self {
+        self.settings.fiat_shamir = fiat_shamir;
+        self
+    }
+
+    /// Binding of the statement of the proofs to the transcript
+    pub fn fiat_shamir(&self) -> FiatShamir {
+        self.settings.fiat_shamir
+    }
+
+    /// Number of gates of the circuit of the prover, without the padding to
+    /// the size of the domain
+    pub fn constraints(&self) -> usize {
+        self.constraints
+    }
+
+    /// Precompute the multiples of the points of the commit key, so that the
+    /// commitments of the proofs skip the doublings of the multi-scalar
+    /// multiplications.
+    ///
+    /// The precomputation holds around `256 / log2(n)` points per point of
+    /// the commit key of size `n`, is shared by the clones of the prover, and
+    /// isn't serialized with it.
+    pub fn with_precomputed_bases(mut self) -> Self {
+        self.bases = Some(Arc::new(self.commit_key.msm_table()));
+        self
+    }
+
+    /// Whether the multiples of the points of the commit key are precomputed
+    pub fn has_precomputed_bases(&self) -> bool {
+        self.bases.is_some()
+    }
+
+    /// Convert the commit key to the Lagrange basis of the domain of the
+    /// circuit, so that the wire and permutation polynomials are committed
+    /// directly from their evaluations.
+    ///
+    /// The conversion is shared by the clones of the prover, and isn't
+    /// serialized with it.
+    pub fn with_lagrange_bases(mut self) -> Result<Self, Error> {
+        let domain = EvaluationDomain::new_mixed_radix(self.size)?;
+        let lagrange = self.commit_key.to_lagrange(&domain)?;
+
+        self.lagrange = Some(Arc::new(lagrange));
+        Ok(self)
+    }
+
+    /// Whether the commit key is converted to the Lagrange basis of the domain
+    pub fn has_lagrange_bases(&self) -> bool {
+        self.lagrange.is_some()
+    }
+
+    /// Set the degrees of the blinding polynomials of the wires and of the
+    /// permutation, to raise their hiding bound.
+    ///
+    /// The proofs verify with the same [`Verifier`](super::Verifier). The
+    /// degrees aren't serialized with the prover. The commit key of a prover
+    /// compiled with [`Compiler::compile`](super::Compiler::compile) only fits
+    /// the default degrees in general: compile the circuit with
+    /// [`Compiler::compile_with_blinding`](super::Compiler::compile_with_blinding)
+    /// to trim it to higher ones.
+    ///
+    /// Returns an error if the commit key can't commit to the blinded
+    /// polynomials and to the split quotient.
+    pub fn with_blinding(mut self, blinding: Blinding) -> Result<Self, Error> {
+        let degree = self.size + blinding.added_degree();
+        let max_degree = self.commit_key.max_degree();
+
+        // the blinding scalars of a polynomial wrap around the domain at most
+        // once, and the quotient fits the coset of size `8n` it is computed
+        // over
+        let wraps = blinding.wires().max(blinding.permutation()) >= self.size;
+        let exceeds = blinding.added_degree() >= 4 * self.size;
+
+        if degree > max_degree || wraps || exceeds {
+            return Err(Error::PolynomialDegreeTooLarge { degree, max_degree });
         }
+
+        self.blinding = blinding;
+        Ok(self)
+    }
+
+    /// Degrees of the blinding polynomials of the proofs
+    pub fn blinding(&self) -> Blinding {
+        self.blinding
     }
 
     /// adds blinding scalars to a witness vector
@@ -73,30 +269,60 @@ impl Prover {
     ///
     /// if hiding degree = 1: (b2*X^(n+1) + b1*X^n - b2*X - b1) + witnesses
     /// if hiding degree = 2: (b3*X^(n+2) + b2*X^(n+1) + b1*X^n - b3*X^2 - b2*X
-    fn blind_poly<R>(
-        rng: &mut R,
+    fn blind_poly(
         witnesses: &[BlsScalar],
-        hiding_degree: usize,
+        blinding_scalars: &[BlsScalar],
         domain: &EvaluationDomain,
-    ) -> FftPolynomial
-    where
-        R: RngCore + CryptoRng,
-    {
+    ) -> FftPolynomial {
         let mut w_vec_inverse = domain.ifft(witnesses);
+        w_vec_inverse.reserve_exact(blinding_scalars.len());
 
-        for i in 0..hiding_degree + 1 {
-            let blinding_scalar = BlsScalar::random(&mut *rng);
-
+        for (i, blinding_scalar) in blinding_scalars.iter().enumerate() {
             w_vec_inverse[i] = w_vec_inverse[i] - blinding_scalar;
-            w_vec_inverse.push(blinding_scalar);
+            w_vec_inverse.push(*blinding_scalar);
         }
 
         FftPolynomial::from_coefficients_vec(w_vec_inverse)
     }
 
+    /// commits to the polynomial of [`Self::blind_poly`] from the witnesses
+    /// in the Lagrange basis, and from the blinding scalars multiplied by the
+    /// vanishing polynomial in the monomial basis
+    fn commit_blinded(
+        lagrange: &LagrangeKey,
+        commit_key: &CommitKey,
+        witnesses: &[BlsScalar],
+        blinding_scalars: &[BlsScalar],
+    ) -> Result<Commitment, Error> {
+        let n = lagrange.domain().size();
+
+        let mut blinding = vec![BlsScalar::zero(); n + blinding_scalars.len()];
+        for (i, blinding_scalar) in blinding_scalars.iter().enumerate() {
+            blinding[i] = -blinding_scalar;
+            blinding[n + i] = *blinding_scalar;
+        }
+
+        let witnesses = lagrange.commit(witnesses)?;
+        let blinding = commit_key.commit(&blinding)?;
+
+        Ok(Commitment::from(
+            G1Projective::from(witnesses.0) + blinding.0,
+        ))
+    }
+
+    /// samples the `hiding_degree + 1` blinding scalars of a witness vector
+    fn blinding_scalars<R>(rng: &mut R, hiding_degree: usize) -> Vec<BlsScalar>
+    where
+        R: RngCore + CryptoRng,
+    {
+        (0..hiding_degree + 1)
+            .map(|_| BlsScalar::random(&mut *rng))
+            .collect()
+    }
+
     fn prepare_serialize(
         &self,
-    ) -> (usize, Vec<u8>, Vec<u8>, [u8; VerifierKey::SIZE]) {
+    ) -> (usize, Vec<u8>, Vec<u8>, [u8; VerifierKey::SIZE], Vec<u8>) {
         let prover_key = self.prover_key.to_var_bytes();
         let commit_key = self.commit_key.to_raw_var_bytes();
         let verifier_key = self.verifier_key.to_bytes();
@@ -106,10 +332,16 @@ impl Prover {
         let commit_key_len = commit_key.len();
         let verifier_key_len = verifier_key.len();
 
-        let size =
-            48 + label_len + prover_key_len + commit_key_len + verifier_key_len;
+        let settings = self.settings.to_var_bytes();
+
+        let size = 48
+            + label_len
+            + prover_key_len
+            + commit_key_len
+            + verifier_key_len
+            + settings.len();
 
-        (size, prover_key, commit_key, verifier_key)
+        (size, prover_key, commit_key, verifier_key, settings)
     }
 
     /// Serialized size in bytes
@@ -119,7 +351,7 @@ impl Prover {
 
     /// Serialize the prover into bytes
     pub fn to_bytes(&self) -> Vec<u8> {
-        let (size, prover_key, commit_key, verifier_key) =
+        let (size, prover_key, commit_key, verifier_key, settings) =
             self.prepare_serialize();
         let mut bytes = Vec::with_capacity(size);
 
@@ -141,13 +373,85 @@ impl Prover {
         bytes.extend(prover_key);
         bytes.extend(commit_key);
         bytes.extend(verifier_key);
+        bytes.extend(settings);
 
         bytes
     }
 
+    /// Hash of the preprocessed circuit, identifying the circuit of both the
+    /// prover and the verifier
+    pub fn circuit_hash(&self) -> [u8; 32] {
+        self.verifier_key.circuit_hash()
+    }
+
+    /// [Fingerprint](super::Verifier::fingerprint) of the verifier key of
+    /// the circuit
+    pub fn fingerprint(&self) -> [u8; 32] {
+        self.verifier_key.fingerprint()
+    }
+
+    /// Check that the prover key was preprocessed for the circuit of the
+    /// verifier key, without trusting the circuit hash embedded in the
+    /// prover key.
+    ///
+    /// The selectors and the permutation of the prover key are committed to
+    /// again, which costs as much as the commitments of the preprocessing,
+    /// so the check is left to the callers loading provers from untrusted
+    /// sources, or serialized without their circuit hash.
+    pub fn verify_integrity(&self) -> Result<(), Error> {
+        let circuit_hash = self
+            .prover_key
+            .compute_circuit_hash(&self.commit_key, self.constraints)?;
+
+        match circuit_hash == self.circuit_hash() {
+            true => Ok(()),
+            false => Err(Error::CircuitHashMismatch),
+        }
+    }
+
+    /// Write the prover to `writer`, framed with a format version, the
+    /// [circuit hash](Self::circuit_hash) and a checksum, so the
+    /// preprocessing can be cached to disk and reloaded with
+    /// [`Prover::from_reader`].
+    ///
+    /// The prover key is cached with the whole prover rather than on its own,
+    /// since it can't prove without the commit key and the verifier key.
+    #[cfg(feature = "std")]
+    pub fn to_writer<W>(&self, writer: W) -> Result<(), Error>
+    where
+        W: std::io::Write,
+    {
+        cache::write(writer, &self.circuit_hash(), &self.to_bytes())
+    }
+
+    /// Read the prover written with [`Self::to_writer`], using the transcript
+    /// `T`.
+    ///
+    /// Fails if the checksum or the circuit hash don't match the content read.
+    #[cfg(feature = "std")]
+    pub fn from_reader_with_transcript<R>(reader: R) -> Result<Self, Error>
+    where
+        R: std::io::Read,
+    {
+        let (circuit_hash, bytes) = cache::read(reader)?;
+        let prover = Self::try_from_bytes_with_transcript(bytes)?;
+
+        if prover.circuit_hash() != circuit_hash {
+            return Err(Error::CircuitHashMismatch);
+        }
+
+        Ok(prover)
+    }
+
     /// Attempt to deserialize the prover from bytes generated via
-    /// [`Self::to_bytes`]
-    pub fn try_from_bytes<B>(bytes: B) -> Result<Self, Error>
+    /// [`Self::to_bytes`], using the transcript `T`
+    ///
+    /// Fails with [`Error::CircuitHashMismatch`] if the prover key embeds the
+    /// [circuit hash](Self::circuit_hash) of another circuit than the
+    /// verifier key. Prover keys serialized without the hash, as by upstream
+    /// `dusk-plonk` 0.16, are still read, and can be checked against the
+    /// verifier key with [`Self::verify_integrity`].
+    pub fn try_from_bytes_with_transcript<B>(bytes: B) -> Result<Self, Error>
     where
         B: AsRef<[u8]>,
     {
@@ -201,6 +505,10 @@ impl Prover {
         bytes = &bytes[commit_key_len..];
 
         let verifier_key = &bytes[..verifier_key_len];
+        bytes = &bytes[verifier_key_len..];
+
+        // the settings are empty if they're the default ones
+        let settings = Settings::from_slice(bytes)?;
 
         let label = label.to_vec();
         let prover_key = ProverKey::from_slice(prover_key)?;
@@ -211,17 +519,28 @@ impl Prover {
 
         let verifier_key = VerifierKey::from_slice(verifier_key)?;
 
-        Ok(Self::new(
+        let prover = Self::new(
             label,
             prover_key,
             commit_key,
             verifier_key,
             size,
             constraints,
-        ))
+        )?;
+
+        let prover = match settings.domain {
+            Some(domain) => prover.with_domain(domain),
+            None => prover,
+        };
+
+        Ok(prover.with_fiat_shamir(settings.fiat_shamir))
     }
 
     /// Prove the circuit
+    ///
+    /// The blinding factors are the only randomness of the proof and are all
+    /// drawn from `rng`, so a seeded generator yields byte-identical proofs,
+    /// e.g. to compare them against golden files in tests.
     pub fn prove<C, R>(
         &self,
         rng: &mut R,
@@ -231,15 +550,158 @@ impl Prover {
         C: Circuit,
         R: RngCore + CryptoRng,
     {
-        let prover = Builder::prove(self.constraints, circuit)?;
+        self.prove_with_stats(rng, circuit)
+            .map(|(proof, public_inputs, _)| (proof, public_inputs))
+    }
 
-        let constraints = self.constraints;
-        let size = self.size;
+    /// Prove the circuit, packaging the proof with its public inputs and the
+    /// [fingerprint](Self::fingerprint) of the circuit, to be verified with
+    /// [`Verifier::verify_bundle`](super::Verifier::verify_bundle)
+    pub fn prove_bundle<C, R>(
+        &self,
+        rng: &mut R,
+        circuit: &C,
+    ) -> Result<ProofBundle, Error>
+    where
+        C: Circuit,
+        R: RngCore + CryptoRng,
+    {
+        let (proof, public_inputs) = self.prove(rng, circuit)?;
+
+        Ok(ProofBundle::new(proof, public_inputs, self.fingerprint()))
+    }
+
+    /// Prove the circuit, reporting the resources used by the prover in
+    /// [`ProveStats`]
+    pub fn prove_with_stats<C, R>(
+        &self,
+        rng: &mut R,
+        circuit: &C,
+    ) -> Result<(Proof, Vec<BlsScalar>, ProveStats), Error>
+    where
+        C: Circuit,
+        R: RngCore + CryptoRng,
+    {
+        let mut transcript = self.transcript.clone();
+
+        block_on(self.prove_rounds(
+            rng,
+            circuit,
+            &mut transcript,
+            Checkpoint::default(),
+        ))
+    }
+
+    /// Prove the circuit, returning the Fiat-Shamir transcript of the proof
+    ///
+    /// With an [`AuditTranscript`](crate::transcript::AuditTranscript), the
+    /// transcript holds every message absorbed and challenge squeezed by the
+    /// prover, to compare them with the ones of a verifier.
+    pub fn prove_with_transcript<C, R>(
+        &self,
+        rng: &mut R,
+        circuit: &C,
+    ) -> Result<(Proof, Vec<BlsScalar>, T), Error>
+    where
+        C: Circuit,
+        R: RngCore + CryptoRng,
+    {
+        let mut transcript = self.transcript.clone();
+
+        let (proof, public_inputs, _) = block_on(self.prove_rounds(
+            rng,
+            circuit,
+            &mut transcript,
+            Checkpoint::default(),
+        ))?;
+
+        Ok((proof, public_inputs, transcript))
+    }
+
+    /// Prove the circuit, calling `on_progress` every time a [`ProveStage`]
+    /// completes, e.g. to drive a progress bar
+    pub fn prove_with_progress<C, R, F>(
+        &self,
+        rng: &mut R,
+        circuit: &C,
+        mut on_progress: F,
+    ) -> Result<(Proof, Vec<BlsScalar>), Error>
+    where
+        C: Circuit,
+        R: RngCore + CryptoRng,
+        F: FnMut(ProveStage) + Send,
+    {
+        let checkpoint = Checkpoint::observed(&mut on_progress);
+        let mut transcript = self.transcript.clone();
 
-        let domain = EvaluationDomain::new(constraints)?;
+        block_on(self.prove_rounds(rng, circuit, &mut transcript, checkpoint))
+            .map(|(proof, public_inputs, _)| (proof, public_inputs))
+    }
 
+    /// Prove the circuit as a future, yielding to the executor between the
+    /// rounds of the prover.
+    ///
+    /// The proof is aborted with [`Error::ProofCancelled`] at the next round
+    /// once `token` is cancelled. Dropping the future aborts it as well, so
+    /// deadlines can be enforced by racing it against a timer.
+    ///
+    /// Each round of the prover runs inline when the future is polled, and
+    /// holds the polling thread until the round completes. Drive the future
+    /// from a blocking pool, e.g. with `tokio::task::spawn_blocking` and
+    /// `Handle::block_on`, rather than as a task of an async executor, whose
+    /// thread would otherwise stall for the whole round.
+    pub async fn prove_async<C, R>(
+        &self,
+        rng: &mut R,
+        circuit: &C,
+        token: &CancellationToken,
+    ) -> Result<(Proof, Vec<BlsScalar>), Error>
+    where
+        C: Circuit,
+        R: RngCore + CryptoRng,
+    {
         let mut transcript = self.transcript.clone();
 
+        self.prove_rounds(
+            rng,
+            circuit,
+            &mut transcript,
+            Checkpoint::cancellable(token),
+        )
+        .await
+        .map(|(proof, public_inputs, _)| (proof, public_inputs))
+    }
+
+    /// Run the rounds of the prover over the `transcript`, entering the
+    /// `checkpoint` before each
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(constraints = self.constraints)
+        )
+    )]
+    async fn prove_rounds<C, R>(
+        &self,
+        rng: &mut R,
+        circuit: &C,
+        transcript: &mut T,
+        mut checkpoint: Checkpoint<'_>,
+    ) -> Result<(Proof, Vec<BlsScalar>, ProveStats), Error>
+    where
+        C: Circuit,
+        R: RngCore + CryptoRng,
+    {
+        let mut stats = ProveStats::default();
+
+        let prover = Builder::prove(self.constraints, circuit)?;
+        prover.check_witnesses()?;
+
+        let size = self.size;
+
+        let domain = EvaluationDomain::new_mixed_radix(size)?;
+
         let public_inputs = prover.public_inputs();
         let public_input_indexes = prover.public_input_indexes();
         let dense_public_inputs = Builder::dense_public_inputs(
@@ -247,17 +709,28 @@ impl Prover {
             &public_inputs,
             self.size,
         );
+        stats.alloc(size);
 
-        public_inputs
-            .iter()
-            .for_each(|pi| transcript.append_scalar(b"pi", pi));
+        transcript::append_statement(
+            transcript,
+            self.settings.fiat_shamir,
+            &self.verifier_key,
+            &public_input_indexes,
+            &public_inputs,
+        );
+
+        checkpoint.round(1).await?;
 
         // round 1
+        let round = span!("round", n = 1);
+        stats.start_round();
+
         // convert wires to padded scalars
         let mut a_w_scalar = vec![BlsScalar::zero(); size];
         let mut b_w_scalar = vec![BlsScalar::zero(); size];
         let mut o_w_scalar = vec![BlsScalar::zero(); size];
         let mut d_w_scalar = vec![BlsScalar::zero(); size];
+        stats.alloc(4 * size);
 
         prover.constraints.iter().enumerate().for_each(|(i, c)| {
             a_w_scalar[i] = prover[c.w_a];
@@ -266,17 +739,41 @@ impl Prover {
             d_w_scalar[i] = prover[c.w_d];
         });
 
-        let a_w_poly = Self::blind_poly(rng, &a_w_scalar, 1, &domain);
-        let b_w_poly = Self::blind_poly(rng, &b_w_scalar, 1, &domain);
-        let o_w_poly = Self::blind_poly(rng, &o_w_scalar, 1, &domain);
-        let d_w_poly = Self::blind_poly(rng, &d_w_scalar, 1, &domain);
+        // the blinding scalars are sampled upfront so the wire polynomials can
+        // be computed independently
+        let wires = [&a_w_scalar, &b_w_scalar, &o_w_scalar, &d_w_scalar]
+            .map(|w| (w, Self::blinding_scalars(rng, self.blinding.wires())));
+        let [a_w_poly, b_w_poly, o_w_poly, d_w_poly] =
+            util::map_4(wires.clone(), |(w, b)| {
+                Self::blind_poly(w, &b, &domain)
+            });
+
+        stats.fft(4);
+        stats.alloc(a_w_poly.len() * 4);
 
         // commit to wire polynomials
         // ([a(x)]_1, [b(x)]_1, [c(x)]_1, [d(x)]_1)
-        let a_w_poly_commit = self.commit_key.commit(&a_w_poly)?;
-        let b_w_poly_commit = self.commit_key.commit(&b_w_poly)?;
-        let o_w_poly_commit = self.commit_key.commit(&o_w_poly)?;
-        let d_w_poly_commit = self.commit_key.commit(&d_w_poly)?;
+        let commit_key = &self.commit_key;
+        let bases = self.bases.as_deref();
+        let lagrange = self.lagrange.as_deref();
+        stats.msm(a_w_poly.len());
+        stats.msm(b_w_poly.len());
+        stats.msm(o_w_poly.len());
+        stats.msm(d_w_poly.len());
+        let [a_w_poly_commit, b_w_poly_commit, o_w_poly_commit, d_w_poly_commit] =
+            match lagrange {
+                Some(lagrange) => util::map_4(wires, |(w, b)| {
+                    Self::commit_blinded(lagrange, commit_key, w, &b)
+                }),
+                None => util::map_4(
+                    [&a_w_poly, &b_w_poly, &o_w_poly, &d_w_poly],
+                    |p| commit_key.commit_with(p, bases),
+                ),
+            };
+        let a_w_poly_commit = a_w_poly_commit?;
+        let b_w_poly_commit = b_w_poly_commit?;
+        let o_w_poly_commit = o_w_poly_commit?;
+        let d_w_poly_commit = d_w_poly_commit?;
 
         // Add wire polynomial commitments to transcript
         transcript.append_commitment(b"a_w", &a_w_poly_commit);
@@ -284,7 +781,16 @@ impl Prover {
         transcript.append_commitment(b"c_w", &o_w_poly_commit);
         transcript.append_commitment(b"d_w", &d_w_poly_commit);
 
+        stats.end_round(1);
+        round.exit();
+        checkpoint.completed(ProveStage::WiresCommitted);
+
+        checkpoint.round(2).await?;
+
         // round 2
+        let round = span!("round", n = 2);
+        stats.start_round();
+
         // permutation challenges
         let beta = transcript.challenge_scalar(b"beta");
         transcript.append_scalar(b"beta", &beta);
@@ -305,12 +811,43 @@ impl Prover {
         let permutation = prover
             .perm
             .compute_permutation_vec(&domain, wires, &beta, &gamma, sigma);
+        stats.alloc(permutation.len());
+
+        // the wire evaluations are not needed anymore
+        drop((a_w_scalar, b_w_scalar, o_w_scalar, d_w_scalar));
+        stats.release(4 * size);
+
+        let blinding_scalars =
+            Self::blinding_scalars(rng, self.blinding.permutation());
+        let z_poly = Self::blind_poly(&permutation, &blinding_scalars, &domain);
+        stats.fft(1);
+        stats.alloc(z_poly.len());
+
+        stats.msm(z_poly.len());
+        let z_poly_commit = match lagrange {
+            Some(lagrange) => Self::commit_blinded(
+                lagrange,
+                commit_key,
+                &permutation,
+                &blinding_scalars,
+            )?,
+            None => self.commit_key.commit_with(&z_poly, bases)?,
+        };
 
-        let z_poly = Self::blind_poly(rng, &permutation, 2, &domain);
-        let z_poly_commit = self.commit_key.commit(&z_poly)?;
+        stats.release(permutation.len());
+        drop(permutation);
         transcript.append_commitment(b"z", &z_poly_commit);
 
+        stats.end_round(2);
+        round.exit();
+        checkpoint.completed(ProveStage::PermutationCommitted);
+
+        checkpoint.round(3).await?;
+
         // round 3
+        let round = span!("round", n = 3);
+        stats.start_round();
+
         // compute quotient challenge alpha
         let alpha = transcript.challenge_scalar(b"alpha");
         let range_sep_challenge =
@@ -325,6 +862,10 @@ impl Prover {
         // compute public inputs polynomial
         let pi_poly = domain.ifft(&dense_public_inputs);
         let pi_poly = FftPolynomial::from_coefficients_vec(pi_poly);
+        stats.fft(1);
+        stats.alloc(pi_poly.len());
+        drop(dense_public_inputs);
+        stats.release(size);
 
         // compute quotient polynomial
         let wires = (&a_w_poly, &b_w_poly, &o_w_poly, &d_w_poly);
@@ -344,6 +885,7 @@ impl Prover {
             wires,
             &pi_poly,
             args,
+            &mut stats,
         )?;
 
         // split quotient polynomial into 4 degree `n` polynomials
@@ -360,12 +902,27 @@ impl Prover {
         let t_4_poly = FftPolynomial::from_coefficients_vec(
             t_poly[3 * domain_size..].to_vec(),
         );
+        stats.alloc(t_poly.len());
+
+        // the quotient is evaluated from its split parts from now on, so its
+        // `8n` coefficients are released
+        drop(t_poly);
+        stats.release(8 * domain_size);
 
         // commit to split quotient polynomial
-        let t_low_commit = self.commit_key.commit(&t_low_poly)?;
-        let t_mid_commit = self.commit_key.commit(&t_mid_poly)?;
-        let t_high_commit = self.commit_key.commit(&t_high_poly)?;
-        let t_4_commit = self.commit_key.commit(&t_4_poly)?;
+        stats.msm(t_low_poly.len());
+        stats.msm(t_mid_poly.len());
+        stats.msm(t_high_poly.len());
+        stats.msm(t_4_poly.len());
+        let [t_low_commit, t_mid_commit, t_high_commit, t_4_commit] =
+            util::map_4(
+                [&t_low_poly, &t_mid_poly, &t_high_poly, &t_4_poly],
+                |p| commit_key.commit_with(p, bases),
+            );
+        let t_low_commit = t_low_commit?;
+        let t_mid_commit = t_mid_commit?;
+        let t_high_commit = t_high_commit?;
+        let t_4_commit = t_4_commit?;
 
         // add quotient polynomial commitments to transcript
         transcript.append_commitment(b"t_low", &t_low_commit);
@@ -373,11 +930,42 @@ impl Prover {
         transcript.append_commitment(b"t_high", &t_high_commit);
         transcript.append_commitment(b"t_4", &t_4_commit);
 
+        stats.end_round(3);
+        round.exit();
+        checkpoint.completed(ProveStage::QuotientCommitted);
+
+        checkpoint.round(4).await?;
+
         // round 4
+        let round = span!("round", n = 4);
+        stats.start_round();
+
         // compute evaluation challenge 'z'
         let z_challenge = transcript.challenge_scalar(b"z_challenge");
 
+        stats.end_round(4);
+        round.exit();
+
+        checkpoint.round(5).await?;
+
         // round 5
+        let round = span!("round", n = 5);
+        stats.start_round();
+
+        // compute the quotient polynomial linearized at the challenge
+        let z_n = z_challenge.pow(&[domain_size as u64, 0, 0, 0]);
+        let z_two_n = z_challenge.pow(&[2 * domain_size as u64, 0, 0, 0]);
+        let z_three_n = z_challenge.pow(&[3 * domain_size as u64, 0, 0, 0]);
+
+        let a = &t_low_poly;
+        let b = &t_mid_poly * &z_n;
+        let c = &t_high_poly * &z_two_n;
+        let d = &t_4_poly * &z_three_n;
+        let abc = &(a + &b) + &c;
+
+        let quot = &abc + &d;
+        stats.alloc(quot.len());
+
         // compute linearization polynomial
         let (r_poly, evaluations) = linearization_poly::compute(
             &domain,
@@ -396,10 +984,12 @@ impl Prover {
             &b_w_poly,
             &o_w_poly,
             &d_w_poly,
-            &t_poly,
+            &quot,
             &z_poly,
         );
 
+        stats.alloc(r_poly.len());
+
         // add evaluations to transcript.
         transcript.append_scalar(b"a_eval", &evaluations.proof.a_eval);
         transcript.append_scalar(b"b_eval", &evaluations.proof.b_eval);
@@ -432,48 +1022,45 @@ impl Prover {
         transcript.append_scalar(b"t_eval", &evaluations.t_eval);
         transcript.append_scalar(b"r_eval", &evaluations.proof.r_poly_eval);
 
-        // compute Openings using KZG10
-        let z_n = z_challenge.pow(&[domain_size as u64, 0, 0, 0]);
-        let z_two_n = z_challenge.pow(&[2 * domain_size as u64, 0, 0, 0]);
-        let z_three_n = z_challenge.pow(&[3 * domain_size as u64, 0, 0, 0]);
-
-        let a = &t_low_poly;
-        let b = &t_mid_poly * &z_n;
-        let c = &t_high_poly * &z_two_n;
-        let d = &t_4_poly * &z_three_n;
-        let abc = &(a + &b) + &c;
-
-        let quot = &abc + &d;
-
         // compute aggregate witness to polynomials evaluated at the evaluation
         // challenge z. The challenge v is selected inside
         let aggregate_witness = self.commit_key.compute_aggregate_witness(
             &[
-                quot,
-                r_poly,
-                a_w_poly.clone(),
-                b_w_poly.clone(),
-                o_w_poly,
-                d_w_poly.clone(),
-                self.prover_key.permutation.s_sigma_1.0.clone(),
-                self.prover_key.permutation.s_sigma_2.0.clone(),
-                self.prover_key.permutation.s_sigma_3.0.clone(),
+                &quot,
+                &r_poly,
+                &a_w_poly,
+                &b_w_poly,
+                &o_w_poly,
+                &d_w_poly,
+                &self.prover_key.permutation.s_sigma_1.0,
+                &self.prover_key.permutation.s_sigma_2.0,
+                &self.prover_key.permutation.s_sigma_3.0,
             ],
             &z_challenge,
-            &mut transcript,
+            transcript,
         );
-        let w_z_chall_comm = self.commit_key.commit(&aggregate_witness)?;
+        stats.alloc(aggregate_witness.len());
+        stats.msm(aggregate_witness.len());
+        let w_z_chall_comm =
+            self.commit_key.commit_with(&aggregate_witness, bases)?;
 
         // compute aggregate witness to polynomials evaluated at the shifted
         // evaluation challenge
         let shifted_aggregate_witness =
             self.commit_key.compute_aggregate_witness(
-                &[z_poly, a_w_poly, b_w_poly, d_w_poly],
+                &[&z_poly, &a_w_poly, &b_w_poly, &d_w_poly],
                 &(z_challenge * domain.group_gen),
-                &mut transcript,
+                transcript,
             );
-        let w_z_chall_w_comm =
-            self.commit_key.commit(&shifted_aggregate_witness)?;
+        stats.alloc(shifted_aggregate_witness.len());
+        stats.msm(shifted_aggregate_witness.len());
+        let w_z_chall_w_comm = self
+            .commit_key
+            .commit_with(&shifted_aggregate_witness, bases)?;
+
+        stats.end_round(5);
+        round.exit();
+        checkpoint.completed(ProveStage::OpeningsComputed);
 
         let proof = Proof {
             a_comm: a_w_poly_commit,
@@ -494,6 +1081,43 @@ impl Prover {
             evaluations: evaluations.proof,
         };
 
-        Ok((proof, public_inputs))
+        Ok((proof, public_inputs, stats))
+    }
+}
+
+impl<T: TranscriptProtocol + Sync> Prover<T> {
+    /// Prove many instances of the circuit, in parallel with the `parallel`
+    /// feature.
+    ///
+    /// Every proof draws its blinding factors from its own generator, seeded
+    /// from `rng`, so the proofs can be created independently. The proofs are
+    /// returned with their public inputs, in the order of `circuits`, and the
+    /// first failure aborts the batch.
+    pub fn prove_many<C, R>(
+        &self,
+        rng: &mut R,
+        circuits: &[C],
+    ) -> Result<Vec<(Proof, Vec<BlsScalar>)>, Error>
+    where
+        C: Circuit + Sync,
+        R: RngCore + CryptoRng + SeedableRng + Send,
+    {
+        let rngs: Vec<R> = circuits
+            .iter()
+            .map(|_| {
+                let mut seed = R::Seed::default();
+                rng.fill_bytes(seed.as_mut());
+                R::from_seed(seed)
+            })
+            .collect();
+
+        #[cfg(not(feature = "parallel"))]
+        let jobs = circuits.iter().zip(rngs);
+
+        #[cfg(feature = "parallel")]
+        let jobs = circuits.par_iter().zip(rngs);
+
+        jobs.map(|(circuit, mut rng)| self.prove(&mut rng, circuit))
+            .collect()
     }
 }
@@ -0,0 +1,119 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use alloc::vec::Vec;
+
+use dusk_bls12_381::BlsScalar;
+use dusk_jubjub::{JubJubAffine, JubJubExtended};
+
+/// Bytes packed into a single scalar, so every chunk is below the modulus
+const BYTES_PER_SCALAR: usize = 31;
+
+/// Value of a public input, expanded into the scalars the circuit exposes for
+/// it.
+///
+/// The expansion of a value matches the public inputs appended by
+/// [`Composer::append_public_value`](super::Composer::append_public_value):
+///
+/// - a scalar is a single scalar
+/// - a point is its affine coordinates `(x, y)`, as in
+///   [`Composer::append_public_point`](super::Composer::append_public_point)
+/// - a boolean is `0` or `1`
+/// - a `u64` is a single scalar
+/// - bytes are split into chunks of 31 bytes, each read as a little endian
+///   scalar, with the last chunk padded with zeros. The length of the bytes
+///   isn't encoded, so their length is expected to be fixed by the circuit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PublicInputValue {
+    /// Scalar of the field of the circuit
+    Scalar(BlsScalar),
+    /// Point of the embedded curve
+    Point(JubJubAffine),
+    /// Boolean
+    Bool(bool),
+    /// Unsigned integer
+    U64(u64),
+    /// Bytes, packed into scalars
+    Bytes(Vec<u8>),
+}
+
+impl PublicInputValue {
+    /// Scalars of the public inputs standing for the value
+    pub fn expand(&self) -> Vec<BlsScalar> {
+        match self {
+            Self::Scalar(scalar) => vec![*scalar],
+            Self::Point(point) => vec![point.get_u(), point.get_v()],
+            Self::Bool(b) => vec![BlsScalar::from(*b as u64)],
+            Self::U64(n) => vec![BlsScalar::from(*n)],
+            Self::Bytes(bytes) => bytes
+                .chunks(BYTES_PER_SCALAR)
+                .map(|chunk| {
+                    let mut buf = [0u8; 32];
+                    buf[..chunk.len()].copy_from_slice(chunk);
+
+                    BlsScalar::from_bytes(&buf).expect("chunk below modulus")
+                })
+                .collect(),
+        }
+    }
+
+    /// Scalars of the public inputs standing for the `values`, in order, to
+    /// be passed to [`Verifier::verify`](super::Verifier::verify)
+    pub fn expand_all<'a, I>(values: I) -> Vec<BlsScalar>
+    where
+        I: IntoIterator<Item = &'a Self>,
+    {
+        values.into_iter().flat_map(Self::expand).collect()
+    }
+}
+
+impl From<BlsScalar> for PublicInputValue {
+    fn from(scalar: BlsScalar) -> Self {
+        Self::Scalar(scalar)
+    }
+}
+
+impl From<JubJubAffine> for PublicInputValue {
+    fn from(point: JubJubAffine) -> Self {
+        Self::Point(point)
+    }
+}
+
+impl From<JubJubExtended> for PublicInputValue {
+    fn from(point: JubJubExtended) -> Self {
+        Self::Point(point.into())
+    }
+}
+
+impl From<bool> for PublicInputValue {
+    fn from(b: bool) -> Self {
+        Self::Bool(b)
+    }
+}
+
+impl From<u64> for PublicInputValue {
+    fn from(n: u64) -> Self {
+        Self::U64(n)
+    }
+}
+
+impl From<Vec<u8>> for PublicInputValue {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self::Bytes(bytes)
+    }
+}
+
+impl From<&[u8]> for PublicInputValue {
+    fn from(bytes: &[u8]) -> Self {
+        Self::Bytes(bytes.to_vec())
+    }
+}
+
+impl<const N: usize> From<[u8; N]> for PublicInputValue {
+    fn from(bytes: [u8; N]) -> Self {
+        Self::Bytes(bytes.to_vec())
+    }
+}
@@ -0,0 +1,267 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Row by row satisfiability check of the gates of a [`Builder`].
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use dusk_bls12_381::BlsScalar;
+
+use crate::constraint_system::Witness;
+use crate::error::Error;
+use crate::fft::{EvaluationDomain, Evaluations, Polynomial as FftPolynomial};
+use crate::proof_system::widget;
+
+use super::Builder;
+
+/// Separation challenges of the range, logic, fixed base and variable base
+/// widgets.
+///
+/// They combine the identities of a widget as the prover's challenges do, so
+/// a deliberately bad witness is caught unless crafted against them.
+const SEPARATION_CHALLENGES: [u64; 4] = [
+    0x9e37_79b9_7f4a_7c15,
+    0xbf58_476d_1ce4_e5b9,
+    0x94d0_49bb_1331_11eb,
+    0xd6e8_feb8_6659_fd93,
+];
+
+/// Perturbations added in turn to a witness by
+/// [`Builder::check_witness_mutations`]
+const MUTATIONS: [u64; 3] = [1, 2, 0x2545_f491_4f6c_dd1d];
+
+/// Identities of the widgets over the rows of a [`Builder`]
+struct Identities {
+    arithmetic: widget::arithmetic::ProverKey,
+    range: widget::range::ProverKey,
+    logic: widget::logic::ProverKey,
+    fixed_base: widget::ecc::scalar_mul::fixed_base::ProverKey,
+    variable_base: widget::ecc::curve_addition::ProverKey,
+}
+
+impl Identities {
+    fn new(builder: &Builder) -> Result<Self, Error> {
+        // the evaluations of the selectors over the rows of the circuit
+        let domain = EvaluationDomain::new(builder.constraints.len())?;
+        let rows = |selector: fn(&super::Polynomial) -> BlsScalar| {
+            let evals: Vec<BlsScalar> =
+                builder.constraints.iter().map(selector).collect();
+            (
+                FftPolynomial::zero(),
+                Evaluations::from_vec_and_domain(evals, domain),
+            )
+        };
+
+        Ok(Self {
+            arithmetic: widget::arithmetic::ProverKey {
+                q_m: rows(|c| c.q_m),
+                q_l: rows(|c| c.q_l),
+                q_r: rows(|c| c.q_r),
+                q_o: rows(|c| c.q_o),
+                q_c: rows(|c| c.q_c),
+                q_4: rows(|c| c.q_d),
+                q_arith: rows(|c| c.q_arith),
+            },
+            range: widget::range::ProverKey {
+                q_range: rows(|c| c.q_range),
+            },
+            logic: widget::logic::ProverKey {
+                q_c: rows(|c| c.q_c),
+                q_logic: rows(|c| c.q_logic),
+            },
+            fixed_base: widget::ecc::scalar_mul::fixed_base::ProverKey {
+                q_l: rows(|c| c.q_l),
+                q_r: rows(|c| c.q_r),
+                q_c: rows(|c| c.q_c),
+                q_fixed_group_add: rows(|c| c.q_fixed_group_add),
+            },
+            variable_base: widget::ecc::curve_addition::ProverKey {
+                q_variable_group_add: rows(|c| c.q_variable_group_add),
+            },
+        })
+    }
+
+    /// Check the identities of the gate `i`, with the witnesses valued by
+    /// `value`
+    fn satisfied<F>(&self, builder: &Builder, i: usize, value: F) -> bool
+    where
+        F: Fn(Witness) -> BlsScalar,
+    {
+        let [range_challenge, logic_challenge, fixed_base_challenge, variable_base_challenge] =
+            SEPARATION_CHALLENGES.map(BlsScalar::from);
+
+        // the wires of the row, and the ones of the next row, that are zero
+        // past the last gate as in the padded circuit
+        let wires = |i: usize| match builder.constraints.get(i) {
+            Some(c) => c.wires().map(&value),
+            None => [BlsScalar::zero(); 4],
+        };
+
+        let [a, b, o, d] = wires(i);
+        let [a_next, b_next, _, d_next] = wires(i + 1);
+        let pi = builder.public_inputs.get(&i).copied().unwrap_or_default();
+
+        let identities = [
+            self.arithmetic.compute_quotient_i(i, &a, &b, &o, &d) + pi,
+            self.range.compute_quotient_i(
+                i,
+                &range_challenge,
+                &a,
+                &b,
+                &o,
+                &d,
+                &d_next,
+            ),
+            self.logic.compute_quotient_i(
+                i,
+                &logic_challenge,
+                &a,
+                &a_next,
+                &b,
+                &b_next,
+                &o,
+                &d,
+                &d_next,
+            ),
+            self.fixed_base.compute_quotient_i(
+                i,
+                &fixed_base_challenge,
+                &a,
+                &a_next,
+                &b,
+                &b_next,
+                &o,
+                &d,
+                &d_next,
+            ),
+            self.variable_base.compute_quotient_i(
+                i,
+                &variable_base_challenge,
+                &a,
+                &a_next,
+                &b,
+                &b_next,
+                &o,
+                &d,
+                &d_next,
+            ),
+        ];
+
+        identities.iter().all(|id| *id == BlsScalar::zero())
+    }
+}
+
+impl Builder {
+    /// Label of the `gate`, set with
+    /// [`Composer::label`](super::Composer::label) before the gate was
+    /// appended
+    pub fn label_of(&self, gate: usize) -> Option<&'static str> {
+        self.labels
+            .iter()
+            .rev()
+            .find(|(start, _)| *start <= gate)
+            .map(|(_, label)| *label)
+    }
+
+    /// Check that the witnesses satisfy the constraint of every gate, with
+    /// the identities of the prover, and return the first unsatisfied gate
+    /// as [`Error::UnsatisfiedConstraint`] along with its label.
+    ///
+    /// The copy constraints always hold, as the gates are wired to the
+    /// witnesses of the builder. The gates are checked after the
+    /// optimization passes, which may shift the gates a label applies to.
+    pub fn check_satisfied(&self) -> Result<(), Error> {
+        self.check_witnesses()?;
+
+        if self.constraints.is_empty() {
+            return Ok(());
+        }
+
+        let identities = Identities::new(self)?;
+
+        (0..self.constraints.len()).try_for_each(|i| {
+            match identities.satisfied(self, i, |w| self[w]) {
+                true => Ok(()),
+                false => Err(Error::UnsatisfiedConstraint {
+                    gate: i,
+                    label: self.label_of(i),
+                }),
+            }
+        })
+    }
+
+    /// Soundness self-test of the circuit: perturb each witness wired to a
+    /// gate in turn, and check that some gate becomes unsatisfied.
+    ///
+    /// The first witness that can take another value while every gate stays
+    /// satisfied is returned as [`Error::UnderConstrainedWitness`], along
+    /// with the first gate wired to it and its label. Witnesses that aren't
+    /// wired to any gate are skipped, as they don't take part in the proof.
+    /// Private inputs that no gate pins down are flagged as well, e.g. the
+    /// value a select gadget discards.
+    ///
+    /// The satisfiability of the circuit is checked first with
+    /// [`Builder::check_satisfied`]. This re-evaluates the gates of every
+    /// witness a few times, so it is meant for tests only.
+    pub fn check_witness_mutations(&self) -> Result<(), Error> {
+        self.check_satisfied()?;
+
+        if self.constraints.is_empty() {
+            return Ok(());
+        }
+
+        let identities = Identities::new(self)?;
+
+        // the gates wired to each witness, that read it on their own row or
+        // on the next one
+        let mut gates: Vec<Vec<usize>> = vec![Vec::new(); self.witnesses.len()];
+        self.constraints.iter().enumerate().for_each(|(i, poly)| {
+            poly.wires().iter().for_each(|w| {
+                let gates = &mut gates[w.index()];
+                (i.saturating_sub(1)..=i).for_each(|gate| {
+                    if gates.last() < Some(&gate) {
+                        gates.push(gate);
+                    }
+                })
+            })
+        });
+
+        gates
+            .iter()
+            .enumerate()
+            .filter(|(_, gates)| !gates.is_empty())
+            .try_for_each(|(index, gates)| {
+                let witness = Witness::new(index);
+
+                let detected = MUTATIONS.iter().all(|mutation| {
+                    let mutated = self[witness] + BlsScalar::from(*mutation);
+                    let value = |w: Witness| match w == witness {
+                        true => mutated,
+                        false => self[w],
+                    };
+
+                    gates.iter().any(|i| !identities.satisfied(self, *i, value))
+                });
+
+                // the first gate that reads the witness on its own row
+                let gate = gates
+                    .iter()
+                    .copied()
+                    .find(|i| self.constraints[*i].wires().contains(&witness))
+                    .unwrap_or_default();
+
+                match detected {
+                    true => Ok(()),
+                    false => Err(Error::UnderConstrainedWitness {
+                        witness: index,
+                        gate,
+                        label: self.label_of(gate),
+                    }),
+                }
+            })
+    }
+}
@@ -0,0 +1,103 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use alloc::vec::Vec;
+use core::time::Duration;
+
+use dusk_bls12_381::BlsScalar;
+use dusk_bytes::Serializable;
+
+/// Number of rounds of the prover
+pub const ROUNDS: usize = 5;
+
+/// Report of the resources used to create a proof, returned by
+/// [`Prover::prove_with_stats`](super::Prover::prove_with_stats).
+///
+/// The figures allow to plan the capacity of a prover without an external
+/// profiler: the time of the rounds grows with the FFTs and the MSMs, and the
+/// peak memory with the size of the circuit.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProveStats {
+    rounds: [Duration; ROUNDS],
+    ffts: usize,
+    msm_sizes: Vec<usize>,
+    memory: usize,
+    peak_memory: usize,
+    #[cfg(feature = "std")]
+    round_start: Option<std::time::Instant>,
+}
+
+impl ProveStats {
+    /// Wall time of every round of the prover.
+    ///
+    /// The time is only measured with the `std` feature, and is zero
+    /// otherwise.
+    pub const fn rounds(&self) -> &[Duration; ROUNDS] {
+        &self.rounds
+    }
+
+    /// Total wall time of the rounds of the prover
+    pub fn total_time(&self) -> Duration {
+        self.rounds.iter().sum()
+    }
+
+    /// Number of FFTs and inverse FFTs computed, over the domain and its
+    /// cosets
+    pub const fn ffts(&self) -> usize {
+        self.ffts
+    }
+
+    /// Number of points of every multi-scalar multiplication, in the order
+    /// the commitments were computed
+    pub fn msm_sizes(&self) -> &[usize] {
+        &self.msm_sizes
+    }
+
+    /// Estimate of the peak memory, in bytes, of the polynomials and the
+    /// evaluations held by the prover at the same time.
+    ///
+    /// The keys of the prover are not accounted for, since they stay in
+    /// memory between proofs.
+    pub const fn peak_memory(&self) -> usize {
+        self.peak_memory
+    }
+
+    pub(crate) fn start_round(&mut self) {
+        #[cfg(feature = "std")]
+        {
+            self.round_start = Some(std::time::Instant::now());
+        }
+    }
+
+    pub(crate) fn end_round(&mut self, round: usize) {
+        #[cfg(feature = "std")]
+        if let Some(start) = self.round_start.take() {
+            self.rounds[round - 1] = start.elapsed();
+        }
+
+        #[cfg(not(feature = "std"))]
+        let _ = round;
+    }
+
+    pub(crate) fn fft(&mut self, count: usize) {
+        self.ffts += count;
+    }
+
+    pub(crate) fn msm(&mut self, size: usize) {
+        self.msm_sizes.push(size);
+    }
+
+    /// Account for the allocation of `scalars` scalars
+    pub(crate) fn alloc(&mut self, scalars: usize) {
+        self.memory += scalars * BlsScalar::SIZE;
+        self.peak_memory = self.peak_memory.max(self.memory);
+    }
+
+    /// Account for the release of `scalars` scalars
+    pub(crate) fn release(&mut self, scalars: usize) {
+        self.memory = self.memory.saturating_sub(scalars * BlsScalar::SIZE);
+    }
+}
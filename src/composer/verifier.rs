@@ -6,29 +6,87 @@
 
 use alloc::vec::Vec;
 
-use dusk_bls12_381::BlsScalar;
+use dusk_bls12_381::{BlsScalar, G1Projective};
 use dusk_bytes::{DeserializableSlice, Serializable};
 use merlin::Transcript;
+use rand_core::{CryptoRng, RngCore};
 
 use crate::commitment_scheme::OpeningKey;
 use crate::error::Error;
+use crate::fft::EvaluationDomain;
 use crate::proof_system::{Proof, VerifierKey};
-use crate::transcript::TranscriptProtocol;
+use crate::transcript::{
+    self, Compat, Domain, FiatShamir, Settings, TranscriptProtocol,
+};
 
-use super::Builder;
+#[cfg(feature = "std")]
+use super::cache;
+use super::heapless::VerifierBytes;
+use super::{Accumulator, ProofBundle, VerifierCost};
 
 /// Verify proofs of a given circuit
-pub struct Verifier {
+///
+/// The Fiat-Shamir transcript `T` defaults to the Merlin transcript, and must
+/// match the transcript of the [`Prover`](super::Prover).
+pub struct Verifier<T = Transcript> {
     label: Vec<u8>,
     verifier_key: VerifierKey,
     opening_key: OpeningKey,
     public_input_indexes: Vec<usize>,
-    transcript: Transcript,
+    transcript: T,
+    settings: Settings,
     size: usize,
     constraints: usize,
 }
 
 impl Verifier {
+    /// Attempt to deserialize the verifier from bytes generated via
+    /// [`Self::to_bytes`]
+    pub fn try_from_bytes<B>(bytes: B) -> Result<Self, Error>
+    where
+        B: AsRef<[u8]>,
+    {
+        Self::try_from_bytes_with_transcript(bytes)
+    }
+
+    /// Reset the transcript of the verifier to the one of the upstream
+    /// `dusk-plonk` version `compat`, dropping any application domain and
+    /// strong binding of the statement.
+    ///
+    /// The verifier then interoperates with a prover of the upstream crate, or
+    /// with a [`Prover`](super::Prover) of this crate in the same mode.
+    pub fn with_compat(mut self, compat: Compat) -> Self {
+        match compat {
+            Compat::V0_16 => {
+                self.transcript = transcript::base(
+                    self.label.as_slice(),
+                    None,
+                    &self.verifier_key,
+                    self.constraints,
+                );
+                self.settings = Settings::default();
+            }
+        }
+
+        self
+    }
+
+    /// Upstream `dusk-plonk` version the verifier is compatible with, if any
+    pub fn compat(&self) -> Option<Compat> {
+        Compat::of(&self.settings)
+    }
+
+    /// Read the verifier written with [`Self::to_writer`]
+    #[cfg(feature = "std")]
+    pub fn from_reader<R>(reader: R) -> Result<Self, Error>
+    where
+        R: std::io::Read,
+    {
+        Self::from_reader_with_transcript(reader)
+    }
+}
+
+impl<T: TranscriptProtocol> Verifier<T> {
     pub(crate) fn new(
         label: Vec<u8>,
         verifier_key: VerifierKey,
@@ -37,8 +95,12 @@ impl Verifier {
         size: usize,
         constraints: usize,
     ) -> Self {
-        let transcript =
-            Transcript::base(label.as_slice(), &verifier_key, constraints);
+        let transcript = transcript::base(
+            label.as_slice(),
+            None,
+            &verifier_key,
+            constraints,
+        );
 
         Self {
             label,
@@ -46,14 +108,54 @@ impl Verifier {
             opening_key,
             public_input_indexes,
             transcript,
+            settings: Settings::default(),
             size,
             constraints,
         }
     }
 
+    /// Absorb the application `domain` in the transcript of the verifier.
+    ///
+    /// Only the proofs of a [`Prover`](super::Prover) of the same domain will
+    /// verify.
+    pub fn with_domain(mut self, domain: Domain) -> Self {
+        self.transcript = transcript::base(
+            self.label.as_slice(),
+            Some(domain.as_parts()),
+            &self.verifier_key,
+            self.constraints,
+        );
+        self.settings.domain = Some(domain);
+
+        self
+    }
+
+    /// Application domain of the verifier, if any
+    pub fn domain(&self) -> Option<&Domain> {
+        self.settings.domain.as_ref()
+    }
+
+    /// Set the binding of the statement of the proofs to the transcript.
+    ///
+    /// The verifier and the prover must use the same mode.
+    pub fn with_fiat_shamir(mut self, fiat_shamir: FiatShamir) -> Self {
+        self.settings.fiat_shamir = fiat_shamir;
+        self
+    }
+
+    /// Binding of the statement of the proofs to the transcript
+    pub fn fiat_shamir(&self) -> FiatShamir {
+        self.settings.fiat_shamir
+    }
+
     fn prepare_serialize(
         &self,
-    ) -> (usize, [u8; VerifierKey::SIZE], [u8; OpeningKey::SIZE]) {
+    ) -> (
+        usize,
+        [u8; VerifierKey::SIZE],
+        [u8; OpeningKey::SIZE],
+        Vec<u8>,
+    ) {
         let verifier_key = self.verifier_key.to_bytes();
         let opening_key = self.opening_key.to_bytes();
 
@@ -62,13 +164,16 @@ impl Verifier {
         let opening_key_len = opening_key.len();
         let public_input_indexes_len = self.public_input_indexes.len() * 8;
 
+        let settings = self.settings.to_var_bytes();
+
         let size = 48
             + label_len
             + verifier_key_len
             + opening_key_len
-            + public_input_indexes_len;
+            + public_input_indexes_len
+            + settings.len();
 
-        (size, verifier_key, opening_key)
+        (size, verifier_key, opening_key, settings)
     }
 
     /// Serialized size in bytes
@@ -78,7 +183,8 @@ impl Verifier {
 
     /// Serialize the verifier into bytes
     pub fn to_bytes(&self) -> Vec<u8> {
-        let (size, verifier_key, opening_key) = self.prepare_serialize();
+        let (size, verifier_key, opening_key, settings) =
+            self.prepare_serialize();
         let mut bytes = Vec::with_capacity(size);
 
         let label_len = self.label.len() as u64;
@@ -105,96 +211,259 @@ impl Verifier {
             .map(u64::to_be_bytes)
             .for_each(|i| bytes.extend(i));
 
+        bytes.extend(settings);
+
         bytes
     }
 
-    /// Attempt to deserialize the prover from bytes generated via
-    /// [`Self::to_bytes`]
-    pub fn try_from_bytes<B>(bytes: B) -> Result<Self, Error>
+    /// Hash of the preprocessed circuit, identifying the circuit of both the
+    /// prover and the verifier
+    pub fn circuit_hash(&self) -> [u8; 32] {
+        self.verifier_key.circuit_hash()
+    }
+
+    /// Canonical fingerprint of the verifier key, to identify the circuit
+    /// outside of this crate, e.g. in on-chain registries.
+    ///
+    /// The fingerprint is the SHA-256 digest of:
+    ///
+    /// | field       | size      |                                       |
+    /// |-------------|-----------|---------------------------------------|
+    /// | domain      | 25        | `dusk-plonk-fingerprint-v1` in ASCII  |
+    /// | gates       | 8         | little endian number of gates         |
+    /// | commitments | 15 × 48   | compressed G1 points                  |
+    ///
+    /// The commitments are the ones of `q_m`, `q_l`, `q_r`, `q_o`, `q_4`,
+    /// `q_c`, `q_arith`, `q_logic`, `q_range`, `q_fixed_group_add`,
+    /// `q_variable_group_add`, `s_sigma_1`, `s_sigma_2`, `s_sigma_3` and
+    /// `s_sigma_4`, in this order, each in the compressed encoding of
+    /// `dusk-bls12_381`. This is the verifier key as serialized in
+    /// [`Self::to_bytes`], without its trailing padding. Since only canonical
+    /// encodings are accepted, every verifier key has a single fingerprint.
+    pub fn fingerprint(&self) -> [u8; 32] {
+        self.verifier_key.fingerprint()
+    }
+
+    /// Cost profile of the verification of a proof of the circuit
+    pub fn cost(&self) -> VerifierCost {
+        VerifierCost::new(self.public_input_indexes.len())
+    }
+
+    /// Write the verifier to `writer`, framed with a format version, the
+    /// [circuit hash](Self::circuit_hash) and a checksum, so the
+    /// preprocessing can be cached to disk and reloaded with
+    /// [`Verifier::from_reader`]
+    #[cfg(feature = "std")]
+    pub fn to_writer<W>(&self, writer: W) -> Result<(), Error>
     where
-        B: AsRef<[u8]>,
+        W: std::io::Write,
     {
-        let mut bytes = bytes.as_ref();
+        cache::write(writer, &self.circuit_hash(), &self.to_bytes())
+    }
 
-        if bytes.len() < 48 {
-            return Err(Error::NotEnoughBytes);
-        }
+    /// Read the verifier written with [`Self::to_writer`], using the transcript
+    /// `T`.
+    ///
+    /// Fails if the checksum or the circuit hash don't match the content read.
+    #[cfg(feature = "std")]
+    pub fn from_reader_with_transcript<R>(reader: R) -> Result<Self, Error>
+    where
+        R: std::io::Read,
+    {
+        let (circuit_hash, bytes) = cache::read(reader)?;
+        let verifier = Self::try_from_bytes_with_transcript(bytes)?;
 
-        let label_len = <[u8; 8]>::try_from(&bytes[..8]).expect("checked len");
-        let label_len = u64::from_be_bytes(label_len) as usize;
-        bytes = &bytes[8..];
-
-        let verifier_key_len =
-            <[u8; 8]>::try_from(&bytes[..8]).expect("checked len");
-        let verifier_key_len = u64::from_be_bytes(verifier_key_len) as usize;
-        bytes = &bytes[8..];
-
-        let opening_key_len =
-            <[u8; 8]>::try_from(&bytes[..8]).expect("checked len");
-        let opening_key_len = u64::from_be_bytes(opening_key_len) as usize;
-        bytes = &bytes[8..];
-
-        let public_input_indexes_len =
-            <[u8; 8]>::try_from(&bytes[..8]).expect("checked len");
-        let public_input_indexes_len =
-            u64::from_be_bytes(public_input_indexes_len) as usize;
-        bytes = &bytes[8..];
-
-        let size = <[u8; 8]>::try_from(&bytes[..8]).expect("checked len");
-        let size = u64::from_be_bytes(size) as usize;
-        bytes = &bytes[8..];
-
-        let constraints =
-            <[u8; 8]>::try_from(&bytes[..8]).expect("checked len");
-        let constraints = u64::from_be_bytes(constraints) as usize;
-        bytes = &bytes[8..];
-
-        if bytes.len()
-            < label_len
-                + verifier_key_len
-                + opening_key_len
-                + public_input_indexes_len * 8
-        {
-            return Err(Error::NotEnoughBytes);
+        if verifier.circuit_hash() != circuit_hash {
+            return Err(Error::CircuitHashMismatch);
         }
 
-        let label = &bytes[..label_len];
-        bytes = &bytes[label_len..];
-
-        let verifier_key = &bytes[..verifier_key_len];
-        bytes = &bytes[verifier_key_len..];
+        Ok(verifier)
+    }
 
-        let opening_key = &bytes[..opening_key_len];
-        bytes = &bytes[opening_key_len..];
+    /// Attempt to deserialize the verifier from bytes generated via
+    /// [`Self::to_bytes`], using the transcript `T`
+    pub fn try_from_bytes_with_transcript<B>(bytes: B) -> Result<Self, Error>
+    where
+        B: AsRef<[u8]>,
+    {
+        let bytes = VerifierBytes::split(bytes.as_ref())?;
 
-        let public_input_indexes = &bytes[..public_input_indexes_len * 8];
+        // the settings are empty if they're the default ones
+        let settings = Settings::from_slice(bytes.settings)?;
 
-        let label = label.to_vec();
-        let verifier_key = VerifierKey::from_slice(verifier_key)?;
-        let opening_key = OpeningKey::from_slice(opening_key)?;
-        let public_input_indexes = public_input_indexes
-            .chunks_exact(8)
-            .map(|c| <[u8; 8]>::try_from(c).expect("checked len"))
-            .map(u64::from_be_bytes)
-            .map(|n| n as usize)
-            .collect();
+        let label = bytes.label.to_vec();
+        let verifier_key = VerifierKey::from_slice(bytes.verifier_key)?;
+        let opening_key = OpeningKey::from_slice(bytes.opening_key)?;
+        let public_input_indexes = bytes.public_input_indexes().collect();
+        let (size, constraints) = (bytes.size, bytes.constraints);
 
-        Ok(Self::new(
+        let verifier = Self::new(
             label,
             verifier_key,
             opening_key,
             public_input_indexes,
             size,
             constraints,
-        ))
+        );
+
+        let verifier = match settings.domain {
+            Some(domain) => verifier.with_domain(domain),
+            None => verifier,
+        };
+
+        Ok(verifier.with_fiat_shamir(settings.fiat_shamir))
     }
 
     /// Verify a generated proof
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(constraints = self.constraints)
+        )
+    )]
     pub fn verify(
         &self,
         proof: &Proof,
         public_inputs: &[BlsScalar],
     ) -> Result<(), Error> {
+        let mut transcript = self.transcript.clone();
+
+        self.verify_over(&mut transcript, proof, public_inputs)
+    }
+
+    /// Verify a generated proof, returning the Fiat-Shamir transcript of the
+    /// verification along with its result
+    ///
+    /// The transcript is returned whether the proof verifies or not. With an
+    /// [`AuditTranscript`](crate::transcript::AuditTranscript), it holds
+    /// every message absorbed and challenge squeezed by the verifier, and its
+    /// [divergence](crate::transcript::AuditTranscript::divergence) from the
+    /// transcript of the prover points to the first message they don't
+    /// agree on. The transcript of a valid proof extends the one of the
+    /// prover with the opening proofs and the challenge batching them.
+    pub fn verify_with_transcript(
+        &self,
+        proof: &Proof,
+        public_inputs: &[BlsScalar],
+    ) -> (Result<(), Error>, T) {
+        let mut transcript = self.transcript.clone();
+        let result = self.verify_over(&mut transcript, proof, public_inputs);
+
+        (result, transcript)
+    }
+
+    fn verify_over(
+        &self,
+        transcript: &mut T,
+        proof: &Proof,
+        public_inputs: &[BlsScalar],
+    ) -> Result<(), Error> {
+        let (w, c) = self.accumulate_proof(transcript, proof, public_inputs)?;
+
+        self.opening_key
+            .check_accumulated(w, c)
+            .map_err(|_| Error::ProofVerificationError)
+    }
+
+    /// Verify a proof of a circuit exposing the [hash](super::hash) of
+    /// `public_inputs` as its single public input, appended with
+    /// [`Composer::append_public_hash`](super::Composer::append_public_hash)
+    pub fn verify_hashed(
+        &self,
+        proof: &Proof,
+        public_inputs: &[BlsScalar],
+    ) -> Result<(), Error> {
+        self.verify(proof, &[super::hash(public_inputs)])
+    }
+
+    /// Verify a proof packaged by
+    /// [`Prover::prove_bundle`](super::Prover::prove_bundle) with its public
+    /// inputs
+    ///
+    /// Fails with [`Error::FingerprintMismatch`] if the bundle was created for
+    /// another circuit.
+    pub fn verify_bundle(&self, bundle: &ProofBundle) -> Result<(), Error> {
+        if bundle.fingerprint() != &self.fingerprint() {
+            return Err(Error::FingerprintMismatch);
+        }
+
+        self.verify(bundle.proof(), bundle.public_inputs())
+    }
+
+    /// Verify a batch of proofs generated for the circuit of this verifier
+    ///
+    /// The final pairing checks of the proofs are combined with random
+    /// scalars sampled from `rng`, so the whole batch is verified with a
+    /// single multi-pairing. An error is returned if any of the proofs is
+    /// invalid, without identifying which one.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(constraints = self.constraints, proofs = proofs.len())
+        )
+    )]
+    pub fn verify_batch<R>(
+        &self,
+        rng: &mut R,
+        proofs: &[(Proof, Vec<BlsScalar>)],
+    ) -> Result<(), Error>
+    where
+        R: RngCore + CryptoRng,
+    {
+        let mut acc = Accumulator::new();
+
+        proofs.iter().try_for_each(|(proof, public_inputs)| {
+            self.accumulate(rng, &mut acc, proof, public_inputs)
+        })?;
+
+        self.discharge(&acc)
+    }
+
+    /// Verify a proof up to its final pairing check, which is deferred into
+    /// the accumulator
+    ///
+    /// The proof is only known to be valid once the accumulator is
+    /// discharged.
+    pub fn accumulate<R>(
+        &self,
+        rng: &mut R,
+        acc: &mut Accumulator,
+        proof: &Proof,
+        public_inputs: &[BlsScalar],
+    ) -> Result<(), Error>
+    where
+        R: RngCore + CryptoRng,
+    {
+        let mut transcript = self.transcript.clone();
+        let (w, c) =
+            self.accumulate_proof(&mut transcript, proof, public_inputs)?;
+
+        acc.fold(rng, w, c);
+
+        Ok(())
+    }
+
+    /// Perform the pairing checks deferred into the accumulator
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all)
+    )]
+    pub fn discharge(&self, acc: &Accumulator) -> Result<(), Error> {
+        self.opening_key
+            .check_accumulated(acc.w.into(), acc.c.into())
+            .map_err(|_| Error::ProofVerificationError)
+    }
+
+    fn accumulate_proof(
+        &self,
+        transcript: &mut T,
+        proof: &Proof,
+        public_inputs: &[BlsScalar],
+    ) -> Result<(G1Projective, G1Projective), Error> {
         if public_inputs.len() != self.public_input_indexes.len() {
             return Err(Error::InconsistentPublicInputsLen {
                 expected: self.public_input_indexes.len(),
@@ -202,23 +471,23 @@ impl Verifier {
             });
         }
 
-        let mut transcript = self.transcript.clone();
-
-        public_inputs
-            .iter()
-            .for_each(|pi| transcript.append_scalar(b"pi", pi));
-
-        let dense_public_inputs = Builder::dense_public_inputs(
+        transcript::append_statement(
+            transcript,
+            self.settings.fiat_shamir,
+            &self.verifier_key,
             &self.public_input_indexes,
             public_inputs,
-            self.size,
         );
 
-        proof.verify(
+        let domain = EvaluationDomain::new_mixed_radix(self.size)?;
+
+        proof.accumulate(
+            &domain,
             &self.verifier_key,
-            &mut transcript,
+            transcript,
             &self.opening_key,
-            &dense_public_inputs,
+            &self.public_input_indexes,
+            public_inputs,
         )
     }
 }
@@ -6,6 +6,8 @@
 
 use crate::constraint_system::Witness;
 use dusk_bls12_381::BlsScalar;
+#[cfg(feature = "constant_time")]
+use dusk_jubjub::{JubJubAffine, JubJubScalar};
 
 /// Represents a JubJub point in the circuit
 #[derive(Debug, Clone, Copy)]
@@ -59,3 +61,100 @@ pub(crate) struct WnafRound<T: Into<Witness>> {
     /// This is the multiplication of x_\beta * y_\beta
     pub xy_beta: BlsScalar,
 }
+
+/// Non-adjacent form of `scalar`, the digits of `compute_windowed_naf` of
+/// width 2, as the bits `(positive, negative)` of each digit
+///
+/// The digits are computed in constant time, as the differences of the bits
+/// of `3 · scalar` and of `scalar`, while `compute_windowed_naf` branches on
+/// the bits of the scalar.
+#[cfg(feature = "constant_time")]
+pub(crate) fn naf(scalar: &JubJubScalar) -> [(u8, u8); 256] {
+    let bytes = scalar.to_bytes();
+
+    let mut k = [0u64; 4];
+    k.iter_mut().zip(bytes.chunks(8)).for_each(|(limb, chunk)| {
+        let mut limb_bytes = [0u8; 8];
+        limb_bytes.copy_from_slice(chunk);
+        *limb = u64::from_le_bytes(limb_bytes);
+    });
+
+    // `3 · scalar` fits 256 bits since the scalar is lower than 2^252
+    let mut h = [0u64; 4];
+    let mut carry = 0u128;
+    let mut shifted_out = 0;
+    for (h, k) in h.iter_mut().zip(k.iter()) {
+        let double = (k << 1) | shifted_out;
+        shifted_out = k >> 63;
+
+        let sum = *k as u128 + double as u128 + carry;
+        *h = sum as u64;
+        carry = sum >> 64;
+    }
+
+    let bit = |limbs: &[u64; 4], i: usize| match i < 256 {
+        true => (limbs[i / 64] >> (i % 64)) as u8 & 1,
+        false => 0,
+    };
+
+    let mut digits = [(0, 0); 256];
+    digits.iter_mut().enumerate().for_each(|(i, digit)| {
+        let h = bit(&h, i + 1);
+        let k = bit(&k, i + 1);
+
+        *digit = (h & !k & 1, k & !h & 1);
+    });
+
+    digits
+}
+
+/// Scalar and point added to the accumulators of a fixed base scalar
+/// multiplication for the NAF `digit` of the `multiple` of the base,
+/// selected with arithmetic instead of branches
+#[cfg(feature = "constant_time")]
+pub(crate) fn naf_addend(
+    digit: (u8, u8),
+    multiple: &JubJubAffine,
+) -> (BlsScalar, JubJubAffine) {
+    let (positive, negative) = digit;
+    let scalar =
+        BlsScalar::from(positive as u64) - BlsScalar::from(negative as u64);
+    let nonzero = BlsScalar::from((positive | negative) as u64);
+
+    // the identity is `(0, 1)` and the negation of `(x, y)` is `(-x, y)`
+    let x = scalar * multiple.get_u();
+    let y = nonzero * (multiple.get_v() - BlsScalar::one()) + BlsScalar::one();
+
+    (scalar, JubJubAffine::from_raw_unchecked(x, y))
+}
+
+#[cfg(all(test, feature = "constant_time"))]
+mod tests {
+    use super::*;
+    use dusk_jubjub::GENERATOR;
+    use rand_core::OsRng;
+
+    #[test]
+    fn naf_matches_windowed_naf() {
+        let max = -JubJubScalar::one();
+        let scalars = [JubJubScalar::zero(), JubJubScalar::one(), max]
+            .into_iter()
+            .chain((0..100).map(|_| JubJubScalar::random(&mut OsRng)));
+
+        for scalar in scalars {
+            let expected = scalar.compute_windowed_naf(2);
+            let digits = naf(&scalar);
+
+            for (digit, expected) in digits.iter().zip(expected.iter()) {
+                assert_eq!(digit.0 as i8 - digit.1 as i8, *expected);
+
+                let point = match expected {
+                    0 => JubJubAffine::identity(),
+                    -1 => -GENERATOR,
+                    _ => GENERATOR,
+                };
+                assert_eq!(naf_addend(*digit, &GENERATOR).1, point);
+            }
+        }
+    }
+}
@@ -0,0 +1,178 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Text encodings of the byte representations of the public types.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::error::Error;
+
+const HEX: &[u8; 16] = b"0123456789abcdef";
+
+/// Encode the bytes as a lowercase hexadecimal string
+pub(crate) fn to_hex(bytes: &[u8]) -> String {
+    let mut hex = String::with_capacity(2 * bytes.len());
+
+    bytes.iter().for_each(|b| {
+        hex.push(HEX[(b >> 4) as usize] as char);
+        hex.push(HEX[(b & 0x0f) as usize] as char);
+    });
+
+    hex
+}
+
+/// Decode a hexadecimal string, with an optional `0x` prefix
+pub(crate) fn from_hex(hex: &str) -> Result<Vec<u8>, Error> {
+    let hex = hex.strip_prefix("0x").unwrap_or(hex).as_bytes();
+
+    if hex.len() & 1 == 1 {
+        return Err(Error::InvalidHex);
+    }
+
+    let nibble = |c: u8| match c {
+        b'0'..=b'9' => Ok(c - b'0'),
+        b'a'..=b'f' => Ok(c - b'a' + 10),
+        b'A'..=b'F' => Ok(c - b'A' + 10),
+        _ => Err(Error::InvalidHex),
+    };
+
+    hex.chunks(2)
+        .map(|pair| Ok(nibble(pair[0])? << 4 | nibble(pair[1])?))
+        .collect()
+}
+
+/// Encode the bytes as a padded base64 string of the standard alphabet
+#[cfg(feature = "typescript")]
+pub(crate) fn to_base64(bytes: &[u8]) -> String {
+    const BASE64: &[u8; 64] =
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut base64 = String::with_capacity(4 * bytes.chunks(3).len());
+
+    bytes.chunks(3).for_each(|chunk| {
+        let mut group = [0u8; 3];
+        group[..chunk.len()].copy_from_slice(chunk);
+        let group = u32::from_be_bytes([0, group[0], group[1], group[2]]);
+
+        (0..4).for_each(|i| match i <= chunk.len() {
+            true => {
+                let sextet = (group >> (18 - 6 * i)) & 0x3f;
+                base64.push(BASE64[sextet as usize] as char);
+            }
+            false => base64.push('='),
+        });
+    });
+
+    base64
+}
+
+/// Value of a flat JSON object
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum JsonValue {
+    /// Unsigned integer
+    Number(u64),
+    /// String without escape sequences
+    String(String),
+}
+
+/// Write a flat JSON object
+pub(crate) fn to_json(fields: &[(&str, JsonValue)]) -> String {
+    let mut json = String::from("{");
+
+    fields.iter().enumerate().for_each(|(i, (key, value))| {
+        if i > 0 {
+            json.push(',');
+        }
+
+        json.push('"');
+        json.push_str(key);
+        json.push_str("\":");
+
+        match value {
+            JsonValue::Number(n) => json.push_str(&n.to_string()),
+            JsonValue::String(s) => {
+                json.push('"');
+                json.push_str(s);
+                json.push('"');
+            }
+        }
+    });
+
+    json.push('}');
+    json
+}
+
+/// Read a flat JSON object whose values are unsigned integers or strings
+/// without escape sequences, such as the ones written by [`to_json`]
+pub(crate) fn from_json(json: &str) -> Result<Vec<(String, JsonValue)>, Error> {
+    let mut fields = Vec::new();
+    let mut json = json.trim();
+
+    json = json
+        .strip_prefix('{')
+        .and_then(|json| json.strip_suffix('}'))
+        .ok_or(Error::InvalidJson)?
+        .trim();
+
+    while !json.is_empty() {
+        let (key, rest) = read_string(json)?;
+        let rest = rest
+            .trim_start()
+            .strip_prefix(':')
+            .ok_or(Error::InvalidJson)?
+            .trim_start();
+
+        let (value, rest) = match rest.strip_prefix('"') {
+            Some(_) => {
+                let (s, rest) = read_string(rest)?;
+                (JsonValue::String(s), rest)
+            }
+            None => {
+                let end = rest
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(rest.len());
+                let n = rest[..end].parse().map_err(|_| Error::InvalidJson)?;
+                (JsonValue::Number(n), &rest[end..])
+            }
+        };
+
+        fields.push((key, value));
+
+        let rest = rest.trim_start();
+        json = match rest.strip_prefix(',') {
+            Some(rest) if !rest.trim().is_empty() => rest.trim_start(),
+            Some(_) => return Err(Error::InvalidJson),
+            None if rest.is_empty() => rest,
+            None => return Err(Error::InvalidJson),
+        };
+    }
+
+    Ok(fields)
+}
+
+/// Find the value of `key` in the fields of a JSON object
+pub(crate) fn json_field<'a>(
+    fields: &'a [(String, JsonValue)],
+    key: &str,
+) -> Result<&'a JsonValue, Error> {
+    fields
+        .iter()
+        .find(|(k, _)| k == key)
+        .map(|(_, v)| v)
+        .ok_or(Error::InvalidJson)
+}
+
+fn read_string(json: &str) -> Result<(String, &str), Error> {
+    let json = json.strip_prefix('"').ok_or(Error::InvalidJson)?;
+    let end = json.find(['"', '\\']).ok_or(Error::InvalidJson)?;
+
+    if json.as_bytes()[end] == b'\\' {
+        return Err(Error::InvalidJson);
+    }
+
+    Ok((String::from(&json[..end]), &json[end + 1..]))
+}
@@ -9,7 +9,11 @@
 use dusk_bytes::Error as DuskBytesError;
 
 /// Defines all possible errors that can be encountered in PLONK.
+///
+/// The enum is non-exhaustive, since some variants, such as
+/// [`Error::IoError`], only exist with some features.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
 pub enum Error {
     // FFT errors
     /// This error occurs when an error triggers on any of the fft module
@@ -26,7 +30,12 @@ pub enum Error {
     ProofVerificationError,
     /// This error occurs when the circuit is not provided with all of the
     /// required inputs.
-    CircuitInputsNotFound,
+    CircuitInputsNotFound {
+        /// Number of inputs of the circuit
+        expected: usize,
+        /// Number of inputs provided, or referenced by the public inputs
+        provided: usize,
+    },
     /// This error occurs when we want to verify a Proof but the pi_constructor
     /// attribute is uninitialized.
     UninitializedPIGenerator,
@@ -37,7 +46,46 @@ pub enum Error {
     CircuitAlreadyPreprocessed,
     /// This error occurs when the circuit for the proof has a different size
     /// than the prover circuit description
-    InvalidCircuitSize,
+    InvalidCircuitSize {
+        /// Constraints count of the circuit description
+        expected: usize,
+        /// Constraints count of the circuit for the proof
+        provided: usize,
+    },
+    /// This error occurs when a gate is wired to a witness that wasn't
+    /// allocated by the circuit builder.
+    WitnessNotFound {
+        /// Index of the gate
+        gate: usize,
+        /// Index of the witness
+        witness: usize,
+    },
+    /// This error occurs when the witnesses of a gate don't satisfy its
+    /// constraint.
+    UnsatisfiedConstraint {
+        /// Index of the gate
+        gate: usize,
+        /// Label of the gate, if any
+        label: Option<&'static str>,
+    },
+    /// This error occurs when a witness can take another value while every
+    /// gate stays satisfied.
+    UnderConstrainedWitness {
+        /// Index of the witness
+        witness: usize,
+        /// Index of the first gate wired to the witness
+        gate: usize,
+        /// Label of the gate, if any
+        label: Option<&'static str>,
+    },
+    /// This error occurs when an imported witness assigns different values
+    /// to the same witness on different wires.
+    InconsistentWitness {
+        /// Index of the gate of the conflicting value
+        gate: usize,
+        /// Index of the witness
+        witness: usize,
+    },
 
     // Preprocessing errors
     /// This error occurs when an error triggers during the preprocessing
@@ -50,19 +98,49 @@ pub enum Error {
     DegreeIsZero,
     /// This error occurs when the user tries to trim PublicParameters
     /// to a degree that is larger than the maximum degree.
-    TruncatedDegreeTooLarge,
+    TruncatedDegreeTooLarge {
+        /// Requested degree
+        degree: usize,
+        /// Maximum degree of the PublicParameters
+        max_degree: usize,
+    },
     /// This error occurs when the user tries to trim PublicParameters
     /// down to a degree that is zero.
     TruncatedDegreeIsZero,
     /// This error occurs when the user tries to commit to a polynomial whose
     /// degree is larger than the supported degree for that proving key.
-    PolynomialDegreeTooLarge,
+    PolynomialDegreeTooLarge {
+        /// Degree of the polynomial
+        degree: usize,
+        /// Maximum degree supported by the proving key
+        max_degree: usize,
+    },
     /// This error occurs when the user tries to commit to a polynomial whose
     /// degree is zero.
     PolynomialDegreeIsZero,
     /// This error occurs when the pairing check fails at being equal to the
     /// Identity point.
     PairingCheckFailure,
+    /// This error occurs when a power of the public parameters is the
+    /// identity, or not a valid point of the prime order subgroup.
+    PowerMalformed {
+        /// Index of the power
+        index: usize,
+    },
+    /// This error occurs when a contribution to a trusted setup ceremony
+    /// doesn't follow from the previous contributions.
+    InvalidContribution {
+        /// Index of the contribution
+        index: usize,
+    },
+    /// This error occurs when a batch of openings is empty, or doesn't have
+    /// as many points as proofs.
+    InvalidOpeningBatch {
+        /// Number of evaluation points
+        points: usize,
+        /// Number of proofs
+        proofs: usize,
+    },
 
     // Serialization errors
     /// Dusk-bytes serialization error
@@ -91,11 +169,82 @@ pub enum Error {
     },
     /// The provided compressed circuit bytes representation is invalid.
     InvalidCompressedCircuit,
+    /// This error occurs when a versioned byte representation was encoded
+    /// with a version this crate doesn't support.
+    UnsupportedVersion {
+        /// Version of the encoding
+        version: u8,
+    },
+    /// This error occurs when a malformed hexadecimal string is decoded.
+    InvalidHex,
+    /// This error occurs when a malformed JSON representation is decoded.
+    InvalidJson,
+    /// This error occurs when a proof is aborted by its cancellation token.
+    ProofCancelled,
+    /// This error occurs when reading or writing a cached prover or verifier
+    /// fails.
+    #[cfg(feature = "std")]
+    IoError(std::io::ErrorKind),
+    /// This error occurs when the checksum of a cached prover or verifier
+    /// doesn't match its content.
+    InvalidChecksum,
+    /// This error occurs when the circuit hash of a cached prover or verifier
+    /// doesn't match its keys.
+    CircuitHashMismatch,
+    /// This error occurs when a zkInterface message is malformed.
+    InvalidZkInterface,
+    /// This error occurs when a zkInterface circuit is defined over another
+    /// field than the scalar field of BLS12-381.
+    UnsupportedZkInterfaceField,
+    /// This error occurs when a gate can't be expressed as a rank-1
+    /// constraint.
+    UnsupportedGate {
+        /// Index of the gate
+        gate: usize,
+    },
+    /// This error occurs when an ACIR opcode references a missing witness,
+    /// or spans more bits than the scalar field.
+    InvalidAcirOpcode {
+        /// Index of the opcode
+        opcode: usize,
+    },
+    /// This error occurs when the name of a public input isn't a valid
+    /// identifier, or is repeated.
+    InvalidPublicInputName {
+        /// Index of the public input
+        index: usize,
+    },
+    /// This error occurs when a circom `.r1cs` or `.wtns` file is malformed.
+    InvalidCircomFile,
+    /// This error occurs when a circom file is defined over another field
+    /// than the scalar field of BLS12-381.
+    UnsupportedCircomPrime,
+    /// This error occurs when a protobuf message is malformed, or misses a
+    /// required field.
+    InvalidProtobuf,
+    /// This error occurs when a proof bundle was created for another circuit
+    /// than the one of the verifier.
+    FingerprintMismatch,
+    /// This error occurs when a padded vector is created with more values
+    /// than its capacity.
+    PaddedVecTooLong {
+        /// Number of values
+        length: usize,
+        /// Capacity of the vector
+        capacity: usize,
+    },
+    /// This error occurs when a circuit has more public inputs than the
+    /// capacity of a heapless verifier.
+    TooManyPublicInputs {
+        /// Number of public inputs of the circuit
+        public_inputs: usize,
+        /// Capacity of the verifier
+        capacity: usize,
+    },
 }
 
-#[cfg(feature = "std")]
-impl std::fmt::Display for Error {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::InvalidEvalDomainSize {
                 log_size_of_group,
@@ -109,9 +258,11 @@ impl std::fmt::Display for Error {
             Self::ProofVerificationError => {
                 write!(f, "proof verification failed")
             }
-            Self::CircuitInputsNotFound => {
-                write!(f, "circuit inputs not found")
-            }
+            Self::CircuitInputsNotFound { expected, provided } => write!(
+                f,
+                "circuit inputs not found: {} provided for {} inputs",
+                provided, expected
+            ),
             Self::UninitializedPIGenerator => {
                 write!(f, "PI generator uninitialized")
             }
@@ -124,27 +275,75 @@ impl std::fmt::Display for Error {
             Self::CircuitAlreadyPreprocessed => {
                 write!(f, "circuit has already been preprocessed")
             }
-            Self::InvalidCircuitSize => {
-                write!(f, "circuit size doesn't match with circuit description")
-            }
+            Self::InvalidCircuitSize { expected, provided } => write!(
+                f,
+                "circuit size {} doesn't match with circuit description: {}",
+                provided, expected
+            ),
+            Self::WitnessNotFound { gate, witness } => write!(
+                f,
+                "gate {} is wired to the witness {} that wasn't allocated",
+                gate, witness
+            ),
+            Self::UnsatisfiedConstraint { gate, label } => match label {
+                Some(label) => {
+                    write!(f, "gate {} ({}) is unsatisfied", gate, label)
+                }
+                None => write!(f, "gate {} is unsatisfied", gate),
+            },
+            Self::UnderConstrainedWitness {
+                witness,
+                gate,
+                label,
+            } => match label {
+                Some(label) => write!(
+                    f,
+                    "the witness {} of gate {} ({}) is under-constrained",
+                    witness, gate, label
+                ),
+                None => write!(
+                    f,
+                    "the witness {} of gate {} is under-constrained",
+                    witness, gate
+                ),
+            },
+            Self::InconsistentWitness { gate, witness } => write!(
+                f,
+                "gate {} assigns a conflicting value to the witness {}",
+                gate, witness
+            ),
             Self::DegreeIsZero => {
                 write!(f, "cannot create PublicParameters with max degree 0")
             }
-            Self::TruncatedDegreeTooLarge => {
-                write!(f, "cannot trim more than the maximum degree")
-            }
+            Self::TruncatedDegreeTooLarge { degree, max_degree } => write!(
+                f,
+                "cannot trim to degree {} more than the maximum degree {}",
+                degree, max_degree
+            ),
             Self::TruncatedDegreeIsZero => write!(
                 f,
                 "cannot trim PublicParameters to a maximum size of zero"
             ),
-            Self::PolynomialDegreeTooLarge => write!(
+            Self::PolynomialDegreeTooLarge { degree, max_degree } => write!(
                 f,
-                "proving key is not large enough to commit to said polynomial"
+                "proving key of maximum degree {} is not large enough to commit to polynomial of degree {}",
+                max_degree, degree
             ),
             Self::PolynomialDegreeIsZero => {
                 write!(f, "cannot commit to polynomial of zero degree")
             }
             Self::PairingCheckFailure => write!(f, "pairing check failed"),
+            Self::PowerMalformed { index } => {
+                write!(f, "power {} of the public parameters malformed", index)
+            }
+            Self::InvalidContribution { index } => {
+                write!(f, "invalid ceremony contribution {}", index)
+            }
+            Self::InvalidOpeningBatch { points, proofs } => write!(
+                f,
+                "invalid batch of {} openings at {} points",
+                proofs, points
+            ),
             Self::NotEnoughBytes => write!(f, "not enough bytes left to read"),
             Self::PointMalformed => write!(f, "BLS point bytes malformed"),
             Self::BlsScalarMalformed => write!(f, "BLS scalar bytes malformed"),
@@ -160,6 +359,58 @@ impl std::fmt::Display for Error {
                 expected, provided,
             } => write!(f, "The provided public inputs set of length {} doesn't match the processed verifier: {}", provided, expected),
             Self::InvalidCompressedCircuit => write!(f, "invalid compressed circuit"),
+            Self::UnsupportedVersion { version } => {
+                write!(f, "unsupported encoding version {}", version)
+            }
+            Self::InvalidHex => write!(f, "invalid hexadecimal string"),
+            Self::InvalidJson => write!(f, "invalid JSON representation"),
+            Self::ProofCancelled => write!(f, "the proof was cancelled"),
+            #[cfg(feature = "std")]
+            Self::IoError(kind) => write!(f, "I/O error: {}", kind),
+            Self::InvalidChecksum => write!(f, "invalid checksum"),
+            Self::CircuitHashMismatch => {
+                write!(f, "the circuit hash doesn't match the keys")
+            }
+            Self::InvalidZkInterface => {
+                write!(f, "invalid zkInterface message")
+            }
+            Self::UnsupportedZkInterfaceField => write!(
+                f,
+                "the zkInterface circuit isn't defined over the scalar field of BLS12-381"
+            ),
+            Self::UnsupportedGate { gate } => write!(
+                f,
+                "gate {} can't be expressed as a rank-1 constraint",
+                gate
+            ),
+            Self::InvalidAcirOpcode { opcode } => {
+                write!(f, "invalid ACIR opcode {}", opcode)
+            }
+            Self::InvalidPublicInputName { index } => {
+                write!(f, "invalid name of the public input {}", index)
+            }
+            Self::InvalidCircomFile => write!(f, "invalid circom file"),
+            Self::UnsupportedCircomPrime => write!(
+                f,
+                "the circom file isn't defined over the scalar field of BLS12-381"
+            ),
+            Self::InvalidProtobuf => write!(f, "invalid protobuf message"),
+            Self::FingerprintMismatch => {
+                write!(f, "the bundle was created for another circuit")
+            }
+            Self::PaddedVecTooLong { length, capacity } => write!(
+                f,
+                "{} values exceed the capacity {} of the padded vector",
+                length, capacity
+            ),
+            Self::TooManyPublicInputs {
+                public_inputs,
+                capacity,
+            } => write!(
+                f,
+                "{} public inputs exceed the capacity {} of the verifier",
+                public_inputs, capacity
+            ),
         }
     }
 }
@@ -170,5 +421,12 @@ impl From<DuskBytesError> for Error {
     }
 }
 
+#[cfg(feature = "std")]
+impl From<std::io::Error> for Error {
+    fn from(io_err: std::io::Error) -> Self {
+        Self::IoError(io_err.kind())
+    }
+}
+
 #[cfg(feature = "std")]
 impl std::error::Error for Error {}
@@ -7,6 +7,53 @@
 //! FFT module contains the tools needed by the Composer backend
 //! to know and use the logic behind Polynomials. As well as
 //! the operations that the `Composer` needs to peform with them.
+//!
+//! The polynomials over the scalar field are represented either by their
+//! coefficients, with [`Polynomial`], or by their evaluations over an
+//! [`EvaluationDomain`], with [`Evaluations`], and are converted from one
+//! form to the other with the FFTs of the domain.
+//!
+//! ```
+//! use dusk_plonk::fft::{EvaluationDomain, Polynomial};
+//! use dusk_plonk::prelude::BlsScalar;
+//!
+//! let domain = EvaluationDomain::new(4).unwrap();
+//!
+//! // p(x) = x^5 - x, vanishing over the domain of size 4
+//! let mut coeffs = vec![BlsScalar::zero(); 6];
+//! coeffs[1] = -BlsScalar::one();
+//! coeffs[5] = BlsScalar::one();
+//! let p = Polynomial::from_coefficients_vec(coeffs);
+//!
+//! assert!(domain.elements().all(|x| p.evaluate(&x) == BlsScalar::zero()));
+//!
+//! // the evaluations over a domain larger than the degree interpolate `p`
+//! let domain_8 = EvaluationDomain::new(8).unwrap();
+//! let evals = domain_8.fft(&p);
+//! assert_eq!(domain_8.ifft(&evals)[..p.len()], p[..]);
+//!
+//! // p(x) = x · (x^4 - 1)
+//! let (quotient, remainder) = p.divide_by_vanishing_poly(&domain);
+//! assert!(remainder.is_zero());
+//! assert_eq!(quotient.evaluate(&BlsScalar::from(3)), BlsScalar::from(3));
+//! ```
+
+cfg_if::cfg_if!(
+if #[cfg(feature = "heapless")]
+{
+    pub(crate) mod domain;
+
+    pub use domain::EvaluationDomain;
+});
+
+cfg_if::cfg_if!(
+if #[cfg(feature = "verifier")]
+{
+    mod cache;
+
+    pub use cache::clear_cache;
+    pub use domain::alloc::Elements;
+});
 
 cfg_if::cfg_if!(
 if #[cfg(feature = "alloc")]
@@ -14,11 +61,6 @@ if #[cfg(feature = "alloc")]
     pub(crate) mod evaluations;
     pub(crate) mod polynomial;
 
-    pub(crate) use evaluations::Evaluations;
-    pub(crate) use polynomial::Polynomial;
+    pub use evaluations::Evaluations;
+    pub use polynomial::Polynomial;
 });
-
-pub(crate) mod domain;
-
-#[allow(unused_imports)]
-pub(crate) use domain::EvaluationDomain;
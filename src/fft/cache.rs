@@ -0,0 +1,150 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Precomputations of the evaluation domains and of the twiddle factors of
+//! their FFTs.
+//!
+//! With the `std` feature, they are cached in memory shared across threads,
+//! so the FFTs of repeated proofs of a circuit don't recompute them. The
+//! cache holds half the size of every FFT computed, and is released with
+//! [`clear_cache`].
+
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+use dusk_bls12_381::BlsScalar;
+
+use super::EvaluationDomain;
+use crate::error::Error;
+
+/// Twiddle factors `omega^i`, for `i < 2^(log_n - 1)`, of the radix 2 FFT of
+/// size `2^log_n`
+#[cfg(not(feature = "std"))]
+pub(crate) fn twiddles(omega: BlsScalar, log_n: u32) -> Arc<Vec<BlsScalar>> {
+    compute_twiddles(omega, log_n)
+}
+
+/// Twiddle factors `omega^i`, for `i < 2^(log_n - 1)`, of the radix 2 FFT of
+/// size `2^log_n`
+#[cfg(feature = "std")]
+pub(crate) fn twiddles(omega: BlsScalar, log_n: u32) -> Arc<Vec<BlsScalar>> {
+    store::twiddles(omega, log_n)
+}
+
+/// Evaluation domain of the given `size`, constructed with `new`
+#[cfg(not(feature = "std"))]
+pub(crate) fn domain<F>(_size: usize, new: F) -> Result<EvaluationDomain, Error>
+where
+    F: FnOnce() -> Result<EvaluationDomain, Error>,
+{
+    new()
+}
+
+/// Evaluation domain of the given `size`, constructed with `new` unless
+/// cached
+#[cfg(feature = "std")]
+pub(crate) fn domain<F>(size: usize, new: F) -> Result<EvaluationDomain, Error>
+where
+    F: FnOnce() -> Result<EvaluationDomain, Error>,
+{
+    store::domain(size, new)
+}
+
+/// Release the evaluation domains and the twiddle factors cached by the FFTs.
+///
+/// Without the `std` feature, nothing is cached.
+pub fn clear_cache() {
+    #[cfg(feature = "std")]
+    store::clear();
+}
+
+fn compute_twiddles(omega: BlsScalar, log_n: u32) -> Arc<Vec<BlsScalar>> {
+    let half = (1usize << log_n) / 2;
+
+    let mut twiddles = Vec::with_capacity(half);
+    let mut w = BlsScalar::one();
+    for _ in 0..half {
+        twiddles.push(w);
+        w *= &omega;
+    }
+
+    Arc::new(twiddles)
+}
+
+#[cfg(feature = "std")]
+mod store {
+    use std::sync::Mutex;
+
+    use super::*;
+
+    type Twiddles = Vec<((BlsScalar, u32), Arc<Vec<BlsScalar>>)>;
+
+    /// Twiddle factors by root of unity and size of the FFT
+    static TWIDDLES: Mutex<Twiddles> = Mutex::new(Vec::new());
+
+    /// Evaluation domains, by size
+    static DOMAINS: Mutex<Vec<EvaluationDomain>> = Mutex::new(Vec::new());
+
+    pub(super) fn twiddles(
+        omega: BlsScalar,
+        log_n: u32,
+    ) -> Arc<Vec<BlsScalar>> {
+        let key = (omega, log_n);
+
+        // the lock isn't held while the twiddles are computed, so concurrent
+        // FFTs of other sizes aren't blocked
+        let cached = TWIDDLES
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .find(|(k, _)| k == &key)
+            .map(|(_, twiddles)| twiddles.clone());
+        if let Some(twiddles) = cached {
+            return twiddles;
+        }
+
+        let twiddles = compute_twiddles(omega, log_n);
+
+        let mut cache = TWIDDLES.lock().unwrap_or_else(|e| e.into_inner());
+        if !cache.iter().any(|(k, _)| k == &key) {
+            cache.push((key, twiddles.clone()));
+        }
+
+        twiddles
+    }
+
+    pub(super) fn domain<F>(
+        size: usize,
+        new: F,
+    ) -> Result<EvaluationDomain, Error>
+    where
+        F: FnOnce() -> Result<EvaluationDomain, Error>,
+    {
+        let cached = DOMAINS
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .find(|domain| domain.size() == size)
+            .copied();
+        if let Some(domain) = cached {
+            return Ok(domain);
+        }
+
+        let domain = new()?;
+
+        let mut cache = DOMAINS.lock().unwrap_or_else(|e| e.into_inner());
+        if !cache.iter().any(|domain| domain.size() == size) {
+            cache.push(domain);
+        }
+
+        Ok(domain)
+    }
+
+    pub(super) fn clear() {
+        TWIDDLES.lock().unwrap_or_else(|e| e.into_inner()).clear();
+        DOMAINS.lock().unwrap_or_else(|e| e.into_inner()).clear();
+    }
+}
@@ -25,7 +25,7 @@ use rkyv::{
 
 /// Defines a domain over which finite field (I)FFTs can be performed. Works
 /// only for fields that have a large multiplicative subgroup of size that is
-/// a power-of-2.
+/// a power-of-2, optionally times 3.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 #[cfg_attr(
     feature = "rkyv-impl",
@@ -33,11 +33,11 @@ use rkyv::{
     archive(bound(serialize = "__S: Serializer + ScratchSpace")),
     archive_attr(derive(CheckBytes))
 )]
-pub(crate) struct EvaluationDomain {
+pub struct EvaluationDomain {
     /// The size of the domain.
     #[cfg_attr(feature = "rkyv-impl", omit_bounds)]
     pub(crate) size: u64,
-    /// `log_2(self.size)`.
+    /// `log_2` of the largest power of two dividing `self.size`.
     #[cfg_attr(feature = "rkyv-impl", omit_bounds)]
     pub(crate) log_size_of_group: u32,
     /// Size of the domain as a field element.
@@ -103,25 +103,40 @@ impl Serializable<{ u64::SIZE + u32::SIZE + 5 * BlsScalar::SIZE }>
     }
 }
 
-#[cfg(feature = "alloc")]
-pub(crate) mod alloc {
-
+#[cfg(feature = "heapless")]
+mod verifier {
     use super::*;
     use crate::error::Error;
-    use crate::fft::Evaluations;
-    #[rustfmt::skip]
-    use ::alloc::vec::Vec;
-    use core::ops::MulAssign;
+    #[cfg(feature = "verifier")]
+    use crate::fft::cache;
     use dusk_bls12_381::{GENERATOR, ROOT_OF_UNITY, TWO_ADACITY};
-    #[cfg(feature = "std")]
-    use rayon::prelude::*;
+
+    /// `(r - 1) / 3`, for the cube roots of unity of the scalar field
+    const CUBE_ROOT_EXP: [u64; 4] = [
+        0x5555555500000000,
+        0x713f36abaaaa1eaa,
+        0x66689d580335f2ac,
+        0x26a48d1bb889d46d,
+    ];
 
     impl EvaluationDomain {
         /// Construct a domain that is large enough for evaluations of a
         /// polynomial having `num_coeffs` coefficients.
-        pub(crate) fn new(num_coeffs: usize) -> Result<Self, Error> {
+        pub fn new(num_coeffs: usize) -> Result<Self, Error> {
             // Compute the size of our evaluation domain
-            let size = num_coeffs.next_power_of_two() as u64;
+            let size = num_coeffs.next_power_of_two();
+
+            #[cfg(feature = "verifier")]
+            let domain = cache::domain(size, || Self::compute(size as u64));
+
+            #[cfg(not(feature = "verifier"))]
+            let domain = Self::compute(size as u64);
+
+            domain
+        }
+
+        /// Compute the domain of the given power of two `size`
+        fn compute(size: u64) -> Result<Self, Error> {
             let log_size_of_group = size.trailing_zeros();
 
             if log_size_of_group >= TWO_ADACITY {
@@ -152,13 +167,158 @@ pub(crate) mod alloc {
             })
         }
 
+        /// Construct a domain that is large enough for evaluations of a
+        /// polynomial having `num_coeffs` coefficients, of size either `2^k`
+        /// or `3·2^k`, whichever is the smallest.
+        ///
+        /// The domains of size `3·2^k` are evaluated with a mixed radix FFT.
+        /// The size of a domain is left unchanged, so this also reconstructs
+        /// a domain from its size.
+        pub fn new_mixed_radix(num_coeffs: usize) -> Result<Self, Error> {
+            let size = Self::mixed_radix_size(num_coeffs);
+            if size.is_power_of_two() {
+                return Self::new(size);
+            }
+
+            let compute = || {
+                // the subgroup of size `3·2^k` is generated by a root of unity
+                // of order `3·2^TWO_ADACITY` squared `TWO_ADACITY - k` times,
+                // so the generators of the domains of sizes `3·2^k` and
+                // `3·2^(k + 3)` are related as the ones of the radix 2 domains
+                let mut domain = Self::new(size / 3)?;
+
+                let mut group_gen =
+                    ROOT_OF_UNITY * GENERATOR.pow(&CUBE_ROOT_EXP);
+                for _ in domain.log_size_of_group..TWO_ADACITY {
+                    group_gen = group_gen.square();
+                }
+
+                let size = size as u64;
+                let size_as_field_element = BlsScalar::from(size);
+
+                domain.size = size;
+                domain.size_as_field_element = size_as_field_element;
+                domain.size_inv = size_as_field_element.invert().unwrap();
+                domain.group_gen = group_gen;
+                domain.group_gen_inv = group_gen.invert().unwrap();
+
+                Ok(domain)
+            };
+
+            #[cfg(feature = "verifier")]
+            let domain = cache::domain(size, compute);
+
+            #[cfg(not(feature = "verifier"))]
+            let domain = compute();
+
+            domain
+        }
+
+        /// Size of the domain of [`Self::new_mixed_radix`]
+        pub(crate) fn mixed_radix_size(num_coeffs: usize) -> usize {
+            let size = num_coeffs.next_power_of_two();
+            match size {
+                size if size >= 4 && 3 * size / 4 >= num_coeffs => 3 * size / 4,
+                size => size,
+            }
+        }
+
         /// Return the size of `self`.
-        pub(crate) fn size(&self) -> usize {
+        pub fn size(&self) -> usize {
             self.size as usize
         }
 
-        /// Compute a FFT.
-        pub(crate) fn fft(&self, coeffs: &[BlsScalar]) -> Vec<BlsScalar> {
+        /// Return the generator of the subgroup of `self`.
+        pub fn group_gen(&self) -> BlsScalar {
+            self.group_gen
+        }
+
+        /// This evaluates the vanishing polynomial for this domain at tau.
+        /// For multiplicative subgroups, this polynomial is `z(X) = X^self.size
+        /// - 1`.
+        pub fn evaluate_vanishing_polynomial(
+            &self,
+            tau: &BlsScalar,
+        ) -> BlsScalar {
+            tau.pow(&[self.size, 0, 0, 0]) - BlsScalar::one()
+        }
+
+        /// Evaluate at `point` the polynomial of degree lower than the size
+        /// of the domain whose evaluations are `evals` over the elements of
+        /// the domain at `indexes`, and zero over the others.
+        ///
+        /// This is the barycentric formula of
+        /// [`barycentric_eval`](Self::barycentric_eval), whose sum of
+        /// fractions is accumulated as a single fraction so the evaluation
+        /// needs neither an allocation nor more than one inversion.
+        pub(crate) fn sparse_barycentric_eval(
+            &self,
+            indexes: &[usize],
+            evals: &[BlsScalar],
+            point: &BlsScalar,
+        ) -> BlsScalar {
+            let vanishing = self.evaluate_vanishing_polynomial(point);
+
+            // the point is an element of the domain
+            if vanishing == BlsScalar::zero() {
+                return indexes
+                    .iter()
+                    .zip(evals)
+                    .find(|(&i, _)| {
+                        self.group_gen.pow(&[i as u64, 0, 0, 0]) == *point
+                    })
+                    .map(|(_, eval)| *eval)
+                    .unwrap_or_else(BlsScalar::zero);
+            }
+
+            // Σ e_i / d_i, with d_i = ω^-i · z - 1, as `numerator /
+            // denominator`
+            let (numerator, denominator) = indexes.iter().zip(evals).fold(
+                (BlsScalar::zero(), BlsScalar::one()),
+                |(numerator, denominator), (&i, eval)| {
+                    let d = self.group_gen_inv.pow(&[i as u64, 0, 0, 0])
+                        * point
+                        - BlsScalar::one();
+
+                    (numerator * d + eval * denominator, denominator * d)
+                },
+            );
+
+            numerator
+                * denominator.invert().unwrap()
+                * vanishing
+                * self.size_inv
+        }
+    }
+}
+
+#[cfg(feature = "verifier")]
+pub(crate) mod alloc {
+
+    use super::*;
+    use crate::fft::cache;
+    #[cfg(feature = "alloc")]
+    use crate::fft::Evaluations;
+    use crate::util::batch_inversion;
+    #[rustfmt::skip]
+    use ::alloc::vec::Vec;
+    use dusk_bls12_381::GENERATOR;
+
+    #[cfg(feature = "parallel")]
+    use rayon::prelude::*;
+
+    impl EvaluationDomain {
+        /// Compute a FFT of the polynomial of coefficients `coeffs`, which
+        /// must not be more than the size of the domain.
+        #[cfg_attr(
+            feature = "tracing",
+            tracing::instrument(
+                level = "trace",
+                skip_all,
+                fields(size = self.size())
+            )
+        )]
+        pub fn fft(&self, coeffs: &[BlsScalar]) -> Vec<BlsScalar> {
             let mut coeffs = coeffs.to_vec();
             self.fft_in_place(&mut coeffs);
             coeffs
@@ -167,11 +327,19 @@ pub(crate) mod alloc {
         /// Compute a FFT, modifying the vector in place.
         fn fft_in_place(&self, coeffs: &mut Vec<BlsScalar>) {
             coeffs.resize(self.size(), BlsScalar::zero());
-            best_fft(coeffs, self.group_gen, self.log_size_of_group)
+            mixed_fft(coeffs, self.group_gen, self.log_size_of_group)
         }
 
-        /// Compute an IFFT.
-        pub(crate) fn ifft(&self, evals: &[BlsScalar]) -> Vec<BlsScalar> {
+        /// Compute an IFFT of the evaluations `evals` over the domain.
+        #[cfg_attr(
+            feature = "tracing",
+            tracing::instrument(
+                level = "trace",
+                skip_all,
+                fields(size = self.size())
+            )
+        )]
+        pub fn ifft(&self, evals: &[BlsScalar]) -> Vec<BlsScalar> {
             let mut evals = evals.to_vec();
             self.ifft_in_place(&mut evals);
             evals
@@ -181,15 +349,16 @@ pub(crate) mod alloc {
         #[inline]
         pub(crate) fn ifft_in_place(&self, evals: &mut Vec<BlsScalar>) {
             evals.resize(self.size(), BlsScalar::zero());
-            best_fft(evals, self.group_gen_inv, self.log_size_of_group);
+            mixed_fft(evals, self.group_gen_inv, self.log_size_of_group);
 
-            #[cfg(not(feature = "std"))]
+            #[cfg(not(feature = "parallel"))]
             evals.iter_mut().for_each(|val| *val *= &self.size_inv);
 
-            #[cfg(feature = "std")]
+            #[cfg(feature = "parallel")]
             evals.par_iter_mut().for_each(|val| *val *= &self.size_inv);
         }
 
+        #[cfg(not(feature = "parallel"))]
         fn distribute_powers(coeffs: &mut [BlsScalar], g: BlsScalar) {
             let mut pow = BlsScalar::one();
             coeffs.iter_mut().for_each(|c| {
@@ -198,8 +367,33 @@ pub(crate) mod alloc {
             })
         }
 
+        #[cfg(feature = "parallel")]
+        fn distribute_powers(coeffs: &mut [BlsScalar], g: BlsScalar) {
+            // every chunk starts from the power of its first coefficient
+            let size = (coeffs.len() / rayon::current_num_threads()).max(1024);
+
+            coeffs
+                .par_chunks_mut(size)
+                .enumerate()
+                .for_each(|(i, chunk)| {
+                    let mut pow = g.pow(&[(i * size) as u64, 0, 0, 0]);
+                    chunk.iter_mut().for_each(|c| {
+                        *c *= &pow;
+                        pow *= &g
+                    })
+                })
+        }
+
         /// Compute a FFT over a coset of the domain.
-        pub(crate) fn coset_fft(&self, coeffs: &[BlsScalar]) -> Vec<BlsScalar> {
+        #[cfg_attr(
+            feature = "tracing",
+            tracing::instrument(
+                level = "trace",
+                skip_all,
+                fields(size = self.size())
+            )
+        )]
+        pub fn coset_fft(&self, coeffs: &[BlsScalar]) -> Vec<BlsScalar> {
             let mut coeffs = coeffs.to_vec();
             self.coset_fft_in_place(&mut coeffs);
             coeffs
@@ -213,7 +407,7 @@ pub(crate) mod alloc {
         }
 
         /// Compute an IFFT over a coset of the domain.
-        pub(crate) fn coset_ifft(&self, evals: &[BlsScalar]) -> Vec<BlsScalar> {
+        pub fn coset_ifft(&self, evals: &[BlsScalar]) -> Vec<BlsScalar> {
             let mut evals = evals.to_vec();
             self.coset_ifft_in_place(&mut evals);
             evals
@@ -221,7 +415,15 @@ pub(crate) mod alloc {
 
         /// Compute an IFFT over a coset of the domain, modifying the input
         /// vector in place.
-        fn coset_ifft_in_place(&self, evals: &mut Vec<BlsScalar>) {
+        #[cfg_attr(
+            feature = "tracing",
+            tracing::instrument(
+                level = "trace",
+                skip_all,
+                fields(size = self.size())
+            )
+        )]
+        pub(crate) fn coset_ifft_in_place(&self, evals: &mut Vec<BlsScalar>) {
             self.ifft_in_place(evals);
             Self::distribute_powers(evals, self.generator_inv);
         }
@@ -229,7 +431,7 @@ pub(crate) mod alloc {
         #[allow(clippy::needless_range_loop)]
         /// Evaluate all the lagrange polynomials defined by this domain at the
         /// point `tau`.
-        pub(crate) fn evaluate_all_lagrange_coefficients(
+        pub fn evaluate_all_lagrange_coefficients(
             &self,
             tau: BlsScalar,
         ) -> Vec<BlsScalar> {
@@ -249,8 +451,6 @@ pub(crate) mod alloc {
                 }
                 u
             } else {
-                use crate::util::batch_inversion;
-
                 let mut l = (t_size - one) * self.size_inv;
                 let mut r = one;
                 let mut u = vec![BlsScalar::zero(); size];
@@ -264,12 +464,12 @@ pub(crate) mod alloc {
 
                 batch_inversion(u.as_mut_slice());
 
-                #[cfg(not(feature = "std"))]
+                #[cfg(not(feature = "parallel"))]
                 u.iter_mut().zip(ls).for_each(|(tau_minus_r, l)| {
                     *tau_minus_r = l * *tau_minus_r;
                 });
 
-                #[cfg(feature = "std")]
+                #[cfg(feature = "parallel")]
                 u.par_iter_mut().zip(ls).for_each(|(tau_minus_r, l)| {
                     *tau_minus_r = l * *tau_minus_r;
                 });
@@ -278,19 +478,89 @@ pub(crate) mod alloc {
             }
         }
 
-        /// This evaluates the vanishing polynomial for this domain at tau.
-        /// For multiplicative subgroups, this polynomial is `z(X) = X^self.size
-        /// - 1`.
-        pub(crate) fn evaluate_vanishing_polynomial(
+        /// Evaluate at `point` the polynomial of degree lower than the size
+        /// of the domain with the evaluations `evals` over its elements,
+        /// without interpolating it.
+        ///
+        /// The barycentric formula `p(z) = (z^n - 1) / n · Σ e_i · ω^i / (z -
+        /// ω^i)` is computed with a single inversion, and the evaluations that
+        /// are zero are skipped, so that sparse evaluations such as the public
+        /// inputs of a circuit are evaluated in the time of the non zero ones.
+        /// The evaluations missing at the end of `evals` are zero.
+        pub fn barycentric_eval(
             &self,
-            tau: &BlsScalar,
+            evals: &[BlsScalar],
+            point: &BlsScalar,
         ) -> BlsScalar {
-            tau.pow(&[self.size, 0, 0, 0]) - BlsScalar::one()
+            let vanishing = self.evaluate_vanishing_polynomial(point);
+
+            // the point is an element of the domain
+            if vanishing == BlsScalar::zero() {
+                return evals
+                    .iter()
+                    .zip(self.elements())
+                    .find(|(_, x)| x == point)
+                    .map(|(eval, _)| *eval)
+                    .unwrap_or_else(BlsScalar::zero);
+            }
+
+            let numerator = vanishing * self.size_inv;
+
+            // Indices with non-zero evaluations
+            #[cfg(not(feature = "parallel"))]
+            let range = (0..evals.len()).into_iter();
+
+            #[cfg(feature = "parallel")]
+            let range = (0..evals.len()).into_par_iter();
+
+            let non_zero_evals: Vec<usize> =
+                range.filter(|&i| evals[i] != BlsScalar::zero()).collect();
+
+            // Only compute the denominators with non-zero evaluations
+            #[cfg(not(feature = "parallel"))]
+            let range = (0..non_zero_evals.len()).into_iter();
+
+            #[cfg(feature = "parallel")]
+            let range = (0..non_zero_evals.len()).into_par_iter();
+
+            let mut denominators: Vec<BlsScalar> = range
+                .clone()
+                .map(|i| {
+                    let index = non_zero_evals[i] as u64;
+
+                    // ω^i / (z - ω^i) = 1 / (ω^-i · z - 1)
+                    self.group_gen_inv.pow(&[index, 0, 0, 0]) * point
+                        - BlsScalar::one()
+                })
+                .collect();
+            batch_inversion(&mut denominators);
+
+            let result: BlsScalar = range
+                .map(|i| denominators[i] * evals[non_zero_evals[i]])
+                .sum();
+
+            result * numerator
+        }
+
+        /// Evaluate at `point` the polynomial of degree lower than the size
+        /// of the domain with the evaluations `evals` over the coset of the
+        /// domain used by [`coset_fft`](Self::coset_fft), without
+        /// interpolating it.
+        ///
+        /// See [`barycentric_eval`](Self::barycentric_eval).
+        pub fn coset_barycentric_eval(
+            &self,
+            evals: &[BlsScalar],
+            point: &BlsScalar,
+        ) -> BlsScalar {
+            // p(z) = q(z / g), with q(x) = p(g · x) evaluated over the domain
+            self.barycentric_eval(evals, &(point * self.generator_inv))
         }
 
         /// Given that the domain size is `D`  
         /// This function computes the `D` evaluation points for
         /// the vanishing polynomial of degree `n` over a coset
+        #[cfg(feature = "alloc")]
         pub(crate) fn compute_vanishing_poly_over_coset(
             &self,            // domain to evaluate over
             poly_degree: u64, // degree of the vanishing polynomial
@@ -313,7 +583,7 @@ pub(crate) mod alloc {
         }
 
         /// Return an iterator over the elements of the domain.
-        pub(crate) fn elements(&self) -> Elements {
+        pub fn elements(&self) -> Elements {
             Elements {
                 cur_elem: BlsScalar::one(),
                 cur_pow: 0,
@@ -322,12 +592,57 @@ pub(crate) mod alloc {
         }
     }
 
-    #[cfg(feature = "alloc")]
+    /// Compute a FFT of size `2^log_n` or `3·2^log_n`.
+    ///
+    /// The mixed radix FFT splits `a(x) = a_0(x^3) + x·a_1(x^3) + x^2·a_2(x^3)`
+    /// and evaluates the `a_i` with radix 2 FFTs of size `2^log_n`, so
+    /// `a(ω^(j + s·2^log_n))` is recombined from their `j`-th evaluations.
+    fn mixed_fft(a: &mut [BlsScalar], omega: BlsScalar, log_n: u32) {
+        let m = 1 << log_n;
+        if a.len() == m {
+            return best_fft(a, omega, log_n);
+        }
+        assert_eq!(a.len(), 3 * m);
+
+        let omega_cube = omega.square() * omega;
+        let mut parts = [0, 1, 2]
+            .map(|i| a.iter().skip(i).step_by(3).copied().collect::<Vec<_>>());
+        parts
+            .iter_mut()
+            .for_each(|part| best_fft(part, omega_cube, log_n));
+
+        // `ω^(s·2^log_n)` are the cube roots of unity
+        let cube_root = omega.pow(&[m as u64, 0, 0, 0]);
+        let roots = [BlsScalar::one(), cube_root, cube_root.square()];
+
+        let [a_0, a_1, a_2] = &parts;
+        let mut w = BlsScalar::one();
+        for j in 0..m {
+            for (s, root) in roots.iter().enumerate() {
+                let x = w * root;
+                a[j + s * m] = a_0[j] + x * (a_1[j] + x * a_2[j]);
+            }
+            w *= &omega;
+        }
+    }
+
+    #[cfg(not(feature = "parallel"))]
     fn best_fft(a: &mut [BlsScalar], omega: BlsScalar, log_n: u32) {
         serial_fft(a, omega, log_n)
     }
 
-    #[cfg(feature = "alloc")]
+    #[cfg(feature = "parallel")]
+    fn best_fft(a: &mut [BlsScalar], omega: BlsScalar, log_n: u32) {
+        let threads = rayon::current_num_threads();
+        let log_threads = usize::BITS - 1 - threads.leading_zeros();
+
+        if log_n <= log_threads {
+            serial_fft(a, omega, log_n)
+        } else {
+            parallel_fft(a, omega, log_n, log_threads)
+        }
+    }
+
     #[inline]
     fn bitreverse(mut n: u32, l: u32) -> u32 {
         let mut r = 0;
@@ -338,7 +653,6 @@ pub(crate) mod alloc {
         r
     }
 
-    #[cfg(feature = "alloc")]
     pub(crate) fn serial_fft(
         a: &mut [BlsScalar],
         omega: BlsScalar,
@@ -354,21 +668,23 @@ pub(crate) mod alloc {
             }
         }
 
+        // the twiddle factors of the stage `m` are `omega^(j·n/2m)`
+        let twiddles = cache::twiddles(omega, log_n);
+
         let mut m = 1;
         for _ in 0..log_n {
-            let w_m = omega.pow(&[(n / (2 * m)) as u64, 0, 0, 0]);
+            let stride = n / (2 * m);
 
             let mut k = 0;
             while k < n {
-                let mut w = BlsScalar::one();
                 for j in 0..m {
+                    let w = &twiddles[(j * stride) as usize];
                     let mut t = a[(k + j + m) as usize];
-                    t *= &w;
+                    t *= w;
                     let mut tmp = a[(k + j) as usize];
                     tmp -= &t;
                     a[(k + j + m) as usize] = tmp;
                     a[(k + j) as usize] += &t;
-                    w.mul_assign(&w_m);
                 }
 
                 k += 2 * m;
@@ -378,9 +694,55 @@ pub(crate) mod alloc {
         }
     }
 
+    /// Split the FFT of size `2^log_n` in `2^log_threads` FFTs of size
+    /// `2^(log_n - log_threads)` computed in parallel.
+    ///
+    /// The `j`-th sub-FFT is over the coefficients folded by the powers of
+    /// `omega^j`, and its evaluations are the ones of `a` at the indexes
+    /// congruent to `j` modulo `2^log_threads`.
+    #[cfg(feature = "parallel")]
+    pub(crate) fn parallel_fft(
+        a: &mut [BlsScalar],
+        omega: BlsScalar,
+        log_n: u32,
+        log_threads: u32,
+    ) {
+        assert!(log_n >= log_threads);
+
+        let threads = 1 << log_threads;
+        let log_new_n = log_n - log_threads;
+        let new_n = 1 << log_new_n;
+        let mask = (1 << log_n) - 1;
+
+        let new_omega = omega.pow(&[threads as u64, 0, 0, 0]);
+
+        let mut tmp = vec![vec![BlsScalar::zero(); new_n]; threads];
+        tmp.par_iter_mut().enumerate().for_each(|(j, tmp)| {
+            let omega_j = omega.pow(&[j as u64, 0, 0, 0]);
+            let omega_step = omega.pow(&[(j as u64) << log_new_n, 0, 0, 0]);
+
+            let mut elt = BlsScalar::one();
+            for (i, t) in tmp.iter_mut().enumerate() {
+                for s in 0..threads {
+                    let idx = (i + (s << log_new_n)) & mask;
+                    *t += a[idx] * elt;
+                    elt *= &omega_step;
+                }
+                elt *= &omega_j;
+            }
+
+            serial_fft(tmp, new_omega, log_new_n);
+        });
+
+        let mask = threads - 1;
+        a.par_iter_mut().enumerate().for_each(|(idx, a)| {
+            *a = tmp[idx & mask][idx >> log_threads];
+        });
+    }
+
     /// An iterator over the elements of the domain.
     #[derive(Debug)]
-    pub(crate) struct Elements {
+    pub struct Elements {
         cur_elem: BlsScalar,
         cur_pow: u64,
         domain: EvaluationDomain,
@@ -426,6 +788,76 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg(feature = "parallel")]
+    fn parallel_fft_matches_serial() {
+        use ff::Field;
+        use rand_core::OsRng;
+
+        let domain = EvaluationDomain::new(1 << 10).unwrap();
+        let coeffs: ::alloc::vec::Vec<BlsScalar> = (0..domain.size())
+            .map(|_| BlsScalar::random(&mut OsRng))
+            .collect();
+
+        let mut serial = coeffs.clone();
+        alloc::serial_fft(&mut serial, domain.group_gen, 10);
+
+        for log_threads in 0..=4 {
+            let mut parallel = coeffs.clone();
+            alloc::parallel_fft(
+                &mut parallel,
+                domain.group_gen,
+                10,
+                log_threads,
+            );
+            assert_eq!(parallel, serial);
+        }
+
+        assert_eq!(domain.ifft(&domain.fft(&coeffs)), coeffs);
+        let mut evals = domain.coset_fft(&coeffs);
+        domain.coset_ifft_in_place(&mut evals);
+        assert_eq!(evals, coeffs);
+    }
+
+    #[test]
+    fn mixed_radix_fft() {
+        use ff::Field;
+        use rand_core::OsRng;
+
+        assert_eq!(EvaluationDomain::mixed_radix_size(64), 64);
+        assert_eq!(EvaluationDomain::mixed_radix_size(65), 96);
+        assert_eq!(EvaluationDomain::mixed_radix_size(96), 96);
+        assert_eq!(EvaluationDomain::mixed_radix_size(97), 128);
+
+        let domain = EvaluationDomain::new_mixed_radix(3 << 4).unwrap();
+        assert_eq!(domain.size(), 3 << 4);
+        assert_eq!(domain.log_size_of_group, 4);
+        assert_eq!(domain.elements().last().unwrap(), domain.group_gen_inv);
+        assert_ne!(domain.group_gen.pow(&[1 << 4, 0, 0, 0]), BlsScalar::one());
+        assert_ne!(domain.group_gen.pow(&[3 << 3, 0, 0, 0]), BlsScalar::one());
+
+        let domain_8n = EvaluationDomain::new_mixed_radix(3 << 7).unwrap();
+        assert_eq!(domain_8n.group_gen.pow(&[8, 0, 0, 0]), domain.group_gen);
+
+        let coeffs: ::alloc::vec::Vec<BlsScalar> = (0..domain.size())
+            .map(|_| BlsScalar::random(&mut OsRng))
+            .collect();
+
+        let evals = domain.fft(&coeffs);
+        domain.elements().zip(evals.iter()).for_each(|(x, eval)| {
+            let expected = coeffs
+                .iter()
+                .rev()
+                .fold(BlsScalar::zero(), |acc, c| acc * x + c);
+            assert_eq!(eval, &expected);
+        });
+
+        assert_eq!(domain.ifft(&evals), coeffs);
+        let mut evals = domain.coset_fft(&coeffs);
+        domain.coset_ifft_in_place(&mut evals);
+        assert_eq!(evals, coeffs);
+    }
+
     #[test]
     fn dusk_bytes_evaluation_domain_serde() {
         let eval_domain = EvaluationDomain::new(1 << (13 - 1))
@@ -4,7 +4,7 @@
 //
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
-//! A polynomial represented in evaluations form over a domain of size 2^n.
+//! A polynomial represented in evaluations form over an evaluation domain.
 
 use super::domain::EvaluationDomain;
 use super::polynomial::Polynomial;
@@ -32,7 +32,7 @@ use rkyv::{
     archive(bound(serialize = "__S: Serializer + ScratchSpace")),
     archive_attr(derive(CheckBytes))
 )]
-pub(crate) struct Evaluations {
+pub struct Evaluations {
     /// The evaluations of a polynomial over the domain `D`
     #[cfg_attr(feature = "rkyv-impl", omit_bounds)]
     pub(crate) evals: Vec<BlsScalar>,
@@ -68,15 +68,20 @@ impl Evaluations {
     }
 
     /// Construct `Self` from evaluations and a domain.
-    pub(crate) const fn from_vec_and_domain(
+    pub const fn from_vec_and_domain(
         evals: Vec<BlsScalar>,
         domain: EvaluationDomain,
     ) -> Self {
         Self { evals, domain }
     }
 
+    /// Return the evaluations over the domain.
+    pub fn evals(&self) -> &[BlsScalar] {
+        &self.evals
+    }
+
     /// Interpolate a polynomial from a list of evaluations
-    pub(crate) fn interpolate(self) -> Polynomial {
+    pub fn interpolate(self) -> Polynomial {
         let Self { mut evals, domain } = self;
         domain.ifft_in_place(&mut evals);
         Polynomial::from_coefficients_vec(evals)
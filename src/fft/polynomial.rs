@@ -31,7 +31,7 @@ use rkyv::{
     archive(bound(serialize = "__S: Serializer + ScratchSpace")),
     archive_attr(derive(CheckBytes))
 )]
-pub(crate) struct Polynomial {
+pub struct Polynomial {
     /// The coefficient of `x^i` is stored at location `i` in `self.coeffs`.
     #[cfg_attr(feature = "rkyv-impl", omit_bounds)]
     coeffs: Vec<BlsScalar>,
@@ -62,12 +62,12 @@ impl IntoIterator for Polynomial {
 
 impl Polynomial {
     /// Returns the zero polynomial.
-    pub(crate) const fn zero() -> Self {
+    pub const fn zero() -> Self {
         Self { coeffs: Vec::new() }
     }
 
     /// Checks if the given polynomial is zero.
-    pub(crate) fn is_zero(&self) -> bool {
+    pub fn is_zero(&self) -> bool {
         self.coeffs.is_empty()
             || self.coeffs.iter().all(|coeff| coeff == &BlsScalar::zero())
     }
@@ -76,7 +76,7 @@ impl Polynomial {
     ///
     /// # Panics
     /// When the length of the coeffs is zero.
-    pub(crate) fn from_coefficients_vec(coeffs: Vec<BlsScalar>) -> Self {
+    pub fn from_coefficients_vec(coeffs: Vec<BlsScalar>) -> Self {
         let mut result = Self { coeffs };
         // While there are zeros at the end of the coefficient vector, pop them
         // off.
@@ -92,7 +92,7 @@ impl Polynomial {
     }
 
     /// Returns the degree of the [`Polynomial`].
-    pub(crate) fn degree(&self) -> usize {
+    pub fn degree(&self) -> usize {
         if self.is_zero() {
             return 0;
         }
@@ -114,7 +114,7 @@ impl Polynomial {
     }
 
     /// Evaluates a [`Polynomial`] at a given point in the field.
-    pub(crate) fn evaluate(&self, point: &BlsScalar) -> BlsScalar {
+    pub fn evaluate(&self, point: &BlsScalar) -> BlsScalar {
         if self.is_zero() {
             return BlsScalar::zero();
         }
@@ -347,6 +347,37 @@ impl Polynomial {
         quotient.reverse();
         Polynomial::from_coefficients_vec(quotient)
     }
+
+    /// Divides a [`Polynomial`] by the vanishing polynomial `x^n - 1` of the
+    /// `domain` of size `n`, returning the quotient and the remainder.
+    ///
+    /// The remainder is zero iff the polynomial vanishes over the domain.
+    pub fn divide_by_vanishing_poly(
+        &self,
+        domain: &EvaluationDomain,
+    ) -> (Polynomial, Polynomial) {
+        let n = domain.size();
+        if self.coeffs.len() <= n {
+            return (Polynomial::zero(), self.clone());
+        }
+
+        // `x^(n + i) = x^i · (x^n - 1) + x^i`, so every coefficient of degree
+        // `n + i` is moved to the quotient and folded in the remainder, from
+        // the leading coefficient down
+        let mut remainder = self.coeffs.clone();
+        let mut quotient = vec![BlsScalar::zero(); self.coeffs.len() - n];
+        for i in (n..remainder.len()).rev() {
+            let coeff = remainder[i];
+            quotient[i - n] = coeff;
+            remainder[i - n] += coeff;
+        }
+        remainder.truncate(n);
+
+        (
+            Polynomial::from_coefficients_vec(quotient),
+            Polynomial::from_coefficients_vec(remainder),
+        )
+    }
 }
 
 /// Performs O(nlogn) multiplication of polynomials if F is smooth.
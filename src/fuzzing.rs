@@ -0,0 +1,221 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Hooks of the fuzz targets of the `fuzz` directory.
+//!
+//! The fuzzer input is decoded into a small random [`FuzzCircuit`] and its
+//! witnesses, that [`prove_and_verify`] proves and verifies before checking
+//! that mutated witnesses and mutated proof bytes never verify.
+
+use dusk_bls12_381::BlsScalar;
+use dusk_bytes::Serializable;
+use rand_core::{CryptoRng, RngCore};
+
+use crate::commitment_scheme::PublicParameters;
+use crate::composer::{Circuit, Compiler, Composer};
+use crate::constraint_system::Constraint;
+use crate::error::Error;
+use crate::proof_system::Proof;
+
+/// Max number of inputs of a circuit
+const MAX_INPUTS: usize = 4;
+
+/// Max number of operations of a circuit
+const MAX_OPS: usize = 32;
+
+/// Degree of the public parameters, enough for the largest circuits
+const PP_DEGREE: usize = 1 << 7;
+
+/// Operation of a [`FuzzCircuit`], appending a new value computed from the
+/// values at the given indices
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Add(usize, usize),
+    Mul(usize, usize),
+    AddConstant(usize, u64),
+}
+
+/// Circuit of arithmetic operations over its inputs, whose last value is
+/// asserted equal to a public input.
+///
+/// Every value of the circuit depends on the inputs, so that a mutated
+/// input changes the public input unless the operations cancel it.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct FuzzCircuit {
+    inputs: Vec<BlsScalar>,
+    ops: Vec<Op>,
+}
+
+impl FuzzCircuit {
+    /// Decode a circuit from fuzzer input, returning `None` if the input is
+    /// too short.
+    ///
+    /// The first byte is the number of inputs, followed by 8 bytes per input,
+    /// and by 3 bytes per operation.
+    pub fn from_fuzz_input(data: &[u8]) -> Option<Self> {
+        let (&inputs_len, mut data) = data.split_first()?;
+        let inputs_len = 1 + inputs_len as usize % MAX_INPUTS;
+
+        let mut inputs = Vec::with_capacity(inputs_len);
+        for _ in 0..inputs_len {
+            if data.len() < 8 {
+                return None;
+            }
+            let (input, rest) = data.split_at(8);
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(input);
+            inputs.push(BlsScalar::from(u64::from_le_bytes(bytes)));
+            data = rest;
+        }
+
+        let ops = data
+            .chunks_exact(3)
+            .take(MAX_OPS)
+            .enumerate()
+            .map(|(k, op)| {
+                // the operations read the inputs and the previous values
+                let len = inputs_len + k;
+                let i = op[1] as usize % len;
+                let j = op[2] as usize % len;
+                match op[0] % 3 {
+                    0 => Op::Add(i, j),
+                    1 => Op::Mul(i, j),
+                    _ => Op::AddConstant(i, op[2] as u64),
+                }
+            })
+            .collect();
+
+        Some(Self { inputs, ops })
+    }
+
+    /// Number of inputs of the circuit
+    pub fn inputs(&self) -> usize {
+        self.inputs.len()
+    }
+
+    /// Copy of the circuit with the input at `index` incremented
+    pub fn mutate_input(&self, index: usize) -> Self {
+        let mut circuit = self.clone();
+        circuit.inputs[index] += BlsScalar::one();
+        circuit
+    }
+
+    /// Last value of the circuit, computed natively
+    pub fn output(&self) -> BlsScalar {
+        let mut values = self.inputs.clone();
+        self.ops.iter().for_each(|op| {
+            let value = match *op {
+                Op::Add(i, j) => values[i] + values[j],
+                Op::Mul(i, j) => values[i] * values[j],
+                Op::AddConstant(i, c) => values[i] + BlsScalar::from(c),
+            };
+            values.push(value);
+        });
+
+        values.last().copied().unwrap_or_default()
+    }
+}
+
+impl Circuit for FuzzCircuit {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer,
+    {
+        let mut values: Vec<_> = self
+            .inputs
+            .iter()
+            .map(|input| composer.append_witness(*input))
+            .collect();
+
+        for op in self.ops.iter() {
+            let value = match *op {
+                Op::Add(i, j) => {
+                    let constraint = Constraint::new()
+                        .left(1)
+                        .right(1)
+                        .a(values[i])
+                        .b(values[j]);
+                    composer.gate_add(constraint)
+                }
+                Op::Mul(i, j) => {
+                    let constraint =
+                        Constraint::new().mult(1).a(values[i]).b(values[j]);
+                    composer.gate_mul(constraint)
+                }
+                Op::AddConstant(i, c) => {
+                    let constraint = Constraint::new()
+                        .left(1)
+                        .constant(BlsScalar::from(c))
+                        .a(values[i]);
+                    composer.gate_add(constraint)
+                }
+            };
+            values.push(value);
+        }
+
+        if let Some(&last) = values.last() {
+            let output = composer.append_public(self.output());
+            composer.assert_equal(last, output);
+        }
+
+        Ok(())
+    }
+}
+
+/// Prove and verify the circuit decoded from the fuzzer input `data`, and
+/// check that mutated witnesses and proof bytes don't verify.
+///
+/// # Panics
+/// If the proof of the circuit doesn't verify, or if a mutated witness or a
+/// mutated proof verifies.
+pub fn prove_and_verify<R>(data: &[u8], rng: &mut R)
+where
+    R: RngCore + CryptoRng,
+{
+    let circuit = match FuzzCircuit::from_fuzz_input(data) {
+        Some(circuit) => circuit,
+        None => return,
+    };
+
+    let pp = PublicParameters::setup(PP_DEGREE, rng)
+        .expect("the public parameters should be created");
+    let (prover, verifier) =
+        Compiler::compile_with_circuit(&pp, b"fuzz", &circuit)
+            .expect("the circuit should compile");
+
+    let (proof, public_inputs) = prover
+        .prove(rng, &circuit)
+        .expect("the circuit should be proved");
+    verifier
+        .verify(&proof, &public_inputs)
+        .expect("the proof should verify");
+
+    // a mutated witness changing the output doesn't verify against the
+    // original public inputs
+    let index = data[0] as usize % circuit.inputs();
+    let mutated = circuit.mutate_input(index);
+    if mutated.output() != circuit.output() {
+        if let Ok((mutated_proof, _)) = prover.prove(rng, &mutated) {
+            assert!(
+                verifier.verify(&mutated_proof, &public_inputs).is_err(),
+                "the proof of a mutated witness should not verify"
+            );
+        }
+    }
+
+    // a mutated proof doesn't verify, whenever its points are valid
+    let mut bytes = proof.to_bytes();
+    let index = data.len() % bytes.len();
+    bytes[index] ^= 1 << (data[data.len() - 1] % 8);
+    if let Ok(mutated_proof) = Proof::from_bytes(&bytes) {
+        if mutated_proof != proof {
+            assert!(
+                verifier.verify(&mutated_proof, &public_inputs).is_err(),
+                "a mutated proof should not verify"
+            );
+        }
+    }
+}
@@ -0,0 +1,164 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Parameters of the Hades252 permutation, the ones of `dusk-poseidon`.
+//!
+//! Only the permutation is shared with `dusk-poseidon`: the sponge of
+//! [`hash`] starts its capacity from the number of inputs, so its outputs
+//! differ from the hashes of `dusk-poseidon`.
+
+use alloc::vec::Vec;
+
+use dusk_bls12_381::BlsScalar;
+use sha2::{Digest, Sha512};
+
+const CONSTANTS: usize = 960;
+
+// Extracted from
+// https://github.com/dusk-network/Hades252/blob/a4d55e06ee9ff7f549043582e8d194eb0a01bf24/assets/HOWTO.md
+
+pub(crate) fn constants() -> [BlsScalar; CONSTANTS] {
+    let mut cnst = [BlsScalar::zero(); CONSTANTS];
+    let mut p = BlsScalar::one();
+    let mut bytes = b"poseidon-for-plonk".to_vec();
+
+    cnst.iter_mut().for_each(|c| {
+        bytes = Sha512::digest(bytes.as_slice()).to_vec();
+
+        let mut v = [0x00u8; 64];
+        v.copy_from_slice(&bytes[0..64]);
+
+        *c = BlsScalar::from_bytes_wide(&v) + p;
+        p = *c;
+    });
+
+    cnst
+}
+
+pub(crate) const WIDTH: usize = 5;
+
+/// Number of elements absorbed between two permutations
+pub(crate) const RATE: usize = WIDTH - 1;
+
+/// Number of rounds applying the S-box to the whole state
+pub(crate) const FULL_ROUNDS: usize = 8;
+
+/// Number of rounds applying the S-box to the last element of the state
+pub(crate) const PARTIAL_ROUNDS: usize = 59;
+
+pub(crate) fn mds() -> [[BlsScalar; WIDTH]; WIDTH] {
+    let mut matrix = [[BlsScalar::zero(); WIDTH]; WIDTH];
+    let mut xs = [BlsScalar::zero(); WIDTH];
+    let mut ys = [BlsScalar::zero(); WIDTH];
+
+    // Generate x and y values deterministically for the cauchy matrix
+    // where x[i] != y[i] to allow the values to be inverted
+    // and there are no duplicates in the x vector or y vector, so that the
+    // determinant is always non-zero [a b]
+    // [c d]
+    // det(M) = (ad - bc) ; if a == b and c == d => det(M) =0
+    // For an MDS matrix, every possible mxm submatrix, must have det(M) != 0
+    (0..WIDTH).for_each(|i| {
+        xs[i] = BlsScalar::from(i as u64);
+        ys[i] = BlsScalar::from((i + WIDTH) as u64);
+    });
+
+    let mut m = 0;
+    (0..WIDTH).for_each(|i| {
+        (0..WIDTH).for_each(|j| {
+            matrix[m][j] = (xs[i] + ys[j]).invert().unwrap();
+        });
+        m += 1;
+    });
+
+    matrix
+}
+
+/// Hades252 permutation: width 5, `x^5` S-box, 8 full rounds and 59 partial
+/// rounds
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Permutation {
+    round_constants: Vec<BlsScalar>,
+    mds: [[BlsScalar; WIDTH]; WIDTH],
+}
+
+impl Permutation {
+    pub(crate) fn new() -> Self {
+        let round_constants =
+            constants()[..(FULL_ROUNDS + PARTIAL_ROUNDS) * WIDTH].to_vec();
+
+        Self {
+            round_constants,
+            mds: mds(),
+        }
+    }
+
+    /// Constants added to the state at the start of each round
+    pub(crate) fn round_constants(
+        &self,
+    ) -> impl Iterator<Item = &[BlsScalar]> + '_ {
+        self.round_constants.chunks_exact(WIDTH)
+    }
+
+    /// Matrix multiplying the state at the end of each round
+    #[cfg(feature = "alloc")]
+    pub(crate) fn mds(&self) -> &[[BlsScalar; WIDTH]; WIDTH] {
+        &self.mds
+    }
+
+    /// Whether `round` applies the S-box to the whole state
+    pub(crate) fn is_full_round(round: usize) -> bool {
+        let half = FULL_ROUNDS / 2;
+        round < half || round >= half + PARTIAL_ROUNDS
+    }
+
+    pub(crate) fn permute(&self, state: &mut [BlsScalar; WIDTH]) {
+        self.round_constants()
+            .enumerate()
+            .for_each(|(round, constants)| {
+                state.iter_mut().zip(constants).for_each(|(s, c)| *s += c);
+
+                if Self::is_full_round(round) {
+                    state.iter_mut().for_each(|s| *s = quintic(s));
+                } else {
+                    state[WIDTH - 1] = quintic(&state[WIDTH - 1]);
+                }
+
+                let mut next = [BlsScalar::zero(); WIDTH];
+                next.iter_mut().zip(self.mds.iter()).for_each(|(n, row)| {
+                    *n = row.iter().zip(state.iter()).map(|(m, s)| m * s).sum()
+                });
+                *state = next;
+            });
+    }
+}
+
+fn quintic(s: &BlsScalar) -> BlsScalar {
+    s.square().square() * s
+}
+
+/// Hash `inputs` with a sponge of the Hades252 permutation.
+///
+/// The capacity element of the state starts as the number of inputs, which
+/// are absorbed by groups of four, permuting the state after each group. The
+/// hash is the first element of the rate after the last permutation. It is
+/// the hash computed in circuits by
+/// [`Composer::component_hash`](crate::prelude::Composer::component_hash).
+pub fn hash(inputs: &[BlsScalar]) -> BlsScalar {
+    let permutation = Permutation::new();
+    let mut state = [BlsScalar::zero(); WIDTH];
+    state[0] = BlsScalar::from(inputs.len() as u64);
+
+    let mut chunks = inputs.chunks(RATE);
+    let first = chunks.next().unwrap_or(&[]);
+
+    core::iter::once(first).chain(chunks).for_each(|chunk| {
+        state[1..].iter_mut().zip(chunk).for_each(|(s, i)| *s += i);
+        permutation.permute(&mut state);
+    });
+
+    state[1]
+}
@@ -53,7 +53,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 cfg_if::cfg_if!(
-if #[cfg(feature = "alloc")] {
+if #[cfg(feature = "verifier")] {
     /// `macro_use` will declare `vec!`. However, if `libstd` is present, then this
     /// is declared in the prelude and there will be a conflicting implementation.
     ///
@@ -62,21 +62,65 @@ if #[cfg(feature = "alloc")] {
     #[cfg_attr(not(feature = "std"), macro_use)]
     extern crate alloc;
 
-    mod bit_iterator;
+    #[cfg(feature = "alloc")]
+    #[macro_use]
+    mod trace;
+
+    mod encoding;
+    mod hades;
+
+    #[cfg(feature = "wasm")]
+    pub mod wasm;
+});
+
+cfg_if::cfg_if!(
+if #[cfg(feature = "heapless")] {
     mod permutation;
     mod util;
-    mod transcript;
 
-    pub mod constraint_system;
     pub mod composer;
+    pub mod transcript;
+});
+
+cfg_if::cfg_if!(
+if #[cfg(feature = "alloc")] {
+    mod bit_iterator;
+    #[cfg(any(feature = "circom", feature = "zkinterface"))]
+    mod r1cs;
+
+    #[cfg(feature = "serde")]
+    mod serde_support;
+
+    pub mod constraint_system;
     pub mod runtime;
+
+    #[cfg(feature = "acir")]
+    pub mod acir;
+    #[cfg(feature = "circom")]
+    pub mod circom;
+    #[cfg(feature = "fuzzing")]
+    pub mod fuzzing;
+    #[cfg(feature = "protobuf")]
+    pub mod protobuf;
+    #[cfg(feature = "proptest")]
+    pub mod strategy;
+    #[cfg(feature = "typescript")]
+    pub mod typescript;
+    #[cfg(feature = "zkinterface")]
+    pub mod zkinterface;
 });
 
-mod fft;
+pub mod fft;
 
 #[cfg(feature = "debug")]
 pub(crate) mod debugger;
 
+#[cfg(feature = "capi")]
+pub mod capi;
+
+#[cfg(feature = "pyo3")]
+pub mod python;
+
 pub mod commitment_scheme;
 pub mod error;
 pub mod prelude;
@@ -4,25 +4,35 @@
 //
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
-use crate::constraint_system::{WireData, Witness};
-use crate::fft::{EvaluationDomain, Polynomial};
-use alloc::vec::Vec;
-use constants::{K1, K2, K3};
-use dusk_bls12_381::BlsScalar;
-use hashbrown::HashMap;
-use itertools::izip;
+cfg_if::cfg_if!(
+    if #[cfg(feature = "alloc")] {
+        use crate::constraint_system::{WireData, Witness};
+        use crate::fft::{EvaluationDomain, Polynomial};
+        use crate::util::batch_inversion;
+        use alloc::vec::Vec;
+        use constants::{K1, K2, K3};
+        use dusk_bls12_381::BlsScalar;
+        use hashbrown::HashMap;
+        use itertools::izip;
+    }
+);
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
 pub(crate) mod constants;
 
 /// Permutation provides the necessary state information and functions
 /// to create the permutation polynomial. In the literature, Z(X) is the
 /// "accumulator", this is what this codebase calls the permutation polynomial.
+#[cfg(feature = "alloc")]
 #[derive(Debug, Clone)]
 pub(crate) struct Permutation {
     // Maps a witness to the wires that it is associated to.
     pub(crate) witness_map: HashMap<Witness, Vec<WireData>>,
 }
 
+#[cfg(feature = "alloc")]
 impl Permutation {
     /// Creates a Permutation struct with an expected capacity of zero.
     pub(crate) fn new() -> Permutation {
@@ -206,9 +216,18 @@ impl Permutation {
         ]
     }
 
-    // Uses a rayon multizip to allow more code flexibility while remaining
-    // parallelizable. This can be adapted into a general product argument
-    // for any number of wires.
+    // The ratios of the gates are independent, and their running product is
+    // computed in chunks stitched together, so that both are parallelizable.
+    // This can be adapted into a general product argument for any number of
+    // wires.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(size = domain.size())
+        )
+    )]
     pub(crate) fn compute_permutation_vec(
         &self,
         domain: &EvaluationDomain,
@@ -220,76 +239,55 @@ impl Permutation {
         let n = domain.size();
 
         // Constants defining cosets H, k1H, k2H, etc
-        let ks = vec![BlsScalar::one(), K1, K2, K3];
+        let ks = [BlsScalar::one(), K1, K2, K3];
 
-        // Transpose wires and sigma values to get "rows" in the form [a_w_i,
-        // b_w_i, c_w_i, ... ] where each row contains the wire and sigma
-        // values for a single gate
-        let gatewise_wires = izip!(wires[0], wires[1], wires[2], wires[3])
-            .map(|(w0, w1, w2, w3)| vec![w0, w1, w2, w3]);
-
-        let gatewise_sigmas: Vec<Vec<BlsScalar>> =
+        let sigmas: Vec<Vec<BlsScalar>> =
             sigma_polys.iter().map(|sigma| domain.fft(sigma)).collect();
-        let gatewise_sigmas = izip!(
-            &gatewise_sigmas[0],
-            &gatewise_sigmas[1],
-            &gatewise_sigmas[2],
-            &gatewise_sigmas[3]
-        )
-        .map(|(s0, s1, s2, s3)| vec![s0, s1, s2, s3]);
-
-        // Compute all roots
-        // Non-parallelizable?
         let roots: Vec<BlsScalar> = domain.elements().collect();
 
-        let product_argument = izip!(roots, gatewise_sigmas, gatewise_wires)
-            // Associate each wire value in a gate with the k defining its coset
-            .map(|(gate_root, gate_sigmas, gate_wires)| {
-                (gate_root, izip!(gate_sigmas, gate_wires, &ks))
-            })
-            // Now the ith element represents gate i and will have the form:
-            //   (root_i, ((w0_i, s0_i, k0), (w1_i, s1_i, k1), ..., (wm_i, sm_i,
-            // km)))   for m different wires, which is all the
-            // information   needed for a single product coefficient
-            // for a single gate Multiply up the numerator and
-            // denominator irreducibles for each gate   and pair the
-            // results
-            .map(|(gate_root, wire_params)| {
-                (
-                    // Numerator product
-                    wire_params
-                        .clone()
-                        .map(|(_sigma, wire, k)| {
-                            wire + beta * k * gate_root + gamma
-                        })
-                        .product::<BlsScalar>(),
-                    // Denominator product
-                    wire_params
-                        .map(|(sigma, wire, _k)| wire + beta * sigma + gamma)
-                        .product::<BlsScalar>(),
-                )
+        #[cfg(not(feature = "parallel"))]
+        let range = (0..n).into_iter();
+
+        #[cfg(feature = "parallel")]
+        let range = (0..n).into_par_iter();
+
+        // The ith elements are the numerator and denominator irreducibles of
+        // gate i, multiplied over the wires:
+        //   prod_j (w_j_i + beta * k_j * root_i + gamma)
+        //   prod_j (w_j_i + beta * s_j_i + gamma)
+        let (mut product_argument, mut denominators): (Vec<_>, Vec<_>) = range
+            .map(|i| {
+                izip!(wires, &sigmas, &ks)
+                    .map(|(wire, sigma, k)| {
+                        (
+                            wire[i] + beta * k * roots[i] + gamma,
+                            wire[i] + beta * sigma[i] + gamma,
+                        )
+                    })
+                    .fold((BlsScalar::one(), BlsScalar::one()), |acc, t| {
+                        (acc.0 * t.0, acc.1 * t.1)
+                    })
             })
-            // Divide each pair to get the single scalar representing each gate
-            .map(|(n, d)| n * d.invert().unwrap())
-            // Collect into vector intermediary since rayon does not support
-            // `scan`
-            .collect::<Vec<BlsScalar>>();
+            .unzip();
 
-        let mut z = Vec::with_capacity(n);
+        // Divide each pair to get the single scalar representing each gate
+        batch_inversion(&mut denominators);
 
-        // First element is one
-        let mut state = BlsScalar::one();
-        z.push(state);
+        #[cfg(not(feature = "parallel"))]
+        let ratios = product_argument.iter_mut().zip(denominators);
 
-        // Accumulate by successively multiplying the scalars
-        // Non-parallelizable?
-        for s in product_argument {
-            state *= s;
-            z.push(state);
-        }
+        #[cfg(feature = "parallel")]
+        let ratios = product_argument.par_iter_mut().zip(denominators);
 
-        // Remove the last(n+1'th) element
-        z.remove(n);
+        ratios.for_each(|(ratio, denominator)| *ratio *= denominator);
+
+        // The first element is one, and the ith element the product of the
+        // i - 1 first ratios. The last ratio closes the cycle back to one.
+        let mut z = Vec::with_capacity(n);
+        z.push(BlsScalar::one());
+        z.extend_from_slice(&product_argument[..n - 1]);
+
+        running_product(&mut z);
 
         assert_eq!(n, z.len());
 
@@ -297,6 +295,49 @@ impl Permutation {
     }
 }
 
+/// Replace every scalar of `values` by the product of the scalars up to it.
+///
+/// With the `parallel` feature, the running products of chunks of the values
+/// are computed in parallel, and then multiplied by the product of the
+/// chunks before them.
+#[cfg(feature = "alloc")]
+fn running_product(values: &mut [BlsScalar]) {
+    #[cfg(not(feature = "parallel"))]
+    (1..values.len()).for_each(|i| {
+        let previous = values[i - 1];
+        values[i] *= previous;
+    });
+
+    #[cfg(feature = "parallel")]
+    {
+        let chunk = (values.len() / rayon::current_num_threads()).max(1024);
+
+        values.par_chunks_mut(chunk).for_each(|chunk| {
+            (1..chunk.len()).for_each(|i| {
+                let previous = chunk[i - 1];
+                chunk[i] *= previous;
+            })
+        });
+
+        // the product of the chunks before every chunk
+        let mut product = BlsScalar::one();
+        let offsets: Vec<BlsScalar> = values
+            .chunks(chunk)
+            .map(|chunk| {
+                let offset = product;
+                product *= chunk[chunk.len() - 1];
+                offset
+            })
+            .collect();
+
+        values.par_chunks_mut(chunk).zip(offsets).skip(1).for_each(
+            |(chunk, offset)| {
+                chunk.iter_mut().for_each(|value| *value *= offset)
+            },
+        );
+    }
+}
+
 #[cfg(feature = "std")]
 #[cfg(test)]
 mod test {
@@ -939,6 +980,25 @@ mod test {
         assert_eq!(encoded_s_sigma_4[3], K3);
     }
 
+    #[test]
+    fn test_running_product() {
+        let values: Vec<BlsScalar> =
+            (0..5000).map(|_| BlsScalar::random(&mut OsRng)).collect();
+
+        let mut product = BlsScalar::one();
+        let expected: Vec<BlsScalar> = values
+            .iter()
+            .map(|value| {
+                product *= value;
+                product
+            })
+            .collect();
+
+        let mut running = values;
+        running_product(&mut running);
+        assert_eq!(running, expected);
+    }
+
     #[test]
     fn test_basic_slow_permutation_poly() {
         let num_wire_mappings = 2;
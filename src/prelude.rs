@@ -11,11 +11,23 @@
 
 #[cfg(feature = "alloc")]
 pub use crate::{
-    commitment_scheme::PublicParameters,
-    composer::{Builder, Circuit, Compiler, Composer, Prover, Verifier},
+    commitment_scheme::{PowersOfTau, PublicParameters, TestParameters},
+    composer::{
+        Builder, CancellationToken, Circuit, Compiler, Composer, OptLevel,
+        ProveStage, ProveStats, Prover,
+    },
     constraint_system::{Constraint, Witness, WitnessPoint},
 };
 
+#[cfg(feature = "verifier")]
+pub use crate::composer::{
+    Accumulator, PaddedVec, ProofBundle, PublicInputValue, Verifier,
+    VerifierCost,
+};
+
+#[cfg(feature = "heapless")]
+pub use crate::composer::HeaplessVerifier;
+
 pub use crate::error::Error;
 pub use crate::proof_system::Proof;
 pub use dusk_bls12_381::BlsScalar;
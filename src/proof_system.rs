@@ -8,7 +8,14 @@
 
 pub(crate) mod linearization_poly;
 pub(crate) mod proof;
-pub(crate) mod widget;
+
+cfg_if::cfg_if!(
+    if #[cfg(feature = "heapless")] {
+        pub(crate) mod widget;
+
+        pub(crate) use widget::VerifierKey;
+    }
+);
 
 cfg_if::cfg_if!(
     if #[cfg(feature = "alloc")] {
@@ -16,7 +23,6 @@ cfg_if::cfg_if!(
         pub(crate) mod preprocess;
 
         pub(crate) use widget::alloc::ProverKey;
-        pub(crate) use widget::VerifierKey;
 
         cfg_if::cfg_if!(
             if #[cfg(feature = "rkyv-impl")] {
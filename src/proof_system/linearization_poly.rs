@@ -171,6 +171,10 @@ impl Serializable<{ 16 * BlsScalar::SIZE }> for ProofEvaluations {
 /// Compute the linearization polynomial.
 // TODO: Improve the method signature
 #[allow(clippy::type_complexity)]
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip_all, fields(size = domain.size()))
+)]
 pub(crate) fn compute(
     domain: &EvaluationDomain,
     prover_key: &ProverKey,
@@ -246,6 +250,7 @@ pub(crate) fn compute(
     );
 
     let f_2 = prover_key.permutation.compute_linearization(
+        domain,
         z_challenge,
         (alpha, beta, gamma),
         (&a_eval, &b_eval, &c_eval, &d_eval),
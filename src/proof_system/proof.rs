@@ -9,6 +9,7 @@
 
 use super::linearization_poly::ProofEvaluations;
 use crate::commitment_scheme::Commitment;
+use crate::error::Error;
 
 use dusk_bytes::{DeserializableSlice, Serializable};
 
@@ -108,6 +109,41 @@ impl<C> CheckBytes<C> for ArchivedProof {
     }
 }
 
+impl Proof {
+    /// Version of the encoding of [`Self::to_versioned_bytes`]
+    ///
+    /// The version is bumped whenever the byte representation of the proof
+    /// changes, and the previous versions remain readable by
+    /// [`Self::from_versioned_bytes`].
+    pub const VERSION: u8 = 1;
+
+    /// Size of the encoding of [`Self::to_versioned_bytes`]
+    pub const VERSIONED_SIZE: usize = 1 + Self::SIZE;
+
+    /// Serialize the proof prefixed by the version of its encoding
+    pub fn to_versioned_bytes(&self) -> [u8; Self::VERSIONED_SIZE] {
+        let mut buf = [0u8; Self::VERSIONED_SIZE];
+
+        buf[0] = Self::VERSION;
+        buf[1..].copy_from_slice(&self.to_bytes());
+
+        buf
+    }
+
+    /// Deserialize a proof serialized with [`Self::to_versioned_bytes`] by
+    /// this or any previous version of the crate
+    pub fn from_versioned_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        match bytes.split_first() {
+            Some((&1, proof)) if proof.len() == Self::SIZE => {
+                Ok(Self::from_slice(proof)?)
+            }
+            Some((&1, _)) => Err(Error::NotEnoughBytes),
+            Some((&version, _)) => Err(Error::UnsupportedVersion { version }),
+            None => Err(Error::NotEnoughBytes),
+        }
+    }
+}
+
 // The struct Proof has 11 commitments + 1 ProofEvaluations
 impl Serializable<{ 11 * Commitment::SIZE + ProofEvaluations::SIZE }>
     for Proof
@@ -169,37 +205,89 @@ impl Serializable<{ 11 * Commitment::SIZE + ProofEvaluations::SIZE }>
     }
 }
 
-#[cfg(feature = "alloc")]
+#[cfg(feature = "verifier")]
 pub(crate) mod alloc {
+    use super::*;
+    use crate::encoding::{self, JsonValue};
+    use crate::error::Error;
+    #[rustfmt::skip]
+    use ::alloc::{string::String, vec::Vec};
+
+    impl Proof {
+        /// Encode the versioned bytes of the proof as a hexadecimal string
+        pub fn to_hex(&self) -> String {
+            encoding::to_hex(&self.to_versioned_bytes())
+        }
+
+        /// Decode a proof encoded with [`Self::to_hex`]
+        pub fn from_hex(hex: &str) -> Result<Self, Error> {
+            Self::from_versioned_bytes(&encoding::from_hex(hex)?)
+        }
+
+        /// Encode the proof as a JSON object, holding the version of the
+        /// encoding and the hexadecimal representation of the proof bytes
+        ///
+        /// `{"version":1,"proof":"<hex>"}`
+        pub fn to_json(&self) -> String {
+            encoding::to_json(&[
+                ("version", JsonValue::Number(Self::VERSION as u64)),
+                (
+                    "proof",
+                    JsonValue::String(encoding::to_hex(&self.to_bytes())),
+                ),
+            ])
+        }
+
+        /// Decode a proof encoded with [`Self::to_json`]
+        pub fn from_json(json: &str) -> Result<Self, Error> {
+            let fields = encoding::from_json(json)?;
+
+            let version = match encoding::json_field(&fields, "version")? {
+                JsonValue::Number(v) => {
+                    u8::try_from(*v).map_err(|_| Error::InvalidJson)?
+                }
+                _ => return Err(Error::InvalidJson),
+            };
+            let proof = match encoding::json_field(&fields, "proof")? {
+                JsonValue::String(hex) => encoding::from_hex(hex)?,
+                _ => return Err(Error::InvalidJson),
+            };
+
+            let mut bytes = Vec::with_capacity(1 + proof.len());
+            bytes.push(version);
+            bytes.extend(proof);
+
+            Self::from_versioned_bytes(&bytes)
+        }
+    }
+}
+
+#[cfg(feature = "heapless")]
+pub(crate) mod verifier {
     use super::*;
     use crate::{
-        commitment_scheme::{AggregateProof, OpeningKey},
+        commitment_scheme::{flatten, OpeningKey},
         error::Error,
         fft::EvaluationDomain,
-        proof_system::widget::VerifierKey,
-        transcript::TranscriptProtocol,
-        util::batch_inversion,
+        proof_system::widget::{LinearizationTerms, VerifierKey},
+        transcript::{TranscriptExt, TranscriptProtocol},
+        util,
     };
-    #[rustfmt::skip]
-    use ::alloc::vec::Vec;
-    use dusk_bls12_381::{
-        multiscalar_mul::msm_variable_base, BlsScalar, G1Affine,
-    };
-    use merlin::Transcript;
-    #[cfg(feature = "std")]
-    use rayon::prelude::*;
+    use dusk_bls12_381::{BlsScalar, G1Projective};
 
     impl Proof {
-        /// Performs the verification of a [`Proof`] returning a boolean result.
-        pub(crate) fn verify(
+        /// Performs the verification of a [`Proof`] up to the final pairing
+        /// check, and returns the pair of points `(w, c)` that satisfies
+        /// `e(-w, beta_h) · e(c, h) = 1` iff the proof is valid.
+        pub(crate) fn accumulate<T: TranscriptProtocol>(
             &self,
+            domain: &EvaluationDomain,
             verifier_key: &VerifierKey,
-            transcript: &mut Transcript,
+            transcript: &mut T,
             opening_key: &OpeningKey,
-            pub_inputs: &[BlsScalar],
-        ) -> Result<(), Error> {
-            let domain = EvaluationDomain::new(verifier_key.n)?;
-
+            public_input_indexes: &[usize],
+            public_inputs: &[BlsScalar],
+        ) -> Result<(G1Projective, G1Projective), Error> {
             // Subgroup checks are done when the proof is deserialized.
 
             // In order for the Verifier and Prover to have the same view in the
@@ -248,15 +336,15 @@ pub(crate) mod alloc {
 
             // Compute first lagrange polynomial evaluated at challenge `z`
             let l1_eval = compute_first_lagrange_evaluation(
-                &domain,
+                domain,
                 &z_h_eval,
                 &z_challenge,
             );
 
             // Compute quotient polynomial evaluated at challenge `z`
             let t_eval = self.compute_quotient_evaluation(
-                &domain,
-                pub_inputs,
+                domain,
+                (public_input_indexes, public_inputs),
                 &alpha,
                 &beta,
                 &gamma,
@@ -328,67 +416,64 @@ pub(crate) mod alloc {
             // is proof that the permutation polynomial evaluated at the shifted
             // root of unity is correct
 
-            // Compose the Aggregated Proof
-            //
-            let mut aggregate_proof =
-                AggregateProof::with_witness(self.w_z_chall_comm);
-            aggregate_proof.add_part((t_eval, t_comm));
-            aggregate_proof.add_part((self.evaluations.r_poly_eval, r_comm));
-            aggregate_proof.add_part((self.evaluations.a_eval, self.a_comm));
-            aggregate_proof.add_part((self.evaluations.b_eval, self.b_comm));
-            aggregate_proof.add_part((self.evaluations.c_eval, self.c_comm));
-            aggregate_proof.add_part((self.evaluations.d_eval, self.d_comm));
-            aggregate_proof.add_part((
-                self.evaluations.s_sigma_1_eval,
-                verifier_key.permutation.s_sigma_1,
-            ));
-            aggregate_proof.add_part((
-                self.evaluations.s_sigma_2_eval,
-                verifier_key.permutation.s_sigma_2,
-            ));
-            aggregate_proof.add_part((
-                self.evaluations.s_sigma_3_eval,
-                verifier_key.permutation.s_sigma_3,
-            ));
-            // Flatten proof with opening challenge
-            let flattened_proof_a = aggregate_proof.flatten(transcript);
+            // Compose the Aggregated Proof and flatten it with the opening
+            // challenge
+            let flattened_proof_a = flatten(
+                self.w_z_chall_comm,
+                &[
+                    t_eval,
+                    self.evaluations.r_poly_eval,
+                    self.evaluations.a_eval,
+                    self.evaluations.b_eval,
+                    self.evaluations.c_eval,
+                    self.evaluations.d_eval,
+                    self.evaluations.s_sigma_1_eval,
+                    self.evaluations.s_sigma_2_eval,
+                    self.evaluations.s_sigma_3_eval,
+                ],
+                &[
+                    t_comm,
+                    r_comm,
+                    self.a_comm,
+                    self.b_comm,
+                    self.c_comm,
+                    self.d_comm,
+                    verifier_key.permutation.s_sigma_1,
+                    verifier_key.permutation.s_sigma_2,
+                    verifier_key.permutation.s_sigma_3,
+                ],
+                transcript,
+            );
 
             // Compose the shifted aggregate proof
-            let mut shifted_aggregate_proof =
-                AggregateProof::with_witness(self.w_z_chall_w_comm);
-            shifted_aggregate_proof
-                .add_part((self.evaluations.perm_eval, self.z_comm));
-            shifted_aggregate_proof
-                .add_part((self.evaluations.a_next_eval, self.a_comm));
-            shifted_aggregate_proof
-                .add_part((self.evaluations.b_next_eval, self.b_comm));
-            shifted_aggregate_proof
-                .add_part((self.evaluations.d_next_eval, self.d_comm));
-
-            let flattened_proof_b = shifted_aggregate_proof.flatten(transcript);
+            let flattened_proof_b = flatten(
+                self.w_z_chall_w_comm,
+                &[
+                    self.evaluations.perm_eval,
+                    self.evaluations.a_next_eval,
+                    self.evaluations.b_next_eval,
+                    self.evaluations.d_next_eval,
+                ],
+                &[self.z_comm, self.a_comm, self.b_comm, self.d_comm],
+                transcript,
+            );
+
             // Add commitment to openings to transcript
             transcript.append_commitment(b"w_z", &self.w_z_chall_comm);
             transcript.append_commitment(b"w_z_w", &self.w_z_chall_w_comm);
             // Batch check
-            if opening_key
-                .batch_check(
-                    &[z_challenge, (z_challenge * domain.group_gen)],
-                    &[flattened_proof_a, flattened_proof_b],
-                    transcript,
-                )
-                .is_err()
-            {
-                return Err(Error::ProofVerificationError);
-            }
-
-            Ok(())
+            Ok(opening_key.accumulate(
+                &[z_challenge, (z_challenge * domain.group_gen)],
+                &[flattened_proof_a, flattened_proof_b],
+                transcript,
+            ))
         }
 
         #[allow(clippy::too_many_arguments)]
         fn compute_quotient_evaluation(
             &self,
             domain: &EvaluationDomain,
-            pub_inputs: &[BlsScalar],
+            (public_input_indexes, public_inputs): (&[usize], &[BlsScalar]),
             alpha: &BlsScalar,
             beta: &BlsScalar,
             gamma: &BlsScalar,
@@ -398,8 +483,11 @@ pub(crate) mod alloc {
             z_hat_eval: &BlsScalar,
         ) -> BlsScalar {
             // Compute the public input polynomial evaluated at challenge `z`
-            let pi_eval =
-                compute_barycentric_eval(pub_inputs, z_challenge, domain);
+            let pi_eval = domain.sparse_barycentric_eval(
+                public_input_indexes,
+                public_inputs,
+                z_challenge,
+            );
 
             // Compute powers of alpha_0
             let alpha_sq = alpha.square();
@@ -466,8 +554,8 @@ pub(crate) mod alloc {
             l1_eval: BlsScalar,
             verifier_key: &VerifierKey,
         ) -> Commitment {
-            let mut scalars: Vec<_> = Vec::with_capacity(6);
-            let mut points: Vec<G1Affine> = Vec::with_capacity(6);
+            let mut scalars = LinearizationTerms::new();
+            let mut points = LinearizationTerms::new();
 
             verifier_key.arithmetic.compute_linearization_commitment(
                 &mut scalars,
@@ -513,7 +601,7 @@ pub(crate) mod alloc {
                 self.z_comm.0,
             );
 
-            Commitment::from(msm_variable_base(&points, &scalars))
+            Commitment::from(util::msm(&points, &scalars))
         }
     }
 
@@ -526,60 +614,6 @@ pub(crate) mod alloc {
         let denom = n_fr * (z_challenge - BlsScalar::one());
         z_h_eval * denom.invert().unwrap()
     }
-
-    fn compute_barycentric_eval(
-        evaluations: &[BlsScalar],
-        point: &BlsScalar,
-        domain: &EvaluationDomain,
-    ) -> BlsScalar {
-        let numerator = (point.pow(&[domain.size() as u64, 0, 0, 0])
-            - BlsScalar::one())
-            * domain.size_inv;
-
-        // Indices with non-zero evaluations
-        #[cfg(not(feature = "std"))]
-        let range = (0..evaluations.len()).into_iter();
-
-        #[cfg(feature = "std")]
-        let range = (0..evaluations.len()).into_par_iter();
-
-        let non_zero_evaluations: Vec<usize> = range
-            .filter(|&i| {
-                let evaluation = &evaluations[i];
-                evaluation != &BlsScalar::zero()
-            })
-            .collect();
-
-        // Only compute the denominators with non-zero evaluations
-        #[cfg(not(feature = "std"))]
-        let range = (0..non_zero_evaluations.len()).into_iter();
-
-        #[cfg(feature = "std")]
-        let range = (0..non_zero_evaluations.len()).into_par_iter();
-
-        let mut denominators: Vec<BlsScalar> = range
-            .clone()
-            .map(|i| {
-                // index of non-zero evaluation
-                let index = non_zero_evaluations[i];
-
-                (domain.group_gen_inv.pow(&[index as u64, 0, 0, 0]) * point)
-                    - BlsScalar::one()
-            })
-            .collect();
-        batch_inversion(&mut denominators);
-
-        let result: BlsScalar = range
-            .map(|i| {
-                let eval_index = non_zero_evaluations[i];
-                let eval = evaluations[eval_index];
-
-                denominators[i] * eval
-            })
-            .sum();
-
-        result * numerator
-    }
 }
 
 #[cfg(test)]
@@ -589,9 +623,8 @@ mod proof_tests {
     use ff::Field;
     use rand_core::OsRng;
 
-    #[test]
-    fn test_dusk_bytes_serde_proof() {
-        let proof = Proof {
+    fn random_proof() -> Proof {
+        Proof {
             a_comm: Commitment::default(),
             b_comm: Commitment::default(),
             c_comm: Commitment::default(),
@@ -621,10 +654,66 @@ mod proof_tests {
                 r_poly_eval: BlsScalar::random(&mut OsRng),
                 perm_eval: BlsScalar::random(&mut OsRng),
             },
-        };
+        }
+    }
+
+    #[test]
+    fn test_dusk_bytes_serde_proof() {
+        let proof = random_proof();
 
         let proof_bytes = proof.to_bytes();
         let got_proof = Proof::from_bytes(&proof_bytes).unwrap();
         assert_eq!(got_proof, proof);
     }
+
+    #[test]
+    fn test_versioned_serde_proof() {
+        let proof = random_proof();
+
+        let mut proof_bytes = proof.to_versioned_bytes();
+        assert_eq!(proof_bytes[0], Proof::VERSION);
+        assert_eq!(&proof_bytes[1..], &proof.to_bytes()[..]);
+
+        let got_proof = Proof::from_versioned_bytes(&proof_bytes).unwrap();
+        assert_eq!(got_proof, proof);
+
+        assert_eq!(
+            Proof::from_versioned_bytes(&proof_bytes[..Proof::SIZE]),
+            Err(Error::NotEnoughBytes)
+        );
+
+        proof_bytes[0] = 0xff;
+        assert_eq!(
+            Proof::from_versioned_bytes(&proof_bytes),
+            Err(Error::UnsupportedVersion { version: 0xff })
+        );
+    }
+
+    #[cfg(feature = "verifier")]
+    #[test]
+    fn test_text_serde_proof() {
+        let proof = random_proof();
+
+        let hex = proof.to_hex();
+        assert_eq!(Proof::from_hex(&hex).unwrap(), proof);
+        assert_eq!(Proof::from_hex(&format!("0x{}", hex)).unwrap(), proof);
+        assert_eq!(Proof::from_hex(&hex[1..]), Err(Error::InvalidHex));
+
+        let json = proof.to_json();
+        assert!(json.starts_with("{\"version\":1,\"proof\":\""));
+        assert_eq!(Proof::from_json(&json).unwrap(), proof);
+
+        // whitespace and the order of the fields are irrelevant
+        let pretty = format!(
+            "{{\n  \"proof\": \"{}\",\n  \"version\": 1\n}}\n",
+            crate::encoding::to_hex(&proof.to_bytes())
+        );
+        assert_eq!(Proof::from_json(&pretty).unwrap(), proof);
+
+        assert_eq!(Proof::from_json(&json[1..]), Err(Error::InvalidJson));
+        assert_eq!(
+            Proof::from_json("{\"version\":1}"),
+            Err(Error::InvalidJson)
+        );
+    }
 }
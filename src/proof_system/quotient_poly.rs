@@ -4,18 +4,28 @@
 //
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
+use alloc::vec::Vec;
+
 use crate::{
+    composer::ProveStats,
     error::Error,
     fft::{EvaluationDomain, Polynomial},
     proof_system::ProverKey,
+    util::batch_inversion,
 };
-use alloc::vec::Vec;
 use dusk_bls12_381::BlsScalar;
-#[cfg(feature = "std")]
+#[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
 /// Computes the Quotient [`Polynomial`] given the [`EvaluationDomain`], a
 /// [`ProverKey`] and some other info.
+///
+/// The FFTs and the buffers of the computation are accounted in `stats`, and
+/// the `8n` coefficients of the returned polynomial stay accounted for.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip_all, fields(size = domain.size()))
+)]
 pub(crate) fn compute(
     domain: &EvaluationDomain,
     prover_key: &ProverKey,
@@ -44,69 +54,123 @@ pub(crate) fn compute(
         BlsScalar,
         BlsScalar,
     ),
+    stats: &mut ProveStats,
 ) -> Result<Polynomial, Error> {
     // Compute 8n evals
-    let domain_8n = EvaluationDomain::new(8 * domain.size())?;
-
-    let mut z_eval_8n = domain_8n.coset_fft(z_poly);
-
-    let mut a_w_eval_8n = domain_8n.coset_fft(a_w_poly);
-    let mut b_w_eval_8n = domain_8n.coset_fft(b_w_poly);
-    let c_w_eval_8n = domain_8n.coset_fft(c_w_poly);
-    let mut d_w_eval_8n = domain_8n.coset_fft(d_w_poly);
-
-    for i in 0..8 {
-        z_eval_8n.push(z_eval_8n[i]);
-        a_w_eval_8n.push(a_w_eval_8n[i]);
-        b_w_eval_8n.push(b_w_eval_8n[i]);
-        // c_w_eval_8n push not required
-        d_w_eval_8n.push(d_w_eval_8n[i]);
-    }
-
-    let t_1 = compute_circuit_satisfiability_equation(
-        domain,
-        (
-            range_challenge,
-            logic_challenge,
-            fixed_base_challenge,
-            var_base_challenge,
-        ),
-        prover_key,
-        (&a_w_eval_8n, &b_w_eval_8n, &c_w_eval_8n, &d_w_eval_8n),
-        public_inputs_poly,
-    );
+    let domain_8n = EvaluationDomain::new_mixed_radix(8 * domain.size())?;
 
-    let t_2 = compute_permutation_checks(
-        domain,
-        prover_key,
-        (&a_w_eval_8n, &b_w_eval_8n, &c_w_eval_8n, &d_w_eval_8n),
-        &z_eval_8n,
-        (alpha, beta, gamma),
-    );
+    let l1_poly_alpha =
+        compute_first_lagrange_poly_scaled(domain, alpha.square());
+    stats.fft(1);
 
-    #[cfg(not(feature = "std"))]
-    let range = (0..domain_8n.size()).into_iter();
+    // the coset evaluations are independent, and are computed concurrently.
+    // The first 8 evaluations are repeated at the end, for the evaluations at
+    // the next row
+    let polys = [
+        z_poly,
+        a_w_poly,
+        b_w_poly,
+        c_w_poly,
+        d_w_poly,
+        public_inputs_poly,
+        &l1_poly_alpha,
+    ];
+
+    #[cfg(not(feature = "parallel"))]
+    let polys_iter = polys.iter();
 
-    #[cfg(feature = "std")]
-    let range = (0..domain_8n.size()).into_par_iter();
+    #[cfg(feature = "parallel")]
+    let polys_iter = polys.par_iter();
 
-    let quotient: Vec<_> = range
-        .map(|i| {
-            let numerator = t_1[i] + t_2[i];
-            let denominator = prover_key.v_h_coset_8n()[i];
-            numerator * denominator.invert().unwrap()
+    let coset_evals: Vec<Vec<BlsScalar>> = polys_iter
+        .map(|poly| {
+            let mut evals = domain_8n.coset_fft(poly);
+            evals.extend_from_within(..8);
+            evals
         })
         .collect();
+    stats.fft(coset_evals.len());
+    stats.alloc(coset_evals.len() * (domain_8n.size() + 8));
+
+    let evals_8n = Evals8n {
+        z: &coset_evals[0],
+        a_w: &coset_evals[1],
+        b_w: &coset_evals[2],
+        c_w: &coset_evals[3],
+        d_w: &coset_evals[4],
+        pi: &coset_evals[5],
+        l1_alpha_sq: &coset_evals[6],
+    };
+
+    // the numerator is computed in chunks of rows across threads, and divided
+    // by the vanishing polynomial in the same pass
+    let mut quotient = vec![BlsScalar::zero(); domain_8n.size()];
+    stats.alloc(domain_8n.size());
+
+    #[cfg(not(feature = "parallel"))]
+    let chunks = quotient.chunks_mut(QUOTIENT_CHUNK);
+
+    #[cfg(feature = "parallel")]
+    let chunks = quotient.par_chunks_mut(QUOTIENT_CHUNK);
+
+    chunks.enumerate().for_each(|(k, chunk)| {
+        let start = k * QUOTIENT_CHUNK;
+        let mut denominators =
+            prover_key.v_h_coset_8n().evals[start..][..chunk.len()].to_vec();
+        batch_inversion(&mut denominators);
+
+        chunk.iter_mut().enumerate().for_each(|(j, t)| {
+            let i = start + j;
 
-    let coset = domain_8n.coset_ifft(&quotient);
+            let numerator = compute_circuit_satisfiability_equation(
+                i,
+                (
+                    range_challenge,
+                    logic_challenge,
+                    fixed_base_challenge,
+                    var_base_challenge,
+                ),
+                prover_key,
+                &evals_8n,
+            ) + compute_permutation_checks(
+                i,
+                prover_key,
+                &evals_8n,
+                (alpha, beta, gamma),
+            );
+
+            *t = numerator * denominators[j];
+        })
+    });
+
+    // the coset evaluations are released before the interpolation
+    stats.release(coset_evals.len() * (domain_8n.size() + 8));
+    drop(coset_evals);
 
-    Ok(Polynomial::from_coefficients_vec(coset))
+    domain_8n.coset_ifft_in_place(&mut quotient);
+    stats.fft(1);
+
+    Ok(Polynomial::from_coefficients_vec(quotient))
+}
+
+/// Rows of the quotient computed in a single task
+const QUOTIENT_CHUNK: usize = 1 << 10;
+
+/// Evaluations over the coset of the `8n` domain of the polynomials of the
+/// quotient, with the first 8 repeated at the end
+struct Evals8n<'a> {
+    z: &'a [BlsScalar],
+    a_w: &'a [BlsScalar],
+    b_w: &'a [BlsScalar],
+    c_w: &'a [BlsScalar],
+    d_w: &'a [BlsScalar],
+    pi: &'a [BlsScalar],
+    l1_alpha_sq: &'a [BlsScalar],
 }
 
-// Ensures that the circuit is satisfied
 // Ensures that the circuit is satisfied
 fn compute_circuit_satisfiability_equation(
-    domain: &EvaluationDomain,
+    i: usize,
     (
         range_challenge,
         logic_challenge,
@@ -114,132 +178,91 @@ fn compute_circuit_satisfiability_equation(
         var_base_challenge,
     ): (&BlsScalar, &BlsScalar, &BlsScalar, &BlsScalar),
     prover_key: &ProverKey,
-    (a_w_eval_8n, b_w_eval_8n, c_w_eval_8n, d_w_eval_8n): (
-        &[BlsScalar],
-        &[BlsScalar],
-        &[BlsScalar],
-        &[BlsScalar],
-    ),
-    pi_poly: &Polynomial,
-) -> Vec<BlsScalar> {
-    let domain_8n = EvaluationDomain::new(8 * domain.size()).unwrap();
-    let public_eval_8n = domain_8n.coset_fft(pi_poly);
-
-    #[cfg(not(feature = "std"))]
-    let range = (0..domain_8n.size()).into_iter();
-
-    #[cfg(feature = "std")]
-    let range = (0..domain_8n.size()).into_par_iter();
-
-    let t: Vec<_> = range
-        .map(|i| {
-            let a_w = &a_w_eval_8n[i];
-            let b_w = &b_w_eval_8n[i];
-            let c_w = &c_w_eval_8n[i];
-            let d_w = &d_w_eval_8n[i];
-            let a_w_next = &a_w_eval_8n[i + 8];
-            let b_w_next = &b_w_eval_8n[i + 8];
-            let d_w_next = &d_w_eval_8n[i + 8];
-            let pi = &public_eval_8n[i];
-
-            let a = prover_key
-                .arithmetic
-                .compute_quotient_i(i, a_w, b_w, c_w, d_w);
-
-            let b = prover_key.range.compute_quotient_i(
-                i,
-                range_challenge,
-                a_w,
-                b_w,
-                c_w,
-                d_w,
-                d_w_next,
-            );
+    evals_8n: &Evals8n,
+) -> BlsScalar {
+    let a_w = &evals_8n.a_w[i];
+    let b_w = &evals_8n.b_w[i];
+    let c_w = &evals_8n.c_w[i];
+    let d_w = &evals_8n.d_w[i];
+    let a_w_next = &evals_8n.a_w[i + 8];
+    let b_w_next = &evals_8n.b_w[i + 8];
+    let d_w_next = &evals_8n.d_w[i + 8];
+    let pi = &evals_8n.pi[i];
 
-            let c = prover_key.logic.compute_quotient_i(
-                i,
-                logic_challenge,
-                a_w,
-                a_w_next,
-                b_w,
-                b_w_next,
-                c_w,
-                d_w,
-                d_w_next,
-            );
+    let a = prover_key
+        .arithmetic
+        .compute_quotient_i(i, a_w, b_w, c_w, d_w);
 
-            let d = prover_key.fixed_base.compute_quotient_i(
-                i,
-                fixed_base_challenge,
-                a_w,
-                a_w_next,
-                b_w,
-                b_w_next,
-                c_w,
-                d_w,
-                d_w_next,
-            );
+    let b = prover_key.range.compute_quotient_i(
+        i,
+        range_challenge,
+        a_w,
+        b_w,
+        c_w,
+        d_w,
+        d_w_next,
+    );
 
-            let e = prover_key.variable_base.compute_quotient_i(
-                i,
-                var_base_challenge,
-                a_w,
-                a_w_next,
-                b_w,
-                b_w_next,
-                c_w,
-                d_w,
-                d_w_next,
-            );
+    let c = prover_key.logic.compute_quotient_i(
+        i,
+        logic_challenge,
+        a_w,
+        a_w_next,
+        b_w,
+        b_w_next,
+        c_w,
+        d_w,
+        d_w_next,
+    );
 
-            (a + pi) + b + c + d + e
-        })
-        .collect();
-    t
+    let d = prover_key.fixed_base.compute_quotient_i(
+        i,
+        fixed_base_challenge,
+        a_w,
+        a_w_next,
+        b_w,
+        b_w_next,
+        c_w,
+        d_w,
+        d_w_next,
+    );
+
+    let e = prover_key.variable_base.compute_quotient_i(
+        i,
+        var_base_challenge,
+        a_w,
+        a_w_next,
+        b_w,
+        b_w_next,
+        c_w,
+        d_w,
+        d_w_next,
+    );
+
+    (a + pi) + b + c + d + e
 }
 
 fn compute_permutation_checks(
-    domain: &EvaluationDomain,
+    i: usize,
     prover_key: &ProverKey,
-    (a_w_eval_8n, b_w_eval_8n, c_w_eval_8n, d_w_eval_8n): (
-        &[BlsScalar],
-        &[BlsScalar],
-        &[BlsScalar],
-        &[BlsScalar],
-    ),
-    z_eval_8n: &[BlsScalar],
+    evals_8n: &Evals8n,
     (alpha, beta, gamma): (&BlsScalar, &BlsScalar, &BlsScalar),
-) -> Vec<BlsScalar> {
-    let domain_8n = EvaluationDomain::new(8 * domain.size()).unwrap();
-    let l1_poly_alpha =
-        compute_first_lagrange_poly_scaled(domain, alpha.square());
-    let l1_alpha_sq_evals = domain_8n.coset_fft(&l1_poly_alpha);
-
-    #[cfg(not(feature = "std"))]
-    let range = (0..domain_8n.size()).into_iter();
-
-    #[cfg(feature = "std")]
-    let range = (0..domain_8n.size()).into_par_iter();
-
-    let t: Vec<_> = range
-        .map(|i| {
-            prover_key.permutation.compute_quotient_i(
-                i,
-                &a_w_eval_8n[i],
-                &b_w_eval_8n[i],
-                &c_w_eval_8n[i],
-                &d_w_eval_8n[i],
-                &z_eval_8n[i],
-                &z_eval_8n[i + 8],
-                alpha,
-                &l1_alpha_sq_evals[i],
-                beta,
-                gamma,
-            )
-        })
-        .collect();
-    t
+) -> BlsScalar {
+    prover_key.permutation.compute_quotient_i(
+        i,
+        &evals_8n.a_w[i],
+        &evals_8n.b_w[i],
+        &evals_8n.c_w[i],
+        &evals_8n.d_w[i],
+        &evals_8n.z[i],
+        &evals_8n.z[i + 8],
+        alpha,
+        &evals_8n.l1_alpha_sq[i],
+        beta,
+        gamma,
+    )
 }
+
 fn compute_first_lagrange_poly_scaled(
     domain: &EvaluationDomain,
     scale: BlsScalar,
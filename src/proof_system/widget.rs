@@ -192,22 +192,63 @@ impl VerifierKey {
     }
 }
 
-#[cfg(feature = "alloc")]
-pub(crate) mod alloc {
+/// Terms of the linearization commitment, collected by the widgets without
+/// allocating: six for the arithmetic widget, one each for the range, logic,
+/// fixed and variable base widgets, and two for the permutation widget.
+#[cfg(feature = "heapless")]
+pub(crate) type LinearizationTerms<T> = crate::util::ArrayVec<T, 12>;
+
+#[cfg(feature = "heapless")]
+mod verifier {
     use super::*;
-    use crate::{
-        error::Error,
-        fft::{EvaluationDomain, Evaluations, Polynomial},
-        transcript::TranscriptProtocol,
-    };
-    #[rustfmt::skip]
-    use ::alloc::vec::Vec;
-    use dusk_bls12_381::BlsScalar;
-    use merlin::Transcript;
+    use crate::transcript::{TranscriptExt, TranscriptProtocol};
+    use sha2::{Digest, Sha256};
+
+    /// Domain separator of the circuit hash
+    const CIRCUIT_DOMAIN: &[u8] = b"dusk-plonk-circuit";
+
+    /// Domain separator and version of the fingerprint encoding
+    const FINGERPRINT_DOMAIN: &[u8] = b"dusk-plonk-fingerprint-v1";
+
+    /// Number of commitments of the verifier key
+    const COMMITMENTS: usize = 15;
 
     impl VerifierKey {
+        /// Hash of the preprocessed circuit, committing to its size, selectors
+        /// and permutation.
+        ///
+        /// The hash is embedded in the prover key of the same circuit, so a
+        /// prover key paired with the verifier key of another circuit is
+        /// rejected instead of producing invalid proofs. The verifier key is
+        /// hashed as is and doesn't embed it: a proof of another circuit is
+        /// rejected by the verification itself, since the transcript absorbs
+        /// the verifier key.
+        pub fn circuit_hash(&self) -> [u8; 32] {
+            let mut hasher = Sha256::new();
+            hasher.update(CIRCUIT_DOMAIN);
+            hasher.update(self.to_bytes());
+
+            hasher.finalize().into()
+        }
+
+        /// Canonical fingerprint of the key, whose encoding is documented on
+        /// [`Verifier::fingerprint`](crate::composer::Verifier::fingerprint)
+        pub fn fingerprint(&self) -> [u8; 32] {
+            let bytes = self.to_bytes();
+            let len = u64::SIZE + COMMITMENTS * Commitment::SIZE;
+
+            let mut hasher = Sha256::new();
+            hasher.update(FINGERPRINT_DOMAIN);
+            hasher.update(&bytes[..len]);
+
+            hasher.finalize().into()
+        }
+
         /// Adds the circuit description to the transcript
-        pub(crate) fn seed_transcript(&self, transcript: &mut Transcript) {
+        pub(crate) fn seed_transcript<T: TranscriptProtocol>(
+            &self,
+            transcript: &mut T,
+        ) {
             transcript.append_commitment(b"q_m", &self.arithmetic.q_m);
             transcript.append_commitment(b"q_l", &self.arithmetic.q_l);
             transcript.append_commitment(b"q_r", &self.arithmetic.q_r);
@@ -239,6 +280,19 @@ pub(crate) mod alloc {
             transcript.circuit_domain_sep(self.n as u64);
         }
     }
+}
+
+#[cfg(feature = "alloc")]
+pub(crate) mod alloc {
+    use super::*;
+    use crate::{
+        commitment_scheme::CommitKey,
+        error::Error,
+        fft::{EvaluationDomain, Evaluations, Polynomial},
+    };
+    #[rustfmt::skip]
+    use ::alloc::vec::Vec;
+    use dusk_bls12_381::BlsScalar;
 
     /// PLONK circuit Proving Key.
     ///
@@ -280,6 +334,10 @@ pub(crate) mod alloc {
         // polynomial without having to perform IFFT
         #[cfg_attr(feature = "rkyv-impl", omit_bounds)]
         pub(crate) v_h_coset_8n: Evaluations,
+        /// [Circuit hash](VerifierKey::circuit_hash) of the verifier key of
+        /// the circuit, missing from keys serialized without it
+        #[cfg_attr(feature = "rkyv-impl", omit_bounds)]
+        pub(crate) circuit_hash: Option<[u8; 32]>,
     }
 
     impl ProverKey {
@@ -308,8 +366,14 @@ pub(crate) mod alloc {
             //  poly_num + 1 (self.n) + 1 (eval_size)
             let i64_num = poly_num + 2;
 
+            // The circuit hash, appended if known
+            let hash_size = self.circuit_hash.map_or(0, |hash| hash.len());
+
             // Calculate the amount of bytes needed to serialize `ProverKey`
-            poly_size * poly_num + eval_size * eval_num + u64::SIZE * i64_num
+            poly_size * poly_num
+                + eval_size * eval_num
+                + u64::SIZE * i64_num
+                + hash_size
         }
 
         /// Serializes a [`ProverKey`] struct into a Vec of bytes.
@@ -410,6 +474,15 @@ pub(crate) mod alloc {
 
             writer.write(&self.v_h_coset_8n.to_var_bytes());
 
+            if let Some(circuit_hash) = &self.circuit_hash {
+                writer.write(circuit_hash);
+            }
+
+            // the zero polynomials are shorter than the size accounted for,
+            // so the key ends exactly after its circuit hash
+            let len = size - writer.len();
+            bytes.truncate(len);
+
             bytes
         }
 
@@ -514,6 +587,20 @@ pub(crate) mod alloc {
 
             let v_h_coset_8n = evals_from_reader(&mut buffer)?;
 
+            // the keys serialized without the circuit hash end here, or with
+            // the zero padding of the previous versions of the encoding
+            let circuit_hash = match buffer.len() {
+                32 => Some(buffer.try_into().expect("checked len")),
+                _ if buffer.iter().all(|b| *b == 0) => None,
+                found => {
+                    return Err(dusk_bytes::Error::BadLength {
+                        found,
+                        expected: 32,
+                    }
+                    .into())
+                }
+            };
+
             let arithmetic = arithmetic::ProverKey {
                 q_m,
                 q_l: q_l.clone(),
@@ -559,11 +646,58 @@ pub(crate) mod alloc {
                 variable_base,
                 permutation,
                 v_h_coset_8n,
+                circuit_hash,
             };
 
             Ok(prover_key)
         }
 
+        /// [Circuit hash](VerifierKey::circuit_hash) of the verifier key of
+        /// the circuit of the key, if known
+        pub fn circuit_hash(&self) -> Option<[u8; 32]> {
+            self.circuit_hash
+        }
+
+        /// Compute the [circuit hash](VerifierKey::circuit_hash) of the
+        /// circuit of `constraints` gates from the commitments to the
+        /// selectors and the permutation of the key, in the order of the
+        /// verifier key, so it can be checked against a verifier key without
+        /// trusting the hash embedded in the key
+        pub(crate) fn compute_circuit_hash(
+            &self,
+            commit_key: &CommitKey,
+            constraints: usize,
+        ) -> Result<[u8; 32], Error> {
+            // constant selectors are committed as the identity, as in the
+            // preprocessing
+            let selector = |(poly, _): &(Polynomial, Evaluations)| {
+                commit_key.commit(poly).unwrap_or_default()
+            };
+            let sigma =
+                |(poly, _): &(Polynomial, Evaluations)| commit_key.commit(poly);
+
+            let verifier_key = VerifierKey::from_polynomial_commitments(
+                constraints,
+                selector(&self.arithmetic.q_m),
+                selector(&self.arithmetic.q_l),
+                selector(&self.arithmetic.q_r),
+                selector(&self.arithmetic.q_o),
+                selector(&self.arithmetic.q_4),
+                selector(&self.arithmetic.q_c),
+                selector(&self.arithmetic.q_arith),
+                selector(&self.logic.q_logic),
+                selector(&self.range.q_range),
+                selector(&self.fixed_base.q_fixed_group_add),
+                selector(&self.variable_base.q_variable_group_add),
+                sigma(&self.permutation.s_sigma_1)?,
+                sigma(&self.permutation.s_sigma_2)?,
+                sigma(&self.permutation.s_sigma_3)?,
+                sigma(&self.permutation.s_sigma_4)?,
+            );
+
+            Ok(verifier_key.circuit_hash())
+        }
+
         pub(crate) fn v_h_coset_8n(&self) -> &Evaluations {
             &self.v_h_coset_8n
         }
@@ -668,6 +802,7 @@ mod test {
             variable_base,
             permutation,
             v_h_coset_8n,
+            circuit_hash: Some([0xc1; 32]),
         };
 
         let prover_key_bytes = prover_key.to_var_bytes();
@@ -675,6 +810,28 @@ mod test {
 
         assert_eq!(pk, prover_key);
         assert_eq!(pk.to_var_bytes(), prover_key.to_var_bytes());
+
+        // keys serialized without the circuit hash are still parsed
+        let bytes = &prover_key_bytes[..prover_key_bytes.len() - 32];
+        let pk = ProverKey::from_slice(bytes).unwrap();
+
+        assert_eq!(pk.circuit_hash(), None);
+        assert_eq!(pk.to_var_bytes(), bytes);
+
+        // as well as the keys with the zero padding of the previous encoding
+        let mut padded = bytes.to_vec();
+        padded.extend_from_slice(&[0; 64]);
+        let pk = ProverKey::from_slice(&padded).unwrap();
+
+        assert_eq!(pk.circuit_hash(), None);
+        assert_eq!(pk.to_var_bytes(), bytes);
+
+        // trailing bytes other than the circuit hash are rejected
+        let mut bytes = prover_key_bytes.clone();
+        bytes.push(0);
+        assert!(ProverKey::from_slice(&bytes).is_err());
+        let bytes = &prover_key_bytes[..prover_key_bytes.len() - 1];
+        assert!(ProverKey::from_slice(bytes).is_err());
     }
 
     #[test]
@@ -749,4 +906,68 @@ mod test {
 
         assert_eq!(got, verifier_key);
     }
+
+    #[test]
+    fn test_fingerprint_verifier_key() {
+        use crate::commitment_scheme::Commitment;
+        use dusk_bls12_381::{G1Affine, G1Projective};
+        use sha2::{Digest, Sha256};
+
+        let n = 2usize.pow(5);
+        let points: Vec<G1Affine> = (1..16)
+            .map(|i| {
+                (G1Projective::generator() * BlsScalar::from(i as u64)).into()
+            })
+            .collect();
+        let c = |i: usize| Commitment(points[i]);
+
+        let verifier_key = VerifierKey::from_polynomial_commitments(
+            n,
+            c(0),
+            c(1),
+            c(2),
+            c(3),
+            c(4),
+            c(5),
+            c(6),
+            c(7),
+            c(8),
+            c(9),
+            c(10),
+            c(11),
+            c(12),
+            c(13),
+            c(14),
+        );
+
+        // the documented encoding
+        let mut hasher = Sha256::new();
+        hasher.update(b"dusk-plonk-fingerprint-v1");
+        hasher.update((n as u64).to_le_bytes());
+        points.iter().for_each(|p| hasher.update(p.to_compressed()));
+        let fingerprint: [u8; 32] = hasher.finalize().into();
+
+        assert_eq!(verifier_key.fingerprint(), fingerprint);
+
+        let other = VerifierKey::from_polynomial_commitments(
+            n,
+            c(0),
+            c(1),
+            c(2),
+            c(3),
+            c(4),
+            c(5),
+            c(6),
+            c(7),
+            c(8),
+            c(9),
+            c(10),
+            c(11),
+            c(12),
+            c(14),
+            c(13),
+        );
+
+        assert_ne!(other.fingerprint(), fingerprint);
+    }
 }
@@ -80,19 +80,18 @@ impl Serializable<{ 7 * Commitment::SIZE }> for VerifierKey {
     }
 }
 
-#[cfg(feature = "alloc")]
-mod alloc {
+#[cfg(feature = "heapless")]
+mod verifier {
     use super::*;
     use crate::proof_system::linearization_poly::ProofEvaluations;
-    #[rustfmt::skip]
-    use ::alloc::vec::Vec;
+    use crate::proof_system::widget::LinearizationTerms;
     use dusk_bls12_381::{BlsScalar, G1Affine};
 
     impl VerifierKey {
         pub(crate) fn compute_linearization_commitment(
             &self,
-            scalars: &mut Vec<BlsScalar>,
-            points: &mut Vec<G1Affine>,
+            scalars: &mut LinearizationTerms<BlsScalar>,
+            points: &mut LinearizationTerms<G1Affine>,
             evaluations: &ProofEvaluations,
         ) {
             let q_arith_eval = evaluations.q_arith_eval;
@@ -26,12 +26,11 @@ pub(crate) struct VerifierKey {
     pub(crate) q_variable_group_add: Commitment,
 }
 
-#[cfg(feature = "alloc")]
-mod alloc {
+#[cfg(feature = "heapless")]
+mod verifier {
     use super::*;
     use crate::proof_system::linearization_poly::ProofEvaluations;
-    #[rustfmt::skip]
-    use ::alloc::vec::Vec;
+    use crate::proof_system::widget::LinearizationTerms;
     use dusk_bls12_381::{BlsScalar, G1Affine};
     use dusk_jubjub::EDWARDS_D;
 
@@ -39,8 +38,8 @@ mod alloc {
         pub(crate) fn compute_linearization_commitment(
             &self,
             curve_add_separation_challenge: &BlsScalar,
-            scalars: &mut Vec<BlsScalar>,
-            points: &mut Vec<G1Affine>,
+            scalars: &mut LinearizationTerms<BlsScalar>,
+            points: &mut LinearizationTerms<G1Affine>,
             evaluations: &ProofEvaluations,
         ) {
             let kappa = curve_add_separation_challenge.square();
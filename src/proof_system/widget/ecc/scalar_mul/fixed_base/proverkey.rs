@@ -8,6 +8,8 @@ use crate::fft::{Evaluations, Polynomial};
 use dusk_bls12_381::BlsScalar;
 use dusk_jubjub::EDWARDS_D;
 
+use super::{check_bit_consistency, extract_bit};
+
 #[cfg(feature = "rkyv-impl")]
 use bytecheck::CheckBytes;
 #[cfg(feature = "rkyv-impl")]
@@ -166,17 +168,3 @@ impl ProverKey {
         q_fixed_group_add_poly * &(a * ecc_separation_challenge)
     }
 }
-
-pub(crate) fn extract_bit(
-    curr_acc: &BlsScalar,
-    next_acc: &BlsScalar,
-) -> BlsScalar {
-    // Next - 2 * current
-    next_acc - curr_acc - curr_acc
-}
-
-// Ensures that the bit is either +1, -1 or 0
-pub(crate) fn check_bit_consistency(bit: BlsScalar) -> BlsScalar {
-    let one = BlsScalar::one();
-    bit * (bit - one) * (bit + one)
-}
@@ -30,15 +30,14 @@ pub(crate) struct VerifierKey {
     pub(crate) q_fixed_group_add: Commitment,
 }
 
-#[cfg(feature = "alloc")]
-mod alloc {
+#[cfg(feature = "heapless")]
+mod verifier {
     use super::*;
     use crate::proof_system::linearization_poly::ProofEvaluations;
-    use crate::proof_system::widget::ecc::scalar_mul::fixed_base::proverkey::{
+    use crate::proof_system::widget::ecc::scalar_mul::fixed_base::{
         check_bit_consistency, extract_bit,
     };
-    #[rustfmt::skip]
-    use ::alloc::vec::Vec;
+    use crate::proof_system::widget::LinearizationTerms;
     use dusk_bls12_381::{BlsScalar, G1Affine};
     use dusk_jubjub::EDWARDS_D;
 
@@ -46,8 +45,8 @@ mod alloc {
         pub(crate) fn compute_linearization_commitment(
             &self,
             ecc_separation_challenge: &BlsScalar,
-            scalars: &mut Vec<BlsScalar>,
-            points: &mut Vec<G1Affine>,
+            scalars: &mut LinearizationTerms<BlsScalar>,
+            points: &mut LinearizationTerms<G1Affine>,
             evaluations: &ProofEvaluations,
         ) {
             let kappa = ecc_separation_challenge.square();
@@ -129,6 +129,7 @@ impl ProverKey {
 
     pub(crate) fn compute_linearization(
         &self,
+        domain: &EvaluationDomain,
         z_challenge: &BlsScalar,
         (alpha, beta, gamma): (&BlsScalar, &BlsScalar, &BlsScalar),
         (a_eval, b_eval, c_eval, d_eval): (
@@ -161,10 +162,8 @@ impl ProverKey {
             &self.s_sigma_4.0,
         );
 
-        // the poly is increased by 2 after blinding it
-        let domain = EvaluationDomain::new(z_poly.degree() - 2).unwrap();
         let c = self.compute_linearizer_check_is_one(
-            &domain,
+            domain,
             z_challenge,
             &alpha.square(),
             z_poly,
@@ -32,20 +32,19 @@ pub(crate) struct VerifierKey {
     pub(crate) s_sigma_4: Commitment,
 }
 
-#[cfg(feature = "alloc")]
-mod alloc {
+#[cfg(feature = "heapless")]
+mod verifier {
     use super::*;
     use crate::permutation::constants::{K1, K2, K3};
     use crate::proof_system::linearization_poly::ProofEvaluations;
-    #[rustfmt::skip]
-    use ::alloc::vec::Vec;
+    use crate::proof_system::widget::LinearizationTerms;
     use dusk_bls12_381::{BlsScalar, G1Affine};
 
     impl VerifierKey {
         pub(crate) fn compute_linearization_commitment(
             &self,
-            scalars: &mut Vec<BlsScalar>,
-            points: &mut Vec<G1Affine>,
+            scalars: &mut LinearizationTerms<BlsScalar>,
+            points: &mut LinearizationTerms<G1Affine>,
             evaluations: &ProofEvaluations,
             z_challenge: &BlsScalar,
             (alpha, beta, gamma): (&BlsScalar, &BlsScalar, &BlsScalar),
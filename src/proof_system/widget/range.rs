@@ -4,6 +4,8 @@
 //
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
+use dusk_bls12_381::BlsScalar;
+
 #[cfg(feature = "alloc")]
 mod proverkey;
 
@@ -13,3 +15,11 @@ mod verifierkey;
 pub(crate) use proverkey::ProverKey;
 
 pub(crate) use verifierkey::VerifierKey;
+
+// Computes f(f-1)(f-2)(f-3)
+pub(crate) fn delta(f: BlsScalar) -> BlsScalar {
+    let f_1 = f - BlsScalar::one();
+    let f_2 = f - BlsScalar::from(2);
+    let f_3 = f - BlsScalar::from(3);
+    f * f_1 * f_2 * f_3
+}
@@ -7,6 +7,8 @@
 use crate::fft::{Evaluations, Polynomial};
 use dusk_bls12_381::BlsScalar;
 
+use super::delta;
+
 #[cfg(feature = "rkyv-impl")]
 use bytecheck::CheckBytes;
 #[cfg(feature = "rkyv-impl")]
@@ -84,11 +86,3 @@ impl ProverKey {
         q_range_poly * &t
     }
 }
-
-// Computes f(f-1)(f-2)(f-3)
-pub(crate) fn delta(f: BlsScalar) -> BlsScalar {
-    let f_1 = f - BlsScalar::one();
-    let f_2 = f - BlsScalar::from(2);
-    let f_3 = f - BlsScalar::from(3);
-    f * f_1 * f_2 * f_3
-}
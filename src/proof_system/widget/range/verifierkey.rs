@@ -26,21 +26,20 @@ pub(crate) struct VerifierKey {
     pub(crate) q_range: Commitment,
 }
 
-#[cfg(feature = "alloc")]
-mod alloc {
+#[cfg(feature = "heapless")]
+mod verifier {
     use super::*;
     use crate::proof_system::linearization_poly::ProofEvaluations;
-    use crate::proof_system::widget::range::proverkey::delta;
-    #[rustfmt::skip]
-    use ::alloc::vec::Vec;
+    use crate::proof_system::widget::range::delta;
+    use crate::proof_system::widget::LinearizationTerms;
     use dusk_bls12_381::{BlsScalar, G1Affine};
 
     impl VerifierKey {
         pub(crate) fn compute_linearization_commitment(
             &self,
             range_separation_challenge: &BlsScalar,
-            scalars: &mut Vec<BlsScalar>,
-            points: &mut Vec<G1Affine>,
+            scalars: &mut LinearizationTerms<BlsScalar>,
+            points: &mut LinearizationTerms<G1Affine>,
             evaluations: &ProofEvaluations,
         ) {
             let four = BlsScalar::from(4);
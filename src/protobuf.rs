@@ -0,0 +1,337 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Protobuf encoding of proofs, verifiers and public inputs.
+//!
+//! The messages are the ones of the [`SCHEMA`] of the `dusk.plonk.v1`
+//! package, shipped as `proto/dusk_plonk.proto`, so services in other
+//! languages can generate their types with `protoc` and exchange proofs over
+//! gRPC. Unknown fields are skipped when decoding, so the messages can gain
+//! fields without breaking older readers.
+//!
+//! ```
+//! use dusk_plonk::prelude::*;
+//! use dusk_plonk::protobuf;
+//! use rand_core::OsRng;
+//!
+//! #[derive(Default)]
+//! struct Square(BlsScalar);
+//!
+//! impl Circuit for Square {
+//!     fn circuit<C: Composer>(&self, composer: &mut C) -> Result<(), Error> {
+//!         let a = composer.append_witness(self.0);
+//!         let b = composer.gate_mul(Constraint::new().mult(1).a(a).b(a));
+//!         composer.assert_equal_constant(b, 0, Some(self.0.square()));
+//!
+//!         Ok(())
+//!     }
+//! }
+//!
+//! # fn main() -> Result<(), Error> {
+//! let pp = PublicParameters::setup(1 << 4, &mut OsRng)?;
+//! let (prover, verifier) = Compiler::compile::<Square>(&pp, b"square")?;
+//! let (proof, public_inputs) =
+//!     prover.prove(&mut OsRng, &Square(BlsScalar::from(3)))?;
+//!
+//! let proof = Proof::from_protobuf(&proof.to_protobuf())?;
+//! let public_inputs = protobuf::public_inputs_from_protobuf(
+//!     &protobuf::public_inputs_to_protobuf(&public_inputs),
+//! )?;
+//! let verifier = Verifier::from_protobuf(&verifier.to_protobuf())?;
+//!
+//! verifier.verify(&proof, &public_inputs)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use alloc::vec::Vec;
+
+use dusk_bls12_381::{BlsScalar, G1Affine};
+use dusk_bytes::{DeserializableSlice, Serializable};
+
+use crate::composer::Verifier;
+use crate::error::Error;
+use crate::proof_system::Proof;
+
+/// Protobuf schema of the messages of this module
+pub const SCHEMA: &str = include_str!("../proto/dusk_plonk.proto");
+
+/// Number of commitments of a proof, numbered from 1 in the `Proof` message
+const COMMITMENTS: usize = 11;
+
+/// Number of evaluations of a proof, numbered from 1 in the
+/// `ProofEvaluations` message
+const EVALUATIONS: usize = 16;
+
+const _: () = assert!(
+    COMMITMENTS * G1Affine::SIZE + EVALUATIONS * BlsScalar::SIZE == Proof::SIZE
+);
+
+/// Wire type of varints
+const VARINT: u64 = 0;
+/// Wire type of 64-bit fixed fields
+const FIXED64: u64 = 1;
+/// Wire type of length-delimited fields
+const LEN: u64 = 2;
+/// Wire type of 32-bit fixed fields
+const FIXED32: u64 = 5;
+
+/// Largest field number of protobuf
+const MAX_FIELD: u64 = (1 << 29) - 1;
+
+fn write_varint(buf: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buf.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    buf.push(value as u8);
+}
+
+/// Write the length-delimited field `number`
+fn write_bytes(buf: &mut Vec<u8>, number: u64, bytes: &[u8]) {
+    write_varint(buf, number << 3 | LEN);
+    write_varint(buf, bytes.len() as u64);
+    buf.extend_from_slice(bytes);
+}
+
+/// Value of a field of a message
+enum Value<'a> {
+    /// Varint, which no message of the schema holds
+    Varint,
+    Bytes(&'a [u8]),
+}
+
+impl<'a> Value<'a> {
+    fn bytes(self) -> Result<&'a [u8], Error> {
+        match self {
+            Self::Bytes(bytes) => Ok(bytes),
+            Self::Varint => Err(Error::InvalidProtobuf),
+        }
+    }
+}
+
+/// Reader of the fields of a message
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        if len > self.bytes.len() {
+            return Err(Error::InvalidProtobuf);
+        }
+
+        let (bytes, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+
+        Ok(bytes)
+    }
+
+    fn varint(&mut self) -> Result<u64, Error> {
+        let mut value = 0;
+
+        for i in 0..10 {
+            let byte = self.take(1)?[0];
+
+            // the tenth byte holds the last bit of the value
+            if i == 9 && byte > 1 {
+                break;
+            }
+
+            value |= u64::from(byte & 0x7f) << (7 * i);
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+
+        Err(Error::InvalidProtobuf)
+    }
+
+    /// Number and value of the next field, skipping the fixed-size fields
+    /// which no message of the schema holds
+    fn field(&mut self) -> Result<Option<(u64, Value<'a>)>, Error> {
+        while !self.bytes.is_empty() {
+            let key = self.varint()?;
+            let number = key >> 3;
+
+            if number == 0 || number > MAX_FIELD {
+                return Err(Error::InvalidProtobuf);
+            }
+
+            match key & 7 {
+                VARINT => {
+                    self.varint()?;
+                    return Ok(Some((number, Value::Varint)));
+                }
+                LEN => {
+                    let len = usize::try_from(self.varint()?)
+                        .map_err(|_| Error::InvalidProtobuf)?;
+                    return Ok(Some((number, Value::Bytes(self.take(len)?))));
+                }
+                FIXED64 => {
+                    self.take(8)?;
+                }
+                FIXED32 => {
+                    self.take(4)?;
+                }
+                // groups are deprecated, and not skipped
+                _ => return Err(Error::InvalidProtobuf),
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+/// Copy the fields `1..` of a message, holding `size` bytes each, to the
+/// chunks of `buf`, and append the payloads of the field following them to
+/// `nested`, merging the occurrences of an embedded message
+fn read_chunks(
+    message: &[u8],
+    size: usize,
+    buf: &mut [u8],
+    mut nested: Option<&mut Vec<u8>>,
+) -> Result<(), Error> {
+    let chunks = buf.len() / size;
+    let mut read = 0u64;
+    let mut reader = Reader::new(message);
+
+    while let Some((number, value)) = reader.field()? {
+        let index = (number - 1) as usize;
+
+        if index < chunks {
+            let bytes = value.bytes()?;
+            if bytes.len() != size {
+                return Err(Error::InvalidProtobuf);
+            }
+
+            buf[index * size..(index + 1) * size].copy_from_slice(bytes);
+            read |= 1 << index;
+        } else if index == chunks {
+            if let Some(nested) = nested.as_mut() {
+                nested.extend_from_slice(value.bytes()?);
+            }
+        }
+    }
+
+    match read.count_ones() as usize == chunks {
+        true => Ok(()),
+        false => Err(Error::InvalidProtobuf),
+    }
+}
+
+impl Proof {
+    /// Encode the proof as a `Proof` message of the [`SCHEMA`]
+    pub fn to_protobuf(&self) -> Vec<u8> {
+        let bytes = self.to_bytes();
+        let (commitments, evaluations) =
+            bytes.split_at(COMMITMENTS * G1Affine::SIZE);
+
+        let mut nested =
+            Vec::with_capacity(EVALUATIONS * (BlsScalar::SIZE + 2));
+        evaluations
+            .chunks(BlsScalar::SIZE)
+            .zip(1..)
+            .for_each(|(eval, number)| write_bytes(&mut nested, number, eval));
+
+        let mut buf = Vec::with_capacity(Proof::SIZE + 64);
+        commitments
+            .chunks(G1Affine::SIZE)
+            .zip(1..)
+            .for_each(|(comm, number)| write_bytes(&mut buf, number, comm));
+        write_bytes(&mut buf, COMMITMENTS as u64 + 1, &nested);
+
+        buf
+    }
+
+    /// Decode a `Proof` message encoded with [`Self::to_protobuf`]
+    pub fn from_protobuf(message: &[u8]) -> Result<Self, Error> {
+        let mut bytes = [0u8; Proof::SIZE];
+        let (commitments, evaluations) =
+            bytes.split_at_mut(COMMITMENTS * G1Affine::SIZE);
+
+        let mut nested = Vec::new();
+        read_chunks(message, G1Affine::SIZE, commitments, Some(&mut nested))?;
+        read_chunks(&nested, BlsScalar::SIZE, evaluations, None)?;
+
+        Ok(Self::from_bytes(&bytes)?)
+    }
+}
+
+impl Verifier {
+    /// Encode the verifier as a `Verifier` message of the [`SCHEMA`], along
+    /// with its [circuit hash](Self::circuit_hash)
+    pub fn to_protobuf(&self) -> Vec<u8> {
+        let verifier = self.to_bytes();
+        let mut buf = Vec::with_capacity(verifier.len() + 64);
+
+        write_bytes(&mut buf, 1, &self.circuit_hash());
+        write_bytes(&mut buf, 2, &verifier);
+
+        buf
+    }
+
+    /// Decode a `Verifier` message encoded with [`Self::to_protobuf`]
+    ///
+    /// Fails with [`Error::CircuitHashMismatch`] if the circuit hash of the
+    /// message isn't the one of the verifier.
+    pub fn from_protobuf(message: &[u8]) -> Result<Self, Error> {
+        let mut circuit_hash = None;
+        let mut verifier = None;
+        let mut reader = Reader::new(message);
+
+        while let Some((number, value)) = reader.field()? {
+            match number {
+                1 => circuit_hash = Some(value.bytes()?),
+                2 => verifier = Some(value.bytes()?),
+                _ => (),
+            }
+        }
+
+        let circuit_hash = circuit_hash.ok_or(Error::InvalidProtobuf)?;
+        let verifier = verifier.ok_or(Error::InvalidProtobuf)?;
+        let verifier = Self::try_from_bytes(verifier)?;
+
+        match verifier.circuit_hash() == circuit_hash {
+            true => Ok(verifier),
+            false => Err(Error::CircuitHashMismatch),
+        }
+    }
+}
+
+/// Encode public inputs as a `PublicInputs` message of the [`SCHEMA`]
+pub fn public_inputs_to_protobuf(public_inputs: &[BlsScalar]) -> Vec<u8> {
+    let mut buf =
+        Vec::with_capacity(public_inputs.len() * (BlsScalar::SIZE + 2));
+
+    public_inputs
+        .iter()
+        .for_each(|pi| write_bytes(&mut buf, 1, &pi.to_bytes()));
+
+    buf
+}
+
+/// Decode a `PublicInputs` message encoded with
+/// [`public_inputs_to_protobuf`]
+pub fn public_inputs_from_protobuf(
+    message: &[u8],
+) -> Result<Vec<BlsScalar>, Error> {
+    let mut public_inputs = Vec::new();
+    let mut reader = Reader::new(message);
+
+    while let Some((number, value)) = reader.field()? {
+        if number == 1 {
+            let pi = BlsScalar::from_slice(value.bytes()?)?;
+            public_inputs.push(pi);
+        }
+    }
+
+    Ok(public_inputs)
+}
@@ -0,0 +1,369 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Python bindings of the compiler, prover and verifier.
+//!
+//! The bindings compile and prove circuits of the gadgets of this crate, whose
+//! inputs and public inputs are Python integers:
+//!
+//! | gadget             | private inputs | public inputs                     |
+//! |--------------------|----------------|-----------------------------------|
+//! | `"mul"`            | `a`, `b`       | `a · b`                           |
+//! | `"range"`          | `a`            | none, `a < 2^bits`                |
+//! | `"and"`            | `a`, `b`       | `a & b` over `bits`               |
+//! | `"xor"`            | `a`, `b`       | `a ^ b` over `bits`               |
+//! | `"mul_generator"`  | `a`            | coordinates `(u, v)` of `a · G`   |
+//!
+//! where `bits` is one of 8, 16, 32, 64 or 128, and `G` the generator of
+//! JubJub. `make python` builds the extension module, imported as
+//! `dusk_plonk`.
+//!
+//! ```python
+//! import dusk_plonk
+//!
+//! pp = dusk_plonk.PublicParameters.setup(1 << 10)
+//! prover, verifier = dusk_plonk.compile(pp, "range", bits=64)
+//!
+//! proof, public_inputs = prover.prove([0xcafe])
+//! assert verifier.verify(proof, public_inputs)
+//! ```
+
+use dusk_bls12_381::BlsScalar;
+use dusk_bytes::Serializable;
+use dusk_jubjub::{JubJubAffine, GENERATOR_EXTENDED};
+use pyo3::create_exception;
+use pyo3::exceptions::{PyException, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyLong};
+use rand_core::OsRng;
+
+use crate::commitment_scheme::PublicParameters;
+use crate::composer::{Circuit, Compiler, Composer, Prover, Verifier};
+use crate::constraint_system::{Constraint, Witness};
+use crate::error::Error;
+use crate::proof_system::Proof;
+
+create_exception!(
+    dusk_plonk,
+    PlonkError,
+    PyException,
+    "Error of the compilation, proof or verification of a circuit."
+);
+
+fn error(e: Error) -> PyErr {
+    PlonkError::new_err(e.to_string())
+}
+
+/// Read a Python integer as a scalar
+fn scalar(value: &PyAny) -> PyResult<BlsScalar> {
+    let bytes = value.call_method1("to_bytes", (BlsScalar::SIZE, "little"))?;
+    let bytes: [u8; BlsScalar::SIZE] = bytes
+        .downcast::<PyBytes>()?
+        .as_bytes()
+        .try_into()
+        .map_err(|_| PyValueError::new_err("invalid integer bytes"))?;
+
+    Option::from(BlsScalar::from_bytes(&bytes))
+        .ok_or_else(|| PyValueError::new_err("integer out of the scalar field"))
+}
+
+/// Write a scalar as a Python integer
+fn integer(py: Python, scalar: &BlsScalar) -> PyResult<PyObject> {
+    let bytes = PyBytes::new(py, &scalar.to_bytes());
+    let int = py
+        .get_type::<PyLong>()
+        .call_method1("from_bytes", (bytes, "little"))?;
+
+    Ok(int.into())
+}
+
+/// Evaluate `$body` with the constant `$pairs` of bit pairs of `$bits`
+macro_rules! with_bit_pairs {
+    ($bits:expr, |$pairs:ident| $body:expr) => {
+        match $bits {
+            8 => {
+                const $pairs: usize = 4;
+                $body
+            }
+            16 => {
+                const $pairs: usize = 8;
+                $body
+            }
+            32 => {
+                const $pairs: usize = 16;
+                $body
+            }
+            64 => {
+                const $pairs: usize = 32;
+                $body
+            }
+            128 => {
+                const $pairs: usize = 64;
+                $body
+            }
+            _ => unreachable!("the bits are checked by Gadget::new"),
+        }
+    };
+}
+
+/// Gadget proven by a circuit of the bindings
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Gadget {
+    #[default]
+    Mul,
+    Range(usize),
+    And(usize),
+    Xor(usize),
+    MulGenerator,
+}
+
+impl Gadget {
+    fn new(name: &str, bits: Option<usize>) -> PyResult<Self> {
+        let bits = || match bits {
+            Some(bits @ (8 | 16 | 32 | 64 | 128)) => Ok(bits),
+            _ => Err(PyValueError::new_err(
+                "bits must be one of 8, 16, 32, 64 or 128",
+            )),
+        };
+
+        match name {
+            "mul" => Ok(Self::Mul),
+            "range" => Ok(Self::Range(bits()?)),
+            "and" => Ok(Self::And(bits()?)),
+            "xor" => Ok(Self::Xor(bits()?)),
+            "mul_generator" => Ok(Self::MulGenerator),
+            _ => Err(PyValueError::new_err(format!("unknown gadget {name}"))),
+        }
+    }
+
+    /// Number of private inputs of the gadget
+    fn inputs(&self) -> usize {
+        match self {
+            Self::Mul | Self::And(_) | Self::Xor(_) => 2,
+            Self::Range(_) | Self::MulGenerator => 1,
+        }
+    }
+}
+
+/// Circuit of a gadget over its private inputs
+#[derive(Debug, Default, Clone)]
+struct GadgetCircuit {
+    gadget: Gadget,
+    inputs: Vec<BlsScalar>,
+}
+
+impl GadgetCircuit {
+    /// Append `witness` as a public input
+    fn public<C>(composer: &mut C, witness: Witness)
+    where
+        C: Composer,
+    {
+        let value = composer[witness];
+        composer.assert_equal_constant(witness, BlsScalar::zero(), Some(value));
+    }
+}
+
+impl Circuit for GadgetCircuit {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer,
+    {
+        if self.inputs.len() != self.gadget.inputs() {
+            return Err(Error::CircuitInputsNotFound {
+                expected: self.gadget.inputs(),
+                provided: self.inputs.len(),
+            });
+        }
+
+        let w: Vec<Witness> = self
+            .inputs
+            .iter()
+            .map(|input| composer.append_witness(*input))
+            .collect();
+
+        match self.gadget {
+            Gadget::Mul => {
+                let constraint = Constraint::new().mult(1).a(w[0]).b(w[1]);
+                let c = composer.gate_mul(constraint);
+                Self::public(composer, c);
+            }
+            Gadget::Range(bits) => with_bit_pairs!(bits, |PAIRS| {
+                composer.component_range::<PAIRS>(w[0])
+            }),
+            Gadget::And(bits) => with_bit_pairs!(bits, |PAIRS| {
+                let c = composer.append_logic_and::<PAIRS>(w[0], w[1]);
+                Self::public(composer, c);
+            }),
+            Gadget::Xor(bits) => with_bit_pairs!(bits, |PAIRS| {
+                let c = composer.append_logic_xor::<PAIRS>(w[0], w[1]);
+                Self::public(composer, c);
+            }),
+            Gadget::MulGenerator => {
+                let point = composer
+                    .component_mul_generator(w[0], GENERATOR_EXTENDED)?;
+                let public = JubJubAffine::from_raw_unchecked(
+                    composer[*point.x()],
+                    composer[*point.y()],
+                );
+                composer.assert_equal_public_point(point, public);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Public parameters of the KZG10 commitment scheme.
+#[pyclass(name = "PublicParameters", module = "dusk_plonk")]
+struct PyPublicParameters(PublicParameters);
+
+#[pymethods]
+impl PyPublicParameters {
+    /// Generate public parameters of `max_degree` with randomness of the
+    /// operating system. For tests and prototypes only, as the trapdoor is
+    /// known to this process.
+    #[staticmethod]
+    fn setup(max_degree: usize) -> PyResult<Self> {
+        PublicParameters::setup(max_degree, &mut OsRng)
+            .map(Self)
+            .map_err(error)
+    }
+
+    /// Read public parameters written by `to_bytes`.
+    #[staticmethod]
+    fn from_bytes(bytes: &[u8]) -> PyResult<Self> {
+        PublicParameters::from_slice(bytes).map(Self).map_err(error)
+    }
+
+    /// Bytes of the public parameters.
+    fn to_bytes<'py>(&self, py: Python<'py>) -> &'py PyBytes {
+        PyBytes::new(py, &self.0.to_var_bytes())
+    }
+}
+
+/// Prover of a gadget circuit.
+#[pyclass(name = "Prover", module = "dusk_plonk")]
+struct PyProver {
+    prover: Prover,
+    gadget: Gadget,
+}
+
+#[pymethods]
+impl PyProver {
+    /// Prove the circuit over the integers of its private `inputs`, and
+    /// return the bytes of the proof with the integers of its public inputs.
+    fn prove(
+        &self,
+        py: Python,
+        inputs: Vec<&PyAny>,
+    ) -> PyResult<(PyObject, Vec<PyObject>)> {
+        let inputs = inputs
+            .into_iter()
+            .map(scalar)
+            .collect::<PyResult<Vec<_>>>()?;
+        let circuit = GadgetCircuit {
+            gadget: self.gadget,
+            inputs,
+        };
+
+        let (proof, public_inputs) = py
+            .allow_threads(|| self.prover.prove(&mut OsRng, &circuit))
+            .map_err(error)?;
+
+        let public_inputs = public_inputs
+            .iter()
+            .map(|pi| integer(py, pi))
+            .collect::<PyResult<_>>()?;
+
+        Ok((PyBytes::new(py, &proof.to_bytes()).into(), public_inputs))
+    }
+
+    /// Number of gates of the circuit.
+    #[getter]
+    fn constraints(&self) -> usize {
+        self.prover.constraints()
+    }
+}
+
+/// Verifier of a gadget circuit.
+#[pyclass(name = "Verifier", module = "dusk_plonk")]
+struct PyVerifier(Verifier);
+
+#[pymethods]
+impl PyVerifier {
+    /// Read a verifier written by `to_bytes`.
+    #[staticmethod]
+    fn from_bytes(bytes: &[u8]) -> PyResult<Self> {
+        Verifier::try_from_bytes(bytes).map(Self).map_err(error)
+    }
+
+    /// Bytes of the verifier.
+    fn to_bytes<'py>(&self, py: Python<'py>) -> &'py PyBytes {
+        PyBytes::new(py, &self.0.to_bytes())
+    }
+
+    /// Check the bytes of a proof against the integers of its public inputs.
+    fn verify(
+        &self,
+        proof: &[u8],
+        public_inputs: Vec<&PyAny>,
+    ) -> PyResult<bool> {
+        let public_inputs = public_inputs
+            .into_iter()
+            .map(scalar)
+            .collect::<PyResult<Vec<_>>>()?;
+
+        let proof = match Proof::from_bytes(
+            proof
+                .try_into()
+                .map_err(|_| PyValueError::new_err("invalid proof size"))?,
+        ) {
+            Ok(proof) => proof,
+            Err(_) => return Ok(false),
+        };
+
+        Ok(self.0.verify(&proof, &public_inputs).is_ok())
+    }
+}
+
+/// Compile the circuit of a `gadget` over `bits`, and return its prover and
+/// verifier. The `label` defaults to the name of the gadget.
+#[pyfunction]
+#[pyo3(signature = (pp, gadget, bits = None, label = None))]
+fn compile(
+    py: Python,
+    pp: &PyPublicParameters,
+    gadget: &str,
+    bits: Option<usize>,
+    label: Option<&[u8]>,
+) -> PyResult<(PyProver, PyVerifier)> {
+    let label = label.unwrap_or(gadget.as_bytes());
+    let gadget = Gadget::new(gadget, bits)?;
+    let circuit = GadgetCircuit {
+        gadget,
+        inputs: vec![BlsScalar::zero(); gadget.inputs()],
+    };
+
+    let (prover, verifier) = py
+        .allow_threads(|| {
+            Compiler::compile_with_circuit(&pp.0, label, &circuit)
+        })
+        .map_err(error)?;
+
+    Ok((PyProver { prover, gadget }, PyVerifier(verifier)))
+}
+
+/// Python module `dusk_plonk`
+#[pymodule]
+pub fn dusk_plonk(py: Python, module: &PyModule) -> PyResult<()> {
+    module.add_class::<PyPublicParameters>()?;
+    module.add_class::<PyProver>()?;
+    module.add_class::<PyVerifier>()?;
+    module.add_function(wrap_pyfunction!(compile, module)?)?;
+    module.add("PlonkError", py.get_type::<PlonkError>())?;
+
+    Ok(())
+}
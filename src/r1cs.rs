@@ -0,0 +1,147 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Compilation of rank-1 constraints `A · B = C` into width-4 gates, for the
+//! importers of circuits of other front-ends.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use dusk_bls12_381::BlsScalar;
+
+use crate::composer::Composer;
+use crate::constraint_system::{Constraint, Witness};
+
+/// Linear combination `Σ coeff · wire` of the wires of a circuit, the wire
+/// `0` being the constant `1`
+pub(crate) type LinearCombination = Vec<(usize, BlsScalar)>;
+
+/// Split a linear combination into its constant and its terms over the
+/// witnesses of the `wires`
+fn terms(
+    combination: &LinearCombination,
+    wires: &[Witness],
+) -> (BlsScalar, Vec<(BlsScalar, Witness)>) {
+    combination.iter().fold(
+        (BlsScalar::zero(), Vec::new()),
+        |(constant, mut terms), (wire, coeff)| match wire {
+            0 => (constant + coeff, terms),
+            _ => {
+                terms.push((*coeff, wires[*wire]));
+                (constant, terms)
+            }
+        },
+    )
+}
+
+/// Evaluate a linear combination into a single witness
+fn reduce<C>(
+    composer: &mut C,
+    constant: BlsScalar,
+    terms: &[(BlsScalar, Witness)],
+) -> Witness
+where
+    C: Composer,
+{
+    match terms {
+        [(coeff, witness)]
+            if *coeff == BlsScalar::one() && constant == BlsScalar::zero() =>
+        {
+            *witness
+        }
+        _ => composer.component_linear_combination(terms, constant),
+    }
+}
+
+/// Constrain `constant + Σ coeff · witness = 0`
+fn assert_zero<C>(
+    composer: &mut C,
+    constant: BlsScalar,
+    terms: &[(BlsScalar, Witness)],
+) where
+    C: Composer,
+{
+    // the last three terms fit with the accumulated head in a single gate
+    let (head, tail) = terms.split_at(terms.len().saturating_sub(3));
+
+    let mut constraint = match head {
+        [] => Constraint::new().constant(constant),
+        _ => {
+            let acc = composer.component_linear_combination(head, constant);
+            Constraint::new().fourth(1).d(acc)
+        }
+    };
+    for (i, (coeff, witness)) in tail.iter().enumerate() {
+        constraint = match i {
+            0 => constraint.left(*coeff).a(*witness),
+            1 => constraint.right(*coeff).b(*witness),
+            _ => constraint.output(*coeff).o(*witness),
+        };
+    }
+
+    composer.append_gate(constraint);
+}
+
+/// Append the gates of the constraint `A · B = C` over the witnesses of the
+/// `wires`
+pub(crate) fn append_constraint<C>(
+    composer: &mut C,
+    [a, b, c]: &[LinearCombination; 3],
+    wires: &[Witness],
+) where
+    C: Composer,
+{
+    let (a_constant, a) = terms(a, wires);
+    let (b_constant, b) = terms(b, wires);
+    let (c_constant, mut c) = terms(c, wires);
+
+    // A · B - C = 0
+    let (a_constant, a, b_constant, b) = match a.is_empty() {
+        true => (b_constant, b, a_constant, a),
+        false => (a_constant, a, b_constant, b),
+    };
+    c.iter_mut().for_each(|(coeff, _)| *coeff = -*coeff);
+    let c_constant = -c_constant;
+
+    match b.is_empty() {
+        // the constraint is linear when either side is constant
+        true => {
+            let mut terms: Vec<_> = a
+                .iter()
+                .map(|(coeff, witness)| (coeff * b_constant, *witness))
+                .collect();
+            terms.append(&mut c);
+
+            assert_zero(composer, a_constant * b_constant + c_constant, &terms);
+        }
+        false => {
+            let a = reduce(composer, a_constant, &a);
+            let b = reduce(composer, b_constant, &b);
+
+            // the terms of C past the output and fourth wires are accumulated
+            // first
+            let (c_constant, c) = match c.len() {
+                0..=2 => (c_constant, c),
+                _ => {
+                    let acc =
+                        composer.component_linear_combination(&c, c_constant);
+                    (BlsScalar::zero(), vec![(BlsScalar::one(), acc)])
+                }
+            };
+
+            let mut constraint =
+                Constraint::new().mult(1).a(a).b(b).constant(c_constant);
+            for (i, (coeff, witness)) in c.iter().enumerate() {
+                constraint = match i {
+                    0 => constraint.output(*coeff).o(*witness),
+                    _ => constraint.fourth(*coeff).d(*witness),
+                };
+            }
+
+            composer.append_gate(constraint);
+        }
+    }
+}
@@ -0,0 +1,192 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! `serde` implementations for the public types, built on top of their byte
+//! representations.
+//!
+//! Human readable formats, such as JSON, get the hexadecimal representation
+//! of the bytes, while binary formats, such as CBOR or bincode, get the bytes.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use dusk_bytes::{DeserializableSlice, Serializable};
+use serde::de::{self, Deserializer, SeqAccess, Visitor};
+use serde::ser::Serializer;
+use serde::{Deserialize, Serialize};
+
+use crate::commitment_scheme::{
+    AggregateProof, CommitKey, Commitment, Contribution, LagrangeKey,
+    OpeningKey, OpeningProof,
+};
+#[cfg(feature = "ipa")]
+use crate::commitment_scheme::{IpaParameters, IpaProof};
+use crate::composer::{Accumulator, ProofBundle, Prover, Verifier};
+use crate::encoding;
+use crate::error::Error;
+use crate::fft::{EvaluationDomain, Evaluations, Polynomial};
+use crate::prelude::PublicParameters;
+use crate::proof_system::Proof;
+
+fn serialize_bytes<S: Serializer>(
+    bytes: &[u8],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        serializer.serialize_str(&encoding::to_hex(bytes))
+    } else {
+        serializer.serialize_bytes(bytes)
+    }
+}
+
+fn deserialize_bytes<'de, D, T, F>(
+    deserializer: D,
+    from_bytes: F,
+) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    F: Fn(&[u8]) -> Result<T, Error>,
+{
+    let bytes = if deserializer.is_human_readable() {
+        let hex = String::deserialize(deserializer)?;
+        encoding::from_hex(&hex).map_err(de::Error::custom)?
+    } else {
+        deserializer.deserialize_byte_buf(BytesVisitor)?
+    };
+
+    from_bytes(&bytes).map_err(de::Error::custom)
+}
+
+struct BytesVisitor;
+
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a byte array")
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        Ok(v.to_vec())
+    }
+
+    fn visit_byte_buf<E: de::Error>(
+        self,
+        v: Vec<u8>,
+    ) -> Result<Self::Value, E> {
+        Ok(v)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+
+        while let Some(b) = seq.next_element()? {
+            bytes.push(b);
+        }
+
+        Ok(bytes)
+    }
+}
+
+macro_rules! impl_serde {
+    ($ty:ty, $to_bytes:expr, $from_bytes:expr) => {
+        impl Serialize for $ty {
+            fn serialize<S: Serializer>(
+                &self,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                serialize_bytes(&$to_bytes(self), serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $ty {
+            fn deserialize<D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Self, D::Error> {
+                deserialize_bytes(deserializer, $from_bytes)
+            }
+        }
+    };
+}
+
+impl_serde!(
+    Proof,
+    |p: &Proof| p.to_versioned_bytes(),
+    Proof::from_versioned_bytes
+);
+impl_serde!(ProofBundle, ProofBundle::to_bytes, ProofBundle::from_slice);
+impl_serde!(
+    Accumulator,
+    |acc: &Accumulator| acc.to_bytes(),
+    |bytes: &[u8]| Ok(Accumulator::from_slice(bytes)?)
+);
+impl_serde!(
+    Contribution,
+    |contribution: &Contribution| contribution.to_bytes(),
+    |bytes: &[u8]| Ok(Contribution::from_slice(bytes)?)
+);
+impl_serde!(
+    Commitment,
+    |commitment: &Commitment| commitment.to_bytes(),
+    |bytes: &[u8]| Ok(Commitment::from_slice(bytes)?)
+);
+impl_serde!(
+    OpeningProof,
+    |proof: &OpeningProof| proof.to_bytes(),
+    |bytes: &[u8]| Ok(OpeningProof::from_slice(bytes)?)
+);
+impl_serde!(
+    AggregateProof,
+    AggregateProof::to_var_bytes,
+    |bytes: &[u8]| { Ok(AggregateProof::from_slice(bytes)?) }
+);
+impl_serde!(CommitKey, CommitKey::to_var_bytes, CommitKey::from_slice);
+impl_serde!(
+    OpeningKey,
+    |key: &OpeningKey| key.to_bytes(),
+    |bytes: &[u8]| Ok(OpeningKey::from_slice(bytes)?)
+);
+impl_serde!(
+    LagrangeKey,
+    LagrangeKey::to_var_bytes,
+    LagrangeKey::from_slice
+);
+#[cfg(feature = "ipa")]
+impl_serde!(
+    IpaParameters,
+    IpaParameters::to_var_bytes,
+    IpaParameters::from_slice
+);
+#[cfg(feature = "ipa")]
+impl_serde!(IpaProof, IpaProof::to_var_bytes, |bytes: &[u8]| {
+    Ok(IpaProof::from_slice(bytes)?)
+});
+impl_serde!(
+    EvaluationDomain,
+    |domain: &EvaluationDomain| domain.to_bytes(),
+    |bytes: &[u8]| Ok(EvaluationDomain::from_slice(bytes)?)
+);
+impl_serde!(
+    Evaluations,
+    Evaluations::to_var_bytes,
+    Evaluations::from_slice
+);
+impl_serde!(Polynomial, Polynomial::to_var_bytes, Polynomial::from_slice);
+impl_serde!(
+    PublicParameters,
+    PublicParameters::to_var_bytes,
+    PublicParameters::from_slice
+);
+impl_serde!(Prover, Prover::to_bytes, |bytes: &[u8]| {
+    Prover::try_from_bytes(bytes)
+});
+impl_serde!(Verifier, Verifier::to_bytes, |bytes: &[u8]| {
+    Verifier::try_from_bytes(bytes)
+});
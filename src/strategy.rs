@@ -0,0 +1,126 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! `proptest` strategies and `arbitrary` implementations, to property-test
+//! gadgets against this crate.
+//!
+//! ```
+//! use dusk_plonk::prelude::*;
+//! use dusk_plonk::strategy;
+//! use proptest::prelude::*;
+//!
+//! proptest!(|(a in strategy::scalar(), b in strategy::scalar())| {
+//!     let mut builder = Builder::initialized();
+//!     let wa = builder.append_witness(a);
+//!     let wb = builder.append_witness(b);
+//!
+//!     let sum = builder.gate_add(Constraint::new().left(1).right(1).a(wa).b(wb));
+//!     prop_assert_eq!(builder[sum], a + b);
+//! });
+//! ```
+
+use core::ops::{Range, RangeInclusive};
+
+use dusk_bls12_381::BlsScalar;
+use proptest::collection;
+use proptest::prelude::*;
+
+use crate::fuzzing::FuzzCircuit;
+
+/// Length of the inputs of [`FuzzCircuit::from_fuzz_input`] generated by
+/// [`circuit`], from enough for 4 inputs to enough for 32 operations
+const CIRCUIT_INPUT_LEN: RangeInclusive<usize> = 1 + 4 * 8..=1 + 4 * 8 + 32 * 3;
+
+/// Strategy of uniformly random scalars, with the edge cases `0`, `1` and
+/// `-1` more likely
+pub fn scalar() -> impl Strategy<Value = BlsScalar> {
+    prop_oneof![
+        1 => Just(BlsScalar::zero()),
+        1 => Just(BlsScalar::one()),
+        1 => Just(-BlsScalar::one()),
+        7 => any::<[u64; 4]>().prop_map(BlsScalar::from_raw),
+    ]
+}
+
+/// Strategy of scalars lower than `2^bits`, with `bits <= 64`
+pub fn small_scalar(bits: u32) -> impl Strategy<Value = BlsScalar> {
+    let max = match bits {
+        64 => u64::MAX,
+        _ => (1 << bits) - 1,
+    };
+
+    (0..=max).prop_map(BlsScalar::from)
+}
+
+/// Strategy of vectors of public inputs, with a length in `len`
+pub fn public_inputs(
+    len: Range<usize>,
+) -> impl Strategy<Value = Vec<BlsScalar>> {
+    collection::vec(scalar(), len)
+}
+
+/// Strategy of small random circuits of arithmetic gates, with their
+/// witnesses, as decoded by [`FuzzCircuit::from_fuzz_input`]
+pub fn circuit() -> impl Strategy<Value = FuzzCircuit> {
+    collection::vec(any::<u8>(), CIRCUIT_INPUT_LEN).prop_map(|bytes| {
+        FuzzCircuit::from_fuzz_input(&bytes).expect("the bytes hold the inputs")
+    })
+}
+
+/// Scalar implementing [`proptest::arbitrary::Arbitrary`] with [`scalar`], and
+/// [`arbitrary::Arbitrary`] from 64 bytes reduced modulo the field order
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Scalar(pub BlsScalar);
+
+impl From<Scalar> for BlsScalar {
+    fn from(scalar: Scalar) -> Self {
+        scalar.0
+    }
+}
+
+impl Arbitrary for Scalar {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        scalar().prop_map(Scalar).boxed()
+    }
+}
+
+impl<'a> arbitrary::Arbitrary<'a> for Scalar {
+    fn arbitrary(
+        u: &mut arbitrary::Unstructured<'a>,
+    ) -> arbitrary::Result<Self> {
+        let mut bytes = [0u8; 64];
+        bytes.copy_from_slice(u.bytes(64)?);
+
+        Ok(Scalar(BlsScalar::from_bytes_wide(&bytes)))
+    }
+
+    fn size_hint(_: usize) -> (usize, Option<usize>) {
+        (64, Some(64))
+    }
+}
+
+impl Arbitrary for FuzzCircuit {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        circuit().boxed()
+    }
+}
+
+impl<'a> arbitrary::Arbitrary<'a> for FuzzCircuit {
+    fn arbitrary(
+        u: &mut arbitrary::Unstructured<'a>,
+    ) -> arbitrary::Result<Self> {
+        let len = u.int_in_range(CIRCUIT_INPUT_LEN)?;
+        let bytes = u.bytes(len)?;
+        FuzzCircuit::from_fuzz_input(bytes)
+            .ok_or(arbitrary::Error::NotEnoughData)
+    }
+}
@@ -0,0 +1,34 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Instrumentation with [`tracing`](https://docs.rs/tracing) spans.
+//!
+//! Functions are instrumented with
+//! `#[cfg_attr(feature = "tracing", tracing::instrument(...))]`, while the
+//! [`span!`] macro covers the steps of a longer function, such as the rounds
+//! of the prover. Without the `tracing` feature both are compiled out.
+
+/// Entered span, exited when dropped or when [`Span::exit`] is called
+pub(crate) struct Span {
+    #[cfg(feature = "tracing")]
+    pub(crate) _entered: tracing::span::EnteredSpan,
+}
+
+impl Span {
+    /// Exit the span
+    pub(crate) fn exit(self) {}
+}
+
+/// Enter a new `debug` level span, taking the same arguments as
+/// `tracing::debug_span!`
+macro_rules! span {
+    ($($arg:tt)+) => {
+        $crate::trace::Span {
+            #[cfg(feature = "tracing")]
+            _entered: tracing::debug_span!($($arg)+).entered(),
+        }
+    };
+}
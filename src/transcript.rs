@@ -4,67 +4,331 @@
 //
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
-//! This is an extension over the [Merlin Transcript](Transcript)
-//! which adds a few extra functionalities.
+//! Fiat-Shamir transcripts of the protocol.
+//!
+//! The transcript is pluggable: [`Prover`](crate::composer::Prover) and
+//! [`Verifier`](crate::composer::Verifier) are generic over any
+//! [`TranscriptProtocol`], and default to the [Merlin Transcript](Transcript).
+//! [`PoseidonTranscript`] is an algebraic alternative that is cheap to
+//! recompute within a circuit, and [`AuditTranscript`] records the operations
+//! of another transcript to diagnose proofs that don't verify.
 
+#[cfg(feature = "verifier")]
+use alloc::vec::Vec;
 use core::mem;
 
-use dusk_bls12_381::BlsScalar;
+use dusk_bls12_381::{BlsScalar, G1Affine};
 use dusk_bytes::Serializable;
 use merlin::Transcript;
+use sha2::{Digest, Sha512};
 
 use crate::commitment_scheme::Commitment;
+use crate::error::Error;
 use crate::proof_system::VerifierKey;
 
-/// Transcript adds an abstraction over the Merlin transcript
-/// For convenience
-pub(crate) trait TranscriptProtocol {
-    /// Append a `commitment` with the given `label`.
-    fn append_commitment(&mut self, label: &'static [u8], comm: &Commitment);
+#[cfg(feature = "verifier")]
+mod audit;
+#[cfg(feature = "verifier")]
+mod poseidon;
+
+#[cfg(feature = "verifier")]
+pub use audit::{AuditTranscript, TranscriptEvent};
+#[cfg(feature = "verifier")]
+pub use poseidon::PoseidonTranscript;
+
+/// Fiat-Shamir transcript used by the prover and the verifier to derive the
+/// challenges of the protocol.
+///
+/// The prover and the verifier of a circuit must use the same implementation.
+pub trait TranscriptProtocol: Clone {
+    /// Create a new transcript with the given `label`.
+    fn init(label: &[u8]) -> Self;
+
+    /// Append a `point` with the given `label`.
+    fn append_point(&mut self, label: &'static [u8], point: &G1Affine);
 
     /// Append a `BlsScalar` with the given `label`.
     fn append_scalar(&mut self, label: &'static [u8], s: &BlsScalar);
 
+    /// Append arbitrary `bytes` with the given `label`.
+    fn append_bytes(&mut self, label: &'static [u8], bytes: &[u8]);
+
     /// Compute a `label`ed challenge variable.
     fn challenge_scalar(&mut self, label: &'static [u8]) -> BlsScalar;
 
     /// Append domain separator for the circuit size.
     fn circuit_domain_sep(&mut self, n: u64);
-
-    /// Create a new instance of the base transcript of the protocol
-    fn base(
-        label: &[u8],
-        verifier_key: &VerifierKey,
-        constraints: usize,
-    ) -> Self;
 }
 
-impl TranscriptProtocol for Transcript {
+/// Extension of the [`TranscriptProtocol`] over the types of the crate
+pub(crate) trait TranscriptExt: TranscriptProtocol {
+    /// Append a `commitment` with the given `label`.
     fn append_commitment(&mut self, label: &'static [u8], comm: &Commitment) {
-        self.append_message(label, &comm.0.to_bytes());
+        self.append_point(label, &comm.0);
     }
+}
 
-    fn append_scalar(&mut self, label: &'static [u8], s: &BlsScalar) {
-        self.append_message(label, &s.to_bytes())
+impl<T: TranscriptProtocol> TranscriptExt for T {}
+
+/// Application label and version of a [`Domain`], borrowed from it or from its
+/// bytes
+pub(crate) type DomainParts<'a> = (&'a [u8], u32);
+
+/// Application domain absorbed by the transcripts of a circuit when they are
+/// initialized.
+///
+/// Proofs created under a domain only verify under the same domain, so proofs
+/// of different applications, or of different versions of a circuit, can't be
+/// replayed across contexts.
+#[cfg(feature = "verifier")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Domain {
+    application: Vec<u8>,
+    version: u32,
+}
+
+#[cfg(feature = "verifier")]
+impl Domain {
+    /// Create a new domain for the given application and protocol version
+    pub fn new(application: &[u8], version: u32) -> Self {
+        Self {
+            application: application.to_vec(),
+            version,
+        }
     }
 
-    fn challenge_scalar(&mut self, label: &'static [u8]) -> BlsScalar {
-        let mut buf = [0u8; 64];
-        self.challenge_bytes(label, &mut buf);
+    /// Label of the application
+    pub fn application(&self) -> &[u8] {
+        &self.application
+    }
 
-        BlsScalar::from_bytes_wide(&buf)
+    /// Version of the protocol
+    pub const fn version(&self) -> u32 {
+        self.version
     }
 
-    fn circuit_domain_sep(&mut self, n: u64) {
-        self.append_message(b"dom-sep", b"circuit_size");
-        self.append_u64(b"n", n);
+    /// Serialize the domain into bytes
+    pub(crate) fn to_var_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(12 + self.application.len());
+
+        bytes.extend((self.application.len() as u64).to_be_bytes());
+        bytes.extend(self.application.as_slice());
+        bytes.extend(self.version.to_be_bytes());
+
+        bytes
+    }
+
+    /// Application label and version of the domain, as absorbed by
+    /// [`base`]
+    pub(crate) fn as_parts(&self) -> DomainParts<'_> {
+        (&self.application, self.version)
+    }
+}
+
+/// Binding of the statement of a proof to the Fiat-Shamir transcript
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FiatShamir {
+    /// Absorb the values of the public inputs, as the previous versions of
+    /// the protocol
+    #[default]
+    Weak,
+    /// Additionally absorb, before any challenge is squeezed, a digest of the
+    /// verifier key and of the public inputs along with their positions in the
+    /// circuit
+    Strong,
+}
+
+/// Upstream version of `dusk-plonk` whose proofs are interchangeable with the
+/// proofs of this crate.
+///
+/// A prover or a verifier in compatibility mode uses the Merlin transcript
+/// with the labels of the upstream version, no application [`Domain`] and the
+/// [weak](FiatShamir::Weak) binding of the statement. The proofs and the
+/// serialized keys are then the ones of the upstream crate, as long as the
+/// circuit is compiled without [optimization](crate::composer::OptLevel).
+/// The serialized prover key additionally ends with the
+/// [circuit hash](crate::composer::Prover::circuit_hash), which the
+/// upstream crate ignores.
+///
+/// The provers serialized by the upstream crate, without the circuit hash,
+/// are read as well, so the preprocessing of a circuit can be migrated
+/// without compiling it again. Their prover key can be checked against their
+/// verifier key with
+/// [`Prover::verify_integrity`](crate::composer::Prover::verify_integrity).
+#[cfg(feature = "verifier")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Compat {
+    /// `dusk-plonk` 0.16
+    V0_16,
+}
+
+#[cfg(feature = "verifier")]
+impl Compat {
+    /// Latest upstream version supported
+    pub const LATEST: Self = Self::V0_16;
+
+    /// Semantic version of the upstream crate
+    pub const fn version(&self) -> &'static str {
+        match self {
+            Self::V0_16 => "0.16.0",
+        }
     }
 
-    fn base(
-        label: &[u8],
-        verifier_key: &VerifierKey,
-        constraints: usize,
-    ) -> Self {
+    /// Upstream version of the given `settings`, if they are compatible
+    pub(crate) fn of(settings: &Settings) -> Option<Self> {
+        (settings == &Settings::default()).then_some(Self::LATEST)
+    }
+}
+
+/// Flag of the serialized settings binding the statement strongly
+const SETTINGS_STRONG: u8 = 0b01;
+/// Flag of the serialized settings followed by an application domain
+const SETTINGS_DOMAIN: u8 = 0b10;
+
+/// Transcript settings of a prover or a verifier
+#[cfg(feature = "verifier")]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub(crate) struct Settings {
+    pub(crate) domain: Option<Domain>,
+    pub(crate) fiat_shamir: FiatShamir,
+}
+
+#[cfg(feature = "verifier")]
+impl Settings {
+    /// Serialize the settings into bytes, empty for the default settings
+    pub(crate) fn to_var_bytes(&self) -> Vec<u8> {
+        if self == &Self::default() {
+            return Vec::new();
+        }
+
+        let mut flags = 0;
+        if self.fiat_shamir == FiatShamir::Strong {
+            flags |= SETTINGS_STRONG;
+        }
+        if self.domain.is_some() {
+            flags |= SETTINGS_DOMAIN;
+        }
+
+        let mut bytes = vec![flags];
+        if let Some(domain) = &self.domain {
+            bytes.extend(domain.to_var_bytes());
+        }
+
+        bytes
+    }
+
+    /// Deserialize the settings from bytes generated via
+    /// [`Self::to_var_bytes`]
+    pub(crate) fn from_slice(bytes: &[u8]) -> Result<Self, Error> {
+        let (fiat_shamir, domain) = read_settings(bytes)?;
+        let domain = domain
+            .map(|(application, version)| Domain::new(application, version));
+
+        Ok(Self {
+            domain,
+            fiat_shamir,
+        })
+    }
+}
+
+/// Read the binding of the statement and the application label and version
+/// of the domain of serialized settings, without allocating
+pub(crate) fn read_settings(
+    bytes: &[u8],
+) -> Result<(FiatShamir, Option<DomainParts<'_>>), Error> {
+    let (flags, bytes) = match bytes.split_first() {
+        Some((flags, bytes)) => (*flags, bytes),
+        None => return Ok((FiatShamir::default(), None)),
+    };
+
+    let fiat_shamir = match flags & SETTINGS_STRONG {
+        0 => FiatShamir::Weak,
+        _ => FiatShamir::Strong,
+    };
+
+    if flags & SETTINGS_DOMAIN == 0 {
+        return Ok((fiat_shamir, None));
+    }
+
+    if bytes.len() < 8 {
+        return Err(Error::NotEnoughBytes);
+    }
+
+    let len = <[u8; 8]>::try_from(&bytes[..8]).expect("checked len");
+    let len = u64::from_be_bytes(len) as usize;
+    let bytes = &bytes[8..];
+
+    if bytes.len().saturating_sub(4) < len {
+        return Err(Error::NotEnoughBytes);
+    }
+
+    let application = &bytes[..len];
+    let version =
+        <[u8; 4]>::try_from(&bytes[len..len + 4]).expect("checked len");
+    let version = u32::from_be_bytes(version);
+
+    Ok((fiat_shamir, Some((application, version))))
+}
+
+/// Append the statement of a proof to the transcript
+pub(crate) fn append_statement<T>(
+    transcript: &mut T,
+    fiat_shamir: FiatShamir,
+    verifier_key: &VerifierKey,
+    public_input_indexes: &[usize],
+    public_inputs: &[BlsScalar],
+) where
+    T: TranscriptProtocol,
+{
+    public_inputs
+        .iter()
+        .for_each(|pi| transcript.append_scalar(b"pi", pi));
+
+    if fiat_shamir == FiatShamir::Strong {
+        let mut hasher = Sha512::new();
+
+        hasher.update(verifier_key.to_bytes());
+        hasher.update((public_inputs.len() as u64).to_be_bytes());
+        public_input_indexes.iter().zip(public_inputs).for_each(
+            |(index, pi)| {
+                hasher.update((*index as u64).to_be_bytes());
+                hasher.update(pi.to_bytes());
+            },
+        );
+
+        transcript.append_bytes(b"statement", &hasher.finalize());
+    }
+}
+
+/// Create a new instance of the base transcript of the protocol
+///
+/// The `domain` is the application label and version of the [`Domain`] of the
+/// circuit, if any.
+pub(crate) fn base<T>(
+    label: &[u8],
+    domain: Option<DomainParts<'_>>,
+    verifier_key: &VerifierKey,
+    constraints: usize,
+) -> T
+where
+    T: TranscriptProtocol,
+{
+    let mut transcript = T::init(label);
+
+    if let Some((application, version)) = domain {
+        transcript.append_bytes(b"application", application);
+        transcript.append_bytes(b"version", &version.to_be_bytes());
+    }
+
+    transcript.circuit_domain_sep(constraints as u64);
+
+    verifier_key.seed_transcript(&mut transcript);
+
+    transcript
+}
+
+impl TranscriptProtocol for Transcript {
+    fn init(label: &[u8]) -> Self {
         // Transcript can't be serialized/deserialized. One alternative is to
         // fork merlin and implement these functionalities, so we can use custom
         // transcripts for provers and verifiers. However, we don't have a use
@@ -75,12 +339,30 @@ impl TranscriptProtocol for Transcript {
         // serialization and deserialization of transcripts
         let label = unsafe { mem::transmute(label) };
 
-        let mut transcript = Transcript::new(label);
+        Transcript::new(label)
+    }
 
-        transcript.circuit_domain_sep(constraints as u64);
+    fn append_point(&mut self, label: &'static [u8], point: &G1Affine) {
+        self.append_message(label, &point.to_bytes());
+    }
 
-        verifier_key.seed_transcript(&mut transcript);
+    fn append_scalar(&mut self, label: &'static [u8], s: &BlsScalar) {
+        self.append_message(label, &s.to_bytes())
+    }
 
-        transcript
+    fn append_bytes(&mut self, label: &'static [u8], bytes: &[u8]) {
+        self.append_message(label, bytes)
+    }
+
+    fn challenge_scalar(&mut self, label: &'static [u8]) -> BlsScalar {
+        let mut buf = [0u8; 64];
+        self.challenge_bytes(label, &mut buf);
+
+        BlsScalar::from_bytes_wide(&buf)
+    }
+
+    fn circuit_domain_sep(&mut self, n: u64) {
+        self.append_message(b"dom-sep", b"circuit_size");
+        self.append_u64(b"n", n);
     }
 }
@@ -0,0 +1,167 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Transcript recording the messages absorbed and the challenges squeezed.
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use dusk_bls12_381::{BlsScalar, G1Affine};
+use dusk_bytes::Serializable;
+use merlin::Transcript;
+
+use super::TranscriptProtocol;
+
+/// Operation performed on a transcript, recorded by [`AuditTranscript`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TranscriptEvent {
+    /// Initialization of the transcript with a label
+    Init(Vec<u8>),
+    /// Absorption of the bytes of a point, a scalar or a message
+    Absorb {
+        /// Label of the message
+        label: &'static [u8],
+        /// Bytes of the message, as encoded by `dusk-bytes` for points and
+        /// scalars
+        bytes: Vec<u8>,
+    },
+    /// Squeeze of a challenge
+    Squeeze {
+        /// Label of the challenge
+        label: &'static [u8],
+        /// Value of the challenge
+        challenge: BlsScalar,
+    },
+    /// Domain separation for the number of constraints of the circuit
+    CircuitSize(u64),
+}
+
+fn write_hex(f: &mut fmt::Formatter<'_>, bytes: &[u8]) -> fmt::Result {
+    bytes.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+}
+
+fn write_label(f: &mut fmt::Formatter<'_>, label: &[u8]) -> fmt::Result {
+    match core::str::from_utf8(label) {
+        Ok(label) => write!(f, "{label:?}"),
+        Err(_) => write_hex(f, label),
+    }
+}
+
+impl fmt::Display for TranscriptEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Init(label) => {
+                write!(f, "init ")?;
+                write_label(f, label)
+            }
+            Self::Absorb { label, bytes } => {
+                write!(f, "absorb ")?;
+                write_label(f, label)?;
+                write!(f, " 0x")?;
+                write_hex(f, bytes)
+            }
+            Self::Squeeze { label, challenge } => {
+                write!(f, "squeeze ")?;
+                write_label(f, label)?;
+                write!(f, " 0x")?;
+                write_hex(f, &challenge.to_bytes())
+            }
+            Self::CircuitSize(n) => write!(f, "circuit size {n}"),
+        }
+    }
+}
+
+/// Transcript recording every operation performed on an inner transcript
+/// `T`, to compare the transcripts of two implementations of the protocol.
+///
+/// The challenges are the ones of `T`, so the proofs of a
+/// [`Prover`](crate::composer::Prover) using the audit transcript are the
+/// ones of a prover using `T`. Clones of a transcript fork its log, so the
+/// transcripts returned by
+/// [`Prover::prove_with_transcript`](crate::composer::Prover::prove_with_transcript)
+/// and
+/// [`Verifier::verify_with_transcript`](crate::composer::Verifier::verify_with_transcript)
+/// hold the operations of their proof, from the initialization of the
+/// transcript of the circuit.
+#[derive(Debug, Clone)]
+pub struct AuditTranscript<T = Transcript> {
+    inner: T,
+    events: Vec<TranscriptEvent>,
+}
+
+impl<T> AuditTranscript<T> {
+    /// Operations performed on the transcript, in order
+    pub fn events(&self) -> &[TranscriptEvent] {
+        &self.events
+    }
+
+    /// Inner transcript
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Index of the first operation that differs between the two
+    /// transcripts, if any, e.g. the first message absorbed differently by
+    /// a prover and a verifier
+    ///
+    /// A log that is a prefix of the other diverges at the end of the
+    /// prefix.
+    pub fn divergence(&self, other: &Self) -> Option<usize> {
+        let len = self.events.len().min(other.events.len());
+
+        self.events
+            .iter()
+            .zip(other.events.iter())
+            .position(|(a, b)| a != b)
+            .or_else(|| {
+                (self.events.len() != other.events.len()).then_some(len)
+            })
+    }
+
+    fn absorb(&mut self, label: &'static [u8], bytes: &[u8]) {
+        self.events.push(TranscriptEvent::Absorb {
+            label,
+            bytes: bytes.to_vec(),
+        });
+    }
+}
+
+impl<T: TranscriptProtocol> TranscriptProtocol for AuditTranscript<T> {
+    fn init(label: &[u8]) -> Self {
+        Self {
+            inner: T::init(label),
+            events: vec![TranscriptEvent::Init(label.to_vec())],
+        }
+    }
+
+    fn append_point(&mut self, label: &'static [u8], point: &G1Affine) {
+        self.absorb(label, &point.to_bytes());
+        self.inner.append_point(label, point);
+    }
+
+    fn append_scalar(&mut self, label: &'static [u8], s: &BlsScalar) {
+        self.absorb(label, &s.to_bytes());
+        self.inner.append_scalar(label, s);
+    }
+
+    fn append_bytes(&mut self, label: &'static [u8], bytes: &[u8]) {
+        self.absorb(label, bytes);
+        self.inner.append_bytes(label, bytes);
+    }
+
+    fn challenge_scalar(&mut self, label: &'static [u8]) -> BlsScalar {
+        let challenge = self.inner.challenge_scalar(label);
+        self.events
+            .push(TranscriptEvent::Squeeze { label, challenge });
+
+        challenge
+    }
+
+    fn circuit_domain_sep(&mut self, n: u64) {
+        self.events.push(TranscriptEvent::CircuitSize(n));
+        self.inner.circuit_domain_sep(n);
+    }
+}
@@ -0,0 +1,104 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Algebraic transcript based on the Poseidon permutation.
+
+use dusk_bls12_381::{BlsScalar, G1Affine};
+use dusk_bytes::Serializable;
+
+use super::TranscriptProtocol;
+use crate::hades::{Permutation, RATE, WIDTH};
+
+/// Number of bytes packed in a single scalar
+const CHUNK: usize = 31;
+
+/// Fiat-Shamir transcript over a sponge of the Hades252 permutation, with
+/// the parameters of `dusk-poseidon`: width 5, `x^5` S-box, 8 full rounds
+/// and 59 partial rounds.
+///
+/// Only the permutation is shared with `dusk-poseidon`, the sponge and its
+/// length prefixes are specific to this transcript.
+///
+/// Every message is absorbed as scalars, so the transcript can be recomputed
+/// within a circuit at a fraction of the cost of a bitwise hash. Labels and
+/// byte messages are packed in chunks of 31 bytes, prefixed by their length.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PoseidonTranscript {
+    permutation: Permutation,
+    state: [BlsScalar; WIDTH],
+    absorbed: usize,
+}
+
+impl PoseidonTranscript {
+    fn absorb(&mut self, scalar: BlsScalar) {
+        if self.absorbed == RATE {
+            self.permutation.permute(&mut self.state);
+            self.absorbed = 0;
+        }
+
+        self.state[1 + self.absorbed] += scalar;
+        self.absorbed += 1;
+    }
+
+    fn absorb_bytes(&mut self, bytes: &[u8]) {
+        self.absorb(BlsScalar::from(bytes.len() as u64));
+
+        bytes.chunks(CHUNK).for_each(|chunk| {
+            let mut buf = [0u8; BlsScalar::SIZE];
+            buf[..chunk.len()].copy_from_slice(chunk);
+
+            let scalar = BlsScalar::from_bytes(&buf)
+                .expect("31 bytes are always lower than the modulus");
+            self.absorb(scalar);
+        });
+    }
+
+    fn squeeze(&mut self) -> BlsScalar {
+        self.permutation.permute(&mut self.state);
+        self.absorbed = 0;
+
+        self.state[1]
+    }
+}
+
+impl TranscriptProtocol for PoseidonTranscript {
+    fn init(label: &[u8]) -> Self {
+        let mut transcript = Self {
+            permutation: Permutation::new(),
+            state: [BlsScalar::zero(); WIDTH],
+            absorbed: 0,
+        };
+
+        transcript.absorb_bytes(label);
+
+        transcript
+    }
+
+    fn append_point(&mut self, label: &'static [u8], point: &G1Affine) {
+        self.absorb_bytes(label);
+        self.absorb_bytes(&point.to_bytes());
+    }
+
+    fn append_scalar(&mut self, label: &'static [u8], s: &BlsScalar) {
+        self.absorb_bytes(label);
+        self.absorb(*s);
+    }
+
+    fn append_bytes(&mut self, label: &'static [u8], bytes: &[u8]) {
+        self.absorb_bytes(label);
+        self.absorb_bytes(bytes);
+    }
+
+    fn challenge_scalar(&mut self, label: &'static [u8]) -> BlsScalar {
+        self.absorb_bytes(label);
+        self.squeeze()
+    }
+
+    fn circuit_domain_sep(&mut self, n: u64) {
+        self.absorb_bytes(b"circuit_size");
+        self.absorb(BlsScalar::from(n));
+    }
+}
@@ -0,0 +1,242 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Generation of TypeScript verifiers.
+//!
+//! The generated module embeds the bytes of a [`Verifier`], and wraps the
+//! `verify` function of the `wasm` feature with typed encoders of the public
+//! inputs of its circuit. It imports the package of the crate built with
+//! `wasm-pack` for the `bundler` or `nodejs` targets.
+//!
+//! ```
+//! use dusk_plonk::prelude::*;
+//! use dusk_plonk::typescript::TypeScript;
+//! use rand_core::OsRng;
+//!
+//! #[derive(Default)]
+//! struct Square(BlsScalar);
+//!
+//! impl Circuit for Square {
+//!     fn circuit<C: Composer>(&self, composer: &mut C) -> Result<(), Error> {
+//!         let a = composer.append_witness(self.0);
+//!         let b = composer.gate_mul(Constraint::new().mult(1).a(a).b(a));
+//!         composer.assert_equal_constant(b, 0, Some(self.0.square()));
+//!
+//!         Ok(())
+//!     }
+//! }
+//!
+//! # fn main() -> Result<(), Error> {
+//! let pp = PublicParameters::setup(1 << 4, &mut OsRng)?;
+//! let (_, verifier) = Compiler::compile::<Square>(&pp, b"square")?;
+//!
+//! let ts = TypeScript::new(&verifier).with_names(&["square"])?.generate();
+//! assert!(ts.contains("readonly square: bigint;"));
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ```ts
+//! import { verifyProof } from "./square";
+//!
+//! const valid = verifyProof(proof, { square: 9n });
+//! ```
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+use dusk_bls12_381::BlsScalar;
+use dusk_bytes::Serializable;
+
+use crate::composer::Verifier;
+use crate::encoding;
+use crate::error::Error;
+use crate::proof_system::Proof;
+
+/// Order of the scalar field of BLS12-381
+const MODULUS: &str =
+    "0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001n";
+
+/// Whether `name` is an ASCII identifier of TypeScript
+fn is_identifier(name: &str) -> bool {
+    let start = |c: char| c.is_ascii_alphabetic() || c == '_' || c == '$';
+    let mut chars = name.chars();
+
+    chars.next().filter(|c| start(*c)).is_some()
+        && chars.all(|c| start(c) || c.is_ascii_digit())
+}
+
+/// Generator of the TypeScript verifier of a circuit
+#[derive(Clone)]
+pub struct TypeScript<'a> {
+    verifier: &'a Verifier,
+    package: &'a str,
+    names: Vec<String>,
+}
+
+impl<'a> TypeScript<'a> {
+    /// Generator of the verifier of `verifier`, importing the `dusk-plonk`
+    /// package and naming the public inputs `input0`, `input1`, ...
+    pub fn new(verifier: &'a Verifier) -> Self {
+        let names = (0..verifier.cost().public_inputs())
+            .map(|i| format!("input{i}"))
+            .collect();
+
+        Self {
+            verifier,
+            package: "dusk-plonk",
+            names,
+        }
+    }
+
+    /// Import the `verify` function from `package`
+    pub fn with_package(self, package: &'a str) -> Self {
+        Self { package, ..self }
+    }
+
+    /// Name the fields of the public inputs, in the order they are appended
+    /// to the circuit
+    pub fn with_names(self, names: &[&str]) -> Result<Self, Error> {
+        if names.len() != self.names.len() {
+            return Err(Error::InconsistentPublicInputsLen {
+                expected: self.names.len(),
+                provided: names.len(),
+            });
+        }
+
+        if let Some(index) = names.iter().enumerate().position(|(i, name)| {
+            !is_identifier(name) || names[..i].contains(name)
+        }) {
+            return Err(Error::InvalidPublicInputName { index });
+        }
+
+        let names = names.iter().map(|name| String::from(*name)).collect();
+
+        Ok(Self { names, ..self })
+    }
+
+    /// Source of the TypeScript module of the verifier
+    pub fn generate(&self) -> String {
+        let mut ts = String::new();
+
+        self.write(&mut ts)
+            .expect("writing to a string shouldn't fail");
+
+        ts
+    }
+
+    fn write(&self, ts: &mut String) -> core::fmt::Result {
+        let package = self.package.replace('\\', "\\\\").replace('"', "\\\"");
+        let circuit_hash = encoding::to_hex(&self.verifier.circuit_hash());
+        let verifier = encoding::to_base64(&self.verifier.to_bytes());
+
+        writeln!(
+            ts,
+            "// Verifier of the circuit {circuit_hash}, generated by dusk-plonk {}.",
+            env!("CARGO_PKG_VERSION"),
+        )?;
+        writeln!(ts, "// Do not edit.")?;
+        writeln!(ts)?;
+        writeln!(ts, "import {{ verify }} from \"{package}\";")?;
+        writeln!(ts)?;
+        writeln!(ts, "/** Number of public inputs of a proof */")?;
+        writeln!(ts, "export const PUBLIC_INPUTS = {};", self.names.len())?;
+        writeln!(ts)?;
+        writeln!(ts, "/** Size of the bytes of a proof */")?;
+        writeln!(ts, "export const PROOF_SIZE = {};", Proof::SIZE)?;
+        writeln!(ts)?;
+        writeln!(ts, "/** Hash of the circuit of the verifier */")?;
+        writeln!(ts, "export const CIRCUIT_HASH = \"{circuit_hash}\";")?;
+        writeln!(ts)?;
+        writeln!(ts, "/** Order of the scalar field of BLS12-381 */")?;
+        writeln!(ts, "export const MODULUS =\n  {MODULUS};")?;
+        writeln!(ts)?;
+
+        writeln!(ts, "/** Public inputs of a proof */")?;
+        match self.names.is_empty() {
+            true => writeln!(
+                ts,
+                "export type PublicInputs = Record<string, never>;"
+            )?,
+            false => {
+                writeln!(ts, "export interface PublicInputs {{")?;
+                for name in &self.names {
+                    writeln!(ts, "  readonly {name}: bigint;")?;
+                }
+                writeln!(ts, "}}")?;
+            }
+        }
+        writeln!(ts)?;
+
+        writeln!(ts, "const VERIFIER: Uint8Array = Uint8Array.from(")?;
+        writeln!(ts, "  atob(\"{verifier}\"),")?;
+        writeln!(ts, "  (c) => c.charCodeAt(0),")?;
+        writeln!(ts, ");")?;
+        writeln!(ts)?;
+
+        writeln!(ts, "/** Encode a scalar as its little-endian bytes */")?;
+        writeln!(
+            ts,
+            "export function encodeScalar(value: bigint): Uint8Array {{"
+        )?;
+        writeln!(ts, "  if (value < 0n || value >= MODULUS) {{")?;
+        writeln!(
+            ts,
+            "    throw new RangeError(`scalar out of the field: ${{value}}`);"
+        )?;
+        writeln!(ts, "  }}")?;
+        writeln!(ts, "  const bytes = new Uint8Array({});", BlsScalar::SIZE)?;
+        writeln!(ts, "  for (let i = 0; i < {}; i++) {{", BlsScalar::SIZE)?;
+        writeln!(
+            ts,
+            "    bytes[i] = Number((value >> BigInt(8 * i)) & 0xffn);"
+        )?;
+        writeln!(ts, "  }}")?;
+        writeln!(ts, "  return bytes;")?;
+        writeln!(ts, "}}")?;
+        writeln!(ts)?;
+
+        writeln!(
+            ts,
+            "/** Encode the public inputs in the order of the proof */"
+        )?;
+        writeln!(
+            ts,
+            "export function encodePublicInputs(inputs: PublicInputs): Uint8Array {{"
+        )?;
+        writeln!(
+            ts,
+            "  const bytes = new Uint8Array(PUBLIC_INPUTS * {});",
+            BlsScalar::SIZE
+        )?;
+        for (i, name) in self.names.iter().enumerate() {
+            writeln!(
+                ts,
+                "  bytes.set(encodeScalar(inputs.{name}), {});",
+                i * BlsScalar::SIZE
+            )?;
+        }
+        writeln!(ts, "  return bytes;")?;
+        writeln!(ts, "}}")?;
+        writeln!(ts)?;
+
+        writeln!(
+            ts,
+            "/** Verify the bytes of a proof against its public inputs */"
+        )?;
+        writeln!(ts, "export function verifyProof(")?;
+        writeln!(ts, "  proof: Uint8Array,")?;
+        writeln!(ts, "  inputs: PublicInputs,")?;
+        writeln!(ts, "): boolean {{")?;
+        writeln!(
+            ts,
+            "  return verify(proof, VERIFIER, encodePublicInputs(inputs));"
+        )?;
+        writeln!(ts, "}}")
+    }
+}
@@ -4,11 +4,17 @@
 //
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
+use core::ops::Deref;
+
+use dusk_bls12_381::{BlsScalar, G1Affine, G1Projective};
+
+#[cfg(feature = "verifier")]
 use alloc::vec::Vec;
-use dusk_bls12_381::{
-    BlsScalar, G1Affine, G1Projective, G2Affine, G2Projective,
-};
+#[cfg(feature = "alloc")]
+use dusk_bls12_381::{G2Affine, G2Projective};
+#[cfg(feature = "alloc")]
 use ff::Field;
+#[cfg(feature = "alloc")]
 use rand_core::{CryptoRng, RngCore};
 
 #[cfg(feature = "rkyv-impl")]
@@ -30,7 +36,33 @@ where
     Ok(())
 }
 
+/// Maps four independent items with `f`, in parallel with the `parallel`
+/// feature.
+#[cfg(feature = "alloc")]
+pub(crate) fn map_4<I, O, F>(items: [I; 4], f: F) -> [O; 4]
+where
+    I: Send,
+    O: Send,
+    F: Fn(I) -> O + Sync,
+{
+    #[cfg(not(feature = "parallel"))]
+    let mapped = items.map(f);
+
+    #[cfg(feature = "parallel")]
+    let mapped = {
+        let [a, b, c, d] = items;
+        let ((a, b), (c, d)) = rayon::join(
+            || rayon::join(|| f(a), || f(b)),
+            || rayon::join(|| f(c), || f(d)),
+        );
+        [a, b, c, d]
+    };
+
+    mapped
+}
+
 /// Returns a vector of BlsScalars of increasing powers of x from x^0 to x^d.
+#[cfg(feature = "alloc")]
 pub(crate) fn powers_of(
     scalar: &BlsScalar,
     max_degree: usize,
@@ -44,12 +76,14 @@ pub(crate) fn powers_of(
 }
 
 /// Generates a random G1 Point using an RNG seed.
+#[cfg(feature = "alloc")]
 pub(crate) fn random_g1_point<R: RngCore + CryptoRng>(
     rng: &mut R,
 ) -> G1Projective {
     G1Affine::generator() * BlsScalar::random(rng)
 }
 /// Generates a random G2 point using an RNG seed.
+#[cfg(feature = "alloc")]
 pub(crate) fn random_g2_point<R: RngCore + CryptoRng>(
     rng: &mut R,
 ) -> G2Projective {
@@ -59,6 +93,7 @@ pub(crate) fn random_g2_point<R: RngCore + CryptoRng>(
 /// This function is only used to generate the SRS.
 /// The intention is just to compute the resulting points
 /// of the operation `a*P, b*P, c*P ... (n-1)*P` into a `Vec`.
+#[cfg(feature = "alloc")]
 pub(crate) fn slow_multiscalar_mul_single_base(
     scalars: &[BlsScalar],
     base: G1Projective,
@@ -66,10 +101,73 @@ pub(crate) fn slow_multiscalar_mul_single_base(
     scalars.iter().map(|s| base * *s).collect()
 }
 
+/// Vector of at most `N` items stored inline, for the buffers of the verifier
+/// whose length is bounded, so it doesn't need an allocator
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ArrayVec<T, const N: usize> {
+    items: [T; N],
+    len: usize,
+}
+
+impl<T: Copy + Default, const N: usize> ArrayVec<T, N> {
+    /// Create an empty vector
+    pub(crate) fn new() -> Self {
+        Self {
+            items: [T::default(); N],
+            len: 0,
+        }
+    }
+
+    /// Append `item` to the vector, panicking if it's full
+    pub(crate) fn push(&mut self, item: T) {
+        self.items[self.len] = item;
+        self.len += 1;
+    }
+}
+
+impl<T, const N: usize> Deref for ArrayVec<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.items[..self.len]
+    }
+}
+
+/// Sum of the `points` multiplied by the `scalars`, with the Pippenger
+/// multi-scalar multiplication when an allocator is available
+pub(crate) fn msm(points: &[G1Affine], scalars: &[BlsScalar]) -> G1Projective {
+    #[cfg(feature = "verifier")]
+    let msm =
+        dusk_bls12_381::multiscalar_mul::msm_variable_base(points, scalars);
+
+    #[cfg(not(feature = "verifier"))]
+    let msm = points.iter().zip(scalars).map(|(p, s)| p * s).sum();
+
+    msm
+}
+
 // while we do not have batch inversion for scalars
+#[cfg(feature = "verifier")]
 use core::ops::MulAssign;
 
+/// Invert the non zero scalars of `v` with a single inversion, or one per
+/// chunk of the scalars inverted in parallel with the `parallel` feature.
+#[cfg(feature = "verifier")]
 pub fn batch_inversion(v: &mut [BlsScalar]) {
+    #[cfg(not(feature = "parallel"))]
+    serial_batch_inversion(v);
+
+    #[cfg(feature = "parallel")]
+    {
+        use rayon::prelude::*;
+
+        let chunk = (v.len() / rayon::current_num_threads()).max(1024);
+        v.par_chunks_mut(chunk).for_each(serial_batch_inversion);
+    }
+}
+
+#[cfg(feature = "verifier")]
+fn serial_batch_inversion(v: &mut [BlsScalar]) {
     // Montgomery’s Trick and Fast Implementation of Masked AES
     // Genelle, Prouff and Quisquater
     // Section 3.2
@@ -120,4 +218,21 @@ mod test {
             assert_eq!(x.invert().unwrap(), *x_inv);
         }
     }
+
+    #[test]
+    fn test_batch_inversion_chunks() {
+        // several chunks, with zeros left as they are
+        let original_scalars: Vec<BlsScalar> = (0..5000u64)
+            .map(|i| match i % 7 {
+                0 => BlsScalar::zero(),
+                _ => BlsScalar::from(i),
+            })
+            .collect();
+        let mut inverted_scalars = original_scalars.clone();
+
+        batch_inversion(&mut inverted_scalars);
+        for (x, x_inv) in original_scalars.iter().zip(inverted_scalars.iter()) {
+            assert_eq!(x.invert().unwrap_or(BlsScalar::zero()), *x_inv);
+        }
+    }
 }
@@ -0,0 +1,64 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! JavaScript bindings of the verifier.
+//!
+//! The crate can be built for `wasm32-unknown-unknown` with
+//! `--no-default-features --features wasm`, and the resulting module processed
+//! by `wasm-bindgen` or `wasm-pack` for browsers and Node.
+//!
+//! ```js
+//! import { verify } from "dusk-plonk";
+//!
+//! // Uint8Array of the proof, of `Verifier::to_bytes`, and of the
+//! // concatenated public inputs
+//! const valid = verify(proof, verifier, publicInputs);
+//! ```
+
+use alloc::vec::Vec;
+
+use dusk_bls12_381::BlsScalar;
+use dusk_bytes::{DeserializableSlice, Serializable};
+use wasm_bindgen::prelude::*;
+
+use crate::composer::Verifier;
+use crate::error::Error;
+use crate::proof_system::Proof;
+
+/// Verify a proof against the verifier of its circuit.
+///
+/// The `proof` is given either by [`Proof::to_bytes`] or by
+/// [`Proof::to_versioned_bytes`], the `verifier` by [`Verifier::to_bytes`],
+/// and the `public_inputs` by the concatenation of the 32 bytes of every
+/// public input, in the order they were appended to the circuit.
+///
+/// Returns `false` if the proof is invalid or if any of the bytes is
+/// malformed.
+#[wasm_bindgen]
+pub fn verify(proof: &[u8], verifier: &[u8], public_inputs: &[u8]) -> bool {
+    verify_bytes(proof, verifier, public_inputs).is_ok()
+}
+
+/// Verify a proof given by its bytes, as documented in [`verify`].
+pub fn verify_bytes(
+    proof: &[u8],
+    verifier: &[u8],
+    public_inputs: &[u8],
+) -> Result<(), Error> {
+    let proof = match proof.len() {
+        Proof::SIZE => Proof::from_slice(proof)?,
+        _ => Proof::from_versioned_bytes(proof)?,
+    };
+
+    let verifier = Verifier::try_from_bytes(verifier)?;
+
+    let public_inputs = public_inputs.chunks(BlsScalar::SIZE);
+    let public_inputs = public_inputs
+        .map(BlsScalar::from_slice)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    verifier.verify(&proof, &public_inputs)
+}
@@ -0,0 +1,554 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Reading and writing of the [zkInterface](https://github.com/QED-it/zkinterface)
+//! format of rank-1 constraint systems and witnesses.
+//!
+//! A zkInterface stream is a sequence of size-prefixed flatbuffers messages:
+//! a circuit header with the instance variables, one or more constraint
+//! systems, and optionally the witness with the values of the other
+//! variables. The variable `0` is the constant `1`.
+//!
+//! A [`ZkifCircuit`] proves such a stream, with every constraint compiled into
+//! width-4 gates. The other way around, [`Builder::to_zkinterface`] writes the
+//! circuits made of arithmetic gates, for external front-ends and auditing
+//! tools.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use dusk_bls12_381::BlsScalar;
+use dusk_bytes::Serializable;
+use flatbuffers::{FlatBufferBuilder, WIPOffset};
+
+use crate::composer::{Builder, Circuit, Composer};
+use crate::constraint_system::Witness;
+use crate::error::Error;
+use crate::r1cs::{self, LinearCombination};
+
+/// Identifier of the zkInterface buffers
+const FILE_IDENTIFIER: &str = "zkif";
+
+/// Types of the `Message` union
+const CIRCUIT_HEADER: u8 = 1;
+const CONSTRAINT_SYSTEM: u8 = 2;
+const WITNESS: u8 = 3;
+
+/// Position of the field `id` in the vtable of a table
+const fn slot(id: u16) -> u16 {
+    4 + 2 * id
+}
+
+/// Read `N` bytes of `buf` at `pos`
+fn read<const N: usize>(buf: &[u8], pos: usize) -> Result<[u8; N], Error> {
+    let mut bytes = [0u8; N];
+    bytes.copy_from_slice(
+        buf.get(pos..)
+            .and_then(|buf| buf.get(..N))
+            .ok_or(Error::InvalidZkInterface)?,
+    );
+
+    Ok(bytes)
+}
+
+fn read_u32(buf: &[u8], pos: usize) -> Result<usize, Error> {
+    Ok(u32::from_le_bytes(read(buf, pos)?) as usize)
+}
+
+/// Table of a flatbuffer, with its fields bound checked on access
+#[derive(Clone, Copy)]
+struct Table<'a> {
+    buf: &'a [u8],
+    pos: usize,
+    vtable: usize,
+}
+
+impl<'a> Table<'a> {
+    fn at(buf: &'a [u8], pos: usize) -> Result<Self, Error> {
+        let offset = i32::from_le_bytes(read(buf, pos)?) as i64;
+        let vtable = usize::try_from(pos as i64 - offset)
+            .map_err(|_| Error::InvalidZkInterface)?;
+
+        Ok(Self { buf, pos, vtable })
+    }
+
+    /// Position of the field `id`, if present
+    fn field(&self, id: u16) -> Result<Option<usize>, Error> {
+        let len = u16::from_le_bytes(read(self.buf, self.vtable)?);
+        if slot(id) + 2 > len {
+            return Ok(None);
+        }
+
+        let offset = u16::from_le_bytes(read(
+            self.buf,
+            self.vtable + slot(id) as usize,
+        )?);
+        Ok((offset != 0).then(|| self.pos + offset as usize))
+    }
+
+    fn u8(&self, id: u16) -> Result<u8, Error> {
+        match self.field(id)? {
+            Some(pos) => Ok(read::<1>(self.buf, pos)?[0]),
+            None => Ok(0),
+        }
+    }
+
+    fn u64(&self, id: u16) -> Result<u64, Error> {
+        match self.field(id)? {
+            Some(pos) => Ok(u64::from_le_bytes(read(self.buf, pos)?)),
+            None => Ok(0),
+        }
+    }
+
+    /// Position of the object referenced by the field `id`, if present
+    fn offset(&self, id: u16) -> Result<Option<usize>, Error> {
+        self.field(id)?
+            .map(|pos| {
+                pos.checked_add(read_u32(self.buf, pos)?)
+                    .ok_or(Error::InvalidZkInterface)
+            })
+            .transpose()
+    }
+
+    fn table(&self, id: u16) -> Result<Option<Table<'a>>, Error> {
+        self.offset(id)?
+            .map(|pos| Table::at(self.buf, pos))
+            .transpose()
+    }
+
+    /// Bytes of the elements of the vector `id`, empty if absent
+    fn vector(&self, id: u16, size: usize) -> Result<&'a [u8], Error> {
+        match self.offset(id)? {
+            Some(pos) => {
+                let len = read_u32(self.buf, pos)?;
+                len.checked_mul(size)
+                    .and_then(|len| self.buf.get(pos + 4..)?.get(..len))
+                    .ok_or(Error::InvalidZkInterface)
+            }
+            None => Ok(&[]),
+        }
+    }
+
+    fn u64s(&self, id: u16) -> Result<Vec<u64>, Error> {
+        Ok(self
+            .vector(id, 8)?
+            .chunks_exact(8)
+            .map(|chunk| {
+                let mut bytes = [0u8; 8];
+                bytes.copy_from_slice(chunk);
+                u64::from_le_bytes(bytes)
+            })
+            .collect())
+    }
+
+    fn tables(&self, id: u16) -> Result<Vec<Table<'a>>, Error> {
+        let start = match self.offset(id)? {
+            Some(pos) => pos + 4,
+            None => return Ok(Vec::new()),
+        };
+
+        (0..self.vector(id, 4)?.len() / 4)
+            .map(|i| {
+                let pos = start + 4 * i;
+                let offset = read_u32(self.buf, pos)?;
+                pos.checked_add(offset)
+                    .ok_or(Error::InvalidZkInterface)
+                    .and_then(|pos| Table::at(self.buf, pos))
+            })
+            .collect()
+    }
+}
+
+/// Assignment of values to variables, as a zkInterface `Variables` table
+struct Variables {
+    ids: Vec<u64>,
+    values: Vec<BlsScalar>,
+}
+
+impl Variables {
+    // fields of the `Variables` table
+    const VARIABLE_IDS: u16 = 0;
+    const VALUES: u16 = 1;
+
+    fn read(table: Option<Table>) -> Result<Self, Error> {
+        let table = match table {
+            Some(table) => table,
+            None => {
+                return Ok(Self {
+                    ids: Vec::new(),
+                    values: Vec::new(),
+                })
+            }
+        };
+
+        let ids = table.u64s(Self::VARIABLE_IDS)?;
+        let bytes = table.vector(Self::VALUES, 1)?;
+
+        // the values are optional, and share the same little-endian size
+        let values = match (bytes.len(), ids.len()) {
+            (0, _) | (_, 0) => Vec::new(),
+            (len, n) if len % n == 0 && len / n <= BlsScalar::SIZE => bytes
+                .chunks_exact(len / n)
+                .map(|chunk| {
+                    let mut value = [0u8; BlsScalar::SIZE];
+                    value[..chunk.len()].copy_from_slice(chunk);
+                    Option::from(BlsScalar::from_bytes(&value))
+                        .ok_or(Error::InvalidZkInterface)
+                })
+                .collect::<Result<_, _>>()?,
+            _ => return Err(Error::InvalidZkInterface),
+        };
+
+        Ok(Self { ids, values })
+    }
+
+    /// Linear combination of the variables, with the values as coefficients
+    fn combination(&self, wires: usize) -> Result<LinearCombination, Error> {
+        if self.values.len() != self.ids.len() {
+            return Err(Error::InvalidZkInterface);
+        }
+
+        self.ids
+            .iter()
+            .zip(&self.values)
+            .map(|(id, coeff)| match usize::try_from(*id) {
+                Ok(wire) if wire < wires => Ok((wire, *coeff)),
+                _ => Err(Error::InvalidZkInterface),
+            })
+            .collect()
+    }
+
+    fn write<'a>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'a>,
+    ) -> WIPOffset<flatbuffers::TableFinishedWIPOffset> {
+        let values: Vec<u8> =
+            self.values.iter().flat_map(|v| v.to_bytes()).collect();
+
+        let ids = fbb.create_vector(&self.ids);
+        let values = fbb.create_vector(&values);
+
+        let table = fbb.start_table();
+        fbb.push_slot_always(slot(Self::VARIABLE_IDS), ids);
+        fbb.push_slot_always(slot(Self::VALUES), values);
+        fbb.end_table(table)
+    }
+}
+
+/// Circuit of a zkInterface constraint system, with the values of its
+/// variables
+///
+/// The default circuit is empty. Compile a circuit read from a stream with no
+/// witness through
+/// [`Compiler::compile_with_circuit`](crate::prelude::Compiler), and prove a
+/// circuit read from a stream with its witness.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ZkifCircuit {
+    instance: Vec<usize>,
+    constraints: Vec<[LinearCombination; 3]>,
+    values: Vec<BlsScalar>,
+}
+
+impl ZkifCircuit {
+    // fields of the `Root` table
+    const MESSAGE_TYPE: u16 = 0;
+    const MESSAGE: u16 = 1;
+
+    // fields of the `CircuitHeader` table
+    const INSTANCE_VARIABLES: u16 = 0;
+    const FREE_VARIABLE_ID: u16 = 1;
+    const FIELD_MAXIMUM: u16 = 2;
+
+    // fields of the `ConstraintSystem` table
+    const CONSTRAINTS: u16 = 0;
+
+    // fields of the `Witness` table
+    const ASSIGNED_VARIABLES: u16 = 0;
+
+    /// Read a circuit from the messages of a zkInterface stream, with a
+    /// single circuit header and any number of constraint systems and
+    /// witnesses.
+    ///
+    /// The variables missing from the witness are set to zero, and the field
+    /// of the circuit must be the scalar field of BLS12-381.
+    pub fn from_bytes(mut bytes: &[u8]) -> Result<Self, Error> {
+        let mut header = None;
+        let mut constraints = Vec::new();
+        let mut assigned = Vec::new();
+
+        while !bytes.is_empty() {
+            let len = read_u32(bytes, 0)?;
+            let buf = bytes[4..].get(..len).ok_or(Error::InvalidZkInterface)?;
+            bytes = &bytes[4 + len..];
+
+            if buf.get(4..8) != Some(FILE_IDENTIFIER.as_bytes()) {
+                return Err(Error::InvalidZkInterface);
+            }
+            let root = Table::at(buf, read_u32(buf, 0)?)?;
+            let message = root
+                .table(Self::MESSAGE)?
+                .ok_or(Error::InvalidZkInterface)?;
+
+            match root.u8(Self::MESSAGE_TYPE)? {
+                CIRCUIT_HEADER if header.is_none() => header = Some(message),
+                CONSTRAINT_SYSTEM => constraints.push(message),
+                WITNESS => assigned.push(message),
+                _ => return Err(Error::InvalidZkInterface),
+            }
+        }
+
+        let header = header.ok_or(Error::InvalidZkInterface)?;
+        let wires = usize::try_from(header.u64(Self::FREE_VARIABLE_ID)?)
+            .map_err(|_| Error::InvalidZkInterface)?;
+
+        // the field is given by its largest element
+        let maximum = header.vector(Self::FIELD_MAXIMUM, 1)?;
+        let len = maximum.iter().rposition(|b| *b != 0).map_or(0, |i| i + 1);
+        let maximum = &maximum[..len];
+        if !maximum.is_empty() && maximum != (-BlsScalar::one()).to_bytes() {
+            return Err(Error::UnsupportedZkInterfaceField);
+        }
+
+        let mut values = vec![BlsScalar::zero(); wires];
+        let mut assign = |variables: &Variables| {
+            variables.ids.iter().enumerate().try_for_each(|(i, id)| {
+                match usize::try_from(*id) {
+                    Ok(wire) if wire > 0 && wire < wires => {
+                        values[wire] = variables
+                            .values
+                            .get(i)
+                            .copied()
+                            .unwrap_or_default();
+                        Ok(())
+                    }
+                    _ => Err(Error::InvalidZkInterface),
+                }
+            })
+        };
+
+        let instance =
+            Variables::read(header.table(Self::INSTANCE_VARIABLES)?)?;
+        assign(&instance)?;
+        for witness in assigned {
+            assign(&Variables::read(
+                witness.table(Self::ASSIGNED_VARIABLES)?,
+            )?)?;
+        }
+        if !values.is_empty() {
+            values[0] = BlsScalar::one();
+        }
+
+        let mut instance: Vec<usize> =
+            instance.ids.iter().map(|id| *id as usize).collect();
+        instance.sort_unstable();
+        instance.dedup();
+
+        let constraints = constraints
+            .iter()
+            .map(|system| system.tables(Self::CONSTRAINTS))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .flatten()
+            .map(|constraint| {
+                let combination = |id| {
+                    Variables::read(constraint.table(id)?)?.combination(wires)
+                };
+                Ok([combination(0)?, combination(1)?, combination(2)?])
+            })
+            .collect::<Result<_, Error>>()?;
+
+        Ok(Self {
+            instance,
+            constraints,
+            values,
+        })
+    }
+
+    /// Number of variables of the circuit, including the constant `1`
+    pub fn variables(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Number of constraints of the circuit
+    pub fn constraints(&self) -> usize {
+        self.constraints.len()
+    }
+
+    /// Values of the instance variables, in the order of the public inputs of
+    /// the proof, that is by increasing variable id
+    pub fn public_inputs(&self) -> Vec<BlsScalar> {
+        self.instance.iter().map(|id| self.values[*id]).collect()
+    }
+}
+
+impl Circuit for ZkifCircuit {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer,
+    {
+        let wires: Vec<Witness> = self
+            .values
+            .iter()
+            .enumerate()
+            .map(|(wire, value)| match wire {
+                0 => C::ONE,
+                _ if self.instance.binary_search(&wire).is_ok() => {
+                    composer.append_public(*value)
+                }
+                _ => composer.append_witness(*value),
+            })
+            .collect();
+
+        self.constraints.iter().for_each(|constraint| {
+            r1cs::append_constraint(composer, constraint, &wires)
+        });
+
+        Ok(())
+    }
+}
+
+/// Write a message of the `Message` union as a size-prefixed buffer
+fn message<F>(kind: u8, write: F) -> Vec<u8>
+where
+    F: for<'a> FnOnce(
+        &mut FlatBufferBuilder<'a>,
+    ) -> WIPOffset<flatbuffers::TableFinishedWIPOffset>,
+{
+    let mut fbb = FlatBufferBuilder::new();
+    let message = write(&mut fbb);
+
+    let root = fbb.start_table();
+    fbb.push_slot::<u8>(slot(ZkifCircuit::MESSAGE_TYPE), kind, 0);
+    fbb.push_slot_always(slot(ZkifCircuit::MESSAGE), message);
+    let root = fbb.end_table(root);
+
+    fbb.finish_size_prefixed(root, Some(FILE_IDENTIFIER));
+    fbb.finished_data().to_vec()
+}
+
+impl Builder {
+    /// Write the circuit and its witness as a zkInterface stream: a circuit
+    /// header, a constraint system, and a witness.
+    ///
+    /// Every arithmetic gate becomes the constraint `(q_m·a) · b = -(q_l·a +
+    /// q_r·b + q_o·o + q_4·d + q_c) - pi`, scaled by `q_arith`. The public
+    /// inputs are the instance variables `1..=n`, in the order of the proof,
+    /// followed by the witnesses of the builder. Gates of the range, logic or
+    /// elliptic curve widgets can't be expressed as rank-1 constraints, and
+    /// are rejected with [`Error::UnsupportedGate`].
+    pub fn to_zkinterface(&self) -> Result<Vec<u8>, Error> {
+        let public_inputs = self.public_input_indexes();
+        let witness = |w: Witness| 1 + public_inputs.len() + w.index();
+
+        let mut constraints = Vec::new();
+        for (gate, poly) in self.constraints.iter().enumerate() {
+            let custom = [
+                poly.q_range,
+                poly.q_logic,
+                poly.q_fixed_group_add,
+                poly.q_variable_group_add,
+            ];
+            if custom.iter().any(|q| *q != BlsScalar::zero()) {
+                return Err(Error::UnsupportedGate { gate });
+            }
+
+            let q = poly.q_arith;
+            let [a, b, o, d] = poly.wires();
+            let mut c = vec![
+                (witness(a), -q * poly.q_l),
+                (witness(b), -q * poly.q_r),
+                (witness(o), -q * poly.q_o),
+                (witness(d), -q * poly.q_d),
+                (0, -q * poly.q_c),
+            ];
+            if let Ok(i) = public_inputs.binary_search(&gate) {
+                c.push((1 + i, -BlsScalar::one()));
+            }
+            c.retain(|(_, coeff)| *coeff != BlsScalar::zero());
+
+            let (a, b) = match q * poly.q_m == BlsScalar::zero() {
+                true => (vec![], vec![]),
+                false => (
+                    vec![(witness(a), q * poly.q_m)],
+                    vec![(witness(b), BlsScalar::one())],
+                ),
+            };
+
+            if !(a.is_empty() && c.is_empty()) {
+                constraints.push([a, b, c]);
+            }
+        }
+
+        let variables = |combination: &LinearCombination| Variables {
+            ids: combination.iter().map(|(id, _)| *id as u64).collect(),
+            values: combination.iter().map(|(_, coeff)| *coeff).collect(),
+        };
+        let instance = Variables {
+            ids: (1..=public_inputs.len() as u64).collect(),
+            values: self.public_inputs(),
+        };
+        let assigned = Variables {
+            ids: (0..self.witnesses.len())
+                .map(|w| (1 + public_inputs.len() + w) as u64)
+                .collect(),
+            values: self.witnesses.clone(),
+        };
+        let free = 1 + public_inputs.len() + self.witnesses.len();
+
+        let mut stream = message(CIRCUIT_HEADER, |fbb| {
+            let instance = instance.write(fbb);
+            let maximum = fbb.create_vector(&(-BlsScalar::one()).to_bytes());
+
+            let table = fbb.start_table();
+            fbb.push_slot_always(
+                slot(ZkifCircuit::INSTANCE_VARIABLES),
+                instance,
+            );
+            fbb.push_slot::<u64>(
+                slot(ZkifCircuit::FREE_VARIABLE_ID),
+                free as u64,
+                0,
+            );
+            fbb.push_slot_always(slot(ZkifCircuit::FIELD_MAXIMUM), maximum);
+            fbb.end_table(table)
+        });
+
+        stream.extend(message(CONSTRAINT_SYSTEM, |fbb| {
+            let constraints: Vec<_> = constraints
+                .iter()
+                .map(|[a, b, c]| {
+                    let a = variables(a).write(fbb);
+                    let b = variables(b).write(fbb);
+                    let c = variables(c).write(fbb);
+
+                    let table = fbb.start_table();
+                    fbb.push_slot_always(slot(0), a);
+                    fbb.push_slot_always(slot(1), b);
+                    fbb.push_slot_always(slot(2), c);
+                    fbb.end_table(table)
+                })
+                .collect();
+            let constraints = fbb.create_vector(&constraints);
+
+            let table = fbb.start_table();
+            fbb.push_slot_always(slot(ZkifCircuit::CONSTRAINTS), constraints);
+            fbb.end_table(table)
+        }));
+
+        stream.extend(message(WITNESS, |fbb| {
+            let assigned = assigned.write(fbb);
+
+            let table = fbb.start_table();
+            fbb.push_slot_always(
+                slot(ZkifCircuit::ASSIGNED_VARIABLES),
+                assigned,
+            );
+            fbb.end_table(table)
+        }));
+
+        Ok(stream)
+    }
+}
@@ -0,0 +1,167 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_plonk::acir::{AcirCircuit, Expression, Opcode};
+use dusk_plonk::prelude::*;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+fn expression(
+    mul_terms: &[(i64, u32, u32)],
+    linear_combinations: &[(i64, u32)],
+    q_c: i64,
+) -> Expression {
+    let scalar = |value: i64| match value < 0 {
+        true => -BlsScalar::from(value.unsigned_abs()),
+        false => BlsScalar::from(value as u64),
+    };
+
+    Expression {
+        mul_terms: mul_terms
+            .iter()
+            .map(|(q_m, l, r)| (scalar(*q_m), *l, *r))
+            .collect(),
+        linear_combinations: linear_combinations
+            .iter()
+            .map(|(q, w)| (scalar(*q), *w))
+            .collect(),
+        q_c: scalar(q_c),
+    }
+}
+
+/// The witnesses are `[_, x, y, z, a, b, a & b, a ^ b, s]`, with the public
+/// `z` and `s`
+fn program() -> Vec<Opcode> {
+    vec![
+        // x · y = z
+        Opcode::AssertZero(expression(&[(1, 1, 2)], &[(-1, 3)], 0)),
+        // x · y + a · b + x + y + a + b + 1 = s
+        Opcode::AssertZero(expression(
+            &[(1, 1, 2), (1, 4, 5)],
+            &[(1, 1), (1, 2), (1, 4), (1, 5), (-1, 8)],
+            1,
+        )),
+        Opcode::Range { input: 1, bits: 8 },
+        Opcode::Range { input: 4, bits: 9 },
+        Opcode::Range { input: 8, bits: 17 },
+        Opcode::And {
+            lhs: 4,
+            rhs: 5,
+            output: 6,
+            bits: 8,
+        },
+        Opcode::Xor {
+            lhs: 4,
+            rhs: 5,
+            output: 7,
+            bits: 8,
+        },
+    ]
+}
+
+fn witness(x: u64, y: u64, a: u64, b: u64) -> Vec<BlsScalar> {
+    let s = x * y + a * b + x + y + a + b + 1;
+    [0, x, y, x * y, a, b, a & b, a ^ b, s]
+        .map(BlsScalar::from)
+        .to_vec()
+}
+
+fn check_satisfied(circuit: &AcirCircuit) -> Result<(), Error> {
+    let mut builder = Builder::initialized();
+    circuit.circuit(&mut builder)?;
+    builder.check_satisfied()
+}
+
+#[test]
+fn acir_circuit() {
+    let rng = &mut StdRng::seed_from_u64(0xac12);
+    let circuit = AcirCircuit::new(9, vec![8, 3], program())
+        .expect("the program should be valid");
+
+    let pp = PublicParameters::setup(1 << 8, rng)
+        .expect("Creation of public parameter shouldn't fail");
+    let (prover, verifier) =
+        Compiler::compile_with_circuit(&pp, b"acir", &circuit)
+            .expect("the program should compile");
+
+    let satisfied = circuit
+        .clone()
+        .with_witness(witness(3, 5, 0b1100_1010, 0b1010_0110))
+        .expect("the witness should fit the circuit");
+    assert_eq!(satisfied.public_inputs(), [15, 33924].map(BlsScalar::from));
+    check_satisfied(&satisfied).expect("the circuit should be satisfied");
+
+    let (proof, public_inputs) = prover
+        .prove(rng, &satisfied)
+        .expect("the satisfied circuit should be proven");
+    assert_eq!(public_inputs, satisfied.public_inputs());
+    verifier
+        .verify(&proof, &public_inputs)
+        .expect("the proof should verify");
+
+    // a out of its 9 bits
+    let unsatisfied = circuit
+        .clone()
+        .with_witness(witness(3, 5, 0x200, 0b1010_0110))
+        .expect("the witness should fit the circuit");
+    assert!(matches!(
+        check_satisfied(&unsatisfied),
+        Err(Error::UnsatisfiedConstraint { .. })
+    ));
+    prover
+        .prove(rng, &unsatisfied)
+        .expect_err("the unsatisfied circuit shouldn't be proven");
+
+    // a wrong result of the xor
+    let mut values = witness(3, 5, 0b1100_1010, 0b1010_0110);
+    values[7] += BlsScalar::one();
+    let unsatisfied = circuit
+        .clone()
+        .with_witness(values)
+        .expect("the witness should fit the circuit");
+    assert!(matches!(
+        check_satisfied(&unsatisfied),
+        Err(Error::UnsatisfiedConstraint { .. })
+    ));
+
+    assert_eq!(
+        circuit.with_witness(vec![BlsScalar::one(); 8]),
+        Err(Error::CircuitInputsNotFound {
+            expected: 9,
+            provided: 8
+        })
+    );
+}
+
+#[test]
+fn invalid_program() {
+    let mut opcodes = program();
+    opcodes[3] = Opcode::Range { input: 9, bits: 8 };
+    assert_eq!(
+        AcirCircuit::new(9, vec![3], opcodes),
+        Err(Error::InvalidAcirOpcode { opcode: 3 })
+    );
+
+    let mut opcodes = program();
+    opcodes[6] = Opcode::Xor {
+        lhs: 4,
+        rhs: 5,
+        output: 7,
+        bits: 255,
+    };
+    assert_eq!(
+        AcirCircuit::new(9, vec![3], opcodes),
+        Err(Error::InvalidAcirOpcode { opcode: 6 })
+    );
+
+    assert_eq!(
+        AcirCircuit::new(9, vec![9], program()),
+        Err(Error::CircuitInputsNotFound {
+            expected: 9,
+            provided: 10
+        })
+    );
+}
@@ -0,0 +1,107 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_plonk::prelude::*;
+use ff::Field;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+#[derive(Default)]
+pub struct TestCircuit {
+    a: BlsScalar,
+    b: BlsScalar,
+}
+
+impl Circuit for TestCircuit {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer,
+    {
+        let a = composer.append_public(self.a);
+        let b = composer.append_public(self.b);
+        let c = composer.gate_add(Constraint::new().left(2).right(1).a(a).b(b));
+        composer.assert_equal_constant(c, 0, Some(self.a.double() + self.b));
+
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+pub struct OtherCircuit;
+
+impl Circuit for OtherCircuit {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer,
+    {
+        composer.component_range::<8>(Builder::ONE);
+
+        Ok(())
+    }
+}
+
+#[test]
+fn bundle() {
+    let label = b"bundle";
+    let mut rng = StdRng::seed_from_u64(0xb0d1e);
+    let pp = PublicParameters::setup(1 << 5, &mut rng)
+        .expect("Creation of public parameter shouldn't fail");
+    let (prover, verifier) = Compiler::compile::<TestCircuit>(&pp, label)
+        .expect("Circuit should compile");
+    let (_, other) = Compiler::compile::<OtherCircuit>(&pp, label)
+        .expect("Circuit should compile");
+
+    let circuit = TestCircuit {
+        a: BlsScalar::random(&mut rng),
+        b: BlsScalar::random(&mut rng),
+    };
+    let bundle = prover
+        .prove_bundle(&mut rng, &circuit)
+        .expect("Proof creation of satisfied circuit should pass");
+
+    assert_eq!(bundle.fingerprint(), &verifier.fingerprint());
+    assert_eq!(bundle.public_inputs().len(), 3);
+
+    let bundle = ProofBundle::from_slice(&bundle.to_bytes())
+        .expect("Deserialization of a bundle should pass");
+    verifier
+        .verify_bundle(&bundle)
+        .expect("Verification of a bundle should pass");
+
+    // another circuit
+    assert_eq!(
+        other.verify_bundle(&bundle),
+        Err(Error::FingerprintMismatch)
+    );
+
+    // reordered public inputs
+    let mut public_inputs = bundle.public_inputs().to_vec();
+    public_inputs.swap(0, 1);
+    let reordered = ProofBundle::new(
+        bundle.proof().clone(),
+        public_inputs,
+        verifier.fingerprint(),
+    );
+    assert_eq!(
+        verifier.verify_bundle(&reordered),
+        Err(Error::ProofVerificationError)
+    );
+
+    // unsupported version
+    let mut bytes = bundle.to_bytes();
+    bytes[0] = 0xff;
+    assert_eq!(
+        ProofBundle::from_slice(&bytes),
+        Err(Error::UnsupportedVersion { version: 0xff })
+    );
+
+    // truncated public inputs
+    let bytes = bundle.to_bytes();
+    assert_eq!(
+        ProofBundle::from_slice(&bytes[..bytes.len() - 1]),
+        Err(Error::NotEnoughBytes)
+    );
+}
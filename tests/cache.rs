@@ -0,0 +1,254 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_plonk::prelude::*;
+use ff::Field;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+#[derive(Default)]
+pub struct TestCircuit {
+    a: BlsScalar,
+    b: BlsScalar,
+    c: BlsScalar,
+}
+
+impl Circuit for TestCircuit {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer,
+    {
+        let a = composer.append_witness(self.a);
+        let b = composer.append_witness(self.b);
+
+        let constraint = Constraint::new().mult(1).a(a).b(b);
+        let c = composer.gate_mul(constraint);
+        let expected = composer.append_public(self.c);
+        composer.assert_equal(c, expected);
+
+        Ok(())
+    }
+}
+
+// The gates of `TestCircuit`, with another multiplication selector
+#[derive(Default)]
+pub struct SameShapeCircuit {
+    a: BlsScalar,
+    b: BlsScalar,
+    c: BlsScalar,
+}
+
+impl Circuit for SameShapeCircuit {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer,
+    {
+        let a = composer.append_witness(self.a);
+        let b = composer.append_witness(self.b);
+
+        let constraint = Constraint::new().mult(2).a(a).b(b);
+        let c = composer.gate_mul(constraint);
+        let expected = composer.append_public(self.c);
+        composer.assert_equal(c, expected);
+
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+pub struct OtherCircuit;
+
+impl Circuit for OtherCircuit {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer,
+    {
+        composer.component_range::<8>(Builder::ONE);
+
+        Ok(())
+    }
+}
+
+#[test]
+fn cache() {
+    let label = b"cache";
+    let mut rng = StdRng::seed_from_u64(0xcac);
+    let pp = PublicParameters::setup(1 << 5, &mut rng)
+        .expect("Creation of public parameter shouldn't fail");
+    let (prover, verifier) = Compiler::compile::<TestCircuit>(&pp, label)
+        .expect("Circuit should compile");
+
+    // the prover and the verifier share the hash of their circuit
+    assert_eq!(prover.circuit_hash(), verifier.circuit_hash());
+    let (other, _) = Compiler::compile::<OtherCircuit>(&pp, label)
+        .expect("Circuit should compile");
+    assert_ne!(prover.circuit_hash(), other.circuit_hash());
+
+    // and the fingerprint of their verifier key
+    assert_eq!(prover.fingerprint(), verifier.fingerprint());
+    assert_ne!(prover.fingerprint(), other.fingerprint());
+
+    let mut prover_bytes = Vec::new();
+    prover.to_writer(&mut prover_bytes).unwrap();
+    let mut verifier_bytes = Vec::new();
+    verifier.to_writer(&mut verifier_bytes).unwrap();
+
+    let prover = Prover::from_reader(prover_bytes.as_slice())
+        .expect("Cached prover should be read");
+    let verifier = Verifier::from_reader(verifier_bytes.as_slice())
+        .expect("Cached verifier should be read");
+
+    let a = BlsScalar::random(&mut rng);
+    let b = BlsScalar::random(&mut rng);
+    let circuit = TestCircuit { a, b, c: a * b };
+    let (proof, pi) = prover
+        .prove(&mut rng, &circuit)
+        .expect("Proof creation of satisfied circuit should succeed");
+    verifier
+        .verify(&proof, &pi)
+        .expect("Verification with the cached verifier should succeed");
+
+    // unsupported version
+    let mut bytes = prover_bytes.clone();
+    bytes[0] = 0xff;
+    assert!(matches!(
+        Prover::from_reader(bytes.as_slice()),
+        Err(Error::UnsupportedVersion { version: 0xff })
+    ));
+
+    // corrupted content
+    let mut bytes = prover_bytes.clone();
+    let middle = bytes.len() / 2;
+    bytes[middle] ^= 1;
+    assert_eq!(
+        Prover::from_reader(bytes.as_slice()).err(),
+        Some(Error::InvalidChecksum)
+    );
+
+    // truncated content
+    let bytes = &verifier_bytes[..verifier_bytes.len() - 1];
+    assert!(matches!(
+        Verifier::from_reader(bytes),
+        Err(Error::IoError(std::io::ErrorKind::UnexpectedEof))
+    ));
+}
+
+#[test]
+fn mixed_keys() {
+    let label = b"mixed_keys";
+    let mut rng = StdRng::seed_from_u64(0xd1c);
+    let pp = PublicParameters::setup(1 << 5, &mut rng)
+        .expect("Creation of public parameter shouldn't fail");
+    let (prover, _) = Compiler::compile::<TestCircuit>(&pp, label)
+        .expect("Circuit should compile");
+    let (other, _) = Compiler::compile::<OtherCircuit>(&pp, label)
+        .expect("Circuit should compile");
+
+    // the lengths of the label, prover key, commit key and verifier key
+    // prefix the serialized prover
+    let len = |bytes: &[u8], i: usize| {
+        let len = bytes[8 * i..8 * i + 8].try_into().unwrap();
+        u64::from_be_bytes(len) as usize
+    };
+    let verifier_key = |bytes: &[u8]| {
+        let start = 48 + len(bytes, 0) + len(bytes, 1) + len(bytes, 2);
+        start..start + len(bytes, 3)
+    };
+
+    // the verifier key of the other circuit paired with the prover key
+    let mut bytes = prover.to_bytes();
+    let other_bytes = other.to_bytes();
+    let range = verifier_key(&bytes);
+    bytes[range].copy_from_slice(&other_bytes[verifier_key(&other_bytes)]);
+
+    assert!(matches!(
+        Prover::try_from_bytes(&bytes),
+        Err(Error::CircuitHashMismatch)
+    ));
+    assert!(Prover::try_from_bytes(prover.to_bytes()).is_ok());
+}
+
+#[test]
+fn same_shape_keys() {
+    let label = b"same_shape_keys";
+    let mut rng = StdRng::seed_from_u64(0x5a5);
+    let pp = PublicParameters::setup(1 << 5, &mut rng)
+        .expect("Creation of public parameter shouldn't fail");
+    let (prover, _) = Compiler::compile::<TestCircuit>(&pp, label)
+        .expect("Circuit should compile");
+    let (other, other_verifier) =
+        Compiler::compile::<SameShapeCircuit>(&pp, label)
+            .expect("Circuit should compile");
+
+    assert_eq!(prover.constraints(), other.constraints());
+    assert_ne!(prover.circuit_hash(), other.circuit_hash());
+
+    // the lengths of the label, prover key, commit key and verifier key
+    // prefix the serialized prover
+    let len = |bytes: &[u8], i: usize| {
+        let len = bytes[8 * i..8 * i + 8].try_into().unwrap();
+        u64::from_be_bytes(len) as usize
+    };
+    let prover_key = |bytes: &[u8]| {
+        let start = 48 + len(bytes, 0);
+        start..start + len(bytes, 1)
+    };
+    let verifier_key = |bytes: &[u8]| {
+        let start = 48 + len(bytes, 0) + len(bytes, 1) + len(bytes, 2);
+        start..start + len(bytes, 3)
+    };
+
+    prover
+        .verify_integrity()
+        .expect("The prover key should match its verifier key");
+
+    // the prover key paired with the verifier key of the other circuit, with
+    // the circuit hash of the other circuit embedded, is only caught by the
+    // integrity check
+    let mut bytes = prover.to_bytes();
+    let other_bytes = other.to_bytes();
+    let range = verifier_key(&bytes);
+    bytes[range].copy_from_slice(&other_bytes[verifier_key(&other_bytes)]);
+    let range = prover_key(&bytes);
+    bytes[range.end - 32..range.end].copy_from_slice(&other.circuit_hash());
+
+    let forged = Prover::try_from_bytes(&bytes)
+        .expect("The embedded circuit hash matches the verifier key");
+    assert_eq!(forged.verify_integrity(), Err(Error::CircuitHashMismatch));
+
+    // the prover key serialized without its circuit hash is still read
+    let bytes = prover.to_bytes();
+    let range = prover_key(&bytes);
+    let mut legacy = bytes[..range.end - 32].to_vec();
+    legacy.extend_from_slice(&bytes[range.end..]);
+    legacy[8..16].copy_from_slice(&(range.len() as u64 - 32).to_be_bytes());
+
+    Prover::try_from_bytes(&legacy)
+        .expect("A prover without circuit hash should be read")
+        .verify_integrity()
+        .expect("The prover key should match its verifier key");
+
+    // and paired with the verifier key of the other circuit
+    let range = verifier_key(&legacy);
+    legacy[range].copy_from_slice(&other_bytes[verifier_key(&other_bytes)]);
+
+    let mixed = Prover::try_from_bytes(&legacy)
+        .expect("A prover without circuit hash should be read");
+    assert_eq!(mixed.verify_integrity(), Err(Error::CircuitHashMismatch));
+
+    // the proofs of a circuit don't verify with the verifier of the other
+    let a = BlsScalar::random(&mut rng);
+    let b = BlsScalar::random(&mut rng);
+    let circuit = TestCircuit { a, b, c: a * b };
+    let (proof, pi) = prover
+        .prove(&mut rng, &circuit)
+        .expect("Proof creation of satisfied circuit should pass");
+
+    assert_eq!(
+        other_verifier.verify(&proof, &pi),
+        Err(Error::ProofVerificationError)
+    );
+}
@@ -0,0 +1,266 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use std::ffi::CStr;
+use std::ptr;
+
+use dusk_bytes::Serializable;
+use dusk_plonk::capi::*;
+use dusk_plonk::prelude::*;
+use dusk_plonk::zkinterface::ZkifCircuit;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+// a · b + c = d, with c and d public
+#[derive(Default)]
+struct TestCircuit {
+    a: BlsScalar,
+    b: BlsScalar,
+    c: BlsScalar,
+    d: BlsScalar,
+}
+
+impl TestCircuit {
+    fn new(a: u64, b: u64, c: u64, d: u64) -> Self {
+        Self {
+            a: BlsScalar::from(a),
+            b: BlsScalar::from(b),
+            c: BlsScalar::from(c),
+            d: BlsScalar::from(d),
+        }
+    }
+
+    fn to_zkinterface(&self) -> Vec<u8> {
+        let mut builder = Builder::initialized();
+        self.circuit(&mut builder)
+            .expect("Synthesis of the circuit shouldn't fail");
+
+        builder
+            .to_zkinterface()
+            .expect("the arithmetic circuit should be written")
+    }
+}
+
+impl Circuit for TestCircuit {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer,
+    {
+        let a = composer.append_witness(self.a);
+        let b = composer.append_witness(self.b);
+        let c = composer.append_public(self.c);
+
+        let constraint = Constraint::new().mult(1).a(a).b(b).fourth(1).d(c);
+        let d = composer.gate_mul(constraint);
+        composer.assert_equal_constant(d, 0, Some(self.d));
+
+        Ok(())
+    }
+}
+
+/// Load the keys of the test circuit through the C bindings
+fn keys() -> (*mut PlonkProver, *mut PlonkVerifier) {
+    let rng = &mut StdRng::seed_from_u64(0xc4b1);
+    let pp = PublicParameters::setup(1 << 6, rng)
+        .expect("Creation of public parameter shouldn't fail");
+
+    let circuit = TestCircuit::new(0, 0, 0, 0).to_zkinterface();
+    let circuit = ZkifCircuit::from_bytes(&circuit)
+        .expect("the zkInterface stream should be read");
+    let (prover, verifier) =
+        Compiler::compile_with_circuit(&pp, b"capi", &circuit)
+            .expect("the circuit should compile");
+
+    let prover = prover.to_bytes();
+    let verifier = verifier.to_bytes();
+
+    let mut p = ptr::null_mut();
+    let mut v = ptr::null_mut();
+    unsafe {
+        assert_eq!(
+            plonk_prover_load(prover.as_ptr(), prover.len(), &mut p),
+            PlonkStatus::Ok
+        );
+        assert_eq!(
+            plonk_verifier_load(verifier.as_ptr(), verifier.len(), &mut v),
+            PlonkStatus::Ok
+        );
+    }
+
+    (p, v)
+}
+
+#[test]
+fn capi_prove_verify() {
+    let (prover, verifier) = keys();
+    let circuit = TestCircuit::new(3, 5, 7, 22).to_zkinterface();
+
+    let mut proof = [0u8; PLONK_PROOF_SIZE];
+    let mut public_inputs = [0u8; 2 * PLONK_SCALAR_SIZE];
+
+    unsafe {
+        // the size of the public inputs is returned with a small buffer
+        let mut len = PLONK_SCALAR_SIZE;
+        assert_eq!(
+            plonk_prove(
+                prover,
+                circuit.as_ptr(),
+                circuit.len(),
+                proof.as_mut_ptr(),
+                public_inputs.as_mut_ptr(),
+                &mut len,
+            ),
+            PlonkStatus::BufferTooSmall
+        );
+        assert_eq!(len, public_inputs.len());
+
+        assert_eq!(
+            plonk_prove(
+                prover,
+                circuit.as_ptr(),
+                circuit.len(),
+                proof.as_mut_ptr(),
+                public_inputs.as_mut_ptr(),
+                &mut len,
+            ),
+            PlonkStatus::Ok
+        );
+        assert_eq!(public_inputs[..32], BlsScalar::from(7).to_bytes());
+        assert_eq!(public_inputs[32..], BlsScalar::from(22).to_bytes());
+
+        assert_eq!(
+            plonk_verify(
+                verifier,
+                proof.as_ptr(),
+                proof.len(),
+                public_inputs.as_ptr(),
+                len,
+            ),
+            PlonkStatus::Ok
+        );
+
+        public_inputs[32..].copy_from_slice(&BlsScalar::from(23).to_bytes());
+        assert_eq!(
+            plonk_verify(
+                verifier,
+                proof.as_ptr(),
+                proof.len(),
+                public_inputs.as_ptr(),
+                len,
+            ),
+            PlonkStatus::VerificationFailed
+        );
+
+        // the unsatisfied circuit isn't proven
+        let circuit = TestCircuit::new(3, 5, 7, 23).to_zkinterface();
+        assert_eq!(
+            plonk_prove(
+                prover,
+                circuit.as_ptr(),
+                circuit.len(),
+                proof.as_mut_ptr(),
+                public_inputs.as_mut_ptr(),
+                &mut len,
+            ),
+            PlonkStatus::ProofFailed
+        );
+
+        plonk_prover_free(prover);
+        plonk_verifier_free(verifier);
+    }
+}
+
+#[test]
+fn capi_errors() {
+    let (prover, verifier) = keys();
+    let circuit = TestCircuit::new(3, 5, 7, 22).to_zkinterface();
+
+    let mut proof = [0u8; PLONK_PROOF_SIZE];
+    let mut len = 0;
+
+    unsafe {
+        let mut p = ptr::null_mut();
+        assert_eq!(
+            plonk_prover_load(ptr::null(), 1, &mut p),
+            PlonkStatus::NullPointer
+        );
+        assert_eq!(
+            plonk_prover_load([0u8; 4].as_ptr(), 4, &mut p),
+            PlonkStatus::InvalidKey
+        );
+        assert!(p.is_null());
+
+        assert_eq!(
+            plonk_prove(
+                prover,
+                circuit.as_ptr(),
+                circuit.len() - 1,
+                proof.as_mut_ptr(),
+                ptr::null_mut(),
+                &mut len,
+            ),
+            PlonkStatus::InvalidCircuit
+        );
+        assert_eq!(
+            plonk_prove(
+                prover,
+                circuit.as_ptr(),
+                circuit.len(),
+                proof.as_mut_ptr(),
+                ptr::null_mut(),
+                ptr::null_mut(),
+            ),
+            PlonkStatus::NullPointer
+        );
+
+        assert_eq!(
+            plonk_verify(verifier, proof.as_ptr(), 4, ptr::null(), 0),
+            PlonkStatus::InvalidProof
+        );
+
+        let mut public_inputs = [0u8; 2 * PLONK_SCALAR_SIZE];
+        let mut len = public_inputs.len();
+        assert_eq!(
+            plonk_prove(
+                prover,
+                circuit.as_ptr(),
+                circuit.len(),
+                proof.as_mut_ptr(),
+                public_inputs.as_mut_ptr(),
+                &mut len,
+            ),
+            PlonkStatus::Ok
+        );
+        assert_eq!(
+            plonk_verify(
+                verifier,
+                proof.as_ptr(),
+                proof.len(),
+                [0u8; 4].as_ptr(),
+                4,
+            ),
+            PlonkStatus::InvalidPublicInputs
+        );
+
+        let message = CStr::from_ptr(plonk_status_message(PlonkStatus::Panic));
+        assert_eq!(message.to_str(), Ok("internal panic"));
+
+        plonk_prover_free(prover);
+        plonk_verifier_free(verifier);
+        plonk_prover_free(ptr::null_mut());
+    }
+}
+
+#[test]
+fn capi_header() {
+    let header = include_str!("../include/dusk_plonk.h");
+
+    assert!(
+        header.contains(&format!("#define PLONK_PROOF_SIZE {}", Proof::SIZE))
+    );
+    assert!(header
+        .contains(&format!("#define PLONK_SCALAR_SIZE {}", BlsScalar::SIZE)));
+}
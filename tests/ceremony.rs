@@ -0,0 +1,100 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_bytes::Serializable;
+use dusk_plonk::commitment_scheme::Contribution;
+use dusk_plonk::prelude::*;
+use ff::Field;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+#[derive(Default)]
+pub struct TestCircuit {
+    a: BlsScalar,
+    b: BlsScalar,
+    c: BlsScalar,
+}
+
+impl Circuit for TestCircuit {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer,
+    {
+        let a = composer.append_witness(self.a);
+        let b = composer.append_witness(self.b);
+
+        let constraint = Constraint::new().mult(1).a(a).b(b).public(-self.c);
+        composer.append_gate(constraint);
+
+        Ok(())
+    }
+}
+
+#[test]
+fn ceremony() {
+    let label = b"ceremony";
+    let mut rng = StdRng::seed_from_u64(0xce4);
+
+    let mut pp = PublicParameters::ceremony(1 << 4)
+        .expect("Creation of the ceremony parameters shouldn't fail");
+    pp.verify_contributions(&[])
+        .expect("The initial parameters should be valid");
+
+    let contributions: Vec<Contribution> =
+        (0..3).map(|_| pp.contribute(&mut rng)).collect();
+
+    pp.verify_contributions(&contributions)
+        .expect("A valid chain of contributions should pass");
+
+    // the contributions can be published
+    contributions.iter().for_each(|contribution| {
+        let bytes = contribution.to_bytes();
+        assert_eq!(Contribution::from_bytes(&bytes), Ok(*contribution));
+    });
+
+    let (prover, verifier) = Compiler::compile::<TestCircuit>(&pp, label)
+        .expect("Circuit should compile");
+
+    let a = BlsScalar::random(&mut rng);
+    let b = BlsScalar::random(&mut rng);
+    let circuit = TestCircuit { a, b, c: a * b };
+    let (proof, pi) = prover
+        .prove(&mut rng, &circuit)
+        .expect("Proof creation of satisfied circuit should pass");
+
+    verifier
+        .verify(&proof, &pi)
+        .expect("Verification with the ceremony parameters should pass");
+}
+
+#[test]
+fn ceremony_invalid() {
+    let mut rng = StdRng::seed_from_u64(0xce5);
+
+    let mut pp = PublicParameters::ceremony(1 << 3)
+        .expect("Creation of the ceremony parameters shouldn't fail");
+    let contributions: Vec<Contribution> =
+        (0..3).map(|_| pp.contribute(&mut rng)).collect();
+
+    // a contribution is missing
+    let result = pp.verify_contributions(&contributions[..2]);
+    assert_eq!(result, Err(Error::InvalidContribution { index: 2 }));
+
+    let result = pp.verify_contributions(&contributions[1..]);
+    assert_eq!(result, Err(Error::InvalidContribution { index: 0 }));
+
+    // a contribution is replaced by the contribution of another ceremony
+    let mut other = PublicParameters::ceremony(1 << 3)
+        .expect("Creation of the ceremony parameters shouldn't fail");
+    let mut forged = contributions.clone();
+    forged[1] = other.contribute(&mut rng);
+    let result = pp.verify_contributions(&forged);
+    assert_eq!(result, Err(Error::InvalidContribution { index: 1 }));
+
+    // the parameters are not the outcome of the contributions
+    let result = other.verify_contributions(&contributions);
+    assert_eq!(result, Err(Error::InvalidContribution { index: 3 }));
+}
@@ -0,0 +1,212 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_plonk::circom::{self, CircomCircuit, R1cs};
+use dusk_plonk::prelude::*;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+/// Order of the scalar field of BLS12-381
+const BLS12_381: [u8; 32] = [
+    0x01, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xfe, 0x5b, 0xfe, 0xff,
+    0x02, 0xa4, 0xbd, 0x53, 0x05, 0xd8, 0xa1, 0x09, 0x08, 0xd8, 0x39, 0x33,
+    0x48, 0x7d, 0x9d, 0x29, 0x53, 0xa7, 0xed, 0x73,
+];
+
+/// Order of the scalar field of BN254, the default of circom
+const BN254: [u8; 32] = [
+    0x01, 0x00, 0x00, 0xf0, 0x93, 0xf5, 0xe1, 0x43, 0x91, 0x70, 0xb9, 0x79,
+    0x48, 0xe8, 0x33, 0x28, 0x5d, 0x58, 0x81, 0x81, 0xb6, 0x45, 0x50, 0xb8,
+    0x29, 0xa0, 0x31, 0xe1, 0x72, 0x4e, 0x64, 0x30,
+];
+
+type Combination = Vec<(u32, i64)>;
+
+fn scalar(value: i64) -> BlsScalar {
+    match value < 0 {
+        true => -BlsScalar::from(value.unsigned_abs()),
+        false => BlsScalar::from(value as u64),
+    }
+}
+
+fn file(magic: &[u8], version: u32, sections: &[(u32, Vec<u8>)]) -> Vec<u8> {
+    let mut bytes = magic.to_vec();
+    bytes.extend(version.to_le_bytes());
+    bytes.extend((sections.len() as u32).to_le_bytes());
+    for (kind, section) in sections {
+        bytes.extend(kind.to_le_bytes());
+        bytes.extend((section.len() as u64).to_le_bytes());
+        bytes.extend(section);
+    }
+
+    bytes
+}
+
+fn field(prime: &[u8; 32]) -> Vec<u8> {
+    let mut bytes = 32u32.to_le_bytes().to_vec();
+    bytes.extend(prime);
+    bytes
+}
+
+/// Encode a `.r1cs` file of `wires` wires, with one public output and one
+/// public input
+fn r1cs(
+    prime: &[u8; 32],
+    wires: u32,
+    constraints: &[[Combination; 3]],
+) -> Vec<u8> {
+    let mut header = field(prime);
+    for n in [wires, 1, 1, 2] {
+        header.extend(n.to_le_bytes());
+    }
+    header.extend(u64::from(wires).to_le_bytes());
+    header.extend((constraints.len() as u32).to_le_bytes());
+
+    let mut body = Vec::new();
+    for constraint in constraints {
+        for combination in constraint {
+            body.extend((combination.len() as u32).to_le_bytes());
+            for (wire, coeff) in combination {
+                body.extend(wire.to_le_bytes());
+                body.extend(scalar(*coeff).to_bytes());
+            }
+        }
+    }
+
+    file(b"r1cs", 1, &[(1, header), (2, body)])
+}
+
+fn wtns(prime: &[u8; 32], witness: &[i64]) -> Vec<u8> {
+    let mut header = field(prime);
+    header.extend((witness.len() as u32).to_le_bytes());
+
+    let body = witness
+        .iter()
+        .flat_map(|value| scalar(*value).to_bytes())
+        .collect();
+
+    file(b"wtns", 2, &[(1, header), (2, body)])
+}
+
+/// The wires are `[1, out, x, a, b, t]`, with the public output `out`, the
+/// public input `x`, and the private inputs `a` and `b`
+fn constraints() -> Vec<[Combination; 3]> {
+    vec![
+        // a · b = t
+        [vec![(3, 1)], vec![(4, 1)], vec![(5, 1)]],
+        // (a + 2) · (b - x) = out - t + 3x + a
+        [
+            vec![(3, 1), (0, 2)],
+            vec![(4, 1), (2, -1)],
+            vec![(1, 1), (5, -1), (2, 3), (3, 1)],
+        ],
+        // 0 = out + x + a + b - t - 16
+        [
+            vec![],
+            vec![],
+            vec![(1, 1), (2, 1), (3, 1), (4, 1), (5, -1), (0, -16)],
+        ],
+        // 3 · a = b + 4
+        [vec![(0, 3)], vec![(3, 1)], vec![(4, 1), (0, 4)]],
+    ]
+}
+
+#[test]
+fn circom_circuit() {
+    let rng = &mut StdRng::seed_from_u64(0xc12c);
+    let r1cs = R1cs::from_bytes(&r1cs(&BLS12_381, 6, &constraints()))
+        .expect("the r1cs file should be read");
+    assert_eq!(r1cs.wires(), 6);
+    assert_eq!(r1cs.public_inputs(), 2);
+    assert_eq!(r1cs.constraints(), 4);
+
+    let pp = PublicParameters::setup(1 << 6, rng)
+        .expect("Creation of public parameter shouldn't fail");
+    let circuit = CircomCircuit::new(r1cs);
+    let (prover, verifier) =
+        Compiler::compile_with_circuit(&pp, b"circom", &circuit)
+            .expect("the circom circuit should compile");
+
+    // a = 3, b = 5, x = 2, t = 15, out = 5 · 3 + 15 - 6 - 3
+    let witness =
+        circom::read_witness(&wtns(&BLS12_381, &[1, 21, 2, 3, 5, 15]))
+            .expect("the witness file should be read");
+    let satisfied = circuit
+        .clone()
+        .with_witness(witness)
+        .expect("the witness should fit the circuit");
+    assert_eq!(satisfied.public_inputs(), [21, 2].map(BlsScalar::from));
+
+    let mut builder = Builder::initialized();
+    satisfied
+        .circuit(&mut builder)
+        .expect("Synthesis of the circuit shouldn't fail");
+    builder
+        .check_satisfied()
+        .expect("The gates of the circuit should be satisfied");
+
+    let (proof, public_inputs) = prover
+        .prove(rng, &satisfied)
+        .expect("the satisfied circuit should be proven");
+    assert_eq!(public_inputs, satisfied.public_inputs());
+    verifier
+        .verify(&proof, &public_inputs)
+        .expect("the proof should verify");
+
+    // b = 6 breaks every constraint
+    let witness =
+        circom::read_witness(&wtns(&BLS12_381, &[1, 21, 2, 3, 6, 15]))
+            .expect("the witness file should be read");
+    let unsatisfied = circuit
+        .clone()
+        .with_witness(witness)
+        .expect("the witness should fit the circuit");
+    prover
+        .prove(rng, &unsatisfied)
+        .expect_err("the unsatisfied circuit shouldn't be proven");
+
+    assert_eq!(
+        circuit.with_witness(vec![BlsScalar::one(); 5]),
+        Err(Error::CircuitInputsNotFound {
+            expected: 6,
+            provided: 5
+        })
+    );
+}
+
+#[test]
+fn invalid_files() {
+    let bytes = r1cs(&BLS12_381, 6, &constraints());
+    assert_eq!(
+        R1cs::from_bytes(&bytes[..bytes.len() - 1]),
+        Err(Error::InvalidCircomFile)
+    );
+    assert_eq!(
+        R1cs::from_bytes(&wtns(&BLS12_381, &[1])),
+        Err(Error::InvalidCircomFile)
+    );
+    assert_eq!(
+        R1cs::from_bytes(&r1cs(&BN254, 6, &constraints())),
+        Err(Error::UnsupportedCircomPrime)
+    );
+
+    // a wire out of the circuit
+    let constraints = vec![[vec![(6, 1)], vec![(4, 1)], vec![(5, 1)]]];
+    assert_eq!(
+        R1cs::from_bytes(&r1cs(&BLS12_381, 6, &constraints)),
+        Err(Error::InvalidCircomFile)
+    );
+
+    assert_eq!(
+        circom::read_witness(&wtns(&BN254, &[1, 2])),
+        Err(Error::UnsupportedCircomPrime)
+    );
+    let bytes = wtns(&BLS12_381, &[1, 2]);
+    assert_eq!(
+        circom::read_witness(&bytes[..bytes.len() - 1]),
+        Err(Error::InvalidCircomFile)
+    );
+}
@@ -4,11 +4,44 @@
 //
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use dusk_bytes::Serializable;
 use dusk_plonk::prelude::*;
 use rand::{CryptoRng, RngCore};
 
+// Metrics of the proof of a satisfied circuit, to compare the gadgets before
+// and after a change. Print them with `println!("{metrics}")` and run the
+// tests with `--nocapture`.
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct GadgetMetrics {
+    pub gates: usize,
+    pub public_inputs: usize,
+    pub proof_size: usize,
+    pub prove_time: Duration,
+    pub verify_time: Duration,
+}
+
+impl fmt::Display for GadgetMetrics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "gates: {}, public inputs: {}, proof size: {} bytes, \
+            prove time: {:?}, verify time: {:?}",
+            self.gates,
+            self.public_inputs,
+            self.proof_size,
+            self.prove_time,
+            self.verify_time
+        )
+    }
+}
+
 // Check that proof creation and verification of a satisfied circuit passes
-// and that the public inputs are as expected
+// and that the public inputs are as expected, returning the metrics of the
+// proof
 pub(crate) fn check_satisfied_circuit<C, R>(
     prover: &Prover,
     verifier: &Verifier,
@@ -16,17 +49,38 @@ pub(crate) fn check_satisfied_circuit<C, R>(
     circuit: &C,
     rng: &mut R,
     msg: &str,
-) where
+) -> GadgetMetrics
+where
     C: Circuit,
     R: RngCore + CryptoRng,
 {
+    let mut builder = Builder::initialized();
+    circuit
+        .circuit(&mut builder)
+        .expect("Synthesis of a valid circuit shouldn't fail");
+    builder
+        .check_satisfied()
+        .expect("The gates of a valid circuit should be satisfied");
+
+    let start = Instant::now();
     let (proof, pi_circuit) = prover
         .prove(rng, circuit)
         .expect("Prover for valid circuit shouldn't fail");
+    let prove_time = start.elapsed();
 
     assert_eq!(*pi_expected, pi_circuit);
 
+    let start = Instant::now();
     verifier.verify(&proof, &pi_expected).expect(msg);
+    let verify_time = start.elapsed();
+
+    GadgetMetrics {
+        gates: prover.constraints(),
+        public_inputs: pi_circuit.len(),
+        proof_size: proof.to_bytes().len(),
+        prove_time,
+        verify_time,
+    }
 }
 
 // Check that proof creation and verification of a satisfied circuit fails
@@ -69,3 +123,41 @@ pub(crate) fn check_unsatisfied_circuit<C, R>(
 {
     prover.prove(rng, circuit).expect_err(msg);
 }
+
+// Check that the gates of a circuit with a deliberately bad witness are
+// unsatisfied first at a gate labeled with `label`, and that the proof
+// creation fails
+#[allow(dead_code)]
+pub(crate) fn expect_unsatisfied<C, R>(
+    prover: &Prover,
+    circuit: &C,
+    rng: &mut R,
+    label: &'static str,
+) where
+    C: Circuit,
+    R: RngCore + CryptoRng,
+{
+    let mut builder = Builder::initialized();
+    circuit
+        .circuit(&mut builder)
+        .expect("Synthesis of the circuit shouldn't fail");
+
+    match builder.check_satisfied() {
+        Err(Error::UnsatisfiedConstraint { gate, label: found }) => assert_eq!(
+            found,
+            Some(label),
+            "the gate {} should be labeled `{}`:\n{}",
+            gate,
+            label,
+            builder.dump_gates(gate.saturating_sub(1)..gate + 2),
+        ),
+        result => panic!(
+            "the gate labeled `{}` should be unsatisfied, got {:?}",
+            label, result
+        ),
+    }
+
+    prover
+        .prove(rng, circuit)
+        .expect_err("Proof creation of an unsatisfied circuit should fail");
+}
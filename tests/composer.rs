@@ -4,6 +4,8 @@
 //
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
+use dusk_bytes::Serializable;
+use dusk_plonk::composer::Blinding;
 use dusk_plonk::prelude::*;
 use rand::rngs::StdRng;
 use rand::SeedableRng;
@@ -127,3 +129,485 @@ fn circuit_with_all_gates() {
         .verify(&proof, &public_inputs)
         .expect("failed to verify proof");
 }
+
+#[test]
+fn prove_with_stats() {
+    let rng = &mut StdRng::seed_from_u64(0x57a);
+
+    #[derive(Default)]
+    pub struct RangeCircuit {
+        a: BlsScalar,
+    }
+
+    impl Circuit for RangeCircuit {
+        fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+        where
+            C: Composer,
+        {
+            let a = composer.append_witness(self.a);
+            composer.component_range::<64>(a);
+
+            Ok(())
+        }
+    }
+
+    let label = b"stats";
+    let pp = PublicParameters::setup(1 << 7, rng).expect("failed to create pp");
+    let (prover, verifier) = Compiler::compile::<RangeCircuit>(&pp, label)
+        .expect("failed to compile circuit");
+
+    let circuit = RangeCircuit {
+        a: BlsScalar::from(u64::MAX),
+    };
+    let (proof, public_inputs, stats) = prover
+        .prove_with_stats(rng, &circuit)
+        .expect("failed to prove");
+
+    verifier
+        .verify(&proof, &public_inputs)
+        .expect("failed to verify proof");
+
+    // wires, permutation, public inputs and quotient
+    assert_eq!(stats.ffts(), 4 + 1 + 1 + 9);
+    // wires, permutation, split quotient and the two opening witnesses
+    assert_eq!(stats.msm_sizes().len(), 4 + 1 + 4 + 2);
+
+    // the blinded wire polynomials have `n + 2` coefficients
+    let n = stats.msm_sizes()[0] - 2;
+    assert!(n.is_power_of_two());
+    // the committed polynomials are of degree `n` plus the blinding terms
+    assert!(stats.msm_sizes().iter().all(|size| *size <= n + 8));
+
+    // the prover holds at least the `8n` evaluations of the quotient, the
+    // wires and the permutation
+    assert!(stats.peak_memory() >= 6 * 8 * n * BlsScalar::SIZE);
+    assert!(stats.total_time() > core::time::Duration::ZERO);
+    assert_eq!(stats.total_time(), stats.rounds().iter().sum());
+}
+
+#[test]
+fn compile_mixed_radix() {
+    let rng = &mut StdRng::seed_from_u64(0x3ad1);
+
+    #[derive(Default)]
+    pub struct ChainCircuit {
+        a: BlsScalar,
+    }
+
+    impl Circuit for ChainCircuit {
+        fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+        where
+            C: Composer,
+        {
+            let mut a = composer.append_witness(self.a);
+            for _ in 0..70 {
+                let constraint = Constraint::new().left(1).constant(1).a(a);
+                a = composer.gate_add(constraint);
+            }
+            let b = composer.append_public(self.a + BlsScalar::from(70));
+            composer.assert_equal(a, b);
+
+            Ok(())
+        }
+    }
+
+    let label = b"mixed-radix";
+    let pp = PublicParameters::setup(1 << 8, rng).expect("failed to create pp");
+    let (prover, verifier) =
+        Compiler::compile_mixed_radix::<ChainCircuit>(&pp, label)
+            .expect("failed to compile circuit");
+
+    let circuit = ChainCircuit {
+        a: BlsScalar::from(5),
+    };
+    let (proof, public_inputs, stats) = prover
+        .prove_with_stats(rng, &circuit)
+        .expect("failed to prove");
+
+    verifier
+        .verify(&proof, &public_inputs)
+        .expect("failed to verify proof");
+
+    // the blinded wire polynomials have `n + 2` coefficients
+    let n = stats.msm_sizes()[0] - 2;
+    assert_eq!(n, 3 << 5);
+
+    // the commit key converts to the lagrange basis of the domain
+    let (proof, public_inputs) = prover
+        .with_lagrange_bases()
+        .expect("failed to convert the commit key")
+        .prove(rng, &circuit)
+        .expect("failed to prove");
+    verifier
+        .verify(&proof, &public_inputs)
+        .expect("failed to verify proof");
+
+    // the proof doesn't verify over the power of two domain
+    let (_, verifier) = Compiler::compile::<ChainCircuit>(&pp, label)
+        .expect("failed to compile circuit");
+    verifier
+        .verify(&proof, &public_inputs)
+        .expect_err("verification over another domain should fail");
+}
+
+#[test]
+fn prove_with_precomputed_bases() {
+    let rng = &mut StdRng::seed_from_u64(0xba5e);
+
+    #[derive(Default)]
+    pub struct MulCircuit {
+        a: BlsScalar,
+        b: BlsScalar,
+    }
+
+    impl Circuit for MulCircuit {
+        fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+        where
+            C: Composer,
+        {
+            let a = composer.append_witness(self.a);
+            let b = composer.append_witness(self.b);
+            let constraint = Constraint::new().mult(1).a(a).b(b);
+            let c = composer.gate_mul(constraint);
+            let d = composer.append_public(self.a * self.b);
+            composer.assert_equal(c, d);
+
+            Ok(())
+        }
+    }
+
+    let label = b"precomputed-bases";
+    let pp = PublicParameters::setup(1 << 6, rng).expect("failed to create pp");
+    let (prover, verifier) = Compiler::compile::<MulCircuit>(&pp, label)
+        .expect("failed to compile circuit");
+
+    let circuit = MulCircuit {
+        a: BlsScalar::from(6),
+        b: BlsScalar::from(7),
+    };
+
+    let precomputed = prover.clone().with_precomputed_bases();
+    assert!(!prover.has_precomputed_bases());
+    assert!(precomputed.has_precomputed_bases());
+
+    let lagrange = prover
+        .clone()
+        .with_lagrange_bases()
+        .expect("failed to convert the commit key");
+    assert!(!prover.has_lagrange_bases());
+    assert!(lagrange.has_lagrange_bases());
+
+    // the proofs are identical with the same randomness
+    let (proof, public_inputs) = prover
+        .prove(&mut StdRng::seed_from_u64(0xbeef), &circuit)
+        .expect("failed to prove");
+
+    for prover in [precomputed, lagrange] {
+        let (precomputed_proof, _) = prover
+            .prove(&mut StdRng::seed_from_u64(0xbeef), &circuit)
+            .expect("failed to prove");
+
+        assert_eq!(proof, precomputed_proof);
+        verifier
+            .verify(&precomputed_proof, &public_inputs)
+            .expect("failed to verify proof");
+    }
+}
+
+#[test]
+fn verifier_cost() {
+    let rng = &mut StdRng::seed_from_u64(0xc057);
+
+    #[derive(Default)]
+    pub struct PublicSumCircuit {
+        a: BlsScalar,
+        b: BlsScalar,
+    }
+
+    impl Circuit for PublicSumCircuit {
+        fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+        where
+            C: Composer,
+        {
+            let a = composer.append_public(self.a);
+            let b = composer.append_public(self.b);
+            let constraint = Constraint::new().left(1).right(1).a(a).b(b);
+            composer.append_gate(constraint);
+
+            Ok(())
+        }
+    }
+
+    let label = b"cost";
+    let pp = PublicParameters::setup(1 << 5, rng).expect("failed to create pp");
+    let (_, verifier) = Compiler::compile::<PublicSumCircuit>(&pp, label)
+        .expect("failed to compile circuit");
+
+    let cost = verifier.cost();
+
+    assert_eq!(cost.proof_size(), Proof::SIZE);
+    assert_eq!(cost.public_inputs_size(), 2 * BlsScalar::SIZE);
+    assert_eq!(cost.g1_scalar_muls(), cost.msm_sizes().iter().sum());
+    assert_eq!(cost.pairings(), 2);
+    assert_eq!(cost.field_exponentiations(), 5 + 2);
+
+    // two pairings and at least one G1MSM call per multi-scalar multiplication
+    assert!(cost.eip2537_gas() > 2 * 32600 + 37700);
+}
+
+#[test]
+fn prove_many() {
+    let rng = &mut StdRng::seed_from_u64(0x3a7);
+
+    #[derive(Default)]
+    pub struct MulCircuit {
+        a: BlsScalar,
+        b: BlsScalar,
+        c: BlsScalar,
+    }
+
+    impl Circuit for MulCircuit {
+        fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+        where
+            C: Composer,
+        {
+            let a = composer.append_witness(self.a);
+            let b = composer.append_witness(self.b);
+
+            let c = composer.gate_mul(Constraint::new().mult(1).a(a).b(b));
+            composer.assert_equal_constant(c, 0, Some(self.c));
+
+            Ok(())
+        }
+    }
+
+    let label = b"prove_many";
+    let pp = PublicParameters::setup(1 << 5, rng).expect("failed to create pp");
+    let (prover, verifier) = Compiler::compile::<MulCircuit>(&pp, label)
+        .expect("failed to compile circuit");
+
+    let circuits: Vec<MulCircuit> = (1..=4u64)
+        .map(|i| MulCircuit {
+            a: BlsScalar::from(i),
+            b: BlsScalar::from(i + 1),
+            c: BlsScalar::from(i * (i + 1)),
+        })
+        .collect();
+
+    let proofs = prover
+        .prove_many(rng, &circuits)
+        .expect("failed to prove the circuits");
+    assert_eq!(proofs.len(), circuits.len());
+
+    for ((proof, public_inputs), circuit) in proofs.iter().zip(&circuits) {
+        assert_eq!(public_inputs, &vec![circuit.c]);
+        verifier
+            .verify(proof, public_inputs)
+            .expect("failed to verify proof");
+    }
+
+    // the proofs are blinded independently
+    assert_ne!(proofs[0].0, proofs[1].0);
+
+    // a single unsatisfied circuit fails the batch
+    let mut circuits = circuits;
+    circuits[2].c += BlsScalar::one();
+    prover
+        .prove_many(rng, &circuits)
+        .expect_err("an unsatisfied circuit should fail the batch");
+}
+
+#[test]
+fn prove_async_and_progress() {
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake, Waker};
+
+    struct NoopWaker;
+
+    impl Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    // poll the future to completion, calling `on_pending` every time it yields
+    fn run<F: Future>(future: F, mut on_pending: impl FnMut()) -> F::Output {
+        let waker = Waker::from(Arc::new(NoopWaker));
+        let mut cx = Context::from_waker(&waker);
+        let mut future = Box::pin(future);
+
+        loop {
+            match Pin::as_mut(&mut future).poll(&mut cx) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => on_pending(),
+            }
+        }
+    }
+
+    fn assert_send<F: Send>(future: F) -> F {
+        future
+    }
+
+    #[derive(Default)]
+    pub struct MulCircuit {
+        a: BlsScalar,
+        b: BlsScalar,
+    }
+
+    impl Circuit for MulCircuit {
+        fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+        where
+            C: Composer,
+        {
+            let a = composer.append_witness(self.a);
+            let b = composer.append_witness(self.b);
+
+            let c = composer.gate_mul(Constraint::new().mult(1).a(a).b(b));
+            composer.assert_equal_constant(c, 0, Some(self.a * self.b));
+
+            Ok(())
+        }
+    }
+
+    let rng = &mut StdRng::seed_from_u64(0xa57);
+    let label = b"prove_async";
+    let pp = PublicParameters::setup(1 << 5, rng).expect("failed to create pp");
+    let (prover, verifier) = Compiler::compile::<MulCircuit>(&pp, label)
+        .expect("failed to compile circuit");
+
+    let circuit = MulCircuit {
+        a: BlsScalar::from(3),
+        b: BlsScalar::from(5),
+    };
+
+    // the prover yields before each of its rounds
+    let token = CancellationToken::new();
+    let mut yields = 0;
+    let (proof, public_inputs) = run(
+        assert_send(prover.prove_async(rng, &circuit, &token)),
+        || yields += 1,
+    )
+    .expect("failed to prove");
+    assert_eq!(yields, 5);
+    verifier
+        .verify(&proof, &public_inputs)
+        .expect("failed to verify proof");
+
+    // cancelling the token aborts the proof at the next round
+    let token = CancellationToken::new();
+    let canceller = token.clone();
+    let mut yields = 0;
+    let result = run(prover.prove_async(rng, &circuit, &token), || {
+        yields += 1;
+        if yields == 2 {
+            canceller.cancel();
+        }
+    });
+    assert_eq!(result, Err(Error::ProofCancelled));
+    assert_eq!(yields, 2);
+    assert!(token.is_cancelled());
+
+    // the completed stages are reported in order
+    let mut stages = vec![];
+    let (proof, public_inputs) = prover
+        .prove_with_progress(rng, &circuit, |stage| stages.push(stage))
+        .expect("failed to prove");
+    verifier
+        .verify(&proof, &public_inputs)
+        .expect("failed to verify proof");
+
+    assert_eq!(
+        stages,
+        [
+            ProveStage::WiresCommitted,
+            ProveStage::PermutationCommitted,
+            ProveStage::QuotientCommitted,
+            ProveStage::OpeningsComputed,
+        ]
+    );
+    assert!(stages.windows(2).all(|w| w[0].percent() < w[1].percent()));
+    assert_eq!(ProveStage::OpeningsComputed.percent(), 100);
+}
+
+#[test]
+fn prove_with_blinding() {
+    let rng = &mut StdRng::seed_from_u64(0xb11d);
+
+    #[derive(Default)]
+    pub struct MulCircuit {
+        a: BlsScalar,
+        b: BlsScalar,
+        c: BlsScalar,
+    }
+
+    impl Circuit for MulCircuit {
+        fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+        where
+            C: Composer,
+        {
+            let a = composer.append_witness(self.a);
+            let b = composer.append_witness(self.b);
+
+            let c = composer.gate_mul(Constraint::new().mult(1).a(a).b(b));
+            composer.assert_equal_constant(c, 0, Some(self.c));
+
+            Ok(())
+        }
+    }
+
+    let label = b"prove_with_blinding";
+    let pp = PublicParameters::setup(1 << 6, rng).expect("failed to create pp");
+    let (prover, verifier) = Compiler::compile::<MulCircuit>(&pp, label)
+        .expect("failed to compile circuit");
+    assert_eq!(prover.blinding(), Blinding::default());
+    assert_eq!(Blinding::hiding(2), Some(Blinding::default()));
+    assert_eq!(Blinding::hiding(0), None);
+
+    let circuit = MulCircuit {
+        a: BlsScalar::from(6),
+        b: BlsScalar::from(7),
+        c: BlsScalar::from(42),
+    };
+
+    // the commit key of the circuit only fits the default degrees
+    let blinding = Blinding::hiding(4).expect("the bound is positive");
+    assert_eq!(blinding.hiding_bound(), (4, 5));
+    assert!(matches!(
+        prover.clone().with_blinding(blinding),
+        Err(Error::PolynomialDegreeTooLarge { .. })
+    ));
+
+    let (blinded, blinded_verifier) =
+        Compiler::compile_with_blinding::<MulCircuit>(&pp, label, blinding)
+            .expect("failed to compile circuit");
+    assert_eq!(blinded.blinding(), blinding);
+    assert_eq!(blinded_verifier.to_bytes(), verifier.to_bytes());
+
+    let lagrange = blinded
+        .clone()
+        .with_lagrange_bases()
+        .expect("failed to convert the commit key");
+
+    let (proof, public_inputs) = blinded
+        .prove(&mut StdRng::seed_from_u64(0xbeef), &circuit)
+        .expect("failed to prove");
+    let (lagrange_proof, _) = lagrange
+        .prove(&mut StdRng::seed_from_u64(0xbeef), &circuit)
+        .expect("failed to prove");
+    let (default_proof, _) = prover
+        .prove(&mut StdRng::seed_from_u64(0xbeef), &circuit)
+        .expect("failed to prove");
+
+    assert_eq!(proof, lagrange_proof);
+    assert_ne!(proof, default_proof);
+    verifier
+        .verify(&proof, &public_inputs)
+        .expect("failed to verify proof");
+
+    // the blinding scalars must not wrap around the domain more than once
+    let blinding = Blinding::new(0, 1 << 4);
+    assert!(matches!(
+        Compiler::compile_with_blinding::<MulCircuit>(&pp, label, blinding),
+        Err(Error::PolynomialDegreeTooLarge { .. })
+    ));
+}
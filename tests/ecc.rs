@@ -318,3 +318,106 @@ fn component_mul_point() {
     let circuit = TestCircuit::new(scalar, point, result);
     check_unsatisfied_circuit(&prover, &circuit, rng, msg);
 }
+
+#[test]
+fn component_msm() {
+    pub struct TestCircuit {
+        terms: [(JubJubScalar, JubJubExtended); 2],
+        result: JubJubExtended,
+    }
+
+    impl Default for TestCircuit {
+        fn default() -> Self {
+            let point = dusk_jubjub::GENERATOR_EXTENDED;
+            let terms = [(JubJubScalar::zero(), point); 2];
+            let result = JubJubExtended::identity();
+
+            Self { terms, result }
+        }
+    }
+
+    impl Circuit for TestCircuit {
+        fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+        where
+            C: Composer,
+        {
+            let terms: Vec<(Witness, WitnessPoint)> = self
+                .terms
+                .iter()
+                .map(|(scalar, point)| {
+                    (
+                        composer.append_witness(*scalar),
+                        composer.append_point(*point),
+                    )
+                })
+                .collect();
+            let w_result = composer.append_point(self.result);
+
+            let result_circuit = composer.component_msm(&terms);
+
+            composer.assert_equal_point(w_result, result_circuit);
+
+            Ok(())
+        }
+    }
+
+    let label = b"component_msm";
+    let rng = &mut StdRng::seed_from_u64(0x4d5);
+    let capacity = 1 << 12;
+    let pp = PublicParameters::setup(capacity, rng)
+        .expect("Creation of public parameter shouldn't fail");
+    let (prover, verifier) = Compiler::compile::<TestCircuit>(&pp, label)
+        .expect("Circuit should compile");
+
+    let random_point = |rng: &mut StdRng| {
+        dusk_jubjub::GENERATOR_EXTENDED * &JubJubScalar::random(rng)
+    };
+
+    // Test: random works
+    let msg = "Circuit with a random multi-scalar multiplication should pass";
+    let terms = [
+        (JubJubScalar::random(&mut *rng), random_point(rng)),
+        (JubJubScalar::random(&mut *rng), random_point(rng)),
+    ];
+    let result = terms[0].1 * &terms[0].0 + terms[1].1 * &terms[1].0;
+    let circuit = TestCircuit { terms, result };
+    let pi = vec![];
+    check_satisfied_circuit(&prover, &verifier, &pi, &circuit, rng, &msg);
+
+    // Test:
+    // random · P + random · (-P) = (0, 1)
+    let msg = "Circuit with opposite terms should be the identity";
+    let scalar = JubJubScalar::random(&mut *rng);
+    let point = random_point(rng);
+    let terms = [(scalar, point), (scalar, -point)];
+    let result = JubJubExtended::identity();
+    let circuit = TestCircuit { terms, result };
+    check_satisfied_circuit(&prover, &verifier, &pi, &circuit, rng, &msg);
+
+    // Unsatisfied circuit
+    let msg = "Unsatisfied circuit should not pass";
+    let terms = [
+        (JubJubScalar::random(&mut *rng), random_point(rng)),
+        (JubJubScalar::random(&mut *rng), random_point(rng)),
+    ];
+    let result = terms[0].1 * &terms[0].0;
+    let circuit = TestCircuit { terms, result };
+    check_unsatisfied_circuit(&prover, &circuit, rng, msg);
+
+    // the doublings are shared by the terms
+    let mut msm = Builder::initialized();
+    let mut mul = Builder::initialized();
+    let scalar = msm.append_witness(JubJubScalar::one());
+    let point = msm.append_point(dusk_jubjub::GENERATOR_EXTENDED);
+    let base = msm.constraints();
+    msm.component_msm(&[(scalar, point), (scalar, point)]);
+
+    let scalar = mul.append_witness(JubJubScalar::one());
+    let point = mul.append_point(dusk_jubjub::GENERATOR_EXTENDED);
+    let a = mul.component_mul_point(scalar, point);
+    let b = mul.component_mul_point(scalar, point);
+    mul.component_add_point(a, b);
+
+    assert_eq!(msm.constraints() - base, 2 * (505 + 1008) + 502);
+    assert!(msm.constraints() < mul.constraints());
+}
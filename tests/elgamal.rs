@@ -0,0 +1,264 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_jubjub::GENERATOR_EXTENDED;
+use dusk_plonk::prelude::*;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+mod common;
+use common::{check_satisfied_circuit, check_unsatisfied_circuit};
+
+fn encrypt(
+    public_key: &JubJubExtended,
+    value: &JubJubScalar,
+    randomness: &JubJubScalar,
+) -> (JubJubAffine, JubJubAffine) {
+    let c1 = GENERATOR_EXTENDED * randomness;
+    let c2 = GENERATOR_EXTENDED * value + public_key * randomness;
+
+    (c1.into(), c2.into())
+}
+
+#[test]
+fn assert_elgamal_encryption() {
+    #[derive(Default)]
+    pub struct TestCircuit {
+        public_key: JubJubAffine,
+        value: JubJubScalar,
+        randomness: JubJubScalar,
+        ciphertext: (JubJubAffine, JubJubAffine),
+    }
+
+    impl Circuit for TestCircuit {
+        fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+        where
+            C: Composer,
+        {
+            let public_key = composer.append_public_point(self.public_key);
+            let value = composer.append_witness(self.value);
+            let randomness = composer.append_witness(self.randomness);
+
+            composer.assert_elgamal_encryption(
+                public_key,
+                value,
+                randomness,
+                self.ciphertext,
+            )
+        }
+    }
+
+    let label = b"assert_elgamal_encryption";
+    let rng = &mut StdRng::seed_from_u64(0xe1a);
+    let pp = PublicParameters::setup(1 << 12, rng)
+        .expect("Creation of public parameter shouldn't fail");
+    let (prover, verifier) = Compiler::compile::<TestCircuit>(&pp, label)
+        .expect("Circuit should compile");
+
+    let secret_key = JubJubScalar::random(&mut *rng);
+    let public_key = GENERATOR_EXTENDED * secret_key;
+    let value = JubJubScalar::from(0xc0ffeeu64);
+    let randomness = JubJubScalar::random(&mut *rng);
+    let ciphertext = encrypt(&public_key, &value, &randomness);
+
+    // Test the encryption of the value
+    let msg = "The encryption of the value should satisfy the circuit";
+    let public_key = JubJubAffine::from(public_key);
+    let circuit = TestCircuit {
+        public_key,
+        value,
+        randomness,
+        ciphertext,
+    };
+    let pi = vec![
+        public_key.get_u(),
+        public_key.get_v(),
+        ciphertext.0.get_u(),
+        ciphertext.0.get_v(),
+        ciphertext.1.get_u(),
+        ciphertext.1.get_v(),
+    ];
+    check_satisfied_circuit(&prover, &verifier, &pi, &circuit, rng, &msg);
+
+    // the key holder decrypts the ciphertext to the value times the generator
+    let decrypted = JubJubExtended::from(ciphertext.1)
+        - JubJubExtended::from(ciphertext.0) * secret_key;
+    assert_eq!(decrypted, GENERATOR_EXTENDED * value);
+
+    // Test another value
+    let msg = "Another value should not match the ciphertext";
+    let circuit = TestCircuit {
+        public_key,
+        value: value + JubJubScalar::one(),
+        randomness,
+        ciphertext,
+    };
+    check_unsatisfied_circuit(&prover, &circuit, rng, &msg);
+
+    // Test another randomness
+    let msg = "Another randomness should not match the ciphertext";
+    let circuit = TestCircuit {
+        public_key,
+        value,
+        randomness: JubJubScalar::random(&mut *rng),
+        ciphertext,
+    };
+    check_unsatisfied_circuit(&prover, &circuit, rng, &msg);
+
+    // Test another public key
+    let msg = "The ciphertext under another key should not match";
+    let other_key = GENERATOR_EXTENDED * JubJubScalar::random(&mut *rng);
+    let circuit = TestCircuit {
+        public_key: other_key.into(),
+        value,
+        randomness,
+        ciphertext,
+    };
+    check_unsatisfied_circuit(&prover, &circuit, rng, &msg);
+
+    // Test the encryption under another key
+    let msg = "The encryption under another key should satisfy the circuit";
+    let ciphertext = encrypt(&other_key, &value, &randomness);
+    let circuit = TestCircuit {
+        public_key: other_key.into(),
+        value,
+        randomness,
+        ciphertext,
+    };
+    let pi = vec![
+        JubJubAffine::from(other_key).get_u(),
+        JubJubAffine::from(other_key).get_v(),
+        ciphertext.0.get_u(),
+        ciphertext.0.get_v(),
+        ciphertext.1.get_u(),
+        ciphertext.1.get_v(),
+    ];
+    check_satisfied_circuit(&prover, &verifier, &pi, &circuit, rng, &msg);
+}
+
+#[test]
+fn assert_elgamal_decryption() {
+    #[derive(Default)]
+    pub struct TestCircuit {
+        secret_key: JubJubScalar,
+        value: JubJubScalar,
+        public_key: JubJubAffine,
+        ciphertext: (JubJubAffine, JubJubAffine),
+        plaintext: JubJubAffine,
+    }
+
+    impl Circuit for TestCircuit {
+        fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+        where
+            C: Composer,
+        {
+            let secret_key = composer.append_witness(self.secret_key);
+            let value = composer.append_witness(self.value);
+
+            composer.assert_elgamal_decryption(
+                secret_key,
+                value,
+                self.public_key,
+                self.ciphertext,
+                self.plaintext,
+            )
+        }
+    }
+
+    let label = b"assert_elgamal_decryption";
+    let rng = &mut StdRng::seed_from_u64(0xe1b);
+    let pp = PublicParameters::setup(1 << 12, rng)
+        .expect("Creation of public parameter shouldn't fail");
+    let (prover, verifier) = Compiler::compile::<TestCircuit>(&pp, label)
+        .expect("Circuit should compile");
+
+    let secret_key = JubJubScalar::random(&mut *rng);
+    let public_key = GENERATOR_EXTENDED * secret_key;
+    let value = JubJubScalar::from(0xc0ffeeu64);
+    let randomness = JubJubScalar::random(&mut *rng);
+    let ciphertext = encrypt(&public_key, &value, &randomness);
+    let public_key = JubJubAffine::from(public_key);
+    let plaintext = JubJubAffine::from(GENERATOR_EXTENDED * value);
+
+    // Test the decryption to the value
+    let msg = "The decryption to the value should satisfy the circuit";
+    let circuit = TestCircuit {
+        secret_key,
+        value,
+        public_key,
+        ciphertext,
+        plaintext,
+    };
+    let pi = vec![
+        public_key.get_u(),
+        public_key.get_v(),
+        ciphertext.0.get_u(),
+        ciphertext.0.get_v(),
+        ciphertext.1.get_u(),
+        ciphertext.1.get_v(),
+        plaintext.get_u(),
+        plaintext.get_v(),
+    ];
+    check_satisfied_circuit(&prover, &verifier, &pi, &circuit, rng, &msg);
+
+    // Test the disclosure of another plaintext with the valid key
+    let msg = "The proof should not disclose another plaintext";
+    let (proof, _) = prover
+        .prove(rng, &circuit)
+        .expect("Proof creation of satisfied circuit should pass");
+    let other =
+        JubJubAffine::from(GENERATOR_EXTENDED * (value + JubJubScalar::one()));
+    let mut wrong = pi.clone();
+    wrong[6] = other.get_u();
+    wrong[7] = other.get_v();
+    verifier.verify(&proof, &wrong).expect_err(msg);
+
+    // Test another plaintext point
+    let msg = "Another plaintext point should not satisfy the circuit";
+    let circuit = TestCircuit {
+        secret_key,
+        value,
+        public_key,
+        ciphertext,
+        plaintext: other,
+    };
+    check_unsatisfied_circuit(&prover, &circuit, rng, &msg);
+
+    // Test another value
+    let msg = "The decryption to another value should not satisfy the circuit";
+    let circuit = TestCircuit {
+        secret_key,
+        value: value + JubJubScalar::one(),
+        public_key,
+        ciphertext,
+        plaintext,
+    };
+    check_unsatisfied_circuit(&prover, &circuit, rng, &msg);
+
+    // Test another secret key
+    let msg =
+        "A key not matching the public key should not satisfy the circuit";
+    let circuit = TestCircuit {
+        secret_key: secret_key + JubJubScalar::one(),
+        value,
+        public_key,
+        ciphertext,
+        plaintext,
+    };
+    check_unsatisfied_circuit(&prover, &circuit, rng, &msg);
+
+    // Test the ciphertext under another key
+    let msg = "A ciphertext under another key should not satisfy the circuit";
+    let other_key = GENERATOR_EXTENDED * JubJubScalar::random(&mut *rng);
+    let circuit = TestCircuit {
+        secret_key,
+        value,
+        public_key,
+        ciphertext: encrypt(&other_key, &value, &randomness),
+        plaintext,
+    };
+    check_unsatisfied_circuit(&prover, &circuit, rng, &msg);
+}
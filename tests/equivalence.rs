@@ -0,0 +1,176 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_plonk::composer::Divergence;
+use dusk_plonk::prelude::*;
+
+// a * b + c, allocating the witnesses in order
+#[derive(Default)]
+struct MulAdd {
+    a: BlsScalar,
+    b: BlsScalar,
+    c: BlsScalar,
+}
+
+impl Circuit for MulAdd {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer,
+    {
+        let a = composer.append_witness(self.a);
+        let b = composer.append_witness(self.b);
+        let c = composer.append_witness(self.c);
+
+        let constraint = Constraint::new().mult(1).a(a).b(b).fourth(1).d(c);
+        let result = composer.gate_add(constraint);
+        composer.assert_equal_constant(result, 0, None);
+
+        Ok(())
+    }
+}
+
+// a * b + c, allocating the witnesses in reverse order
+#[derive(Default)]
+struct MulAddReversed {
+    a: BlsScalar,
+    b: BlsScalar,
+    c: BlsScalar,
+}
+
+impl Circuit for MulAddReversed {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer,
+    {
+        let c = composer.append_witness(self.c);
+        let b = composer.append_witness(self.b);
+        let a = composer.append_witness(self.a);
+
+        let constraint = Constraint::new().mult(1).a(a).b(b).fourth(1).d(c);
+        let result = composer.gate_add(constraint);
+        composer.assert_equal_constant(result, 0, None);
+
+        Ok(())
+    }
+}
+
+// a * a + c, with the same selectors but another wiring
+#[derive(Default)]
+struct Square {
+    a: BlsScalar,
+    c: BlsScalar,
+}
+
+impl Circuit for Square {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer,
+    {
+        let a = composer.append_witness(self.a);
+        let _ = composer.append_witness(self.a);
+        let c = composer.append_witness(self.c);
+
+        let constraint = Constraint::new().mult(1).a(a).b(a).fourth(1).d(c);
+        let result = composer.gate_add(constraint);
+        composer.assert_equal_constant(result, 0, None);
+
+        Ok(())
+    }
+}
+
+// a * b + 2c
+#[derive(Default)]
+struct MulAddDouble {
+    a: BlsScalar,
+    b: BlsScalar,
+    c: BlsScalar,
+}
+
+impl Circuit for MulAddDouble {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer,
+    {
+        let a = composer.append_witness(self.a);
+        let b = composer.append_witness(self.b);
+        let c = composer.append_witness(self.c);
+
+        let constraint = Constraint::new().mult(1).a(a).b(b).fourth(2).d(c);
+        let result = composer.gate_add(constraint);
+        composer.assert_equal_constant(result, 0, None);
+
+        Ok(())
+    }
+}
+
+// a * b + c with the result as a public input
+#[derive(Default)]
+struct MulAddPublic {
+    a: BlsScalar,
+    b: BlsScalar,
+    c: BlsScalar,
+}
+
+impl Circuit for MulAddPublic {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer,
+    {
+        let a = composer.append_witness(self.a);
+        let b = composer.append_witness(self.b);
+        let c = composer.append_witness(self.c);
+
+        let constraint = Constraint::new().mult(1).a(a).b(b).fourth(1).d(c);
+        let result = composer.gate_add(constraint);
+        composer.assert_equal_constant(result, 0, Some(self.a + self.b));
+
+        Ok(())
+    }
+}
+
+#[test]
+fn equivalent_circuits() {
+    assert_eq!(Compiler::first_divergence::<MulAdd, MulAdd>(), Ok(None));
+
+    // the witnesses are renamed
+    assert_eq!(
+        Compiler::first_divergence::<MulAdd, MulAddReversed>(),
+        Ok(None)
+    );
+}
+
+#[test]
+fn diverging_circuits() {
+    let mut builder = Builder::initialized();
+    MulAdd::default().circuit(&mut builder).unwrap();
+    let gate = builder.constraints() - 2;
+
+    assert_eq!(
+        Compiler::first_divergence::<MulAdd, Square>(),
+        Ok(Some(Divergence::Wiring { gate, wire: "w_b" }))
+    );
+    assert_eq!(
+        Compiler::first_divergence::<MulAdd, MulAddDouble>(),
+        Ok(Some(Divergence::Selector {
+            gate,
+            selector: "q_d"
+        }))
+    );
+    assert_eq!(
+        Compiler::first_divergence::<MulAdd, MulAddPublic>(),
+        Ok(Some(Divergence::PublicInput { gate: gate + 1 }))
+    );
+
+    let mut longer = builder.clone();
+    longer.append_dummy_gates();
+    assert_eq!(
+        builder.first_divergence(&longer),
+        Some(Divergence::Constraints {
+            left: builder.constraints(),
+            right: longer.constraints(),
+        })
+    );
+}
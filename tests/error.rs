@@ -0,0 +1,143 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_plonk::prelude::*;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+#[test]
+fn witness_not_found() {
+    #[derive(Default)]
+    pub struct TestCircuit;
+
+    impl Circuit for TestCircuit {
+        fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+        where
+            C: Composer,
+        {
+            // a witness allocated by another composer
+            let mut other = C::initialized();
+            let foreign = (0..16)
+                .map(|_| other.append_witness(BlsScalar::one()))
+                .last()
+                .expect("witnesses should be allocated");
+
+            let constraint = Constraint::new().left(1).a(foreign);
+            composer.append_gate(constraint);
+
+            Ok(())
+        }
+    }
+
+    let label = b"witness_not_found";
+    let mut rng = StdRng::seed_from_u64(0xe44);
+    let pp = PublicParameters::setup(1 << 4, &mut rng)
+        .expect("Creation of public parameter shouldn't fail");
+
+    // the gate is appended after the 4 gates of the initialized composer
+    let result = Compiler::compile::<TestCircuit>(&pp, label);
+    assert_eq!(
+        result.err(),
+        Some(Error::WitnessNotFound {
+            gate: 4,
+            witness: 21
+        }),
+        "compilation of a circuit wired to a foreign witness should fail"
+    );
+}
+
+#[test]
+fn witness_not_found_gate_add() {
+    #[derive(Default)]
+    pub struct TestCircuit {
+        foreign: bool,
+    }
+
+    impl Circuit for TestCircuit {
+        fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+        where
+            C: Composer,
+        {
+            let a = composer.append_witness(BlsScalar::one());
+            let b = match self.foreign {
+                // a witness allocated by another composer
+                true => {
+                    let mut other = C::initialized();
+                    (0..16)
+                        .map(|_| other.append_witness(BlsScalar::one()))
+                        .last()
+                        .expect("witnesses should be allocated")
+                }
+                false => composer.append_witness(BlsScalar::one()),
+            };
+
+            let constraint = Constraint::new().left(1).right(1).a(a).b(b);
+            composer.gate_add(constraint);
+
+            Ok(())
+        }
+    }
+
+    let label = b"witness_not_found_gate_add";
+    let mut rng = StdRng::seed_from_u64(0xe46);
+    let pp = PublicParameters::setup(1 << 4, &mut rng)
+        .expect("Creation of public parameter shouldn't fail");
+
+    let expected = Error::WitnessNotFound {
+        gate: 4,
+        witness: 21,
+    };
+
+    let circuit = TestCircuit { foreign: true };
+    let result = Compiler::compile_with_circuit(&pp, label, &circuit);
+    assert_eq!(
+        result.err(),
+        Some(expected),
+        "compilation of a gate wired to a foreign witness should fail"
+    );
+
+    let (prover, _) = Compiler::compile::<TestCircuit>(&pp, label)
+        .expect("Circuit should compile");
+    assert_eq!(
+        prover.prove(&mut rng, &circuit).err(),
+        Some(expected),
+        "proving a gate wired to a foreign witness should fail"
+    );
+}
+
+#[test]
+fn public_parameters_too_small() {
+    #[derive(Default)]
+    pub struct TestCircuit;
+
+    impl Circuit for TestCircuit {
+        fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+        where
+            C: Composer,
+        {
+            (0..16).for_each(|_| {
+                let w = composer.append_witness(BlsScalar::one());
+                composer.assert_equal_constant(w, 1, None);
+            });
+
+            Ok(())
+        }
+    }
+
+    let label = b"public_parameters_too_small";
+    let mut rng = StdRng::seed_from_u64(0xe45);
+    let pp = PublicParameters::setup(1 << 4, &mut rng)
+        .expect("Creation of public parameter shouldn't fail");
+
+    let result = Compiler::compile::<TestCircuit>(&pp, label);
+    match result.err() {
+        Some(Error::TruncatedDegreeTooLarge { degree, max_degree }) => {
+            assert!(degree > max_degree);
+            assert_eq!(max_degree, pp.max_degree());
+        }
+        _ => panic!("compilation with small public parameters should fail"),
+    }
+}
@@ -0,0 +1,156 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_plonk::fft::{EvaluationDomain, Evaluations, Polynomial};
+use dusk_plonk::prelude::*;
+use ff::Field;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+fn random_poly(rng: &mut StdRng, coeffs: usize) -> Polynomial {
+    let coeffs = (0..coeffs).map(|_| BlsScalar::random(&mut *rng)).collect();
+    Polynomial::from_coefficients_vec(coeffs)
+}
+
+#[test]
+fn polynomial_arithmetic() {
+    let mut rng = StdRng::seed_from_u64(0xff7);
+
+    let a = random_poly(&mut rng, 10);
+    let b = random_poly(&mut rng, 7);
+    let x = BlsScalar::random(&mut rng);
+
+    assert_eq!((&a + &b).evaluate(&x), a.evaluate(&x) + b.evaluate(&x));
+    assert_eq!((&a - &b).evaluate(&x), a.evaluate(&x) - b.evaluate(&x));
+    assert_eq!((&a * &b).evaluate(&x), a.evaluate(&x) * b.evaluate(&x));
+    assert_eq!((&a * &b).degree(), a.degree() + b.degree());
+
+    // a(x) - a(z) is divisible by x - z
+    let z = BlsScalar::random(&mut rng);
+    let quotient = (&a - &a.evaluate(&z)).ruffini(z);
+    assert_eq!(
+        quotient.evaluate(&x) * (x - z),
+        a.evaluate(&x) - a.evaluate(&z)
+    );
+}
+
+#[test]
+fn divide_by_vanishing_poly() {
+    let mut rng = StdRng::seed_from_u64(0xd17);
+
+    for domain in [
+        EvaluationDomain::new(16).unwrap(),
+        EvaluationDomain::new_mixed_radix(12).unwrap(),
+    ] {
+        let n = domain.size();
+        let x = BlsScalar::random(&mut rng);
+
+        let a = random_poly(&mut rng, 3 * n + 2);
+        let (quotient, remainder) = a.divide_by_vanishing_poly(&domain);
+        assert!(remainder.degree() < n);
+        assert_eq!(
+            quotient.evaluate(&x) * domain.evaluate_vanishing_polynomial(&x)
+                + remainder.evaluate(&x),
+            a.evaluate(&x)
+        );
+
+        // the remainder interpolates the evaluations over the domain
+        let evals = Evaluations::from_vec_and_domain(
+            domain.elements().map(|e| a.evaluate(&e)).collect(),
+            domain,
+        );
+        assert_eq!(evals.interpolate(), remainder);
+
+        let low = random_poly(&mut rng, n);
+        let (quotient, remainder) = low.divide_by_vanishing_poly(&domain);
+        assert!(quotient.is_zero());
+        assert_eq!(remainder, low);
+    }
+}
+
+#[test]
+fn domain_ffts() {
+    let mut rng = StdRng::seed_from_u64(0xf57);
+
+    for domain in [
+        EvaluationDomain::new(32).unwrap(),
+        EvaluationDomain::new_mixed_radix(48).unwrap(),
+    ] {
+        let a = random_poly(&mut rng, domain.size());
+
+        let evals = domain.fft(&a);
+        domain.elements().zip(evals.iter()).for_each(|(x, eval)| {
+            assert_eq!(&a.evaluate(&x), eval);
+        });
+        assert_eq!(domain.ifft(&evals), a.to_vec());
+
+        let coset_evals = domain.coset_fft(&a);
+        assert_eq!(domain.coset_ifft(&coset_evals), a.to_vec());
+
+        // the lagrange basis evaluated at `x` interpolates `a(x)`
+        let x = BlsScalar::random(&mut rng);
+        let lagrange = domain.evaluate_all_lagrange_coefficients(x);
+        let a_x = lagrange
+            .iter()
+            .zip(evals.iter())
+            .fold(BlsScalar::zero(), |acc, (l, e)| acc + l * e);
+        assert_eq!(a_x, a.evaluate(&x));
+
+        assert_eq!(
+            domain.group_gen().pow(&[domain.size() as u64, 0, 0, 0]),
+            BlsScalar::one()
+        );
+    }
+}
+
+#[test]
+fn barycentric_eval() {
+    let mut rng = StdRng::seed_from_u64(0xba7);
+
+    for domain in [
+        EvaluationDomain::new(32).unwrap(),
+        EvaluationDomain::new_mixed_radix(24).unwrap(),
+    ] {
+        let a = random_poly(&mut rng, domain.size());
+        let x = BlsScalar::random(&mut rng);
+
+        let evals = domain.fft(&a);
+        assert_eq!(domain.barycentric_eval(&evals, &x), a.evaluate(&x));
+
+        let coset_evals = domain.coset_fft(&a);
+        assert_eq!(
+            domain.coset_barycentric_eval(&coset_evals, &x),
+            a.evaluate(&x)
+        );
+
+        // over the domain, the evaluations are returned
+        let w = domain.elements().nth(5).unwrap();
+        assert_eq!(domain.barycentric_eval(&evals, &w), evals[5]);
+
+        // sparse evaluations, missing at the end, are zero
+        let mut sparse = vec![BlsScalar::zero(); 4];
+        sparse[1] = BlsScalar::random(&mut rng);
+        sparse[3] = BlsScalar::random(&mut rng);
+        let mut padded = sparse.clone();
+        padded.resize(domain.size(), BlsScalar::zero());
+        let p = Polynomial::from_coefficients_vec(domain.ifft(&padded));
+        assert_eq!(domain.barycentric_eval(&sparse, &x), p.evaluate(&x));
+    }
+}
+
+#[test]
+fn clear_cache() {
+    let mut rng = StdRng::seed_from_u64(0xcac);
+
+    let domain = EvaluationDomain::new(64).unwrap();
+    let a = random_poly(&mut rng, domain.size());
+    let evals = domain.fft(&a);
+
+    // the cached domains and twiddle factors are recomputed identically
+    dusk_plonk::fft::clear_cache();
+    assert_eq!(EvaluationDomain::new(64).unwrap(), domain);
+    assert_eq!(domain.fft(&a), evals);
+}
@@ -0,0 +1,29 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_plonk::fuzzing::{prove_and_verify, FuzzCircuit};
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+
+#[test]
+fn fuzz_inputs() {
+    let mut rng = StdRng::seed_from_u64(0xf022);
+
+    // too short inputs are skipped
+    assert_eq!(FuzzCircuit::from_fuzz_input(&[]), None);
+    assert_eq!(FuzzCircuit::from_fuzz_input(&[1, 0, 0, 0]), None);
+    prove_and_verify(&[], &mut rng);
+
+    for len in [9, 17, 40, 64, 128] {
+        let mut data = vec![0u8; len];
+        rng.fill_bytes(&mut data);
+
+        let circuit = FuzzCircuit::from_fuzz_input(&data);
+        if circuit.is_some() {
+            prove_and_verify(&data, &mut rng);
+        }
+    }
+}
@@ -0,0 +1,156 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_plonk::composer::hash;
+use dusk_plonk::prelude::*;
+use ff::Field;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+mod common;
+use common::{check_satisfied_circuit, check_unsatisfied_circuit};
+
+struct TestCircuit<const N: usize> {
+    values: [BlsScalar; N],
+    hash: BlsScalar,
+}
+
+impl<const N: usize> Default for TestCircuit<N> {
+    fn default() -> Self {
+        Self {
+            values: [BlsScalar::zero(); N],
+            hash: BlsScalar::zero(),
+        }
+    }
+}
+
+impl<const N: usize> TestCircuit<N> {
+    fn new(values: [BlsScalar; N]) -> Self {
+        Self {
+            values,
+            hash: hash(&values),
+        }
+    }
+}
+
+impl<const N: usize> Circuit for TestCircuit<N> {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer,
+    {
+        let values: Vec<Witness> = self
+            .values
+            .iter()
+            .map(|value| composer.append_witness(*value))
+            .collect();
+
+        let hash = composer.component_hash(&values);
+        composer.assert_equal_constant(hash, 0, Some(self.hash));
+
+        Ok(())
+    }
+}
+
+/// Check the hash of `N` values in a circuit against the native one
+fn component_hash<const N: usize>(rng: &mut StdRng, pp: &PublicParameters) {
+    let label = b"component_hash";
+
+    let (prover, verifier) = Compiler::compile::<TestCircuit<N>>(pp, label)
+        .expect("Circuit should compile");
+
+    let mut values = [BlsScalar::zero(); N];
+    values
+        .iter_mut()
+        .for_each(|value| *value = BlsScalar::random(&mut *rng));
+
+    let msg = "The hash of the values should satisfy the circuit";
+    let circuit = TestCircuit::new(values);
+    let pi = vec![circuit.hash];
+    check_satisfied_circuit(&prover, &verifier, &pi, &circuit, rng, &msg);
+
+    let msg = "The hash of other values should not satisfy the circuit";
+    let mut circuit = TestCircuit::new(values);
+    circuit.hash += BlsScalar::one();
+    check_unsatisfied_circuit(&prover, &circuit, rng, &msg);
+}
+
+#[test]
+fn component_hash_sizes() {
+    let rng = &mut StdRng::seed_from_u64(0x4a5);
+    let pp = PublicParameters::setup(1 << 12, rng)
+        .expect("Creation of public parameter shouldn't fail");
+
+    component_hash::<0>(rng, &pp);
+    component_hash::<1>(rng, &pp);
+    component_hash::<4>(rng, &pp);
+    component_hash::<5>(rng, &pp);
+    component_hash::<9>(rng, &pp);
+
+    // the number of inputs is part of the hash
+    let zero = BlsScalar::zero();
+    assert_ne!(hash(&[]), hash(&[zero]));
+    assert_ne!(hash(&[zero; 4]), hash(&[zero; 5]));
+}
+
+#[test]
+fn append_public_hash() {
+    #[derive(Default)]
+    struct TestCircuit {
+        values: [BlsScalar; 12],
+    }
+
+    impl Circuit for TestCircuit {
+        fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+        where
+            C: Composer,
+        {
+            let values: Vec<Witness> = self
+                .values
+                .iter()
+                .map(|value| composer.append_witness(*value))
+                .collect();
+
+            composer.append_public_hash(&values);
+
+            Ok(())
+        }
+    }
+
+    let label = b"append_public_hash";
+    let rng = &mut StdRng::seed_from_u64(0x4a6);
+    let pp = PublicParameters::setup(1 << 12, rng)
+        .expect("Creation of public parameter shouldn't fail");
+    let (prover, verifier) = Compiler::compile::<TestCircuit>(&pp, label)
+        .expect("Circuit should compile");
+
+    let mut values = [BlsScalar::zero(); 12];
+    values
+        .iter_mut()
+        .for_each(|value| *value = BlsScalar::random(&mut *rng));
+
+    let (proof, public_inputs) = prover
+        .prove(rng, &TestCircuit { values })
+        .expect("Proving a satisfied circuit should pass");
+
+    // the values are replaced by their hash
+    assert_eq!(public_inputs, vec![hash(&values)]);
+    assert_eq!(verifier.cost().public_inputs(), 1);
+
+    verifier
+        .verify_hashed(&proof, &values)
+        .expect("Verification against the values should pass");
+    verifier
+        .verify(&proof, &public_inputs)
+        .expect("Verification against the hash should pass");
+
+    values[11] += BlsScalar::one();
+    verifier
+        .verify_hashed(&proof, &values)
+        .expect_err("Verification against other values should fail");
+    verifier
+        .verify_hashed(&proof, &values[..11])
+        .expect_err("Verification against fewer values should fail");
+}
@@ -0,0 +1,164 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_plonk::prelude::*;
+use dusk_plonk::transcript::{Domain, FiatShamir, PoseidonTranscript};
+use ff::Field;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+#[derive(Default)]
+pub struct TestCircuit {
+    a: BlsScalar,
+    b: BlsScalar,
+    c: BlsScalar,
+}
+
+impl TestCircuit {
+    pub fn new(a: BlsScalar, b: BlsScalar) -> Self {
+        Self { a, b, c: a * b }
+    }
+}
+
+impl Circuit for TestCircuit {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer,
+    {
+        let a = composer.append_witness(self.a);
+        let b = composer.append_public(self.b);
+        composer.component_range::<16>(a);
+
+        let constraint = Constraint::new().mult(1).a(a).b(b);
+        let c = composer.gate_mul(constraint);
+        composer.assert_equal_constant(c, 0, Some(self.c));
+
+        composer.append_public(self.a + self.b);
+
+        Ok(())
+    }
+}
+
+#[test]
+fn heapless() {
+    let label = b"heapless";
+    let mut rng = StdRng::seed_from_u64(0x4ea9);
+    let pp = PublicParameters::setup(1 << 6, &mut rng)
+        .expect("Creation of public parameter shouldn't fail");
+
+    let (prover, verifier) = Compiler::compile::<TestCircuit>(&pp, label)
+        .expect("Circuit should compile");
+    let heapless = HeaplessVerifier::<4>::try_from_bytes(verifier.to_bytes())
+        .expect("Deserialization of the verifier should pass");
+
+    assert_eq!(heapless.public_inputs(), 3);
+    assert_eq!(heapless.fingerprint(), verifier.fingerprint());
+    assert_eq!(heapless.circuit_hash(), verifier.circuit_hash());
+
+    let circuit =
+        TestCircuit::new(BlsScalar::from(0xbeef), BlsScalar::random(&mut rng));
+    let (proof, pi) = prover
+        .prove(&mut rng, &circuit)
+        .expect("Proof creation of satisfied circuit should pass");
+
+    heapless
+        .verify(&proof, &pi)
+        .expect("Verification of a valid proof should pass");
+    verifier
+        .verify(&proof, &pi)
+        .expect("Verification of a valid proof should pass");
+
+    // wrong public inputs
+    let mut wrong = pi.clone();
+    wrong[1] += BlsScalar::one();
+    assert_eq!(
+        heapless.verify(&proof, &wrong),
+        Err(Error::ProofVerificationError)
+    );
+    assert_eq!(
+        heapless.verify(&proof, &pi[..2]),
+        Err(Error::InconsistentPublicInputsLen {
+            expected: 3,
+            provided: 2,
+        })
+    );
+
+    // not enough capacity for the public inputs
+    assert_eq!(
+        HeaplessVerifier::<2>::try_from_bytes(verifier.to_bytes()).err(),
+        Some(Error::TooManyPublicInputs {
+            public_inputs: 3,
+            capacity: 2,
+        })
+    );
+
+    // truncated verifier
+    let bytes = verifier.to_bytes();
+    assert_eq!(
+        HeaplessVerifier::<4>::try_from_bytes(&bytes[..bytes.len() / 2]).err(),
+        Some(Error::NotEnoughBytes)
+    );
+}
+
+#[test]
+fn heapless_settings() {
+    let label = b"heapless_settings";
+    let mut rng = StdRng::seed_from_u64(0x5e77);
+    let pp = PublicParameters::setup(1 << 6, &mut rng)
+        .expect("Creation of public parameter shouldn't fail");
+
+    let (prover, verifier) = Compiler::compile_with_transcript::<
+        TestCircuit,
+        PoseidonTranscript,
+    >(&pp, label)
+    .expect("Circuit should compile");
+
+    let domain = Domain::new(b"attestation", 2);
+    let prover = prover
+        .with_domain(domain.clone())
+        .with_fiat_shamir(FiatShamir::Strong);
+    let verifier = verifier
+        .with_domain(domain)
+        .with_fiat_shamir(FiatShamir::Strong);
+
+    let heapless = HeaplessVerifier::<3, PoseidonTranscript>::try_from_bytes_with_transcript(
+        verifier.to_bytes(),
+    )
+    .expect("Deserialization of the verifier should pass");
+
+    let circuit =
+        TestCircuit::new(BlsScalar::from(0xcafe), BlsScalar::random(&mut rng));
+    let (proof, pi) = prover
+        .prove(&mut rng, &circuit)
+        .expect("Proof creation of satisfied circuit should pass");
+
+    heapless
+        .verify(&proof, &pi)
+        .expect("Verification under the same settings should pass");
+
+    // the statement is bound to the transcript
+    let mut wrong = pi.clone();
+    wrong.swap(0, 1);
+    assert_eq!(
+        heapless.verify(&proof, &wrong),
+        Err(Error::ProofVerificationError)
+    );
+
+    // a verifier without the domain rejects the proof
+    let (_, verifier) = Compiler::compile_with_transcript::<
+        TestCircuit,
+        PoseidonTranscript,
+    >(&pp, label)
+    .expect("Circuit should compile");
+    let heapless = HeaplessVerifier::<3, PoseidonTranscript>::try_from_bytes_with_transcript(
+        verifier.with_fiat_shamir(FiatShamir::Strong).to_bytes(),
+    )
+    .expect("Deserialization of the verifier should pass");
+    assert_eq!(
+        heapless.verify(&proof, &pi),
+        Err(Error::ProofVerificationError)
+    );
+}
@@ -0,0 +1,133 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_plonk::commitment_scheme::{
+    Ipa, IpaParameters, IpaProof, Kzg, PolynomialCommitment,
+};
+use dusk_plonk::prelude::*;
+use ff::Field;
+use merlin::Transcript;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+fn polynomial(degree: usize, rng: &mut StdRng) -> Vec<BlsScalar> {
+    (0..=degree).map(|_| BlsScalar::random(&mut *rng)).collect()
+}
+
+fn evaluate(coefficients: &[BlsScalar], point: &BlsScalar) -> BlsScalar {
+    coefficients
+        .iter()
+        .rev()
+        .fold(BlsScalar::zero(), |acc, c| acc * point + c)
+}
+
+#[test]
+fn ipa() {
+    let mut rng = StdRng::seed_from_u64(0x1ba);
+
+    // the generators are deterministic
+    let params = IpaParameters::new(40).expect("Parameters should derive");
+    assert_eq!(params.max_degree(), 63);
+    assert_eq!(params, IpaParameters::new(63).unwrap());
+
+    // the parameters survive a serialization roundtrip
+    let bytes = params.to_var_bytes();
+    assert_eq!(IpaParameters::from_slice(&bytes), Ok(params.clone()));
+    IpaParameters::from_slice(&bytes[48..])
+        .expect_err("Parameters without a power of two generators should fail");
+
+    let label = b"ipa";
+    for degree in [0, 1, 20, 31, 32, 63] {
+        let p = polynomial(degree, &mut rng);
+        let point = BlsScalar::random(&mut rng);
+
+        let proof = params
+            .open(&p, &point, &mut Transcript::new(label))
+            .expect("Opening should succeed");
+        assert_eq!(proof.commitment(), params.commit(&p).unwrap());
+        assert_eq!(proof.evaluation(), evaluate(&p, &point));
+        assert_eq!(
+            proof.rounds(),
+            (degree + 1).next_power_of_two().trailing_zeros() as usize
+        );
+
+        params
+            .verify(&point, &proof, &mut Transcript::new(label))
+            .expect("Verification of a valid opening should pass");
+
+        // the proof survives a serialization roundtrip
+        let bytes = proof.to_var_bytes();
+        let decoded = IpaProof::from_slice(&bytes).unwrap();
+        assert_eq!(decoded, proof);
+        IpaProof::from_slice(&bytes[..bytes.len() - 1])
+            .expect_err("Truncated proofs should be rejected");
+
+        // constant polynomials evaluate the same everywhere, and their
+        // openings don't take any round
+        if degree > 0 {
+            params
+                .verify(
+                    &(point + BlsScalar::one()),
+                    &proof,
+                    &mut Transcript::new(label),
+                )
+                .expect_err("Verification at another point should fail");
+            params
+                .verify(&point, &proof, &mut Transcript::new(b"other"))
+                .expect_err("Verification with another transcript should fail");
+        }
+    }
+
+    // polynomials over the max degree can't be committed to
+    let p = polynomial(64, &mut rng);
+    assert!(matches!(
+        params.commit(&p),
+        Err(Error::PolynomialDegreeTooLarge { .. })
+    ));
+
+    // proofs made with larger parameters can't be verified
+    let small = IpaParameters::new(15).unwrap();
+    let p = polynomial(40, &mut rng);
+    let point = BlsScalar::random(&mut rng);
+    let proof = params
+        .open(&p, &point, &mut Transcript::new(label))
+        .unwrap();
+    assert!(matches!(
+        small.verify(&point, &proof, &mut Transcript::new(label)),
+        Err(Error::PolynomialDegreeTooLarge { .. })
+    ));
+}
+
+// open and verify a polynomial with any backend
+fn roundtrip<P: PolynomialCommitment>(
+    ck: &P::CommitKey,
+    ok: &P::OpeningKey,
+    rng: &mut StdRng,
+) {
+    let p = polynomial(30, rng);
+    let point = BlsScalar::random(&mut *rng);
+
+    let label = b"backend";
+    let proof = P::open(ck, &p, &point, &mut Transcript::new(label))
+        .expect("Opening should succeed");
+    assert_eq!(P::commitment(&proof), P::commit(ck, &p).unwrap());
+    assert_eq!(P::evaluation(&proof), evaluate(&p, &point));
+
+    P::verify(ok, &point, &proof, &mut Transcript::new(label))
+        .expect("Verification of a valid opening should pass");
+}
+
+#[test]
+fn backends() {
+    let mut rng = StdRng::seed_from_u64(0xbac);
+
+    let pp = PublicParameters::setup(1 << 5, &mut rng).unwrap();
+    let (ck, ok) = pp.trim(1 << 5).unwrap();
+    roundtrip::<Kzg>(&ck, &ok, &mut rng);
+
+    let params = IpaParameters::new(1 << 5).unwrap();
+    roundtrip::<Ipa>(&params, &params, &mut rng);
+}
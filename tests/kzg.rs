@@ -0,0 +1,146 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_bytes::Serializable;
+use dusk_plonk::commitment_scheme::{
+    AggregateProof, CommitKey, OpeningKey, OpeningProof,
+};
+use dusk_plonk::prelude::*;
+use ff::Field;
+use merlin::Transcript;
+use rand::rngs::StdRng;
+use rand::{CryptoRng, RngCore, SeedableRng};
+
+fn keys(rng: &mut StdRng) -> (CommitKey, OpeningKey) {
+    let degree = 1 << 5;
+    let pp = PublicParameters::setup(degree, rng)
+        .expect("Creation of public parameter shouldn't fail");
+    pp.trim(degree).expect("Trimming shouldn't fail")
+}
+
+fn polynomial<R: RngCore + CryptoRng>(
+    degree: usize,
+    rng: &mut R,
+) -> Vec<BlsScalar> {
+    (0..=degree).map(|_| BlsScalar::random(&mut *rng)).collect()
+}
+
+#[test]
+fn open() {
+    let mut rng = StdRng::seed_from_u64(0x6a9);
+    let (ck, ok) = keys(&mut rng);
+
+    let p = polynomial(20, &mut rng);
+    let point = BlsScalar::random(&mut rng);
+
+    let proof = ck.open(&p, &point).expect("Opening should succeed");
+    assert_eq!(proof.commitment(), ck.commit(&p).unwrap());
+    let evaluation = p
+        .iter()
+        .rev()
+        .fold(BlsScalar::zero(), |acc, c| acc * point + c);
+    assert_eq!(proof.evaluation(), evaluation);
+    ok.verify(&point, &proof)
+        .expect("Verification of a valid opening should pass");
+
+    // the proof survives a serialization roundtrip
+    let bytes = proof.to_bytes();
+    let proof = OpeningProof::from_bytes(&bytes).unwrap();
+    ok.verify(&point, &proof)
+        .expect("Verification of a deserialized opening should pass");
+
+    // the proof doesn't verify at another point
+    ok.verify(&(point + BlsScalar::one()), &proof)
+        .expect_err("Verification at another point should fail");
+
+    // the proof doesn't verify for another evaluation
+    let mut bytes = proof.to_bytes();
+    bytes[48..80].copy_from_slice(&(evaluation + BlsScalar::one()).to_bytes());
+    let proof = OpeningProof::from_bytes(&bytes).unwrap();
+    ok.verify(&point, &proof)
+        .expect_err("Verification of a wrong evaluation should fail");
+
+    // polynomials over the max degree can't be committed to
+    let p = polynomial(ck.max_degree() + 1, &mut rng);
+    assert!(matches!(
+        ck.open(&p, &point),
+        Err(Error::PolynomialDegreeTooLarge { .. })
+    ));
+}
+
+#[test]
+fn batch_open() {
+    let mut rng = StdRng::seed_from_u64(0xba7);
+    let (ck, ok) = keys(&mut rng);
+
+    let polynomials: Vec<_> = [10, 20, 30]
+        .iter()
+        .map(|degree| polynomial(*degree, &mut rng))
+        .collect();
+    let point = BlsScalar::random(&mut rng);
+
+    let label = b"batch_open";
+    let proof = ck
+        .batch_open(&polynomials, &point, &mut Transcript::new(label))
+        .expect("Opening should succeed");
+    assert_eq!(proof.commitments().len(), 3);
+    assert_eq!(proof.evaluations().len(), 3);
+
+    ok.verify_aggregate(&point, &proof, &mut Transcript::new(label))
+        .expect("Verification of a valid opening should pass");
+
+    // the proof survives a serialization roundtrip
+    let bytes = proof.to_var_bytes();
+    let decoded = AggregateProof::from_slice(&bytes).unwrap();
+    assert_eq!(decoded, proof);
+    AggregateProof::from_slice(&bytes[..bytes.len() - 1])
+        .expect_err("Truncated proofs should be rejected");
+
+    // the transcripts must agree
+    ok.verify_aggregate(&point, &proof, &mut Transcript::new(b"other"))
+        .expect_err("Verification with another transcript should fail");
+    ok.verify_aggregate(
+        &(point + BlsScalar::one()),
+        &proof,
+        &mut Transcript::new(label),
+    )
+    .expect_err("Verification at another point should fail");
+
+    let empty: [Vec<BlsScalar>; 0] = [];
+    assert!(matches!(
+        ck.batch_open(&empty, &point, &mut Transcript::new(label)),
+        Err(Error::InvalidOpeningBatch { .. })
+    ));
+}
+
+#[test]
+fn batch_verify() {
+    let mut rng = StdRng::seed_from_u64(0xba8);
+    let (ck, ok) = keys(&mut rng);
+
+    let points: Vec<_> = (0..4).map(|_| BlsScalar::random(&mut rng)).collect();
+    let proofs: Vec<_> = points
+        .iter()
+        .map(|point| ck.open(&polynomial(25, &mut rng), point).unwrap())
+        .collect();
+
+    let label = b"batch_verify";
+    ok.batch_verify(&points, &proofs, &mut Transcript::new(label))
+        .expect("Verification of valid openings should pass");
+
+    let mut shuffled = points.clone();
+    shuffled.swap(0, 1);
+    ok.batch_verify(&shuffled, &proofs, &mut Transcript::new(label))
+        .expect_err("Verification of mismatched openings should fail");
+
+    assert!(matches!(
+        ok.batch_verify(&points[1..], &proofs, &mut Transcript::new(label)),
+        Err(Error::InvalidOpeningBatch {
+            points: 3,
+            proofs: 4
+        })
+    ));
+}
@@ -0,0 +1,115 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_plonk::prelude::*;
+use ff::Field;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+mod common;
+use common::{check_satisfied_circuit, check_unsatisfied_circuit};
+
+const TERMS: usize = 8;
+
+// the coefficients are part of the circuit description
+fn coeffs() -> [BlsScalar; TERMS] {
+    let mut coeffs = [BlsScalar::zero(); TERMS];
+    coeffs
+        .iter_mut()
+        .enumerate()
+        .for_each(|(i, coeff)| *coeff = BlsScalar::from(i as u64 * 3 + 1));
+    coeffs[TERMS - 1] = -coeffs[TERMS - 1];
+    coeffs
+}
+
+const CONSTANT: u64 = 42;
+
+#[test]
+fn linear_combination() {
+    #[derive(Default)]
+    pub struct TestCircuit {
+        values: [BlsScalar; TERMS],
+        result: BlsScalar,
+    }
+
+    impl TestCircuit {
+        pub fn new(values: [BlsScalar; TERMS], result: BlsScalar) -> Self {
+            Self { values, result }
+        }
+    }
+
+    impl Circuit for TestCircuit {
+        fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+        where
+            C: Composer,
+        {
+            let terms: Vec<(BlsScalar, Witness)> = coeffs()
+                .iter()
+                .zip(self.values.iter())
+                .map(|(coeff, value)| (*coeff, composer.append_witness(*value)))
+                .collect();
+
+            let result =
+                composer.component_linear_combination(&terms, CONSTANT);
+
+            composer.assert_equal_constant(
+                result,
+                BlsScalar::zero(),
+                Some(self.result),
+            );
+
+            Ok(())
+        }
+    }
+
+    let label = b"linear_combination";
+    let mut rng = StdRng::seed_from_u64(0x11c);
+    let capacity = 1 << 5;
+    let pp = PublicParameters::setup(capacity, &mut rng)
+        .expect("Creation of public parameter shouldn't fail");
+    let (prover, verifier) = Compiler::compile::<TestCircuit>(&pp, label)
+        .expect("Circuit should compile");
+
+    // Test satisfied circuit:
+    // constant + Σ coeff_i · value_i = result
+    let msg = "Verification of satisfied circuit should pass";
+    let mut values = [BlsScalar::zero(); TERMS];
+    values
+        .iter_mut()
+        .for_each(|value| *value = BlsScalar::random(&mut rng));
+    let result = coeffs()
+        .iter()
+        .zip(values.iter())
+        .fold(BlsScalar::from(CONSTANT), |acc, (coeff, value)| {
+            acc + coeff * value
+        });
+    let circuit = TestCircuit::new(values, result);
+    let pi = vec![result];
+    check_satisfied_circuit(&prover, &verifier, &pi, &circuit, &mut rng, &msg);
+
+    // Test unsatisfied circuit:
+    let msg = "Proof creation of unsatisfied circuit should fail";
+    let circuit = TestCircuit::new(values, result + BlsScalar::one());
+    check_unsatisfied_circuit(&prover, &circuit, &mut rng, &msg);
+}
+
+#[test]
+fn linear_combination_size() {
+    let mut composer = Builder::initialized();
+    let one = composer.append_witness(BlsScalar::one());
+
+    // (terms, gates): up to three terms fit a single gate and every
+    // subsequent gate consumes two more terms
+    for (terms, gates) in [(0, 1), (1, 1), (3, 1), (4, 2), (5, 2), (6, 3)] {
+        let terms = vec![(BlsScalar::one(), one); terms];
+
+        let before = composer.constraints();
+        let result = composer.component_linear_combination(&terms, 2);
+
+        assert_eq!(composer.constraints() - before, gates);
+        assert_eq!(composer[result], BlsScalar::from(2 + terms.len() as u64));
+    }
+}
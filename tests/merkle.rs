@@ -0,0 +1,142 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_plonk::composer::hash;
+use dusk_plonk::prelude::*;
+use ff::Field;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+mod common;
+use common::{check_satisfied_circuit, check_unsatisfied_circuit};
+
+/// Opening of a leaf in a tree of arity `A` and depth `D`
+struct TestCircuit<const A: usize, const D: usize> {
+    leaf: BlsScalar,
+    path: [([BlsScalar; A], u64); D],
+    root: BlsScalar,
+}
+
+impl<const A: usize, const D: usize> Default for TestCircuit<A, D> {
+    fn default() -> Self {
+        Self {
+            leaf: BlsScalar::zero(),
+            path: [([BlsScalar::zero(); A], 0); D],
+            root: BlsScalar::zero(),
+        }
+    }
+}
+
+impl<const A: usize, const D: usize> TestCircuit<A, D> {
+    /// Open the leaf at `index` of a tree built from `leaves`
+    fn new(leaves: &[BlsScalar], index: usize) -> Self {
+        let mut path = [([BlsScalar::zero(); A], 0); D];
+        let mut level = leaves.to_vec();
+        let mut position = index;
+
+        path.iter_mut().for_each(|(children, pos)| {
+            let start = position - position % A;
+            children.copy_from_slice(&level[start..start + A]);
+            *pos = (position % A) as u64;
+
+            level = level.chunks(A).map(hash).collect();
+            position /= A;
+        });
+
+        Self {
+            leaf: leaves[index],
+            path,
+            root: level[0],
+        }
+    }
+}
+
+impl<const A: usize, const D: usize> Circuit for TestCircuit<A, D> {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer,
+    {
+        let leaf = composer.append_witness(self.leaf);
+        let path: Vec<([Witness; A], Witness)> = self
+            .path
+            .iter()
+            .map(|(children, position)| {
+                let mut witnesses = [C::ZERO; A];
+                witnesses.iter_mut().zip(children).for_each(|(w, child)| {
+                    *w = composer.append_witness(*child)
+                });
+                (witnesses, composer.append_witness(*position))
+            })
+            .collect();
+
+        let root = composer.component_merkle_root(leaf, &path);
+        composer.assert_equal_constant(root, 0, Some(self.root));
+
+        Ok(())
+    }
+}
+
+/// Open leaves of a tree of arity `A` and depth `D`
+fn component_merkle_root<const A: usize, const D: usize>(
+    rng: &mut StdRng,
+    pp: &PublicParameters,
+) {
+    let label = b"component_merkle_root";
+
+    let (prover, verifier) = Compiler::compile::<TestCircuit<A, D>>(pp, label)
+        .expect("Circuit should compile");
+
+    let leaves: Vec<BlsScalar> = (0..A.pow(D as u32))
+        .map(|_| BlsScalar::random(&mut *rng))
+        .collect();
+    let index = leaves.len() - 2;
+
+    let msg = "The opening of a leaf should satisfy the circuit";
+    let circuit = TestCircuit::<A, D>::new(&leaves, index);
+    let pi = vec![circuit.root];
+    check_satisfied_circuit(&prover, &verifier, &pi, &circuit, rng, &msg);
+
+    let msg = "The opening of another leaf should satisfy the circuit";
+    let circuit = TestCircuit::<A, D>::new(&leaves, 1);
+    check_satisfied_circuit(&prover, &verifier, &pi, &circuit, rng, &msg);
+
+    let msg = "The opening of a wrong leaf should not satisfy the circuit";
+    let mut circuit = TestCircuit::<A, D>::new(&leaves, index);
+    circuit.leaf += BlsScalar::one();
+    check_unsatisfied_circuit(&prover, &circuit, rng, &msg);
+
+    let msg = "The opening at a wrong position should not satisfy the circuit";
+    let mut circuit = TestCircuit::<A, D>::new(&leaves, index);
+    circuit.path[0].1 += 1;
+    check_unsatisfied_circuit(&prover, &circuit, rng, &msg);
+
+    let msg = "A position out of the arity should not satisfy the circuit";
+    let mut circuit = TestCircuit::<A, D>::new(&leaves, index);
+    circuit.path[D - 1].1 = A as u64;
+    check_unsatisfied_circuit(&prover, &circuit, rng, &msg);
+
+    let msg = "A node missing from its parent should not satisfy the circuit";
+    let mut circuit = TestCircuit::<A, D>::new(&leaves, index);
+    let position = circuit.path[D - 1].1 as usize;
+    circuit.path[D - 1].0[position] += BlsScalar::one();
+    check_unsatisfied_circuit(&prover, &circuit, rng, &msg);
+
+    let msg = "The opening of a wrong root should not satisfy the circuit";
+    let mut circuit = TestCircuit::<A, D>::new(&leaves, index);
+    circuit.root += BlsScalar::one();
+    check_unsatisfied_circuit(&prover, &circuit, rng, &msg);
+}
+
+#[test]
+fn component_merkle_root_arities() {
+    let rng = &mut StdRng::seed_from_u64(0x4b0);
+    let pp = PublicParameters::setup(1 << 13, rng)
+        .expect("Creation of public parameter shouldn't fail");
+
+    component_merkle_root::<2, 3>(rng, &pp);
+    component_merkle_root::<4, 3>(rng, &pp);
+    component_merkle_root::<8, 2>(rng, &pp);
+}
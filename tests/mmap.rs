@@ -0,0 +1,105 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use std::fs;
+
+use dusk_plonk::commitment_scheme::MappedPublicParameters;
+use dusk_plonk::prelude::*;
+use ff::Field;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use tempdir::TempDir;
+
+#[derive(Default)]
+pub struct TestCircuit {
+    a: BlsScalar,
+    b: BlsScalar,
+    c: BlsScalar,
+}
+
+impl Circuit for TestCircuit {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer,
+    {
+        let a = composer.append_witness(self.a);
+        let b = composer.append_witness(self.b);
+
+        let constraint = Constraint::new().mult(1).a(a).b(b).public(-self.c);
+        composer.append_gate(constraint);
+
+        Ok(())
+    }
+}
+
+#[test]
+fn mapped_public_parameters() {
+    let label = b"mapped_public_parameters";
+    let mut rng = StdRng::seed_from_u64(0x33a9);
+    let pp = PublicParameters::setup(1 << 8, &mut rng)
+        .expect("Creation of public parameter shouldn't fail");
+
+    let dir = TempDir::new("plonk-mmap").expect("failed to create dir");
+    let path = dir.path().join("pp.bin");
+    fs::write(&path, pp.to_raw_var_bytes()).expect("failed to write pp");
+
+    let mapped = unsafe { MappedPublicParameters::open(&path) }
+        .expect("Mapping the parameters should pass");
+    assert_eq!(mapped.max_degree(), pp.max_degree());
+
+    // the loaded parameters are the ones trimmed from the whole parameters
+    let loaded = mapped.load(32).expect("Loading the parameters should pass");
+    assert_eq!(loaded.max_degree(), 32);
+    let (commit_key, _) = loaded.trim(16).expect("Trimming should pass");
+    let (expected, _) = pp.trim(16).expect("Trimming should pass");
+    assert_eq!(commit_key, expected);
+    assert!(mapped.load(pp.max_degree() + 1).is_err());
+
+    let trimmed = mapped
+        .trim_to_circuit::<TestCircuit>()
+        .expect("Trimming to the circuit should pass");
+    assert_eq!(
+        trimmed.to_raw_var_bytes(),
+        pp.trim_to_circuit::<TestCircuit>()
+            .expect("Trimming to the circuit should pass")
+            .to_raw_var_bytes()
+    );
+
+    let (prover, verifier) = Compiler::compile::<TestCircuit>(&trimmed, label)
+        .expect("Circuit should compile with the trimmed parameters");
+
+    let a = BlsScalar::random(&mut rng);
+    let b = BlsScalar::random(&mut rng);
+    let circuit = TestCircuit { a, b, c: a * b };
+    let (proof, pi) = prover
+        .prove(&mut rng, &circuit)
+        .expect("Proof creation of satisfied circuit should pass");
+
+    verifier
+        .verify(&proof, &pi)
+        .expect("Proof verification should pass");
+}
+
+#[test]
+fn mapped_public_parameters_truncated() {
+    let mut rng = StdRng::seed_from_u64(0x33aa);
+    let pp = PublicParameters::setup(1 << 4, &mut rng)
+        .expect("Creation of public parameter shouldn't fail");
+
+    let dir = TempDir::new("plonk-mmap").expect("failed to create dir");
+    let path = dir.path().join("pp.bin");
+
+    // the file doesn't hold all the powers it declares
+    let mut bytes = pp.to_raw_var_bytes();
+    bytes.truncate(bytes.len() - 1);
+    fs::write(&path, bytes).expect("failed to write pp");
+
+    let mapped = unsafe { MappedPublicParameters::open(&path) };
+    assert_eq!(mapped.err(), Some(Error::NotEnoughBytes));
+
+    let missing = dir.path().join("missing.bin");
+    assert!(unsafe { MappedPublicParameters::open(missing) }.is_err());
+}
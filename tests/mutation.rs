@@ -0,0 +1,122 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_plonk::prelude::*;
+
+// a · b = product, with a in range and the product a public input
+#[derive(Default)]
+struct TestCircuit {
+    a: BlsScalar,
+    b: BlsScalar,
+    product: BlsScalar,
+    unused_factor: bool,
+}
+
+impl Circuit for TestCircuit {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer,
+    {
+        let a = composer.append_witness(self.a);
+        let b = composer.append_witness(self.b);
+
+        composer.label("range");
+        composer.component_range::<8>(a);
+
+        composer.label("product");
+        let b = match self.unused_factor {
+            // b is multiplied by zero, any value satisfies the gate
+            true => {
+                let constraint = Constraint::new().mult(1).a(b).b(C::ZERO);
+                composer.gate_mul(constraint);
+                C::ONE
+            }
+            false => b,
+        };
+        let constraint = Constraint::new().mult(1).a(a).b(b);
+        let product = composer.gate_mul(constraint);
+        composer.assert_equal_constant(product, 0, Some(self.product));
+
+        Ok(())
+    }
+}
+
+fn builder(circuit: &TestCircuit) -> Builder {
+    let mut builder = Builder::initialized();
+    circuit
+        .circuit(&mut builder)
+        .expect("Synthesis of the circuit shouldn't fail");
+
+    builder
+}
+
+#[test]
+fn constrained_witnesses() {
+    let circuit = TestCircuit {
+        a: BlsScalar::from(0xfe),
+        b: BlsScalar::from(3),
+        product: BlsScalar::from(0xfe * 3),
+        unused_factor: false,
+    };
+
+    builder(&circuit)
+        .check_witness_mutations()
+        .expect("Every witness should be constrained");
+}
+
+#[test]
+fn under_constrained_witness() {
+    let circuit = TestCircuit {
+        a: BlsScalar::from(0xfe),
+        b: BlsScalar::from(3),
+        product: BlsScalar::from(0xfe),
+        unused_factor: true,
+    };
+    let builder = builder(&circuit);
+
+    builder
+        .check_satisfied()
+        .expect("The gates of the circuit should be satisfied");
+
+    let err = builder
+        .check_witness_mutations()
+        .expect_err("The witness multiplied by zero should be flagged");
+    match err {
+        Error::UnderConstrainedWitness {
+            witness,
+            gate,
+            label,
+        } => {
+            // b follows a and the 6 witnesses of the initialized builder
+            assert_eq!(witness, 7);
+            assert_eq!(label, Some("product"));
+            assert_eq!(builder.label_of(gate), label);
+        }
+        err => panic!("unexpected error: {err}"),
+    }
+}
+
+#[test]
+fn unsatisfied_circuit() {
+    let circuit = TestCircuit {
+        a: BlsScalar::from(0xfe),
+        b: BlsScalar::from(3),
+        product: BlsScalar::from(0xfe),
+        unused_factor: false,
+    };
+
+    assert_eq!(
+        builder(&circuit).check_witness_mutations(),
+        builder(&circuit).check_satisfied(),
+    );
+    assert!(matches!(
+        builder(&circuit).check_satisfied(),
+        Err(Error::UnsatisfiedConstraint {
+            label: Some("product"),
+            ..
+        })
+    ));
+}
@@ -0,0 +1,454 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_plonk::composer::{DeadGateElimination, Optimizer};
+use dusk_plonk::prelude::*;
+use ff::Field;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+mod common;
+use common::{check_satisfied_circuit, check_unsatisfied_circuit};
+
+#[test]
+fn common_subexpressions() {
+    #[derive(Default)]
+    pub struct TestCircuit {
+        a: BlsScalar,
+        b: BlsScalar,
+        result: BlsScalar,
+    }
+
+    impl TestCircuit {
+        pub fn new(a: BlsScalar, b: BlsScalar, result: BlsScalar) -> Self {
+            Self { a, b, result }
+        }
+    }
+
+    impl Circuit for TestCircuit {
+        fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+        where
+            C: Composer,
+        {
+            let a = composer.append_witness(self.a);
+            let b = composer.append_witness(self.b);
+
+            // a·b + a
+            let constraint = Constraint::new().mult(1).left(1).a(a).b(b);
+            let x = composer.gate_mul(constraint);
+
+            // b·a + a is the same expression
+            let constraint = Constraint::new().mult(1).right(1).a(b).b(a);
+            let y = composer.gate_mul(constraint);
+
+            // the same assertion twice
+            composer.assert_equal(x, y);
+            composer.assert_equal(x, y);
+
+            let constraint = Constraint::new().left(1).right(1).a(x).b(y);
+            let sum = composer.gate_add(constraint);
+            let expected = composer.append_public(self.result);
+            composer.assert_equal(sum, expected);
+
+            composer.eliminate_common_subexpressions();
+
+            Ok(())
+        }
+    }
+
+    // the pass is idempotent
+    let mut composer = Builder::initialized();
+    TestCircuit::default()
+        .circuit(&mut composer)
+        .expect("circuit should build");
+    let constraints = composer.constraints();
+    assert_eq!(composer.eliminate_common_subexpressions(), 0);
+    assert_eq!(composer.constraints(), constraints);
+
+    let label = b"common_subexpressions";
+    let mut rng = StdRng::seed_from_u64(0xc5e);
+    let capacity = 1 << 4;
+    let pp = PublicParameters::setup(capacity, &mut rng)
+        .expect("Creation of public parameter shouldn't fail");
+    let (prover, verifier) = Compiler::compile::<TestCircuit>(&pp, label)
+        .expect("Circuit should compile");
+
+    // Test satisfied circuit
+    let msg = "Verification of satisfied circuit should pass";
+    let a = BlsScalar::random(&mut rng);
+    let b = BlsScalar::random(&mut rng);
+    let result = (a * b + a).double();
+    let circuit = TestCircuit::new(a, b, result);
+    let pi = vec![result];
+    check_satisfied_circuit(&prover, &verifier, &pi, &circuit, &mut rng, &msg);
+
+    // Test unsatisfied circuit
+    let msg = "Proof creation of unsatisfied circuit should fail";
+    let circuit = TestCircuit::new(a, b, result + BlsScalar::one());
+    check_unsatisfied_circuit(&prover, &circuit, &mut rng, &msg);
+}
+
+#[test]
+fn common_subexpressions_count() {
+    let mut composer = Builder::initialized();
+
+    let a = composer.append_witness(BlsScalar::from(3));
+    let b = composer.append_witness(BlsScalar::from(5));
+
+    let constraint = Constraint::new().mult(1).a(a).b(b);
+    let x = composer.gate_mul(constraint);
+    let y = composer.gate_mul(constraint);
+    let z = composer.gate_mul(Constraint::new().mult(1).a(b).b(a));
+
+    // consumes the merged outputs
+    let w = composer.gate_add(Constraint::new().left(1).right(1).a(y).b(z));
+    composer.assert_equal(x, w);
+
+    let constraints = composer.constraints();
+    assert_eq!(composer.eliminate_common_subexpressions(), 2);
+    assert_eq!(composer.constraints(), constraints - 2);
+}
+
+#[test]
+fn constant_folding() {
+    #[derive(Default)]
+    pub struct TestCircuit {
+        a: BlsScalar,
+        result: BlsScalar,
+    }
+
+    impl TestCircuit {
+        pub fn new(a: BlsScalar, result: BlsScalar) -> Self {
+            Self { a, result }
+        }
+    }
+
+    impl Circuit for TestCircuit {
+        fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+        where
+            C: Composer,
+        {
+            let a = composer.append_witness(self.a);
+
+            let three = composer.append_constant(3);
+            let four = composer.append_constant(4);
+
+            // 3 + 4 + 1 is a constant
+            let constraint = Constraint::new()
+                .left(1)
+                .right(1)
+                .constant(1)
+                .a(three)
+                .b(four);
+            let eight = composer.gate_add(constraint);
+
+            // (a · 8) · 3
+            let constraint = Constraint::new().mult(1).a(a).b(eight);
+            let x = composer.gate_mul(constraint);
+            let constraint = Constraint::new().mult(1).a(x).b(three);
+            let x = composer.gate_mul(constraint);
+
+            // 8 == 8 is always satisfied
+            let eight_again = composer.append_constant(8);
+            composer.assert_equal(eight, eight_again);
+
+            let expected = composer.append_public(self.result);
+            composer.assert_equal(x, expected);
+
+            composer.fold_constants();
+
+            Ok(())
+        }
+    }
+
+    // the pass is idempotent
+    let mut composer = Builder::initialized();
+    TestCircuit::default()
+        .circuit(&mut composer)
+        .expect("circuit should build");
+    let constraints = composer.constraints();
+    assert_eq!(composer.fold_constants(), 0);
+    assert_eq!(composer.constraints(), constraints);
+
+    // the constants three, four, eight and one are no longer referenced, and
+    // `8 == 8` is satisfied regardless of the witnesses
+    let mut unoptimized = Builder::initialized();
+    let a = unoptimized.append_witness(BlsScalar::one());
+    let three = unoptimized.append_constant(3);
+    let four = unoptimized.append_constant(4);
+    let constraint = Constraint::new()
+        .left(1)
+        .right(1)
+        .constant(1)
+        .a(three)
+        .b(four);
+    let eight = unoptimized.gate_add(constraint);
+    unoptimized.gate_mul(Constraint::new().mult(1).a(a).b(eight));
+    let eight_again = unoptimized.append_constant(8);
+    unoptimized.assert_equal(eight, eight_again);
+    let constraints = unoptimized.constraints();
+    assert_eq!(unoptimized.fold_constants(), 6);
+    assert_eq!(unoptimized.constraints(), constraints - 6);
+
+    let label = b"constant_folding";
+    let mut rng = StdRng::seed_from_u64(0xf01d);
+    let capacity = 1 << 4;
+    let pp = PublicParameters::setup(capacity, &mut rng)
+        .expect("Creation of public parameter shouldn't fail");
+    let (prover, verifier) = Compiler::compile::<TestCircuit>(&pp, label)
+        .expect("Circuit should compile");
+
+    // Test satisfied circuit
+    let msg = "Verification of satisfied circuit should pass";
+    let a = BlsScalar::random(&mut rng);
+    let result = a * BlsScalar::from(24);
+    let circuit = TestCircuit::new(a, result);
+    let pi = vec![result];
+    check_satisfied_circuit(&prover, &verifier, &pi, &circuit, &mut rng, &msg);
+
+    // Test unsatisfied circuit
+    let msg = "Proof creation of unsatisfied circuit should fail";
+    let circuit = TestCircuit::new(a, result + BlsScalar::one());
+    check_unsatisfied_circuit(&prover, &circuit, &mut rng, &msg);
+}
+
+#[test]
+fn constant_folding_inconsistent() {
+    #[derive(Default)]
+    pub struct TestCircuit;
+
+    impl Circuit for TestCircuit {
+        fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+        where
+            C: Composer,
+        {
+            let three = composer.append_constant(3);
+            let four = composer.append_constant(4);
+
+            // 3 == 4 must remain unsatisfiable
+            composer.assert_equal(three, four);
+
+            composer.fold_constants();
+
+            Ok(())
+        }
+    }
+
+    let label = b"constant_folding_inconsistent";
+    let mut rng = StdRng::seed_from_u64(0xf01e);
+    let capacity = 1 << 4;
+    let pp = PublicParameters::setup(capacity, &mut rng)
+        .expect("Creation of public parameter shouldn't fail");
+    let (prover, verifier) = Compiler::compile::<TestCircuit>(&pp, label)
+        .expect("Circuit should compile");
+
+    // the circuit is too small for the prover to detect it is unsatisfied
+    prover
+        .prove(&mut rng, &TestCircuit)
+        .and_then(|(proof, pi)| verifier.verify(&proof, &pi))
+        .expect_err("Verification of unsatisfied circuit should fail");
+}
+
+#[test]
+fn dead_gates() {
+    #[derive(Default)]
+    pub struct TestCircuit {
+        a: BlsScalar,
+        b: BlsScalar,
+        result: BlsScalar,
+    }
+
+    impl TestCircuit {
+        pub fn new(a: BlsScalar, b: BlsScalar, result: BlsScalar) -> Self {
+            Self { a, b, result }
+        }
+    }
+
+    impl Circuit for TestCircuit {
+        fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+        where
+            C: Composer,
+        {
+            let a = composer.append_witness(self.a);
+            let b = composer.append_witness(self.b);
+
+            // (a · b + 1)^2 is never used
+            let constraint = Constraint::new().mult(1).constant(1).a(a).b(b);
+            let x = composer.gate_mul(constraint);
+            let constraint = Constraint::new().mult(1).a(x).b(x);
+            composer.gate_mul(constraint);
+
+            // a + b is asserted
+            let constraint = Constraint::new().left(1).right(1).a(a).b(b);
+            let sum = composer.gate_add(constraint);
+            let expected = composer.append_public(self.result);
+            composer.assert_equal(sum, expected);
+
+            composer.eliminate_dead_gates(&[]);
+
+            Ok(())
+        }
+    }
+
+    let mut composer = Builder::initialized();
+    let a = composer.append_witness(BlsScalar::from(2));
+    let b = composer.append_witness(BlsScalar::from(3));
+    let constraint = Constraint::new().mult(1).constant(1).a(a).b(b);
+    let x = composer.gate_mul(constraint);
+    let constraint = Constraint::new().mult(1).a(x).b(x);
+    let y = composer.gate_mul(constraint);
+    let constraints = composer.constraints();
+
+    // the squared output is explicitly preserved, hence its input as well
+    assert_eq!(composer.eliminate_dead_gates(&[y]), 0);
+    assert_eq!(composer.constraints(), constraints);
+
+    // the whole chain is removed
+    assert_eq!(composer.eliminate_dead_gates(&[]), 2);
+    assert_eq!(composer.constraints(), constraints - 2);
+
+    let label = b"dead_gates";
+    let mut rng = StdRng::seed_from_u64(0xdead);
+    let capacity = 1 << 4;
+    let pp = PublicParameters::setup(capacity, &mut rng)
+        .expect("Creation of public parameter shouldn't fail");
+    let (prover, verifier) = Compiler::compile::<TestCircuit>(&pp, label)
+        .expect("Circuit should compile");
+
+    // Test satisfied circuit
+    let msg = "Verification of satisfied circuit should pass";
+    let a = BlsScalar::random(&mut rng);
+    let b = BlsScalar::random(&mut rng);
+    let result = a + b;
+    let circuit = TestCircuit::new(a, b, result);
+    let pi = vec![result];
+    check_satisfied_circuit(&prover, &verifier, &pi, &circuit, &mut rng, &msg);
+
+    // Test unsatisfied circuit
+    let msg = "Proof creation of unsatisfied circuit should fail";
+    let circuit = TestCircuit::new(a, b, result + BlsScalar::one());
+    check_unsatisfied_circuit(&prover, &circuit, &mut rng, &msg);
+}
+
+#[test]
+fn optimize() {
+    #[derive(Default)]
+    pub struct TestCircuit {
+        a: BlsScalar,
+        b: BlsScalar,
+        result: BlsScalar,
+    }
+
+    impl TestCircuit {
+        pub fn new(a: BlsScalar, b: BlsScalar, result: BlsScalar) -> Self {
+            Self { a, b, result }
+        }
+    }
+
+    impl Circuit for TestCircuit {
+        fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+        where
+            C: Composer,
+        {
+            let a = composer.append_witness(self.a);
+            let b = composer.append_witness(self.b);
+
+            // a·b is computed twice
+            let constraint = Constraint::new().mult(1).a(a).b(b);
+            let x = composer.gate_mul(constraint);
+            let y = composer.gate_mul(constraint);
+
+            // x + y + 2 · 3
+            let two = composer.append_constant(2);
+            let three = composer.append_constant(3);
+            let constraint = Constraint::new().mult(1).a(two).b(three);
+            let six = composer.gate_mul(constraint);
+            let constraint = Constraint::new()
+                .left(1)
+                .right(1)
+                .fourth(1)
+                .a(x)
+                .b(y)
+                .d(six);
+            let sum = composer.gate_add(constraint);
+
+            // (a + b)^2 is never used
+            let constraint = Constraint::new().left(1).right(1).a(a).b(b);
+            let z = composer.gate_add(constraint);
+            let constraint = Constraint::new().mult(1).a(z).b(z);
+            composer.gate_mul(constraint);
+
+            let expected = composer.append_public(self.result);
+            composer.assert_equal(sum, expected);
+
+            composer.optimize(OptLevel::Aggressive);
+
+            Ok(())
+        }
+    }
+
+    let mut composer = Builder::initialized();
+    TestCircuit::default()
+        .circuit(&mut composer)
+        .expect("circuit should build");
+    let constraints = composer.constraints();
+
+    // the optimized circuit is a fixpoint of the pipeline
+    let report = composer.optimize(OptLevel::Aggressive);
+    assert_eq!(report.constraints(), constraints);
+    assert_eq!(report.removed(), 0);
+    assert_eq!(report.passes().len(), 3);
+
+    // nothing runs without optimization
+    let report = composer.optimize(OptLevel::None);
+    assert!(report.passes().is_empty());
+
+    // the same circuit without the optimization
+    let mut unoptimized = Builder::initialized();
+    let a = unoptimized.append_witness(BlsScalar::one());
+    let b = unoptimized.append_witness(BlsScalar::one());
+    let constraint = Constraint::new().mult(1).a(a).b(b);
+    unoptimized.gate_mul(constraint);
+    unoptimized.gate_mul(constraint);
+    let constraint = Constraint::new().left(1).right(1).a(a).b(b);
+    let z = unoptimized.gate_add(constraint);
+    unoptimized.gate_mul(Constraint::new().mult(1).a(z).b(z));
+
+    let constraints = unoptimized.constraints();
+    let report = Optimizer::with_level(OptLevel::Basic).run(&mut unoptimized);
+    // the constant `1` defined by the initialized builder isn't referenced
+    assert_eq!(report.removed_by("common subexpression elimination"), 1);
+    assert_eq!(report.removed_by("constant folding"), 1);
+    assert_eq!(report.removed_by("dead gate elimination"), 0);
+
+    let report = Optimizer::new()
+        .with_pass(DeadGateElimination::new(&[]))
+        .run(&mut unoptimized);
+    assert_eq!(report.passes(), &[("dead gate elimination", 3)]);
+    assert_eq!(unoptimized.constraints(), constraints - 5);
+
+    let label = b"optimize";
+    let mut rng = StdRng::seed_from_u64(0x0b7);
+    let capacity = 1 << 4;
+    let pp = PublicParameters::setup(capacity, &mut rng)
+        .expect("Creation of public parameter shouldn't fail");
+    let (prover, verifier) = Compiler::compile::<TestCircuit>(&pp, label)
+        .expect("Circuit should compile");
+
+    // Test satisfied circuit
+    let msg = "Verification of satisfied circuit should pass";
+    let a = BlsScalar::random(&mut rng);
+    let b = BlsScalar::random(&mut rng);
+    let result = (a * b).double() + BlsScalar::from(6);
+    let circuit = TestCircuit::new(a, b, result);
+    let pi = vec![result];
+    check_satisfied_circuit(&prover, &verifier, &pi, &circuit, &mut rng, &msg);
+
+    // Test unsatisfied circuit
+    let msg = "Proof creation of unsatisfied circuit should fail";
+    let circuit = TestCircuit::new(a, b, result + BlsScalar::one());
+    check_unsatisfied_circuit(&prover, &circuit, &mut rng, &msg);
+}
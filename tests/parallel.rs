@@ -0,0 +1,167 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_plonk::composer::hash;
+use dusk_plonk::prelude::*;
+use ff::Field;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+mod common;
+use common::{check_satisfied_circuit, check_unsatisfied_circuit};
+
+const OPENINGS: usize = 4;
+const DEPTH: usize = 3;
+
+type Path = [([BlsScalar; 2], u64); DEPTH];
+
+/// Open `OPENINGS` leaves of a binary tree, with the paths synthesized in
+/// parallel if `P`
+struct TestCircuit<const P: bool> {
+    leaves: [BlsScalar; OPENINGS],
+    paths: [Path; OPENINGS],
+    root: BlsScalar,
+}
+
+impl<const P: bool> Default for TestCircuit<P> {
+    fn default() -> Self {
+        Self {
+            leaves: [BlsScalar::zero(); OPENINGS],
+            paths: [[([BlsScalar::zero(); 2], 0); DEPTH]; OPENINGS],
+            root: BlsScalar::zero(),
+        }
+    }
+}
+
+impl<const P: bool> TestCircuit<P> {
+    /// Open the leaves at `indexes` of a tree built from `leaves`
+    fn new(leaves: &[BlsScalar], indexes: [usize; OPENINGS]) -> Self {
+        let mut circuit = Self::default();
+
+        for (i, index) in indexes.iter().enumerate() {
+            let mut level = leaves.to_vec();
+            let mut position = *index;
+
+            circuit.paths[i].iter_mut().for_each(|(children, pos)| {
+                let start = position - position % 2;
+                children.copy_from_slice(&level[start..start + 2]);
+                *pos = (position % 2) as u64;
+
+                level = level.chunks(2).map(hash).collect();
+                position /= 2;
+            });
+
+            circuit.leaves[i] = leaves[*index];
+            circuit.root = level[0];
+        }
+
+        circuit
+    }
+}
+
+/// Root of the tree opened at `leaf` with `path`
+fn opening<C: Composer>(
+    composer: &mut C,
+    leaf: Witness,
+    path: &Path,
+) -> Witness {
+    let path: Vec<([Witness; 2], Witness)> = path
+        .iter()
+        .map(|(children, position)| {
+            let children = [
+                composer.append_witness(children[0]),
+                composer.append_witness(children[1]),
+            ];
+            (children, composer.append_witness(*position))
+        })
+        .collect();
+
+    composer.component_merkle_root(leaf, &path)
+}
+
+impl<const P: bool> Circuit for TestCircuit<P> {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer,
+    {
+        let leaves: Vec<Witness> = self
+            .leaves
+            .iter()
+            .map(|leaf| composer.append_witness(*leaf))
+            .collect();
+
+        let roots: Vec<Witness> = match P {
+            true => {
+                let groups: Vec<(Vec<Witness>, Path)> = leaves
+                    .iter()
+                    .zip(self.paths)
+                    .map(|(leaf, path)| (vec![*leaf], path))
+                    .collect();
+
+                composer
+                    .component_parallel(&groups, |builder, inputs, path| {
+                        vec![opening(builder, inputs[0], path)]
+                    })
+                    .into_iter()
+                    .map(|outputs| outputs[0])
+                    .collect()
+            }
+            false => leaves
+                .iter()
+                .zip(self.paths.iter())
+                .map(|(leaf, path)| opening(composer, *leaf, path))
+                .collect(),
+        };
+
+        roots.iter().for_each(|root| {
+            composer.assert_equal_constant(*root, 0, Some(self.root))
+        });
+
+        Ok(())
+    }
+}
+
+#[test]
+fn component_parallel() {
+    let label = b"component_parallel";
+    let rng = &mut StdRng::seed_from_u64(0x9a7);
+    let pp = PublicParameters::setup(1 << 14, rng)
+        .expect("Creation of public parameter shouldn't fail");
+
+    let (prover, verifier) = Compiler::compile::<TestCircuit<true>>(&pp, label)
+        .expect("Circuit should compile");
+    let (_, sequential) = Compiler::compile::<TestCircuit<false>>(&pp, label)
+        .expect("Circuit should compile");
+
+    // the circuit is the one of the sequential synthesis
+    assert_eq!(verifier.to_bytes(), sequential.to_bytes());
+
+    let leaves: Vec<BlsScalar> = (0..1 << DEPTH)
+        .map(|_| BlsScalar::random(&mut *rng))
+        .collect();
+    let indexes = [0, 3, 5, 7];
+
+    let msg = "The parallel openings should satisfy the circuit";
+    let circuit = TestCircuit::<true>::new(&leaves, indexes);
+    let pi = vec![circuit.root; OPENINGS];
+    check_satisfied_circuit(&prover, &verifier, &pi, &circuit, rng, &msg);
+
+    let msg = "The sequential verifier should verify the parallel proof";
+    let (proof, _) = prover
+        .prove(rng, &circuit)
+        .expect("Proof creation of satisfied circuit should pass");
+    sequential.verify(&proof, &pi).expect(msg);
+
+    let msg = "A wrong leaf should not satisfy the circuit";
+    let mut circuit = TestCircuit::<true>::new(&leaves, indexes);
+    circuit.leaves[2] += BlsScalar::one();
+    check_unsatisfied_circuit(&prover, &circuit, rng, &msg);
+
+    let msg = "A wrong path should not satisfy the circuit";
+    let mut circuit = TestCircuit::<true>::new(&leaves, indexes);
+    circuit.paths[3][1].0[0] += BlsScalar::one();
+    check_unsatisfied_circuit(&prover, &circuit, rng, &msg);
+}
@@ -0,0 +1,87 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_jubjub::{GENERATOR_EXTENDED, GENERATOR_NUMS_EXTENDED};
+use dusk_plonk::prelude::*;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+mod common;
+use common::{check_satisfied_circuit, check_unsatisfied_circuit};
+
+fn commitment(value: &JubJubScalar, blinder: &JubJubScalar) -> JubJubAffine {
+    (GENERATOR_EXTENDED * value + GENERATOR_NUMS_EXTENDED * blinder).into()
+}
+
+#[test]
+fn assert_pedersen_opening() {
+    #[derive(Default)]
+    pub struct TestCircuit {
+        value: JubJubScalar,
+        blinder: JubJubScalar,
+        commitment: JubJubAffine,
+    }
+
+    impl Circuit for TestCircuit {
+        fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+        where
+            C: Composer,
+        {
+            let value = composer.append_witness(self.value);
+            let blinder = composer.append_witness(self.blinder);
+
+            composer.assert_pedersen_opening(value, blinder, self.commitment)
+        }
+    }
+
+    let label = b"assert_pedersen_opening";
+    let rng = &mut StdRng::seed_from_u64(0xbed);
+    let capacity = 1 << 12;
+    let pp = PublicParameters::setup(capacity, rng)
+        .expect("Creation of public parameter shouldn't fail");
+    let (prover, verifier) = Compiler::compile::<TestCircuit>(&pp, label)
+        .expect("Circuit should compile");
+
+    // Test the opening of an external commitment
+    let msg = "The opening of the commitment should satisfy the circuit";
+    let value = JubJubScalar::from(0xdeadu64);
+    let blinder = JubJubScalar::random(&mut *rng);
+    let commitment = commitment(&value, &blinder);
+    let circuit = TestCircuit {
+        value,
+        blinder,
+        commitment,
+    };
+    let pi = vec![commitment.get_u(), commitment.get_v()];
+    check_satisfied_circuit(&prover, &verifier, &pi, &circuit, rng, &msg);
+
+    // Test another value
+    let msg = "Another value should not open the commitment";
+    let circuit = TestCircuit {
+        value: value + JubJubScalar::one(),
+        blinder,
+        commitment,
+    };
+    check_unsatisfied_circuit(&prover, &circuit, rng, &msg);
+
+    // Test another blinder
+    let msg = "Another blinder should not open the commitment";
+    let circuit = TestCircuit {
+        value,
+        blinder: JubJubScalar::random(&mut *rng),
+        commitment,
+    };
+    check_unsatisfied_circuit(&prover, &circuit, rng, &msg);
+
+    // Test the commitment of the swapped generators
+    let msg = "The generators should not be interchangeable";
+    let circuit = TestCircuit {
+        value: blinder,
+        blinder: value,
+        commitment,
+    };
+    check_unsatisfied_circuit(&prover, &circuit, rng, &msg);
+}
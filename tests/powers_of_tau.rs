@@ -0,0 +1,159 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_bls12_381::{G1Affine, G1Projective, G2Affine};
+use dusk_plonk::prelude::*;
+use ff::Field;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+const POWER: u32 = 5;
+
+// Transcript of a ceremony of 2^POWER powers of `tau`, stripped of the alpha
+// and beta powers
+fn transcript(tau: BlsScalar, compressed: bool) -> Vec<u8> {
+    let layout = PowersOfTau::challenge(POWER);
+    let mut bytes = vec![0u8; 64];
+
+    let mut g = G1Projective::from(G1Affine::generator());
+    (0..layout.g1_powers()).for_each(|_| {
+        let p = G1Affine::from(g);
+        match compressed {
+            true => bytes.extend_from_slice(&p.to_compressed()),
+            false => bytes.extend_from_slice(&p.to_uncompressed()),
+        }
+        g *= tau;
+    });
+
+    let mut h = G2Affine::generator();
+    (0..1 << POWER).for_each(|_| {
+        match compressed {
+            true => bytes.extend_from_slice(&h.to_compressed()),
+            false => bytes.extend_from_slice(&h.to_uncompressed()),
+        }
+        h = (h * tau).into();
+    });
+
+    bytes
+}
+
+#[derive(Default)]
+pub struct TestCircuit {
+    a: BlsScalar,
+    b: BlsScalar,
+    c: BlsScalar,
+}
+
+impl Circuit for TestCircuit {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer,
+    {
+        let a = composer.append_witness(self.a);
+        let b = composer.append_witness(self.b);
+
+        let constraint = Constraint::new().mult(1).a(a).b(b).public(-self.c);
+        composer.append_gate(constraint);
+
+        Ok(())
+    }
+}
+
+#[test]
+fn powers_of_tau() {
+    let label = b"powers_of_tau";
+    let mut rng = StdRng::seed_from_u64(0x7a0);
+    let tau = BlsScalar::random(&mut rng);
+
+    for (layout, compressed) in [
+        (PowersOfTau::challenge(POWER), false),
+        (PowersOfTau::response(POWER), true),
+    ] {
+        let bytes = transcript(tau, compressed);
+        let pp = PublicParameters::from_powers_of_tau(&bytes, layout, 1 << 4)
+            .expect("Import of a valid transcript should pass");
+
+        let (prover, verifier) = Compiler::compile::<TestCircuit>(&pp, label)
+            .expect("Circuit should compile");
+
+        let a = BlsScalar::random(&mut rng);
+        let b = BlsScalar::random(&mut rng);
+        let circuit = TestCircuit { a, b, c: a * b };
+        let (proof, pi) = prover
+            .prove(&mut rng, &circuit)
+            .expect("Proof creation of satisfied circuit should pass");
+
+        verifier
+            .verify(&proof, &pi)
+            .expect("Verification with imported parameters should pass");
+    }
+}
+
+#[test]
+fn powers_of_tau_invalid() {
+    let mut rng = StdRng::seed_from_u64(0x7a1);
+    let tau = BlsScalar::random(&mut rng);
+    let layout = PowersOfTau::challenge(POWER);
+    let bytes = transcript(tau, false);
+
+    // the transcript doesn't hold enough powers
+    let result = PublicParameters::from_powers_of_tau(&bytes, layout, 1 << 6);
+    assert_eq!(
+        result.err(),
+        Some(Error::TruncatedDegreeTooLarge {
+            degree: (1 << 6) + 6,
+            max_degree: (1 << 6) - 2,
+        })
+    );
+
+    // the transcript is truncated
+    let result =
+        PublicParameters::from_powers_of_tau(&bytes[..1024], layout, 8);
+    assert_eq!(result.err(), Some(Error::NotEnoughBytes));
+
+    // a point is not on the curve
+    let mut malformed = bytes.clone();
+    malformed[64 + 96 * 3 + 10] ^= 1;
+    let result = PublicParameters::from_powers_of_tau(&malformed, layout, 8);
+    assert_eq!(result.err(), Some(Error::PointMalformed));
+
+    // a point is replaced by a valid point that isn't the expected power
+    let mut tampered = bytes.clone();
+    let p = G1Affine::from(G1Affine::generator() * BlsScalar::from(3));
+    tampered[64 + 96 * 3..64 + 96 * 4].copy_from_slice(&p.to_uncompressed());
+    let result = PublicParameters::from_powers_of_tau(&tampered, layout, 8);
+    assert_eq!(result.err(), Some(Error::PairingCheckFailure));
+}
+
+#[test]
+fn from_powers() {
+    let mut rng = StdRng::seed_from_u64(0x7a2);
+    let tau = BlsScalar::random(&mut rng);
+
+    let mut g = G1Projective::from(G1Affine::generator());
+    let mut powers: Vec<G1Affine> = (0..16)
+        .map(|_| {
+            let p = G1Affine::from(g);
+            g *= tau;
+            p
+        })
+        .collect();
+    let h = G2Affine::generator();
+    let beta_h = G2Affine::from(h * tau);
+
+    PublicParameters::from_powers(powers.clone(), h, beta_h)
+        .expect("Valid powers should pass");
+
+    let result = PublicParameters::from_powers(powers.clone(), h, h);
+    assert_eq!(result.err(), Some(Error::PairingCheckFailure));
+
+    let result = PublicParameters::from_powers(powers[..1].to_vec(), h, beta_h);
+    assert_eq!(result.err(), Some(Error::DegreeIsZero));
+
+    powers[5] = G1Affine::identity();
+    let result = PublicParameters::from_powers(powers, h, beta_h);
+    assert_eq!(result.err(), Some(Error::PointMalformed));
+}
@@ -0,0 +1,201 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_bytes::Serializable;
+use dusk_plonk::prelude::*;
+use dusk_plonk::protobuf;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+// a · b = c, with a and c public
+#[derive(Default)]
+struct TestCircuit {
+    a: BlsScalar,
+    b: BlsScalar,
+}
+
+impl Circuit for TestCircuit {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer,
+    {
+        let a = composer.append_public(self.a);
+        let b = composer.append_witness(self.b);
+
+        let c = composer.gate_mul(Constraint::new().mult(1).a(a).b(b));
+        composer.assert_equal_constant(c, 0, Some(self.a * self.b));
+
+        Ok(())
+    }
+}
+
+fn setup(seed: u64) -> (Prover, Verifier) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let pp = PublicParameters::setup(1 << 4, &mut rng)
+        .expect("Creation of public parameter shouldn't fail");
+
+    Compiler::compile::<TestCircuit>(&pp, b"protobuf")
+        .expect("Circuit should compile")
+}
+
+#[test]
+fn protobuf_roundtrip() {
+    let mut rng = StdRng::seed_from_u64(0x94);
+    let (prover, verifier) = setup(0x93);
+    let circuit = TestCircuit {
+        a: BlsScalar::from(3),
+        b: BlsScalar::from(5),
+    };
+    let (proof, public_inputs) = prover
+        .prove(&mut rng, &circuit)
+        .expect("the proof should be created");
+
+    let message = proof.to_protobuf();
+    let decoded =
+        Proof::from_protobuf(&message).expect("the proof should be decoded");
+    assert_eq!(decoded, proof);
+
+    // the commitments and evaluations are length-delimited fields, with a
+    // key of two bytes for the field 16 and a length of two bytes for the
+    // evaluations
+    assert_eq!(message.len(), Proof::SIZE + 11 * 2 + 3 + 16 * 2 + 1);
+    assert_eq!(&message[..2], &[0x0a, 48]);
+
+    let message = protobuf::public_inputs_to_protobuf(&public_inputs);
+    let decoded = protobuf::public_inputs_from_protobuf(&message)
+        .expect("the public inputs should be decoded");
+    assert_eq!(decoded, public_inputs);
+    assert_eq!(protobuf::public_inputs_from_protobuf(&[]), Ok(Vec::new()));
+
+    let message = verifier.to_protobuf();
+    let decoded = Verifier::from_protobuf(&message)
+        .expect("the verifier should be decoded");
+    assert_eq!(decoded.to_bytes(), verifier.to_bytes());
+    decoded
+        .verify(&proof, &public_inputs)
+        .expect("the proof should verify");
+
+    assert!(protobuf::SCHEMA.contains("package dusk.plonk.v1;"));
+}
+
+#[test]
+fn protobuf_unknown_fields() {
+    let mut rng = StdRng::seed_from_u64(0x95);
+    let (prover, verifier) = setup(0x93);
+    let circuit = TestCircuit {
+        a: BlsScalar::from(7),
+        b: BlsScalar::from(11),
+    };
+    let (proof, public_inputs) = prover
+        .prove(&mut rng, &circuit)
+        .expect("the proof should be created");
+
+    // field 20 as a varint, field 21 as fixed64, field 22 as bytes and field
+    // 23 as fixed32
+    let unknown = [
+        0xa0, 0x01, 0x2a, 0xa9, 0x01, 0, 0, 0, 0, 0, 0, 0, 0, 0xb2, 0x01, 2,
+        0xca, 0xfe, 0xbd, 0x01, 0, 0, 0, 0,
+    ];
+
+    let mut message = unknown.to_vec();
+    message.extend(proof.to_protobuf());
+    message.extend(unknown);
+    assert_eq!(Proof::from_protobuf(&message), Ok(proof.clone()));
+
+    let mut message = protobuf::public_inputs_to_protobuf(&public_inputs);
+    message.extend(unknown);
+    assert_eq!(
+        protobuf::public_inputs_from_protobuf(&message),
+        Ok(public_inputs.clone())
+    );
+
+    let mut message = verifier.to_protobuf();
+    message.extend(unknown);
+    let decoded = Verifier::from_protobuf(&message)
+        .expect("the verifier should be decoded");
+    decoded
+        .verify(&proof, &public_inputs)
+        .expect("the proof should verify");
+
+    // a later occurrence of the evaluations is merged into the first one
+    let mut message = proof.to_protobuf();
+    let mut eval = BlsScalar::from(42).to_bytes().to_vec();
+    eval.splice(0..0, [0x0a, 32]);
+    message.extend([0x62, eval.len() as u8]);
+    message.extend(eval);
+    let decoded = Proof::from_protobuf(&message)
+        .expect("the merged proof should be decoded");
+    assert_ne!(decoded, proof);
+    assert_eq!(
+        &decoded.to_bytes()[11 * 48..11 * 48 + 32],
+        &BlsScalar::from(42).to_bytes()
+    );
+}
+
+#[test]
+fn protobuf_invalid_messages() {
+    let mut rng = StdRng::seed_from_u64(0x96);
+    let (prover, verifier) = setup(0x93);
+    let circuit = TestCircuit {
+        a: BlsScalar::from(2),
+        b: BlsScalar::from(9),
+    };
+    let (proof, _) = prover
+        .prove(&mut rng, &circuit)
+        .expect("the proof should be created");
+    let message = proof.to_protobuf();
+
+    // truncated, missing evaluations and group fields
+    assert_eq!(
+        Proof::from_protobuf(&message[..message.len() - 1]),
+        Err(Error::InvalidProtobuf)
+    );
+    assert_eq!(
+        Proof::from_protobuf(&message[..11 * 50]),
+        Err(Error::InvalidProtobuf)
+    );
+    let mut grouped = message.clone();
+    grouped.extend([0xa3, 0x01, 0xa4, 0x01]);
+    assert_eq!(Proof::from_protobuf(&grouped), Err(Error::InvalidProtobuf));
+
+    // a commitment of the wrong size, or as a varint
+    let mut short = message.clone();
+    short.extend([0x0a, 1, 0]);
+    assert_eq!(Proof::from_protobuf(&short), Err(Error::InvalidProtobuf));
+    let mut varint = message.clone();
+    varint.extend([0x08, 1]);
+    assert_eq!(Proof::from_protobuf(&varint), Err(Error::InvalidProtobuf));
+
+    // a field number of zero, and an overlong varint
+    assert_eq!(
+        Proof::from_protobuf(&[0x02, 0]),
+        Err(Error::InvalidProtobuf)
+    );
+    assert_eq!(
+        protobuf::public_inputs_from_protobuf(&[0xa0; 11]),
+        Err(Error::InvalidProtobuf)
+    );
+
+    // a public input out of the scalar field
+    let mut message = vec![0x0a, 32];
+    message.extend([0xff; 32]);
+    assert!(protobuf::public_inputs_from_protobuf(&message).is_err());
+
+    // a verifier of the circuit preprocessed with other parameters
+    let (_, other) = setup(0x97);
+    let mut message = Vec::new();
+    message.extend([0x0a, 32]);
+    message.extend(other.circuit_hash());
+    message.extend(&verifier.to_protobuf()[34..]);
+    assert_eq!(
+        Verifier::from_protobuf(&message).err(),
+        Some(Error::CircuitHashMismatch)
+    );
+    assert_eq!(
+        Verifier::from_protobuf(&verifier.to_protobuf()[..34]).err(),
+        Some(Error::InvalidProtobuf)
+    );
+}
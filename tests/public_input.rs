@@ -0,0 +1,193 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_plonk::prelude::*;
+use ff::Field;
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+
+mod common;
+use common::{check_satisfied_circuit, check_unsatisfied_circuit};
+
+pub struct TestCircuit {
+    scalar: BlsScalar,
+    point: JubJubAffine,
+    flag: bool,
+    n: u64,
+    bytes: [u8; 40],
+}
+
+impl Default for TestCircuit {
+    fn default() -> Self {
+        Self {
+            scalar: BlsScalar::zero(),
+            point: JubJubAffine::identity(),
+            flag: false,
+            n: 0,
+            bytes: [0; 40],
+        }
+    }
+}
+
+impl TestCircuit {
+    fn values(&self) -> Vec<PublicInputValue> {
+        vec![
+            self.scalar.into(),
+            self.point.into(),
+            self.flag.into(),
+            self.n.into(),
+            self.bytes.into(),
+        ]
+    }
+}
+
+impl Circuit for TestCircuit {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer,
+    {
+        composer.append_public_value(self.scalar);
+
+        // the point appended with its coordinates
+        let point = composer.append_point(self.point);
+        let expected = composer.append_public_value(self.point);
+        composer.assert_equal(*point.x(), expected[0]);
+        composer.assert_equal(*point.y(), expected[1]);
+
+        let flag = composer.append_public_value(self.flag);
+        composer.component_boolean(flag[0]);
+
+        let n = composer.append_public_value(self.n);
+        composer.component_range::<16>(n[0]);
+
+        let bytes = composer.append_public_value(self.bytes);
+        assert_eq!(bytes.len(), 2);
+
+        Ok(())
+    }
+}
+
+#[test]
+fn public_input_value() {
+    let label = b"public_input_value";
+    let rng = &mut StdRng::seed_from_u64(0x9b1);
+    let pp = PublicParameters::setup(1 << 6, rng)
+        .expect("Creation of public parameter shouldn't fail");
+    let (prover, verifier) = Compiler::compile::<TestCircuit>(&pp, label)
+        .expect("Circuit should compile");
+
+    let mut bytes = [0u8; 40];
+    rng.fill_bytes(&mut bytes);
+    let circuit = TestCircuit {
+        scalar: BlsScalar::random(&mut *rng),
+        point: (dusk_jubjub::GENERATOR_EXTENDED * JubJubScalar::from(7u64))
+            .into(),
+        flag: true,
+        n: u32::MAX as u64,
+        bytes,
+    };
+
+    // the expansion of the values are the public inputs of the proof
+    let pi = PublicInputValue::expand_all(&circuit.values());
+    assert_eq!(pi.len(), 7);
+    assert_eq!(pi[1], circuit.point.get_u());
+    assert_eq!(pi[2], circuit.point.get_v());
+
+    let msg = "The expanded values should verify the proof";
+    check_satisfied_circuit(&prover, &verifier, &pi, &circuit, rng, &msg);
+
+    // the bytes are packed by chunks of 31 little endian bytes
+    let mut chunk = [0u8; 32];
+    chunk[..9].copy_from_slice(&bytes[31..]);
+    assert_eq!(pi[6], BlsScalar::from_bytes(&chunk).unwrap());
+
+    let msg = "An integer over 32 bits should not satisfy the circuit";
+    let circuit = TestCircuit {
+        n: u64::MAX,
+        ..Default::default()
+    };
+    check_unsatisfied_circuit(&prover, &circuit, rng, &msg);
+}
+
+const CAPACITY: usize = 4;
+
+#[derive(Default)]
+pub struct BatchCircuit {
+    items: PaddedVec<CAPACITY>,
+}
+
+impl Circuit for BatchCircuit {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer,
+    {
+        let (_, values) = composer.append_public_padded(&self.items);
+
+        // items of 8 bits
+        values
+            .iter()
+            .for_each(|v| composer.component_range::<4>(*v));
+
+        Ok(())
+    }
+}
+
+#[test]
+fn padded_vec() {
+    let label = b"padded_vec";
+    let rng = &mut StdRng::seed_from_u64(0xba7c4);
+    let pp = PublicParameters::setup(1 << 7, rng)
+        .expect("Creation of public parameter shouldn't fail");
+    let (prover, verifier) = Compiler::compile::<BatchCircuit>(&pp, label)
+        .expect("Circuit should compile");
+
+    let values: Vec<BlsScalar> = [3u64, 1, 4].map(BlsScalar::from).to_vec();
+    let items = PaddedVec::new(values.clone()).expect("values should fit");
+
+    // the length followed by the values padded with zeros
+    let pi = items.expand();
+    assert_eq!(pi.len(), CAPACITY + 1);
+    assert_eq!(pi[0], BlsScalar::from(3));
+    assert_eq!(&pi[1..4], values.as_slice());
+    assert_eq!(pi[4], BlsScalar::zero());
+
+    let msg = "A padded vector should satisfy the circuit";
+    let circuit = BatchCircuit { items };
+    check_satisfied_circuit(&prover, &verifier, &pi, &circuit, rng, &msg);
+
+    let msg = "A full vector should satisfy the circuit";
+    let full = PaddedVec::new(vec![BlsScalar::one(); CAPACITY]).unwrap();
+    let pi = full.expand();
+    let circuit = BatchCircuit { items: full };
+    check_satisfied_circuit(&prover, &verifier, &pi, &circuit, rng, &msg);
+
+    // a non-zero padding is rejected by the verifier
+    let (proof, _) = prover
+        .prove(
+            rng,
+            &BatchCircuit {
+                items: PaddedVec::new(values.clone()).unwrap(),
+            },
+        )
+        .expect("Proof creation of satisfied circuit should pass");
+    let mut pi = PaddedVec::<CAPACITY>::new(values).unwrap().expand();
+    pi[4] = BlsScalar::from(5);
+    assert_eq!(
+        verifier.verify(&proof, &pi),
+        Err(Error::ProofVerificationError)
+    );
+
+    let msg = "A vector over its capacity should be rejected";
+    let err = PaddedVec::<CAPACITY>::new(vec![BlsScalar::one(); 5]);
+    assert_eq!(
+        err,
+        Err(Error::PaddedVecTooLong {
+            length: 5,
+            capacity: CAPACITY
+        }),
+        "{msg}"
+    );
+}
@@ -0,0 +1,104 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+/// Run the Python `code` with the `dusk_plonk` module imported
+fn run(code: &str) -> PyResult<()> {
+    pyo3::prepare_freethreaded_python();
+
+    Python::with_gil(|py| {
+        let module = PyModule::new(py, "dusk_plonk")?;
+        dusk_plonk::python::dusk_plonk(py, module)?;
+
+        let globals = PyDict::new(py);
+        globals.set_item("dusk_plonk", module)?;
+
+        py.run(code, Some(globals), None)
+    })
+}
+
+#[test]
+fn python_gadgets() {
+    run(r#"
+pp = dusk_plonk.PublicParameters.setup(1 << 11)
+pp = dusk_plonk.PublicParameters.from_bytes(pp.to_bytes())
+
+prover, verifier = dusk_plonk.compile(pp, "mul")
+proof, public_inputs = prover.prove([3, 5])
+assert public_inputs == [15]
+assert verifier.verify(proof, public_inputs)
+assert not verifier.verify(proof, [16])
+
+verifier = dusk_plonk.Verifier.from_bytes(verifier.to_bytes())
+assert verifier.verify(proof, public_inputs)
+
+prover, verifier = dusk_plonk.compile(pp, "range", bits=16, label=b"range")
+proof, public_inputs = prover.prove([0xffff])
+assert public_inputs == []
+assert verifier.verify(proof, public_inputs)
+try:
+    prover.prove([0x10000])
+    assert False
+except dusk_plonk.PlonkError:
+    pass
+
+prover, verifier = dusk_plonk.compile(pp, "xor", bits=32)
+proof, public_inputs = prover.prove([0xcafe, 0xbeef])
+assert public_inputs == [0xcafe ^ 0xbeef]
+assert verifier.verify(proof, public_inputs)
+
+prover, verifier = dusk_plonk.compile(pp, "and", bits=8)
+proof, public_inputs = prover.prove([0xca, 0xfe])
+assert public_inputs == [0xca & 0xfe]
+assert verifier.verify(proof, public_inputs)
+
+prover, verifier = dusk_plonk.compile(pp, "mul_generator")
+proof, public_inputs = prover.prove([0xf00d])
+assert len(public_inputs) == 2
+assert verifier.verify(proof, public_inputs)
+assert prover.constraints > 0
+"#)
+    .expect("the gadgets should be proven and verified from Python");
+}
+
+#[test]
+fn python_errors() {
+    run(r#"
+pp = dusk_plonk.PublicParameters.setup(1 << 6)
+
+for gadget, bits in [("sha256", None), ("range", None), ("xor", 7)]:
+    try:
+        dusk_plonk.compile(pp, gadget, bits=bits)
+        assert False
+    except ValueError:
+        pass
+
+prover, verifier = dusk_plonk.compile(pp, "mul")
+for inputs in [[1], [1, 2, 3]]:
+    try:
+        prover.prove(inputs)
+        assert False
+    except dusk_plonk.PlonkError:
+        pass
+
+# the integers must fit the scalar field
+r = 0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001
+try:
+    prover.prove([r, 1])
+    assert False
+except ValueError:
+    pass
+
+try:
+    verifier.verify(b"proof", [15])
+    assert False
+except ValueError:
+    pass
+"#)
+    .expect("the errors should be raised to Python");
+}
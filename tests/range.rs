@@ -4,13 +4,16 @@
 //
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
+use dusk_bytes::Serializable;
 use dusk_plonk::prelude::*;
 use ff::Field;
 use rand::rngs::StdRng;
 use rand::SeedableRng;
 
 mod common;
-use common::{check_satisfied_circuit, check_unsatisfied_circuit};
+use common::{
+    check_satisfied_circuit, check_unsatisfied_circuit, expect_unsatisfied,
+};
 
 #[test]
 fn range() {
@@ -109,7 +112,16 @@ fn range() {
     let msg = "Verification of a satisfied circuit should pass";
     let a = BlsScalar::pow_of_2(73);
     let circuit: TestCircuit<BIT_PAIRS_37> = TestCircuit::new(a);
-    check_satisfied_circuit(&prover, &verifier, &pi, &circuit, &mut rng, &msg);
+    let metrics = check_satisfied_circuit(
+        &prover, &verifier, &pi, &circuit, &mut rng, &msg,
+    );
+
+    assert_eq!(metrics.gates, prover.constraints());
+    // 4 gates of the initialized composer, 10 gates accumulating 8 bits
+    // each, the gate of the genesis quad and the final equality
+    assert_eq!(metrics.gates, 4 + (BIT_PAIRS_37 * 2 + 7) / 8 + 1 + 1);
+    assert_eq!(metrics.public_inputs, 0);
+    assert_eq!(metrics.proof_size, Proof::SIZE);
 
     // Test:
     // 2^74 - 1 < 2^74
@@ -147,3 +159,66 @@ fn range() {
     let circuit: TestCircuit<BIT_PAIRS_128> = TestCircuit::new(a);
     check_satisfied_circuit(&prover, &verifier, &pi, &circuit, &mut rng, &msg);
 }
+
+#[test]
+fn range_labeled() {
+    // a + b, with a in range and the sum equal to a public input
+    #[derive(Default)]
+    pub struct TestCircuit {
+        a: BlsScalar,
+        b: BlsScalar,
+        sum: BlsScalar,
+    }
+
+    impl Circuit for TestCircuit {
+        fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+        where
+            C: Composer,
+        {
+            let a = composer.append_witness(self.a);
+            let b = composer.append_witness(self.b);
+
+            composer.label("range");
+            composer.component_range::<8>(a);
+
+            composer.label("sum");
+            let constraint = Constraint::new().left(1).right(1).a(a).b(b);
+            let sum = composer.gate_add(constraint);
+            composer.assert_equal_constant(sum, 0, Some(self.sum));
+
+            Ok(())
+        }
+    }
+
+    let label = b"range_labeled";
+    let mut rng = StdRng::seed_from_u64(0x1abe1);
+    let pp = PublicParameters::setup(1 << 6, &mut rng)
+        .expect("Creation of public parameter shouldn't fail");
+    let (prover, verifier) = Compiler::compile::<TestCircuit>(&pp, label)
+        .expect("Circuit should compile");
+
+    let circuit = TestCircuit {
+        a: BlsScalar::from(0xffff),
+        b: BlsScalar::from(1),
+        sum: BlsScalar::from(0x10000),
+    };
+    let pi = vec![BlsScalar::from(0x10000)];
+    let msg = "Verification of a satisfied circuit should pass";
+    check_satisfied_circuit(&prover, &verifier, &pi, &circuit, &mut rng, &msg);
+
+    // a out of range
+    let circuit = TestCircuit {
+        a: BlsScalar::from(0x10000),
+        b: BlsScalar::from(0),
+        sum: BlsScalar::from(0x10000),
+    };
+    expect_unsatisfied(&prover, &circuit, &mut rng, "range");
+
+    // wrong sum
+    let circuit = TestCircuit {
+        a: BlsScalar::from(0xffff),
+        b: BlsScalar::from(2),
+        sum: BlsScalar::from(0x10000),
+    };
+    expect_unsatisfied(&prover, &circuit, &mut rng, "sum");
+}
@@ -0,0 +1,114 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_bytes::Serializable;
+use dusk_plonk::prelude::*;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+const CAPACITY: usize = 1 << 5;
+const LABEL: &[u8] = b"reproducible_proofs";
+
+#[derive(Debug, Default)]
+struct TestCircuit {
+    a: BlsScalar,
+    b: BlsScalar,
+}
+
+impl TestCircuit {
+    fn new(a: u64, b: u64) -> Self {
+        Self {
+            a: BlsScalar::from(a),
+            b: BlsScalar::from(b),
+        }
+    }
+}
+
+impl Circuit for TestCircuit {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer,
+    {
+        let a = composer.append_witness(self.a);
+        let b = composer.append_witness(self.b);
+
+        let constraint = Constraint::new().mult(1).a(a).b(b);
+        let product = composer.gate_mul(constraint);
+
+        composer.assert_equal_constant(product, 0, Some(self.a * self.b));
+
+        Ok(())
+    }
+}
+
+fn prove(
+    prover: &Prover,
+    seed: u64,
+    circuit: &TestCircuit,
+) -> ([u8; Proof::SIZE], Vec<BlsScalar>) {
+    let rng = &mut StdRng::seed_from_u64(seed);
+    let (proof, public_inputs) =
+        prover.prove(rng, circuit).expect("proving should succeed");
+
+    (proof.to_bytes(), public_inputs)
+}
+
+#[test]
+fn seeded_proofs_are_reproducible() {
+    let rng = &mut StdRng::seed_from_u64(0x5eed);
+    let pp = PublicParameters::setup(CAPACITY, rng)
+        .expect("Creation of public parameter shouldn't fail");
+    let (prover, verifier) = Compiler::compile::<TestCircuit>(&pp, LABEL)
+        .expect("It should be possible to compile the prover and verifier");
+
+    let circuit = TestCircuit::new(7, 11);
+    let (proof, public_inputs) = prove(&prover, 0xbeef, &circuit);
+
+    // the same seed yields the same proof, even from a restored prover
+    assert_eq!(
+        prove(&prover, 0xbeef, &circuit),
+        (proof, public_inputs.clone())
+    );
+    let restored = Prover::try_from_bytes(prover.to_bytes())
+        .expect("the prover should be restored from its bytes");
+    assert_eq!(
+        prove(&restored, 0xbeef, &circuit),
+        (proof, public_inputs.clone())
+    );
+
+    // another seed blinds the same witnesses differently
+    let (other, other_public_inputs) = prove(&prover, 0xcafe, &circuit);
+    assert_ne!(other, proof);
+    assert_eq!(other_public_inputs, public_inputs);
+
+    let proof = Proof::from_bytes(&proof).expect("the proof should decode");
+    verifier
+        .verify(&proof, &public_inputs)
+        .expect("the seeded proof should verify");
+}
+
+#[test]
+fn seeded_batches_are_reproducible() {
+    let rng = &mut StdRng::seed_from_u64(0xba7c);
+    let pp = PublicParameters::setup(CAPACITY, rng)
+        .expect("Creation of public parameter shouldn't fail");
+    let (prover, _verifier) = Compiler::compile::<TestCircuit>(&pp, LABEL)
+        .expect("It should be possible to compile the prover and verifier");
+
+    let circuits = [
+        TestCircuit::new(1, 2),
+        TestCircuit::new(3, 4),
+        TestCircuit::new(5, 6),
+    ];
+    let batch = |seed| {
+        prover
+            .prove_many(&mut StdRng::seed_from_u64(seed), &circuits)
+            .expect("proving should succeed")
+    };
+
+    assert_eq!(batch(0xf00d), batch(0xf00d));
+    assert_ne!(batch(0xf00d), batch(0xd00f));
+}
@@ -0,0 +1,240 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_bytes::Serializable;
+use dusk_plonk::commitment_scheme::{
+    AggregateProof, CommitKey, Commitment, LagrangeKey, OpeningKey,
+    OpeningProof,
+};
+use dusk_plonk::fft::{EvaluationDomain, Evaluations, Polynomial};
+use dusk_plonk::prelude::*;
+use ff::Field;
+use merlin::Transcript;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+#[derive(Default)]
+pub struct TestCircuit {
+    a: BlsScalar,
+    b: BlsScalar,
+}
+
+impl Circuit for TestCircuit {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer,
+    {
+        let a = composer.append_witness(self.a);
+        let b = composer.append_witness(self.b);
+
+        let constraint =
+            Constraint::new().mult(1).a(a).b(b).public(-self.a * self.b);
+        composer.append_gate(constraint);
+
+        Ok(())
+    }
+}
+
+#[test]
+fn serde() {
+    let label = b"serde";
+    let mut rng = StdRng::seed_from_u64(0x5e2de);
+    let capacity = 1 << 4;
+    let pp = PublicParameters::setup(capacity, &mut rng)
+        .expect("Creation of public parameter shouldn't fail");
+    let (prover, verifier) = Compiler::compile::<TestCircuit>(&pp, label)
+        .expect("Circuit should compile");
+
+    let circuit = TestCircuit {
+        a: BlsScalar::random(&mut rng),
+        b: BlsScalar::random(&mut rng),
+    };
+    let (proof, pi) = prover
+        .prove(&mut rng, &circuit)
+        .expect("Proof creation of satisfied circuit should pass");
+
+    // human readable format
+    let json = serde_json::to_string(&pp).expect("serialization should pass");
+    let got: PublicParameters =
+        serde_json::from_str(&json).expect("deserialization should pass");
+    assert_eq!(got.to_var_bytes(), pp.to_var_bytes());
+
+    let json =
+        serde_json::to_string(&prover).expect("serialization should pass");
+    let prover: Prover =
+        serde_json::from_str(&json).expect("deserialization should pass");
+
+    let json = serde_json::to_string(&(&proof, &pi))
+        .expect("serialization should pass");
+    let (proof, pi): (Proof, Vec<BlsScalar>) =
+        serde_json::from_str(&json).expect("deserialization should pass");
+
+    let json =
+        serde_json::to_string(&verifier).expect("serialization should pass");
+    let verifier: Verifier =
+        serde_json::from_str(&json).expect("deserialization should pass");
+
+    verifier
+        .verify(&proof, &pi)
+        .expect("Verification of a deserialized proof should pass");
+
+    // binary format
+    let cbor =
+        serde_cbor::to_vec(&(&proof, &pi)).expect("serialization should pass");
+    let (got_proof, got_pi): (Proof, Vec<BlsScalar>) =
+        serde_cbor::from_slice(&cbor).expect("deserialization should pass");
+    assert_eq!(got_proof, proof);
+    assert_eq!(got_pi, pi);
+
+    let cbor =
+        serde_cbor::to_vec(&verifier).expect("serialization should pass");
+    let verifier: Verifier =
+        serde_cbor::from_slice(&cbor).expect("deserialization should pass");
+
+    let mut acc = Accumulator::new();
+    verifier
+        .accumulate(&mut rng, &mut acc, &proof, &pi)
+        .expect("Accumulation of a valid proof should pass");
+    let cbor = serde_cbor::to_vec(&acc).expect("serialization should pass");
+    let acc: Accumulator =
+        serde_cbor::from_slice(&cbor).expect("deserialization should pass");
+    verifier
+        .discharge(&acc)
+        .expect("Discharge of a valid proof should pass");
+
+    // a fresh proof of the deserialized prover
+    let (proof, pi) = prover
+        .prove(&mut rng, &circuit)
+        .expect("Proof creation of satisfied circuit should pass");
+    verifier
+        .verify(&proof, &pi)
+        .expect("Verification of a satisfied circuit should pass");
+
+    let bundle = prover
+        .prove_bundle(&mut rng, &circuit)
+        .expect("Proof creation of satisfied circuit should pass");
+    let (json, cbor) = roundtrip(&bundle);
+    assert_eq!(json, bundle);
+    verifier
+        .verify_bundle(&cbor)
+        .expect("Verification of a deserialized bundle should pass");
+
+    // malformed inputs
+    serde_json::from_str::<Proof>("\"00\"")
+        .expect_err("deserialization of a truncated proof should fail");
+    serde_json::from_str::<Proof>("\"zz\"")
+        .expect_err("deserialization of an invalid hex string should fail");
+}
+
+/// Decode `value` from its JSON and CBOR representations
+fn roundtrip<T>(value: &T) -> (T, T)
+where
+    T: Serialize + DeserializeOwned,
+{
+    let json = serde_json::to_string(value).expect("serialization should pass");
+    let cbor = serde_cbor::to_vec(value).expect("serialization should pass");
+
+    (
+        serde_json::from_str(&json).expect("deserialization should pass"),
+        serde_cbor::from_slice(&cbor).expect("deserialization should pass"),
+    )
+}
+
+#[test]
+fn serde_commitment_scheme() {
+    let mut rng = StdRng::seed_from_u64(0x5e2df);
+    let pp = PublicParameters::setup(1 << 4, &mut rng)
+        .expect("Creation of public parameter shouldn't fail");
+    let (commit_key, opening_key) = pp.trim(1 << 4).unwrap();
+
+    let (json, cbor) = roundtrip(&commit_key);
+    assert_eq!(json, commit_key);
+    assert_eq!(cbor, commit_key);
+
+    let (json, cbor): (OpeningKey, _) = roundtrip(&opening_key);
+    assert_eq!(json.to_bytes(), opening_key.to_bytes());
+    assert_eq!(cbor.to_bytes(), opening_key.to_bytes());
+
+    let coefficients: Vec<BlsScalar> =
+        (0..8).map(|_| BlsScalar::random(&mut rng)).collect();
+    let point = BlsScalar::random(&mut rng);
+
+    let commitment = commit_key.commit(&coefficients).unwrap();
+    assert_eq!(
+        roundtrip::<Commitment>(&commitment),
+        (commitment, commitment)
+    );
+
+    let proof = commit_key.open(&coefficients, &point).unwrap();
+    let (proof, _): (OpeningProof, _) = roundtrip(&proof);
+    opening_key
+        .verify(&point, &proof)
+        .expect("a deserialized opening should verify");
+
+    let proof = commit_key
+        .batch_open(&[&coefficients], &point, &mut Transcript::new(b"serde"))
+        .unwrap();
+    let (json, cbor): (AggregateProof, _) = roundtrip(&proof);
+    assert_eq!(json, proof);
+    opening_key
+        .verify_aggregate(&point, &cbor, &mut Transcript::new(b"serde"))
+        .expect("a deserialized aggregate opening should verify");
+
+    let domain = EvaluationDomain::new(8).unwrap();
+    assert_eq!(roundtrip(&domain), (domain, domain));
+
+    let lagrange = commit_key.to_lagrange(&domain).unwrap();
+    let (json, cbor): (LagrangeKey, _) = roundtrip(&lagrange);
+    assert_eq!(json, lagrange);
+    assert_eq!(cbor.commit(&domain.fft(&coefficients)), Ok(commitment));
+
+    let evaluations =
+        Evaluations::from_vec_and_domain(domain.fft(&coefficients), domain);
+    let (json, cbor) = roundtrip(&evaluations);
+    assert_eq!(json, evaluations);
+    assert_eq!(cbor, evaluations);
+
+    let polynomial = Polynomial::from_coefficients_vec(coefficients);
+    let (json, cbor) = roundtrip(&polynomial);
+    assert_eq!(json, polynomial);
+    assert_eq!(cbor, polynomial);
+
+    // malformed inputs
+    serde_json::from_str::<CommitKey>("\"00\"")
+        .expect_err("deserialization of a truncated key should fail");
+    serde_json::from_str::<LagrangeKey>(
+        &serde_json::to_string(&domain).unwrap(),
+    )
+    .expect_err("deserialization of a key without bases should fail");
+}
+
+#[cfg(feature = "ipa")]
+#[test]
+fn serde_ipa() {
+    use dusk_plonk::commitment_scheme::{IpaParameters, IpaProof};
+
+    let mut rng = StdRng::seed_from_u64(0x5e2e0);
+    let params = IpaParameters::new(7).expect("Parameters should derive");
+
+    let (json, cbor) = roundtrip(&params);
+    assert_eq!(json, params);
+    assert_eq!(cbor, params);
+
+    let coefficients: Vec<BlsScalar> =
+        (0..8).map(|_| BlsScalar::random(&mut rng)).collect();
+    let point = BlsScalar::random(&mut rng);
+    let proof = params
+        .open(&coefficients, &point, &mut Transcript::new(b"serde"))
+        .unwrap();
+
+    let (json, cbor): (IpaProof, _) = roundtrip(&proof);
+    assert_eq!(json, proof);
+    params
+        .verify(&point, &cbor, &mut Transcript::new(b"serde"))
+        .expect("a deserialized opening should verify");
+}
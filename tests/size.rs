@@ -58,7 +58,10 @@ fn size() {
     let result = prover.prove(rng, &circuit);
     assert_eq!(
         result,
-        Err(Error::InvalidCircuitSize),
+        Err(Error::InvalidCircuitSize {
+            expected: 5,
+            provided: 10,
+        }),
         "proof creation for different sized circuit shouldn't be possible"
     );
 }
@@ -0,0 +1,150 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_jubjub::GENERATOR_EXTENDED;
+use dusk_plonk::composer::hash;
+use dusk_plonk::prelude::*;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+mod common;
+use common::{check_satisfied_circuit, check_unsatisfied_circuit};
+
+/// Hash of the shared secret of `secret` and `public_key`, with its six
+/// highest bits cleared
+fn shared_hash(secret: &JubJubScalar, public_key: &JubJubExtended) -> [u8; 32] {
+    let shared = JubJubAffine::from(public_key * secret);
+    let mut bytes = hash(&[shared.get_u(), shared.get_v()]).to_bytes();
+    bytes[31] &= 0x03;
+
+    bytes
+}
+
+fn stealth_address(
+    secret: &JubJubScalar,
+    public_key: &JubJubExtended,
+    spend_key: &JubJubExtended,
+) -> JubJubAffine {
+    let hash = shared_hash(secret, public_key);
+    let hash = JubJubScalar::from_bytes(&hash)
+        .expect("The truncated hash should fit the scalar field");
+
+    (GENERATOR_EXTENDED * hash + spend_key).into()
+}
+
+#[test]
+fn component_stealth_address() {
+    #[derive(Default)]
+    pub struct TestCircuit {
+        secret: JubJubScalar,
+        public_key: JubJubAffine,
+        spend_key: JubJubAffine,
+        address: JubJubAffine,
+    }
+
+    impl Circuit for TestCircuit {
+        fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+        where
+            C: Composer,
+        {
+            let secret = composer.append_witness(self.secret);
+            let public_key = composer.append_public_point(self.public_key);
+            let spend_key = composer.append_public_point(self.spend_key);
+
+            let address = composer
+                .component_stealth_address(secret, public_key, spend_key)?;
+            composer.assert_equal_public_point(address, self.address);
+
+            Ok(())
+        }
+    }
+
+    fn public_inputs(circuit: &TestCircuit) -> Vec<BlsScalar> {
+        [circuit.public_key, circuit.spend_key, circuit.address]
+            .iter()
+            .flat_map(|point| [point.get_u(), point.get_v()])
+            .collect()
+    }
+
+    let label = b"component_stealth_address";
+    let rng = &mut StdRng::seed_from_u64(0x57e);
+    let pp = PublicParameters::setup(1 << 13, rng)
+        .expect("Creation of public parameter shouldn't fail");
+    let (prover, verifier) = Compiler::compile::<TestCircuit>(&pp, label)
+        .expect("Circuit should compile");
+
+    let view_secret = JubJubScalar::random(&mut *rng);
+    let view_key = GENERATOR_EXTENDED * view_secret;
+    let spend_key = GENERATOR_EXTENDED * JubJubScalar::random(&mut *rng);
+    let r = JubJubScalar::random(&mut *rng);
+    let ephemeral_key = GENERATOR_EXTENDED * r;
+    let address = stealth_address(&r, &view_key, &spend_key);
+
+    // Test the derivation of the sender
+    let msg = "The address derived by the sender should satisfy the circuit";
+    let circuit = TestCircuit {
+        secret: r,
+        public_key: view_key.into(),
+        spend_key: spend_key.into(),
+        address,
+    };
+    let pi = public_inputs(&circuit);
+    check_satisfied_circuit(&prover, &verifier, &pi, &circuit, rng, &msg);
+
+    // Test the derivation of the receiver
+    let msg = "The address derived by the receiver should satisfy the circuit";
+    assert_eq!(
+        stealth_address(&view_secret, &ephemeral_key, &spend_key),
+        address
+    );
+    let circuit = TestCircuit {
+        secret: view_secret,
+        public_key: ephemeral_key.into(),
+        spend_key: spend_key.into(),
+        address,
+    };
+    let pi = public_inputs(&circuit);
+    check_satisfied_circuit(&prover, &verifier, &pi, &circuit, rng, &msg);
+
+    // Test another secret
+    let msg = "Another secret should not derive the address";
+    let circuit = TestCircuit {
+        secret: r + JubJubScalar::one(),
+        public_key: view_key.into(),
+        spend_key: spend_key.into(),
+        address,
+    };
+    check_unsatisfied_circuit(&prover, &circuit, rng, &msg);
+
+    // Test another spend key
+    let msg = "Another spend key should not derive the address";
+    let circuit = TestCircuit {
+        secret: r,
+        public_key: view_key.into(),
+        spend_key: (spend_key + GENERATOR_EXTENDED).into(),
+        address,
+    };
+    check_unsatisfied_circuit(&prover, &circuit, rng, &msg);
+
+    // Test a hash whose five highest bits are the ones of the modulus, for
+    // which the lowest bits are compared to the ones of the modulus
+    let r = std::iter::repeat_with(|| JubJubScalar::random(&mut *rng))
+        .find(|r| {
+            let shared = JubJubAffine::from(view_key * r);
+            let hash = hash(&[shared.get_u(), shared.get_v()]).to_bytes();
+            hash[31] >> 2 == 0b11100
+        })
+        .expect("A hash with the highest bits of the modulus should be found");
+    let msg = "The truncation of a hash close to the modulus should pass";
+    let circuit = TestCircuit {
+        secret: r,
+        public_key: view_key.into(),
+        spend_key: spend_key.into(),
+        address: stealth_address(&r, &view_key, &spend_key),
+    };
+    let pi = public_inputs(&circuit);
+    check_satisfied_circuit(&prover, &verifier, &pi, &circuit, rng, &msg);
+}
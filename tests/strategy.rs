@@ -0,0 +1,70 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_plonk::fuzzing::FuzzCircuit;
+use dusk_plonk::prelude::*;
+use dusk_plonk::strategy::{self, Scalar};
+use proptest::prelude::*;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(8))]
+
+    #[test]
+    fn circuits_are_proved(circuit in strategy::circuit()) {
+        let rng = &mut StdRng::seed_from_u64(0x5787);
+        let pp = PublicParameters::setup(1 << 7, rng)
+            .expect("failed to create pp");
+
+        let (prover, verifier) =
+            Compiler::compile_with_circuit(&pp, b"strategy", &circuit)
+                .expect("failed to compile circuit");
+        let (proof, public_inputs) =
+            prover.prove(rng, &circuit).expect("failed to prove");
+
+        prop_assert_eq!(public_inputs.clone(), vec![circuit.output()]);
+        prop_assert!(verifier.verify(&proof, &public_inputs).is_ok());
+    }
+}
+
+proptest! {
+    #[test]
+    fn public_inputs(pi in strategy::public_inputs(2..5)) {
+        prop_assert!((2..5).contains(&pi.len()));
+    }
+
+    #[test]
+    fn small_scalars(a in strategy::small_scalar(8), b in strategy::small_scalar(64)) {
+        prop_assert!(a.to_bytes()[1..].iter().all(|byte| *byte == 0));
+        prop_assert!(b.to_bytes()[8..].iter().all(|byte| *byte == 0));
+    }
+
+    #[test]
+    fn scalars(a in any::<Scalar>(), b in strategy::scalar()) {
+        // the field operations agree on the wrapped scalars
+        let a = BlsScalar::from(a);
+        prop_assert_eq!((a + b) - b, a);
+    }
+}
+
+#[test]
+fn arbitrary_scalars_and_circuits() {
+    use arbitrary::Unstructured;
+
+    let bytes: Vec<u8> = (0..=255).cycle().take(1 << 10).collect();
+    let mut u = Unstructured::new(&bytes);
+
+    let a = u.arbitrary::<Scalar>().expect("enough bytes for a scalar");
+    let b = u.arbitrary::<Scalar>().expect("enough bytes for a scalar");
+    assert_ne!(a, b);
+
+    let circuit = u.arbitrary::<FuzzCircuit>().expect("enough bytes");
+    assert!(circuit.inputs() > 0);
+
+    let mut u = Unstructured::new(&[]);
+    assert!(u.arbitrary::<Scalar>().is_err());
+}
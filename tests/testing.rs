@@ -0,0 +1,75 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_plonk::prelude::*;
+use ff::Field;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+#[derive(Default)]
+pub struct TestCircuit {
+    a: BlsScalar,
+    b: BlsScalar,
+}
+
+impl Circuit for TestCircuit {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer,
+    {
+        let a = composer.append_witness(self.a);
+        let b = composer.append_public(self.b);
+        let c = composer.gate_add(Constraint::new().left(1).right(1).a(a).b(b));
+        composer.assert_equal_constant(c, 0, Some(self.a + self.b));
+
+        Ok(())
+    }
+}
+
+#[test]
+fn insecure_from_seed() {
+    let pp = PublicParameters::insecure_from_seed(0x5eed, 1 << 5)
+        .expect("Creation of test parameters shouldn't fail");
+    assert_eq!(pp.seed(), 0x5eed);
+    assert_eq!(pp.max_degree(), (1 << 5) + 6);
+
+    // the same seed yields the same parameters
+    let same = PublicParameters::insecure_from_seed(0x5eed, 1 << 5)
+        .expect("Creation of test parameters shouldn't fail");
+    assert_eq!(pp.to_var_bytes(), same.to_var_bytes());
+
+    let other = PublicParameters::insecure_from_seed(0x5eee, 1 << 5)
+        .expect("Creation of test parameters shouldn't fail");
+    assert_ne!(pp.to_var_bytes(), other.to_var_bytes());
+
+    assert_eq!(
+        PublicParameters::insecure_from_seed(0x5eed, 0).err(),
+        Some(Error::DegreeIsZero)
+    );
+}
+
+#[test]
+fn insecure_from_seed_prove() {
+    let label = b"insecure_from_seed";
+    let mut rng = StdRng::seed_from_u64(0x7e57);
+    let pp = PublicParameters::insecure_from_seed(0x5eed, 1 << 5)
+        .expect("Creation of test parameters shouldn't fail");
+
+    let (prover, verifier) = Compiler::compile::<TestCircuit>(&pp, label)
+        .expect("Circuit should compile");
+
+    let circuit = TestCircuit {
+        a: BlsScalar::random(&mut rng),
+        b: BlsScalar::random(&mut rng),
+    };
+    let (proof, pi) = prover
+        .prove(&mut rng, &circuit)
+        .expect("Proof creation of satisfied circuit should pass");
+
+    verifier
+        .verify(&proof, &pi)
+        .expect("Verification of a valid proof should pass");
+}
@@ -0,0 +1,410 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_bytes::{DeserializableSlice, Serializable};
+use dusk_plonk::prelude::*;
+use dusk_plonk::transcript::{
+    AuditTranscript, Compat, Domain, FiatShamir, PoseidonTranscript,
+    TranscriptEvent,
+};
+use ff::Field;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+#[derive(Default)]
+pub struct TestCircuit {
+    a: BlsScalar,
+    b: BlsScalar,
+    c: BlsScalar,
+}
+
+impl TestCircuit {
+    pub fn new(a: BlsScalar, b: BlsScalar) -> Self {
+        Self { a, b, c: a * b }
+    }
+}
+
+impl Circuit for TestCircuit {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer,
+    {
+        let a = composer.append_witness(self.a);
+        let b = composer.append_witness(self.b);
+
+        let constraint = Constraint::new().mult(1).a(a).b(b).public(-self.c);
+        composer.append_gate(constraint);
+
+        Ok(())
+    }
+}
+
+#[test]
+fn poseidon_transcript() {
+    let label = b"poseidon_transcript";
+    let mut rng = StdRng::seed_from_u64(0x7c4);
+    let pp = PublicParameters::setup(1 << 4, &mut rng)
+        .expect("Creation of public parameter shouldn't fail");
+
+    let (prover, verifier) = Compiler::compile_with_transcript::<
+        TestCircuit,
+        PoseidonTranscript,
+    >(&pp, label)
+    .expect("Circuit should compile");
+
+    let a = BlsScalar::random(&mut rng);
+    let b = BlsScalar::random(&mut rng);
+    let circuit = TestCircuit::new(a, b);
+    let (proof, pi) = prover
+        .prove(&mut rng, &circuit)
+        .expect("Proof creation of satisfied circuit should pass");
+
+    verifier
+        .verify(&proof, &pi)
+        .expect("Verification with the same transcript should pass");
+
+    // the transcript survives the serialization of the verifier
+    let bytes = verifier.to_bytes();
+    let verifier =
+        Verifier::<PoseidonTranscript>::try_from_bytes_with_transcript(bytes)
+            .expect("Deserialization of the verifier should pass");
+    verifier
+        .verify(&proof, &pi)
+        .expect("Verification after deserialization should pass");
+
+    // the proof doesn't verify with a different transcript
+    let (_, merlin_verifier) = Compiler::compile::<TestCircuit>(&pp, label)
+        .expect("Circuit should compile");
+    merlin_verifier
+        .verify(&proof, &pi)
+        .expect_err("Verification with another transcript should fail");
+}
+
+#[test]
+fn domain() {
+    let label = b"domain";
+    let mut rng = StdRng::seed_from_u64(0xd0a);
+    let pp = PublicParameters::setup(1 << 4, &mut rng)
+        .expect("Creation of public parameter shouldn't fail");
+
+    let (prover, verifier) = Compiler::compile::<TestCircuit>(&pp, label)
+        .expect("Circuit should compile");
+
+    let domain = Domain::new(b"application", 2);
+    let prover = prover.with_domain(domain.clone());
+
+    let a = BlsScalar::random(&mut rng);
+    let b = BlsScalar::random(&mut rng);
+    let circuit = TestCircuit::new(a, b);
+    let (proof, pi) = prover
+        .prove(&mut rng, &circuit)
+        .expect("Proof creation of satisfied circuit should pass");
+
+    // the proof doesn't verify without the domain, nor in another domain
+    verifier
+        .verify(&proof, &pi)
+        .expect_err("Verification without the domain should fail");
+
+    let other = verifier.with_domain(Domain::new(b"application", 1));
+    other
+        .verify(&proof, &pi)
+        .expect_err("Verification with another version should fail");
+
+    let other = Verifier::try_from_bytes(other.to_bytes())
+        .expect("Deserialization of the verifier should pass");
+    let verifier = other.with_domain(domain.clone());
+    verifier
+        .verify(&proof, &pi)
+        .expect("Verification with the same domain should pass");
+
+    // the domain survives the serialization
+    let prover = Prover::try_from_bytes(prover.to_bytes())
+        .expect("Deserialization of the prover should pass");
+    let verifier = Verifier::try_from_bytes(verifier.to_bytes())
+        .expect("Deserialization of the verifier should pass");
+    assert_eq!(prover.domain(), Some(&domain));
+    assert_eq!(verifier.domain(), Some(&domain));
+
+    let (proof, pi) = prover
+        .prove(&mut rng, &circuit)
+        .expect("Proof creation of satisfied circuit should pass");
+    verifier
+        .verify(&proof, &pi)
+        .expect("Verification after deserialization should pass");
+}
+
+#[test]
+fn malformed_settings() {
+    let label = b"malformed_settings";
+    let mut rng = StdRng::seed_from_u64(0xba5e);
+    let pp = PublicParameters::setup(1 << 4, &mut rng)
+        .expect("Creation of public parameter shouldn't fail");
+
+    let (prover, verifier) = Compiler::compile::<TestCircuit>(&pp, label)
+        .expect("Circuit should compile");
+
+    let domain = Domain::new(b"application", 2);
+    let prover = prover.with_domain(domain.clone());
+    let verifier = verifier.with_domain(domain);
+
+    // the settings close the bytes with the flags, the length of the
+    // application, the application and the version
+    let settings_len = 1 + 8 + b"application".len() + 4;
+    let malformed = |mut bytes: Vec<u8>| {
+        let len = bytes.len() - settings_len + 1;
+        bytes[len..len + 8].copy_from_slice(&u64::MAX.to_be_bytes());
+        bytes
+    };
+
+    assert_eq!(
+        Verifier::try_from_bytes(malformed(verifier.to_bytes())).err(),
+        Some(Error::NotEnoughBytes)
+    );
+    assert_eq!(
+        HeaplessVerifier::<2>::try_from_bytes(malformed(verifier.to_bytes()))
+            .err(),
+        Some(Error::NotEnoughBytes)
+    );
+    assert_eq!(
+        Prover::try_from_bytes(malformed(prover.to_bytes())).err(),
+        Some(Error::NotEnoughBytes)
+    );
+}
+
+#[test]
+fn strong_fiat_shamir() {
+    let label = b"strong_fiat_shamir";
+    let mut rng = StdRng::seed_from_u64(0x5f5);
+    let pp = PublicParameters::setup(1 << 4, &mut rng)
+        .expect("Creation of public parameter shouldn't fail");
+
+    let (prover, verifier) = Compiler::compile::<TestCircuit>(&pp, label)
+        .expect("Circuit should compile");
+    assert_eq!(prover.fiat_shamir(), FiatShamir::Weak);
+
+    let prover = prover.with_fiat_shamir(FiatShamir::Strong);
+    let strong = Verifier::try_from_bytes(verifier.to_bytes())
+        .expect("Deserialization of the verifier should pass")
+        .with_fiat_shamir(FiatShamir::Strong);
+
+    let a = BlsScalar::random(&mut rng);
+    let b = BlsScalar::random(&mut rng);
+    let circuit = TestCircuit::new(a, b);
+    let (proof, pi) = prover
+        .prove(&mut rng, &circuit)
+        .expect("Proof creation of satisfied circuit should pass");
+
+    strong
+        .verify(&proof, &pi)
+        .expect("Verification in the same mode should pass");
+    verifier
+        .verify(&proof, &pi)
+        .expect_err("Verification in another mode should fail");
+
+    // the mode survives the serialization
+    let strong = Verifier::try_from_bytes(strong.to_bytes())
+        .expect("Deserialization of the verifier should pass");
+    assert_eq!(strong.fiat_shamir(), FiatShamir::Strong);
+    strong
+        .verify(&proof, &pi)
+        .expect("Verification after deserialization should pass");
+
+    let prover = Prover::try_from_bytes(prover.to_bytes())
+        .expect("Deserialization of the prover should pass");
+    assert_eq!(prover.fiat_shamir(), FiatShamir::Strong);
+}
+
+#[test]
+fn upstream_compat() {
+    let label = b"upstream_compat";
+    let mut rng = StdRng::seed_from_u64(0xc0a7);
+    let pp = PublicParameters::setup(1 << 4, &mut rng)
+        .expect("Creation of public parameter shouldn't fail");
+
+    let (prover, verifier) = Compiler::compile::<TestCircuit>(&pp, label)
+        .expect("Circuit should compile");
+    assert_eq!(prover.compat(), Some(Compat::V0_16));
+    assert_eq!(verifier.compat(), Some(Compat::V0_16));
+
+    let prover = prover
+        .with_domain(Domain::new(b"application", 1))
+        .with_fiat_shamir(FiatShamir::Strong);
+    assert_eq!(prover.compat(), None);
+
+    // the compatibility mode drops the settings of the transcript
+    let prover = prover.with_compat(Compat::LATEST);
+    assert_eq!(prover.compat(), Some(Compat::V0_16));
+    assert_eq!(prover.domain(), None);
+    assert_eq!(prover.fiat_shamir(), FiatShamir::Weak);
+
+    let circuit = TestCircuit::new(
+        BlsScalar::random(&mut rng),
+        BlsScalar::random(&mut rng),
+    );
+    let (proof, pi) = prover
+        .prove(&mut rng, &circuit)
+        .expect("Proof creation of satisfied circuit should pass");
+
+    verifier
+        .with_compat(Compat::V0_16)
+        .verify(&proof, &pi)
+        .expect("Verification in compatibility mode should pass");
+
+    // the serialized prover carries no settings, as the upstream one
+    let bytes = prover.to_bytes();
+    let prover = Prover::try_from_bytes(&bytes)
+        .expect("Deserialization of the prover should pass");
+    assert_eq!(prover.to_bytes(), bytes);
+    assert_eq!(prover.compat(), Some(Compat::V0_16));
+}
+
+// Golden bytes of the `TestCircuit` of this file, compiled and proven by
+// upstream `dusk-plonk` 0.16.0 with the label `upstream_compat`, the public
+// parameters `setup(1 << 4)` and the blinding factors drawn from
+// `StdRng::seed_from_u64(0xc0a7)`, and the witnesses `a` and `b` drawn from
+// the same generator in between
+const UPSTREAM_PROVER: &[u8] =
+    include_bytes!("fixtures/upstream-0.16/prover.bin");
+const UPSTREAM_VERIFIER: &[u8] =
+    include_bytes!("fixtures/upstream-0.16/verifier.bin");
+const UPSTREAM_PROOF: &[u8] =
+    include_bytes!("fixtures/upstream-0.16/proof.bin");
+const UPSTREAM_PUBLIC_INPUTS: &[u8] =
+    include_bytes!("fixtures/upstream-0.16/public_inputs.bin");
+
+#[test]
+fn upstream_golden() {
+    let proof = Proof::from_slice(UPSTREAM_PROOF)
+        .expect("Deserialization of the upstream proof should pass");
+    let pi: Vec<BlsScalar> = UPSTREAM_PUBLIC_INPUTS
+        .chunks(BlsScalar::SIZE)
+        .map(|pi| BlsScalar::from_slice(pi).expect("canonical scalar"))
+        .collect();
+
+    // the proof of the upstream crate verifies with its verifier
+    let verifier = Verifier::try_from_bytes(UPSTREAM_VERIFIER)
+        .expect("Deserialization of the upstream verifier should pass");
+    assert_eq!(verifier.compat(), Some(Compat::V0_16));
+    verifier
+        .verify(&proof, &pi)
+        .expect("Verification of the upstream proof should pass");
+
+    // the same circuit yields the keys and the proof of the upstream crate
+    let label = b"upstream_compat";
+    let mut rng = StdRng::seed_from_u64(0xc0a7);
+    let pp = PublicParameters::setup(1 << 4, &mut rng)
+        .expect("Creation of public parameter shouldn't fail");
+    let (prover, fork_verifier) = Compiler::compile::<TestCircuit>(&pp, label)
+        .expect("Circuit should compile");
+    let prover = prover.with_compat(Compat::V0_16);
+    assert_eq!(fork_verifier.to_bytes(), UPSTREAM_VERIFIER);
+
+    let circuit = TestCircuit::new(
+        BlsScalar::random(&mut rng),
+        BlsScalar::random(&mut rng),
+    );
+    let (fork_proof, fork_pi) = prover
+        .prove(&mut rng, &circuit)
+        .expect("Proof creation of satisfied circuit should pass");
+    assert_eq!(fork_proof.to_bytes().as_slice(), UPSTREAM_PROOF);
+    assert_eq!(fork_pi, pi);
+
+    // the upstream prover, serialized without circuit hash, is still read
+    let upstream = Prover::try_from_bytes(UPSTREAM_PROVER)
+        .expect("Deserialization of the upstream prover should pass");
+    assert_eq!(upstream.compat(), Some(Compat::V0_16));
+    upstream
+        .verify_integrity()
+        .expect("The upstream prover key should match its verifier key");
+
+    let (proof, pi) = upstream
+        .prove(&mut rng, &circuit)
+        .expect("Proof creation of satisfied circuit should pass");
+    verifier
+        .verify(&proof, &pi)
+        .expect("Verification of a proof of the upstream prover should pass");
+}
+
+#[test]
+fn audit_transcript() {
+    let label = b"audit_transcript";
+    let mut rng = StdRng::seed_from_u64(0xa0d);
+    let pp = PublicParameters::setup(1 << 4, &mut rng)
+        .expect("Creation of public parameter shouldn't fail");
+
+    let (prover, verifier) = Compiler::compile_with_transcript::<
+        TestCircuit,
+        AuditTranscript,
+    >(&pp, label)
+    .expect("Circuit should compile");
+
+    let circuit = TestCircuit::new(
+        BlsScalar::random(&mut rng),
+        BlsScalar::random(&mut rng),
+    );
+    let (proof, pi, prover_transcript) = prover
+        .prove_with_transcript(&mut StdRng::seed_from_u64(0xa0e), &circuit)
+        .expect("Proof creation of satisfied circuit should pass");
+
+    // the proof is the one of the inner transcript
+    let (merlin_prover, _) = Compiler::compile::<TestCircuit>(&pp, label)
+        .expect("Circuit should compile");
+    let (merlin_proof, _) = merlin_prover
+        .prove(&mut StdRng::seed_from_u64(0xa0e), &circuit)
+        .expect("Proof creation of satisfied circuit should pass");
+    assert_eq!(proof, merlin_proof);
+
+    let events = prover_transcript.events();
+    assert_eq!(events[0], TranscriptEvent::Init(label.to_vec()));
+    assert_eq!(events[0].to_string(), "init \"audit_transcript\"");
+    assert!(events.iter().any(|event| matches!(
+        event,
+        TranscriptEvent::Squeeze {
+            label: b"z_challenge",
+            ..
+        }
+    )));
+
+    let (result, verifier_transcript) =
+        verifier.verify_with_transcript(&proof, &pi);
+    result.expect("Verification of the proof should pass");
+    // the verifier additionally absorbs the opening proofs to batch them
+    let len = prover_transcript.events().len();
+    assert_eq!(
+        prover_transcript.divergence(&verifier_transcript),
+        Some(len)
+    );
+    assert_eq!(
+        prover_transcript.events(),
+        &verifier_transcript.events()[..len]
+    );
+    assert!(matches!(
+        verifier_transcript.events().last(),
+        Some(TranscriptEvent::Squeeze {
+            label: b"batch",
+            ..
+        })
+    ));
+
+    // the transcripts diverge at the first public input
+    let wrong_pi = vec![pi[0] + BlsScalar::one()];
+    let (result, verifier_transcript) =
+        verifier.verify_with_transcript(&proof, &wrong_pi);
+    result.expect_err("Verification with a wrong public input should fail");
+
+    let index = prover_transcript
+        .divergence(&verifier_transcript)
+        .expect("The transcripts should diverge");
+    let event = &verifier_transcript.events()[index];
+    assert_eq!(
+        event,
+        &TranscriptEvent::Absorb {
+            label: b"pi",
+            bytes: wrong_pi[0].to_bytes().to_vec(),
+        }
+    );
+    assert!(event.to_string().starts_with("absorb \"pi\" 0x"));
+}
@@ -0,0 +1,116 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_bls12_381::{G1Affine, G2Affine};
+use dusk_bytes::Serializable;
+use dusk_plonk::prelude::*;
+use ff::Field;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+#[derive(Default)]
+pub struct TestCircuit {
+    a: BlsScalar,
+    b: BlsScalar,
+    c: BlsScalar,
+}
+
+impl Circuit for TestCircuit {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer,
+    {
+        let a = composer.append_witness(self.a);
+        let b = composer.append_witness(self.b);
+
+        let constraint = Constraint::new().mult(1).a(a).b(b).public(-self.c);
+        composer.append_gate(constraint);
+
+        Ok(())
+    }
+}
+
+#[test]
+fn trim_to_circuit() {
+    let label = b"trim_to_circuit";
+    let mut rng = StdRng::seed_from_u64(0x7e1);
+    let pp = PublicParameters::setup(1 << 8, &mut rng)
+        .expect("Creation of public parameter shouldn't fail");
+
+    // the circuit has 5 constraints, and needs a degree of 16 + 6 blinding
+    let trimmed = pp
+        .trim_to_circuit::<TestCircuit>()
+        .expect("Trimming to the circuit should pass");
+    assert_eq!(trimmed.max_degree(), 16 + 6);
+
+    let (prover, verifier) = Compiler::compile::<TestCircuit>(&trimmed, label)
+        .expect("Circuit should compile with the trimmed parameters");
+
+    let a = BlsScalar::random(&mut rng);
+    let b = BlsScalar::random(&mut rng);
+    let circuit = TestCircuit { a, b, c: a * b };
+    let (proof, pi) = prover
+        .prove(&mut rng, &circuit)
+        .expect("Proof creation of satisfied circuit should pass");
+
+    verifier
+        .verify(&proof, &pi)
+        .expect("Verification with the trimmed parameters should pass");
+
+    // the circuit doesn't fit in smaller parameters
+    let small = PublicParameters::setup(8, &mut rng)
+        .expect("Creation of public parameter shouldn't fail");
+    assert_eq!(
+        small.trim_to_circuit::<TestCircuit>().err(),
+        Some(Error::TruncatedDegreeTooLarge {
+            degree: 16 + 6,
+            max_degree: 8 + 6,
+        })
+    );
+}
+
+#[test]
+fn trim_checked() {
+    let mut rng = StdRng::seed_from_u64(0x7e2);
+    let pp = PublicParameters::setup(1 << 5, &mut rng)
+        .expect("Creation of public parameter shouldn't fail");
+
+    let trimmed = pp
+        .trim_checked(1 << 4)
+        .expect("Trimming valid parameters should pass");
+    assert_eq!(trimmed.max_degree(), (1 << 4) + 6);
+
+    assert_eq!(pp.trim_checked(0).err(), Some(Error::TruncatedDegreeIsZero));
+    assert_eq!(
+        pp.trim_checked(1 << 6).err(),
+        Some(Error::TruncatedDegreeTooLarge {
+            degree: (1 << 6) + 6,
+            max_degree: (1 << 5) + 6,
+        })
+    );
+
+    // `[x]_2` is replaced by another point
+    let mut bytes = pp.to_var_bytes();
+    let h = G2Affine::generator().to_bytes();
+    let beta_h = G1Affine::SIZE + G2Affine::SIZE;
+    bytes[beta_h..beta_h + G2Affine::SIZE].copy_from_slice(&h);
+    let inconsistent = PublicParameters::from_slice(&bytes)
+        .expect("The points are valid on their own");
+    assert_eq!(
+        inconsistent.trim_checked(1 << 4).err(),
+        Some(Error::PairingCheckFailure)
+    );
+
+    // a power is not on the curve
+    let mut bytes = pp.to_raw_var_bytes();
+    let offset = G1Affine::SIZE + 2 * G2Affine::SIZE + u64::SIZE;
+    bytes[offset + 3 * G1Affine::RAW_SIZE] ^= 1;
+    let malformed = unsafe { PublicParameters::from_slice_unchecked(&bytes) };
+    assert_eq!(
+        malformed.trim_checked(1 << 4).err(),
+        Some(Error::PowerMalformed { index: 3 })
+    );
+}
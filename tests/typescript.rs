@@ -0,0 +1,123 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_bytes::Serializable;
+use dusk_plonk::prelude::*;
+use dusk_plonk::typescript::TypeScript;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+// a · b = c, with a and c public
+#[derive(Default)]
+struct TestCircuit {
+    a: BlsScalar,
+    b: BlsScalar,
+}
+
+impl Circuit for TestCircuit {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer,
+    {
+        let a = composer.append_public(self.a);
+        let b = composer.append_witness(self.b);
+
+        let c = composer.gate_mul(Constraint::new().mult(1).a(a).b(b));
+        composer.assert_equal_constant(c, 0, Some(self.a * self.b));
+
+        Ok(())
+    }
+}
+
+fn verifier() -> Verifier {
+    let mut rng = StdRng::seed_from_u64(0x75);
+    let pp = PublicParameters::setup(1 << 4, &mut rng)
+        .expect("Creation of public parameter shouldn't fail");
+    let (_, verifier) = Compiler::compile::<TestCircuit>(&pp, b"typescript")
+        .expect("Circuit should compile");
+
+    verifier
+}
+
+/// Decode the base64 string embedded in the generated module
+fn from_base64(base64: &str) -> Vec<u8> {
+    const BASE64: &[u8] =
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let sextets: Vec<u32> = base64
+        .bytes()
+        .filter(|c| *c != b'=')
+        .map(|c| BASE64.iter().position(|b| *b == c).unwrap() as u32)
+        .collect();
+
+    sextets
+        .chunks(4)
+        .flat_map(|chunk| {
+            let group = chunk
+                .iter()
+                .enumerate()
+                .fold(0, |group, (i, s)| group | s << (18 - 6 * i));
+            group.to_be_bytes()[1..chunk.len()].to_vec()
+        })
+        .collect()
+}
+
+#[test]
+fn typescript_verifier() {
+    let verifier = verifier();
+    let ts = TypeScript::new(&verifier)
+        .with_package("@dusk/plonk")
+        .with_names(&["a", "product"])
+        .expect("the names should be valid")
+        .generate();
+
+    assert!(ts.contains("import { verify } from \"@dusk/plonk\";"));
+    assert!(ts.contains("export const PUBLIC_INPUTS = 2;"));
+    assert!(ts.contains(&format!("export const PROOF_SIZE = {};", Proof::SIZE)));
+    assert!(ts.contains("  readonly a: bigint;\n  readonly product: bigint;"));
+    assert!(ts.contains("  bytes.set(encodeScalar(inputs.a), 0);"));
+    assert!(ts.contains("  bytes.set(encodeScalar(inputs.product), 32);"));
+
+    // the embedded verifier is the one of the circuit
+    let start = ts.find("atob(\"").expect("the verifier should be embedded");
+    let base64 = &ts[start + 6..];
+    let base64 = &base64[..base64.find('"').unwrap()];
+    assert_eq!(from_base64(base64), verifier.to_bytes());
+
+    // the public inputs are named after their position by default
+    let ts = TypeScript::new(&verifier).generate();
+    assert!(ts.contains("import { verify } from \"dusk-plonk\";"));
+    assert!(
+        ts.contains("  readonly input0: bigint;\n  readonly input1: bigint;")
+    );
+}
+
+#[test]
+fn typescript_names() {
+    let verifier = verifier();
+    let ts = TypeScript::new(&verifier);
+
+    assert!(matches!(
+        ts.clone().with_names(&["a"]),
+        Err(Error::InconsistentPublicInputsLen {
+            expected: 2,
+            provided: 1
+        })
+    ));
+    assert!(matches!(
+        ts.clone().with_names(&["a", "0b"]),
+        Err(Error::InvalidPublicInputName { index: 1 })
+    ));
+    assert!(matches!(
+        ts.clone().with_names(&["a-b", "c"]),
+        Err(Error::InvalidPublicInputName { index: 0 })
+    ));
+    assert!(matches!(
+        ts.clone().with_names(&["a", "a"]),
+        Err(Error::InvalidPublicInputName { index: 1 })
+    ));
+    assert!(ts.with_names(&["$a", "_b1"]).is_ok());
+}
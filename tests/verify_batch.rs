@@ -0,0 +1,144 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_bytes::Serializable;
+use dusk_plonk::prelude::*;
+use ff::Field;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+#[derive(Default)]
+pub struct TestCircuit {
+    a: BlsScalar,
+    b: BlsScalar,
+    c: BlsScalar,
+}
+
+impl TestCircuit {
+    pub fn new(a: BlsScalar, b: BlsScalar) -> Self {
+        Self { a, b, c: a * b }
+    }
+}
+
+impl Circuit for TestCircuit {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer,
+    {
+        let a = composer.append_witness(self.a);
+        let b = composer.append_witness(self.b);
+
+        let constraint = Constraint::new().mult(1).a(a).b(b).public(-self.c);
+        composer.append_gate(constraint);
+
+        Ok(())
+    }
+}
+
+#[test]
+fn verify_batch() {
+    let label = b"verify_batch";
+    let mut rng = StdRng::seed_from_u64(0xba7c);
+    let capacity = 1 << 4;
+    let pp = PublicParameters::setup(capacity, &mut rng)
+        .expect("Creation of public parameter shouldn't fail");
+    let (prover, verifier) = Compiler::compile::<TestCircuit>(&pp, label)
+        .expect("Circuit should compile");
+
+    let mut proofs: Vec<(Proof, Vec<BlsScalar>)> = (0..4)
+        .map(|_| {
+            let a = BlsScalar::random(&mut rng);
+            let b = BlsScalar::random(&mut rng);
+            let circuit = TestCircuit::new(a, b);
+
+            prover
+                .prove(&mut rng, &circuit)
+                .expect("Proof creation of satisfied circuit should pass")
+        })
+        .collect();
+
+    verifier
+        .verify_batch(&mut rng, &[])
+        .expect("Verification of an empty batch should pass");
+
+    verifier
+        .verify_batch(&mut rng, &proofs)
+        .expect("Verification of a batch of valid proofs should pass");
+
+    // a single proof with mismatching public inputs invalidates the batch
+    let pi = proofs[1].1[0];
+    proofs[2].1[0] = pi;
+    verifier.verify_batch(&mut rng, &proofs).expect_err(
+        "Verification of a batch with an invalid proof should fail",
+    );
+
+    // the public inputs are checked for every proof
+    proofs[2].1.clear();
+    assert_eq!(
+        verifier.verify_batch(&mut rng, &proofs),
+        Err(Error::InconsistentPublicInputsLen {
+            expected: 1,
+            provided: 0,
+        })
+    );
+}
+
+#[test]
+fn accumulate() {
+    let mut rng = StdRng::seed_from_u64(0xacc);
+    let capacity = 1 << 4;
+    let pp = PublicParameters::setup(capacity, &mut rng)
+        .expect("Creation of public parameter shouldn't fail");
+
+    // two distinct circuits compiled from the same public parameters
+    let (prover, verifier) =
+        Compiler::compile::<TestCircuit>(&pp, b"accumulate")
+            .expect("Circuit should compile");
+    let (other_prover, other_verifier) =
+        Compiler::compile::<TestCircuit>(&pp, b"accumulate other")
+            .expect("Circuit should compile");
+
+    let mut prove = |prover: &Prover| {
+        let a = BlsScalar::random(&mut rng);
+        let b = BlsScalar::random(&mut rng);
+        let circuit = TestCircuit::new(a, b);
+
+        prover
+            .prove(&mut rng, &circuit)
+            .expect("Proof creation of satisfied circuit should pass")
+    };
+    let (proof, pi) = prove(&prover);
+    let (other_proof, other_pi) = prove(&other_prover);
+    let (invalid_proof, _) = prove(&prover);
+
+    let mut acc = Accumulator::new();
+    verifier
+        .accumulate(&mut rng, &mut acc, &proof, &pi)
+        .expect("Accumulation of a valid proof should pass");
+
+    // the accumulator is carried over to the next step
+    let acc_bytes = acc.to_bytes();
+    let mut acc = Accumulator::from_bytes(&acc_bytes)
+        .expect("Accumulator deserialization should pass");
+    other_verifier
+        .accumulate(&mut rng, &mut acc, &other_proof, &other_pi)
+        .expect("Accumulation of a valid proof should pass");
+
+    verifier
+        .discharge(&acc)
+        .expect("Discharge of valid proofs should pass");
+
+    // the invalid proof isn't detected until the accumulator is discharged
+    let mut invalid = Accumulator::new();
+    verifier
+        .accumulate(&mut rng, &mut invalid, &invalid_proof, &pi)
+        .expect("Accumulation defers the pairing check");
+
+    acc.merge(&mut rng, &invalid);
+    other_verifier
+        .discharge(&acc)
+        .expect_err("Discharge of an invalid proof should fail");
+}
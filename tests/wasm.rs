@@ -0,0 +1,73 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_bytes::Serializable;
+use dusk_plonk::prelude::*;
+use dusk_plonk::wasm::{verify, verify_bytes};
+use ff::Field;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+#[derive(Default)]
+pub struct TestCircuit {
+    a: BlsScalar,
+    b: BlsScalar,
+    c: BlsScalar,
+}
+
+impl Circuit for TestCircuit {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer,
+    {
+        let a = composer.append_witness(self.a);
+        let b = composer.append_witness(self.b);
+
+        let constraint = Constraint::new().mult(1).a(a).b(b);
+        let c = composer.gate_mul(constraint);
+        let expected = composer.append_public(self.c);
+        composer.assert_equal(c, expected);
+
+        Ok(())
+    }
+}
+
+#[test]
+fn wasm_verify() {
+    let label = b"wasm_verify";
+    let mut rng = StdRng::seed_from_u64(0x3a5);
+    let pp = PublicParameters::setup(1 << 4, &mut rng)
+        .expect("Creation of public parameter shouldn't fail");
+    let (prover, verifier) = Compiler::compile::<TestCircuit>(&pp, label)
+        .expect("Circuit should compile");
+
+    let a = BlsScalar::random(&mut rng);
+    let b = BlsScalar::random(&mut rng);
+    let circuit = TestCircuit { a, b, c: a * b };
+    let (proof, pi) = prover
+        .prove(&mut rng, &circuit)
+        .expect("Proof creation of satisfied circuit should succeed");
+
+    let verifier = verifier.to_bytes();
+    let pi: Vec<u8> = pi.iter().flat_map(|p| p.to_bytes()).collect();
+
+    // both proof encodings are accepted
+    assert!(verify(&proof.to_bytes(), &verifier, &pi));
+    assert!(verify(&proof.to_versioned_bytes(), &verifier, &pi));
+
+    // wrong public inputs
+    let wrong: Vec<u8> = (a * b + BlsScalar::one()).to_bytes().to_vec();
+    assert!(!verify(&proof.to_bytes(), &verifier, &wrong));
+    assert!(matches!(
+        verify_bytes(&proof.to_bytes(), &verifier, &wrong),
+        Err(Error::ProofVerificationError)
+    ));
+
+    // malformed bytes
+    assert!(!verify(&proof.to_bytes(), &verifier, &pi[1..]));
+    assert!(!verify(&proof.to_bytes()[1..], &verifier, &pi));
+    assert!(!verify(&proof.to_bytes(), &verifier[1..], &pi));
+}
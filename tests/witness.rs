@@ -0,0 +1,120 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_plonk::prelude::*;
+
+#[derive(Default)]
+struct TestCircuit {
+    a: BlsScalar,
+    b: BlsScalar,
+}
+
+impl Circuit for TestCircuit {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer,
+    {
+        let a = composer.append_witness(self.a);
+        let b = composer.append_witness(self.b);
+
+        let constraint = Constraint::new().mult(1).a(a).b(b);
+        let product = composer.gate_mul(constraint);
+        assert_eq!(composer.witness_of(product), self.a * self.b);
+
+        let constraint = Constraint::new().left(1).right(1).a(product).b(a);
+        composer.gate_add(constraint);
+
+        Ok(())
+    }
+}
+
+fn synthesize(a: u64, b: u64) -> Builder {
+    let mut builder = Builder::initialized();
+    let circuit = TestCircuit {
+        a: BlsScalar::from(a),
+        b: BlsScalar::from(b),
+    };
+    circuit
+        .circuit(&mut builder)
+        .expect("the circuit should be synthesized");
+
+    builder
+}
+
+#[test]
+fn witness_of() {
+    let mut builder = Builder::initialized();
+    let a = builder.append_witness(BlsScalar::from(3));
+
+    assert_eq!(builder.witness_of(a), BlsScalar::from(3));
+    assert_eq!(builder.witness_of(Builder::ONE), BlsScalar::one());
+    assert_eq!(builder.try_witness_of(a), Some(BlsScalar::from(3)));
+
+    // a witness allocated by a larger builder
+    let mut larger = builder.clone();
+    larger.append_witness(BlsScalar::one());
+    let unallocated = larger.append_witness(BlsScalar::one());
+    assert_eq!(builder.try_witness_of(unallocated), None);
+}
+
+#[test]
+fn export_import_witness() {
+    let builder = synthesize(3, 5);
+    let rows = builder.export_witness();
+    assert_eq!(rows.len(), builder.constraints());
+
+    // the last gate adds the product and the first input
+    let last = rows[rows.len() - 1];
+    assert_eq!(last[0], BlsScalar::from(15));
+    assert_eq!(last[1], BlsScalar::from(3));
+    assert_eq!(last[2], BlsScalar::from(18));
+
+    // replay the assignment of another run over the same circuit
+    let mut replayed = synthesize(0, 0);
+    assert_ne!(replayed.export_witness(), rows);
+    replayed
+        .import_witness(&rows)
+        .expect("the witness should be imported");
+    assert_eq!(replayed.export_witness(), rows);
+
+    // the rows assign two values to the first input
+    let mut inconsistent = rows.clone();
+    let gate = inconsistent.len() - 1;
+    inconsistent[gate][1] = BlsScalar::from(4);
+    let mut replayed = synthesize(0, 0);
+    assert!(matches!(
+        replayed.import_witness(&inconsistent),
+        Err(Error::InconsistentWitness { gate: g, .. }) if g == gate
+    ));
+    assert_eq!(replayed.export_witness(), synthesize(0, 0).export_witness());
+
+    assert_eq!(
+        replayed.import_witness(&rows[1..]),
+        Err(Error::InvalidCircuitSize {
+            expected: rows.len(),
+            provided: rows.len() - 1,
+        })
+    );
+}
+
+#[test]
+fn dump_gates() {
+    let builder = synthesize(3, 5);
+    let last = builder.constraints() - 1;
+
+    let table = builder.dump_gates(last - 1..last + 10);
+    let lines: Vec<&str> = table.lines().collect();
+    assert_eq!(lines.len(), 2);
+
+    // the product, and the sum of the product and the first input, whose
+    // witnesses follow the ones of the initialized builder
+    assert!(lines[0].contains("| q_m=1 q_o=-1 q_arith=1 |"));
+    assert!(lines[0].contains("w_a=#6:3 w_b=#7:5 w_o=#8:15 w_d=#0:0"));
+    assert!(lines[1].contains("| q_l=1 q_r=1 q_o=-1 q_arith=1 |"));
+    assert!(lines[1].contains("w_a=#8:15 w_b=#6:3 w_o=#9:18 w_d=#0:0"));
+
+    assert!(builder.dump_gates(last + 1..last + 2).is_empty());
+}
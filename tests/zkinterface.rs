@@ -0,0 +1,152 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_plonk::prelude::*;
+use dusk_plonk::zkinterface::ZkifCircuit;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+// a · b + c = d, with c and d public
+#[derive(Default)]
+struct TestCircuit {
+    a: BlsScalar,
+    b: BlsScalar,
+    c: BlsScalar,
+    d: BlsScalar,
+    range: bool,
+}
+
+impl TestCircuit {
+    fn new(a: u64, b: u64, c: u64, d: u64) -> Self {
+        Self {
+            a: BlsScalar::from(a),
+            b: BlsScalar::from(b),
+            c: BlsScalar::from(c),
+            d: BlsScalar::from(d),
+            range: false,
+        }
+    }
+}
+
+impl Circuit for TestCircuit {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer,
+    {
+        let a = composer.append_witness(self.a);
+        let b = composer.append_witness(self.b);
+        let c = composer.append_public(self.c);
+
+        let constraint = Constraint::new().mult(1).a(a).b(b).fourth(1).d(c);
+        let d = composer.gate_mul(constraint);
+        composer.assert_equal_constant(d, 0, Some(self.d));
+
+        if self.range {
+            composer.component_range::<4>(a);
+        }
+
+        Ok(())
+    }
+}
+
+fn builder(circuit: &TestCircuit) -> Builder {
+    let mut builder = Builder::initialized();
+    circuit
+        .circuit(&mut builder)
+        .expect("Synthesis of the circuit shouldn't fail");
+
+    builder
+}
+
+#[test]
+fn zkinterface_roundtrip() {
+    let rng = &mut StdRng::seed_from_u64(0x2c1f);
+    let pp = PublicParameters::setup(1 << 6, rng)
+        .expect("Creation of public parameter shouldn't fail");
+
+    let bytes = builder(&TestCircuit::new(3, 5, 7, 22))
+        .to_zkinterface()
+        .expect("the arithmetic circuit should be written");
+    let circuit = ZkifCircuit::from_bytes(&bytes)
+        .expect("the zkInterface stream should be read");
+    assert_eq!(circuit.public_inputs(), [7, 22].map(BlsScalar::from));
+
+    let (prover, verifier) =
+        Compiler::compile_with_circuit(&pp, b"zkinterface", &circuit)
+            .expect("the circuit should compile");
+
+    let mut imported = Builder::initialized();
+    circuit
+        .circuit(&mut imported)
+        .expect("Synthesis of the circuit shouldn't fail");
+    imported
+        .check_satisfied()
+        .expect("The gates of the circuit should be satisfied");
+
+    let (proof, public_inputs) = prover
+        .prove(rng, &circuit)
+        .expect("the satisfied circuit should be proven");
+    assert_eq!(public_inputs, circuit.public_inputs());
+    verifier
+        .verify(&proof, &public_inputs)
+        .expect("the proof should verify");
+
+    // the stream of an unsatisfied circuit is read, but not proven
+    let bytes = builder(&TestCircuit::new(3, 5, 7, 23))
+        .to_zkinterface()
+        .expect("the arithmetic circuit should be written");
+    let circuit = ZkifCircuit::from_bytes(&bytes)
+        .expect("the zkInterface stream should be read");
+    prover
+        .prove(rng, &circuit)
+        .expect_err("the unsatisfied circuit shouldn't be proven");
+}
+
+#[test]
+fn unsupported_gates() {
+    let circuit = TestCircuit {
+        range: true,
+        ..TestCircuit::new(3, 5, 7, 22)
+    };
+
+    assert!(matches!(
+        builder(&circuit).to_zkinterface(),
+        Err(Error::UnsupportedGate { .. })
+    ));
+}
+
+#[test]
+fn invalid_streams() {
+    let bytes = builder(&TestCircuit::new(3, 5, 7, 22))
+        .to_zkinterface()
+        .expect("the arithmetic circuit should be written");
+
+    assert_eq!(
+        ZkifCircuit::from_bytes(&bytes[..bytes.len() - 1]),
+        Err(Error::InvalidZkInterface)
+    );
+
+    // the identifier follows the size prefix and the root offset
+    let mut identifier = bytes.clone();
+    identifier[8] = b'x';
+    assert_eq!(
+        ZkifCircuit::from_bytes(&identifier),
+        Err(Error::InvalidZkInterface)
+    );
+
+    // the maximum of the field is the first scalar of the stream
+    let maximum = (-BlsScalar::one()).to_bytes();
+    let pos = bytes
+        .windows(maximum.len())
+        .position(|window| window == maximum)
+        .expect("the stream should hold the maximum of the field");
+    let mut field = bytes;
+    field[pos + 31] ^= 0x01;
+    assert_eq!(
+        ZkifCircuit::from_bytes(&field),
+        Err(Error::UnsupportedZkInterfaceField)
+    );
+}